@@ -0,0 +1,111 @@
+// Copyright 2020-2024, Maxime Ripard
+// Licensed under the MIT License
+// See the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! Generates the CTA-861 Video Formats table from its CSV description.
+
+// Cargo expects the build script instructions on stdout.
+#![allow(clippy::print_stdout)]
+
+use core::fmt::Write as _;
+use std::{env, fs, path::Path};
+
+const CTA_VIDEO_FORMATS_CSV: &str = "data/cta_video_formats.csv";
+const CTA_VIDEO_FORMATS_COLUMNS: [&str; 8] = [
+    "vic",
+    "hactive",
+    "vactive",
+    "interlaced",
+    "pixel_clock_khz",
+    "htotal",
+    "vtotal",
+    "dmt_id",
+];
+
+fn cta_video_format(line: usize, record: &str, previous_vic: u32) -> (u32, String) {
+    let fields: Vec<&str> = record.split(',').map(str::trim).collect();
+    assert_eq!(
+        fields.len(),
+        CTA_VIDEO_FORMATS_COLUMNS.len(),
+        "{CTA_VIDEO_FORMATS_CSV}:{line}: Invalid number of columns"
+    );
+
+    let int = |idx: usize| -> u32 {
+        fields[idx].parse().unwrap_or_else(|_e| {
+            panic!(
+                "{CTA_VIDEO_FORMATS_CSV}:{line}: Invalid {}: {}",
+                CTA_VIDEO_FORMATS_COLUMNS[idx], fields[idx]
+            )
+        })
+    };
+
+    let vic = int(0);
+    assert!(
+        vic > previous_vic && u8::try_from(vic).is_ok(),
+        "{CTA_VIDEO_FORMATS_CSV}:{line}: VICs must be sorted, unique, and fit in a byte"
+    );
+
+    let interlaced = match fields[3] {
+        "true" => true,
+        "false" => false,
+        other => panic!("{CTA_VIDEO_FORMATS_CSV}:{line}: Invalid interlaced: {other}"),
+    };
+
+    let dmt_id = if fields[7].is_empty() {
+        String::from("None")
+    } else {
+        let id = fields[7]
+            .strip_prefix("0x")
+            .and_then(|id| u8::from_str_radix(id, 16).ok())
+            .unwrap_or_else(|| {
+                panic!(
+                    "{CTA_VIDEO_FORMATS_CSV}:{line}: Invalid dmt_id: {}",
+                    fields[7]
+                )
+            });
+
+        format!("Some({id:#04x})")
+    };
+
+    (
+        vic,
+        format!(
+            "EdidCtaVideoFormat {{ vic: {vic}, hactive: {}, vactive: {}, interlaced: {interlaced}, \
+             pixel_clock_khz: {}, htotal: {}, vtotal: {}, dmt_id: {dmt_id} }},",
+            int(1),
+            int(2),
+            int(4),
+            int(5),
+            int(6),
+        ),
+    )
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={CTA_VIDEO_FORMATS_CSV}");
+
+    let csv = fs::read_to_string(CTA_VIDEO_FORMATS_CSV)
+        .unwrap_or_else(|e| panic!("Couldn't read {CTA_VIDEO_FORMATS_CSV}: {e}"));
+
+    let mut lines = csv.lines().enumerate();
+    let (_, header) = lines.next().expect("CTA Video Formats table is empty");
+    assert_eq!(
+        header.split(',').map(str::trim).collect::<Vec<_>>(),
+        CTA_VIDEO_FORMATS_COLUMNS,
+        "{CTA_VIDEO_FORMATS_CSV}: Unexpected header"
+    );
+
+    let mut table = String::from("[\n");
+    let mut previous_vic = 0;
+    for (idx, record) in lines.filter(|(_, l)| !l.trim().is_empty()) {
+        let (vic, format) = cta_video_format(idx + 1, record, previous_vic);
+
+        writeln!(table, "    {format}").expect("Couldn't format the table");
+        previous_vic = vic;
+    }
+    table.push_str("]\n");
+
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR isn't set");
+    fs::write(Path::new(&out_dir).join("cta_video_formats.rs"), table)
+        .expect("Couldn't write the CTA Video Formats table");
+}
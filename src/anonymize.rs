@@ -0,0 +1,158 @@
+//! Removal of the data identifying a particular display unit from an existing EDID.
+
+use crate::{
+    edid_block_checksum, load::check_len, EdidDescriptorString, EdidTypeConversionError, IntoBytes,
+    EDID_BASE_LEN, EDID_BASIC_DISPLAY_PARAMETERS_LEN, EDID_CHROMATICITY_COORDINATES_LEN,
+    EDID_DESCRIPTORS_NUM, EDID_DESCRIPTOR_LEN, EDID_ESTABLISHED_TIMINGS_LEN, EDID_HEADER,
+    EDID_HEADER_LEN, EDID_IDENTIFICATION_LEN, EDID_MANUFACTURER_LEN, EDID_PRODUCT_CODE_LEN,
+    EDID_SERIAL_NUMBER_LEN, EDID_STANDARD_TIMINGS_LEN, EDID_VERSION_REVISION_LEN,
+};
+
+const EDID_SERIAL_NUMBER_OFFSET: usize =
+    EDID_HEADER_LEN + EDID_MANUFACTURER_LEN + EDID_PRODUCT_CODE_LEN;
+const EDID_WEEK_OFFSET: usize = EDID_SERIAL_NUMBER_OFFSET + EDID_SERIAL_NUMBER_LEN;
+const EDID_DESCRIPTORS_OFFSET: usize = EDID_HEADER_LEN
+    + EDID_IDENTIFICATION_LEN
+    + EDID_VERSION_REVISION_LEN
+    + EDID_BASIC_DISPLAY_PARAMETERS_LEN
+    + EDID_CHROMATICITY_COORDINATES_LEN
+    + EDID_ESTABLISHED_TIMINGS_LEN
+    + EDID_STANDARD_TIMINGS_LEN;
+
+const EDID_WEEK_UNSPECIFIED: u8 = 0;
+const EDID_WEEK_MODEL_YEAR: u8 = 0xff;
+
+const EDID_DESCRIPTOR_SERIAL_NUMBER_HEADER: [u8; 5] = [0, 0, 0, 0xff, 0];
+
+/// The string replacing the Product Serial Number descriptors.
+const EDID_ANONYMOUS_SERIAL_NUMBER: &str = "0";
+
+/// Removes the data identifying a particular unit from a serialized EDID.
+///
+/// The serial number is cleared, the Product Serial Number descriptors are replaced by a dummy
+/// serial number, and the week of manufacture is marked as unspecified. The year of manufacture,
+/// and the model year, are left untouched since they are shared by a whole production run and are
+/// often needed to tell apart the revisions of a product.
+///
+/// The base block checksum is updated accordingly, so the result can be shared in bug reports and
+/// loaded back as is.
+///
+/// ```
+/// use redid::{anonymize, edid_fixtures};
+///
+/// let fixture = &edid_fixtures()[0];
+/// let bytes = anonymize(fixture.bytes()).unwrap();
+///
+/// assert_eq!(bytes.len(), fixture.bytes().len());
+/// ```
+///
+/// # Errors
+///
+/// If the bytes don't add up to complete EDID blocks, or don't start with the EDID header.
+pub fn anonymize(bytes: &[u8]) -> Result<Vec<u8>, EdidTypeConversionError<String>> {
+    let mut bytes = check_len(bytes.to_vec())?;
+
+    if !bytes.starts_with(&EDID_HEADER) {
+        return Err(EdidTypeConversionError::Value(String::from(
+            "EDID doesn't start with the EDID header",
+        )));
+    }
+
+    bytes[EDID_SERIAL_NUMBER_OFFSET..EDID_WEEK_OFFSET].fill(0);
+
+    if bytes[EDID_WEEK_OFFSET] != EDID_WEEK_MODEL_YEAR {
+        bytes[EDID_WEEK_OFFSET] = EDID_WEEK_UNSPECIFIED;
+    }
+
+    let serial = EdidDescriptorString::try_from(EDID_ANONYMOUS_SERIAL_NUMBER)?.into_bytes();
+
+    for idx in 0..EDID_DESCRIPTORS_NUM {
+        let start = EDID_DESCRIPTORS_OFFSET + idx * EDID_DESCRIPTOR_LEN;
+        let descriptor = &mut bytes[start..start + EDID_DESCRIPTOR_LEN];
+
+        if descriptor.starts_with(&EDID_DESCRIPTOR_SERIAL_NUMBER_HEADER) {
+            descriptor[EDID_DESCRIPTOR_SERIAL_NUMBER_HEADER.len()..].copy_from_slice(&serial);
+        }
+    }
+
+    bytes[EDID_BASE_LEN - 1] = edid_block_checksum(&bytes[..EDID_BASE_LEN - 1]);
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_anonymize {
+    use super::anonymize;
+    use crate::{
+        edid_block_checksum, edid_fixtures, edid_preset_avr, CecAddress, EdidManufacturer,
+        EdidR4Date, EdidR4ManufactureDate, EdidR4ModelDate, EdidSerialNumber,
+        EdidSerialNumberFormat, IntoBytes, EDID_BASE_LEN,
+    };
+
+    #[test]
+    fn test_serial_number() {
+        let mut edid = edid_preset_avr(
+            EdidManufacturer::try_from("RDD").unwrap(),
+            0x1234.into(),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        );
+        edid.serial_number = Some(EdidSerialNumber::from(0x1234_abcd));
+        edid.serial_number_string = Some(EdidSerialNumberFormat::Hexadecimal);
+
+        let mut expected = edid.clone();
+        expected.serial_number = Some(EdidSerialNumber::from(0));
+        expected.date = EdidR4Date::Manufacture(EdidR4ManufactureDate::try_from(2024).unwrap());
+        let expected = expected.into_bytes();
+
+        let bytes = edid.into_bytes();
+        assert!(bytes.windows(8).any(|w| w == b"1234ABCD"));
+
+        let anonymized = anonymize(&bytes).unwrap();
+        assert!(!anonymized.windows(8).any(|w| w == b"1234ABCD"));
+
+        // Only the descriptor content and checksum should differ from an EDID without any
+        // identifying data.
+        assert_eq!(anonymized[..54], expected[..54]);
+        assert_eq!(anonymized[EDID_BASE_LEN..], bytes[EDID_BASE_LEN..]);
+        assert_eq!(
+            anonymized[EDID_BASE_LEN - 1],
+            edid_block_checksum(&anonymized[..EDID_BASE_LEN - 1])
+        );
+    }
+
+    #[test]
+    fn test_model_year() {
+        let mut edid = edid_preset_avr(
+            EdidManufacturer::try_from("RDD").unwrap(),
+            0x1234.into(),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        );
+        edid.date = EdidR4Date::Model(EdidR4ModelDate::try_from(2024).unwrap());
+
+        let bytes = edid.into_bytes();
+        assert_eq!(anonymize(&bytes).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_fixtures() {
+        for fixture in edid_fixtures() {
+            let anonymized = anonymize(fixture.bytes()).unwrap();
+
+            assert_eq!(anonymized.len(), fixture.bytes().len());
+            assert_eq!(anonymized[12..16], [0; 4]);
+            assert_eq!(
+                anonymized[EDID_BASE_LEN - 1],
+                edid_block_checksum(&anonymized[..EDID_BASE_LEN - 1])
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        let bytes = edid_fixtures()[0].bytes().to_vec();
+
+        assert!(anonymize(&bytes[..100]).is_err());
+        assert!(anonymize(&[0; EDID_BASE_LEN]).is_err());
+    }
+}
@@ -1,61 +1,90 @@
-use core::{cmp, fmt};
+use alloc::collections::BTreeMap;
+use core::{fmt, str::FromStr};
 
 use encoding::{all::ISO_8859_1, EncoderTrap, Encoding};
-use num_traits::{Bounded, CheckedShl, Num, ToPrimitive, WrappingSub};
 use typed_builder::TypedBuilder;
 
 use crate::{
+    field_map::FieldMap,
+    timings,
     utils::{div_round_up, round_up},
-    EdidTypeConversionError, IntoBytes, EDID_DESCRIPTORS_NUM, EDID_DESCRIPTOR_LEN,
-    EDID_DESCRIPTOR_PAYLOAD_LEN,
+    EdidChromaticityPoint, EdidDisplayTransferCharacteristics, EdidSerializationWarning,
+    EdidTypeConversionError, IntoBytes, EDID_DESCRIPTORS_NUM, EDID_DESCRIPTOR_HEADER_LEN,
+    EDID_DESCRIPTOR_LEN, EDID_DESCRIPTOR_PAYLOAD_LEN,
 };
 
-fn compute_max_value<T>(num_bits: usize) -> T
-where
-    T: Num + Bounded + CheckedShl + WrappingSub,
-{
-    let type_num_bits = core::mem::size_of::<T>() * 8;
+// The Display Descriptor Tags, stored in the fourth byte of the descriptors.
+const EDID_DESCRIPTOR_TAG_DUMMY: u8 = 0x10;
+const EDID_DESCRIPTOR_TAG_ESTABLISHED_TIMINGS: u8 = 0xf7;
+const EDID_DESCRIPTOR_TAG_CVT: u8 = 0xf8;
+const EDID_DESCRIPTOR_TAG_DISPLAY_COLOR_MANAGEMENT: u8 = 0xf9;
+const EDID_DESCRIPTOR_TAG_STANDARD_TIMINGS: u8 = 0xfa;
+const EDID_DESCRIPTOR_TAG_COLOR_POINT: u8 = 0xfb;
+const EDID_DESCRIPTOR_TAG_PRODUCT_NAME: u8 = 0xfc;
+const EDID_DESCRIPTOR_TAG_RANGE_LIMITS: u8 = 0xfd;
+const EDID_DESCRIPTOR_TAG_DATA_STRING: u8 = 0xfe;
+const EDID_DESCRIPTOR_TAG_PRODUCT_SERIAL_NUMBER: u8 = 0xff;
+
+const EDID_DESCRIPTOR_ESTABLISHED_TIMINGS_III_BITS_LEN: usize = 6;
+const EDID_DESCRIPTOR_ESTABLISHED_TIMINGS_III_RESERVED_LEN: usize = 6;
+
+// The validation is done on u32 so that it doesn't get monomorphized for each
+// EdidDescriptorTiming variant.
+fn compute_max_value(num_bits: usize) -> u32 {
+    let rhs = u32::try_from(num_bits).expect("Number of bits would overflow our type");
+
+    if let Some(shl) = 1u32.checked_shl(rhs) {
+        shl - 1
+    } else {
+        assert!(
+            rhs == u32::BITS,
+            "Number of bits is greater than can be stored in the type"
+        );
 
-    assert!(
-        num_bits <= type_num_bits,
-        "Number of bits is greater than can be stored in the type"
-    );
+        u32::MAX
+    }
+}
 
-    match num_bits.cmp(&type_num_bits) {
-        cmp::Ordering::Less => {
-            // Thanks to the assert above, we know that num_bits is going to be at most 128, which
-            // fits into a u32 with plenty of headroom.
-            #[allow(clippy::unwrap_used)]
-            let rhs = num_bits.to_u32().unwrap();
-
-            // checked_shl returns None if rhs is equal to or larger than the number of bits in T.
-            // However, we're in that branch only if the number of bits we want to shift of is
-            // strictly lower than the number of bits in our type, so we'll always get a value.
-            #[allow(clippy::unwrap_used)]
-            let shl = T::checked_shl(&T::one(), rhs).unwrap();
-            T::wrapping_sub(&shl, &T::one())
-        }
-        cmp::Ordering::Equal => T::max_value(),
-        cmp::Ordering::Greater => unreachable!(),
+fn check_max_value(value: u32, num_bits: usize) -> Result<(), u32> {
+    let max = compute_max_value(num_bits);
+
+    if value > max {
+        return Err(max);
     }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod test_max_size_bits {
-    use super::compute_max_value;
+    use super::{check_max_value, compute_max_value};
 
     #[test]
     fn test() {
-        assert_eq!(compute_max_value::<u8>(4), 0xf);
-        assert_eq!(compute_max_value::<u32>(4), 0xf);
-        assert_eq!(compute_max_value::<u8>(8), 0xff);
-        assert_eq!(compute_max_value::<u32>(32), 0xffff_ffff);
+        assert_eq!(compute_max_value(4), 0xf);
+        assert_eq!(compute_max_value(8), 0xff);
+        assert_eq!(compute_max_value(12), 0xfff);
+        assert_eq!(compute_max_value(32), 0xffff_ffff);
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check_max_value(0x3f, 6), Ok(()));
+        assert_eq!(check_max_value(0x40, 6), Err(0x3f));
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
 pub struct EdidDescriptorCustomTag(u8);
 
+impl EdidDescriptorCustomTag {
+    pub(crate) const fn into_raw(self) -> u8 {
+        self.0
+    }
+}
+
 impl TryFrom<u8> for EdidDescriptorCustomTag {
     type Error = EdidTypeConversionError<u8>;
 
@@ -68,7 +97,7 @@ impl TryFrom<u8> for EdidDescriptorCustomTag {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct EdidDescriptorCustomPayload(Vec<u8>);
 
 impl TryFrom<Vec<u8>> for EdidDescriptorCustomPayload {
@@ -85,20 +114,28 @@ impl TryFrom<Vec<u8>> for EdidDescriptorCustomPayload {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "(u8, Vec<u8>)"))]
 pub struct EdidDescriptorCustom {
     tag: EdidDescriptorCustomTag,
     payload: EdidDescriptorCustomPayload,
+    padding: u8,
 }
 
 impl IntoBytes for EdidDescriptorCustom {
     fn into_bytes(self) -> Vec<u8> {
         let mut bytes: Vec<u8> = Vec::with_capacity(EDID_DESCRIPTOR_LEN);
 
-        let tag = self.tag.0;
-        bytes.extend_from_slice(&[0, 0, 0, tag, 0]);
-        bytes.extend_from_slice(&self.payload.0);
-        bytes.resize(EDID_DESCRIPTOR_LEN, 0);
+        // The header length is checked at compile time by the array type.
+        let header: [u8; EDID_DESCRIPTOR_HEADER_LEN] = [0, 0, 0, self.tag.0, 0];
+        bytes.extend_from_slice(&header);
+
+        // The payload is at most 13 bytes long, so it can't underflow.
+        let payload = &self.payload.0;
+        let padding_len = EDID_DESCRIPTOR_PAYLOAD_LEN - payload.len();
+        bytes.extend_from_slice(payload);
+        bytes.extend(core::iter::repeat(self.padding).take(padding_len));
 
         let len = bytes.len();
         assert_eq!(
@@ -121,24 +158,387 @@ impl TryFrom<(u8, Vec<u8>)> for EdidDescriptorCustom {
         let tag = value.0.try_into()?;
         let payload = value.1.try_into()?;
 
-        Ok(Self { tag, payload })
+        Ok(Self {
+            tag,
+            payload,
+            padding: 0,
+        })
+    }
+}
+
+/// A Manufacturer Specified Descriptor with a known layout.
+///
+/// Implementing this trait allows to describe a vendor descriptor with a dedicated type, and to
+/// convert it to an [`EdidDescriptorCustom`] through [`EdidDescriptorCustom::from_typed`].
+/// [`EdidDescriptorCustom`] implements it too, and serves as the raw fallback.
+pub trait EdidDescriptorCustomType: fmt::Debug {
+    /// The tag identifying the descriptor.
+    fn tag(&self) -> EdidDescriptorCustomTag;
+
+    /// The payload of the descriptor.
+    fn payload(&self) -> EdidDescriptorCustomPayload;
+
+    /// The byte filling the descriptor after the payload. Defaults to 0.
+    fn padding(&self) -> u8 {
+        0
+    }
+}
+
+impl EdidDescriptorCustomType for EdidDescriptorCustom {
+    fn tag(&self) -> EdidDescriptorCustomTag {
+        self.tag
+    }
+
+    fn payload(&self) -> EdidDescriptorCustomPayload {
+        self.payload.clone()
+    }
+
+    fn padding(&self) -> u8 {
+        self.padding
+    }
+}
+
+impl EdidDescriptorCustom {
+    /// Creates a Manufacturer Specified Descriptor from its typed representation.
+    #[must_use]
+    pub fn from_typed<T: EdidDescriptorCustomType + ?Sized>(desc: &T) -> Self {
+        Self {
+            tag: desc.tag(),
+            payload: desc.payload(),
+            padding: desc.padding(),
+        }
+    }
+
+    /// Sets the byte filling the descriptor after the payload, instead of 0.
+    #[must_use]
+    pub fn with_padding(mut self, padding: u8) -> Self {
+        self.padding = padding;
+        self
+    }
+}
+
+/// Decodes the payload of a Manufacturer Specified Descriptor into its typed representation.
+///
+/// Returns `None` if the payload isn't valid for that type.
+pub type EdidDescriptorCustomDecoder = fn(&[u8]) -> Option<Box<dyn EdidDescriptorCustomType>>;
+
+/// Maps Manufacturer Specified Descriptor tags to the types describing them.
+///
+/// The tags are only meaningful for a given manufacturer, so each registry is expected to cover a
+/// single vendor.
+#[derive(Clone, Debug, Default)]
+pub struct EdidDescriptorCustomRegistry {
+    decoders: BTreeMap<u8, EdidDescriptorCustomDecoder>,
+}
+
+impl EdidDescriptorCustomRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the decoder for the given tag, replacing any previous one.
+    #[must_use]
+    pub fn register(
+        mut self,
+        tag: EdidDescriptorCustomTag,
+        decoder: EdidDescriptorCustomDecoder,
+    ) -> Self {
+        self.decoders.insert(tag.0, decoder);
+        self
+    }
+
+    /// Decodes a Manufacturer Specified Descriptor using the registered decoders.
+    ///
+    /// If its tag isn't registered, or if the decoder rejects the payload, the raw descriptor is
+    /// returned.
+    #[must_use]
+    pub fn decode(&self, desc: &EdidDescriptorCustom) -> Box<dyn EdidDescriptorCustomType> {
+        self.decoders
+            .get(&desc.tag.0)
+            .and_then(|decoder| decoder(&desc.payload.0))
+            .unwrap_or_else(|| Box::new(desc.clone()))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_descriptor_custom_registry {
+    use super::{
+        EdidDescriptorCustom, EdidDescriptorCustomPayload, EdidDescriptorCustomRegistry,
+        EdidDescriptorCustomTag, EdidDescriptorCustomType,
+    };
+    use crate::IntoBytes;
+
+    #[derive(Debug)]
+    struct Brightness(u16);
+
+    impl EdidDescriptorCustomType for Brightness {
+        fn tag(&self) -> EdidDescriptorCustomTag {
+            EdidDescriptorCustomTag::try_from(0x05).unwrap()
+        }
+
+        fn payload(&self) -> EdidDescriptorCustomPayload {
+            EdidDescriptorCustomPayload::try_from(self.0.to_le_bytes().to_vec()).unwrap()
+        }
+    }
+
+    fn decode_brightness(payload: &[u8]) -> Option<Box<dyn EdidDescriptorCustomType>> {
+        let bytes = payload.get(0..2)?.try_into().ok()?;
+
+        Some(Box::new(Brightness(u16::from_le_bytes(bytes))))
+    }
+
+    #[test]
+    fn test_binary() {
+        let desc = EdidDescriptorCustom::from_typed(&Brightness(400));
+
+        assert_eq!(
+            desc.into_bytes(),
+            &[0, 0, 0, 0x05, 0, 0x90, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_padding() {
+        for len in [0, 1, 12, 13] {
+            let payload = vec![0xaa; len];
+            let bytes = EdidDescriptorCustom::try_from((0x05, payload))
+                .unwrap()
+                .with_padding(0xff)
+                .into_bytes();
+
+            assert_eq!(bytes.len(), 18);
+            assert_eq!(&bytes[..5], &[0, 0, 0, 0x05, 0]);
+            assert!(bytes[5..5 + len].iter().all(|b| *b == 0xaa));
+            assert!(bytes[5 + len..].iter().all(|b| *b == 0xff));
+        }
+
+        assert!(EdidDescriptorCustom::try_from((0x05, vec![0xaa; 14])).is_err());
+        assert!(EdidDescriptorCustomPayload::try_from(vec![0xaa; 14]).is_err());
+    }
+
+    #[test]
+    fn test_registry() {
+        let registry = EdidDescriptorCustomRegistry::new().register(
+            EdidDescriptorCustomTag::try_from(0x05).unwrap(),
+            decode_brightness,
+        );
+
+        let known = EdidDescriptorCustom::from_typed(&Brightness(400));
+        let decoded = registry.decode(&known);
+        assert_eq!(EdidDescriptorCustom::from_typed(decoded.as_ref()), known);
+        assert_eq!(format!("{decoded:?}"), "Brightness(400)");
+
+        let unknown = EdidDescriptorCustom::try_from((0x06, vec![0x01, 0x02]))
+            .unwrap()
+            .with_padding(0xff);
+        assert_eq!(
+            EdidDescriptorCustom::from_typed(registry.decode(&unknown).as_ref()),
+            unknown
+        );
     }
 }
 
-#[derive(Clone, Debug)]
-pub struct EdidDescriptorString(String);
+/// The byte marking the end of a Descriptor String shorter than the payload.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EdidDescriptorStringTermination {
+    /// A Line Feed (0x0a), as required by the specification.
+    #[default]
+    LineFeed,
+
+    /// No termination, the padding directly follows the string.
+    None,
+}
+
+/// The byte used to fill the Descriptor String payload after its termination.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EdidDescriptorStringPadding {
+    /// Spaces (0x20), as required by the specification.
+    #[default]
+    Space,
+
+    /// NUL bytes (0x00).
+    Null,
+}
+
+/// How to handle non-ASCII characters in a Descriptor String.
+///
+/// The specification only allows ASCII, but some monitors use accented characters in their name.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EdidDescriptorStringEncodingPolicy {
+    /// Reject any non-ASCII character.
+    #[default]
+    Strict,
+
+    /// Encode the characters in ISO-8859-1, and reject the ones that can't be.
+    MapToLatin1,
+
+    /// Replace any non-ASCII character by a question mark.
+    ReplaceWithQuestionMark,
+}
+
+/// How to handle strings longer than a Descriptor String can hold.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EdidDescriptorStringLengthPolicy {
+    /// Reject the string.
+    #[default]
+    Reject,
+
+    /// Only keep the first [`EdidDescriptorString::MAX_LEN`] characters.
+    Truncate,
+}
+
+/// How a Descriptor String is laid out in its payload.
+///
+/// The defaults follow the specification, the other options are only useful to reproduce existing
+/// EDIDs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, TypedBuilder)]
+pub struct EdidDescriptorStringOptions {
+    #[builder(default)]
+    termination: EdidDescriptorStringTermination,
+
+    #[builder(default)]
+    padding: EdidDescriptorStringPadding,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String"))]
+pub struct EdidDescriptorString {
+    string: String,
+    options: EdidDescriptorStringOptions,
+}
 
 impl EdidDescriptorString {
-    /// Some EDIDs in the test suite use non-ASCII characters, going against the spec. We want to
-    /// prevent that from happening for new EDIDs, but we still need to allow to build our string
-    /// for our tests.
+    pub(crate) fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    /// Returns whether the string is shorter than the payload, and thus needs to be terminated
+    /// and padded.
+    pub(crate) fn is_padded(&self) -> bool {
+        // Every character is encoded on a single byte.
+        self.string.chars().count() < EDID_DESCRIPTOR_PAYLOAD_LEN
+    }
+
+    /// Changes how the string will be terminated and padded.
     #[must_use]
-    #[doc(hidden)]
-    pub fn from_str_encoding_unchecked(value: &str) -> Self {
-        let len = value.chars().count();
-        assert!(len <= EDID_DESCRIPTOR_PAYLOAD_LEN, "String is too long");
+    pub fn with_options(self, options: EdidDescriptorStringOptions) -> Self {
+        Self { options, ..self }
+    }
+
+    /// The maximum number of characters of a Descriptor String.
+    pub const MAX_LEN: usize = EDID_DESCRIPTOR_PAYLOAD_LEN;
+
+    /// Creates a Descriptor String, handling the characters outside of ASCII according to
+    /// `policy`.
+    ///
+    /// # Errors
+    ///
+    /// If the string is too long, or if it contains characters that `policy` rejects.
+    pub fn from_str_with_policy(
+        value: &str,
+        policy: EdidDescriptorStringEncodingPolicy,
+    ) -> Result<Self, EdidTypeConversionError<String>> {
+        Self::from_str_with_policies(value, policy, EdidDescriptorStringLengthPolicy::Reject)
+    }
+
+    /// Creates a Descriptor String, handling the characters outside of ASCII according to
+    /// `encoding`, and the strings longer than [`EdidDescriptorString::MAX_LEN`] according to
+    /// `length`.
+    ///
+    /// # Errors
+    ///
+    /// If the string contains characters that `encoding` rejects, or if it's too long and
+    /// `length` rejects it.
+    pub fn from_str_with_policies(
+        value: &str,
+        encoding: EdidDescriptorStringEncodingPolicy,
+        length: EdidDescriptorStringLengthPolicy,
+    ) -> Result<Self, EdidTypeConversionError<String>> {
+        let string = match encoding {
+            EdidDescriptorStringEncodingPolicy::Strict => {
+                if !value.is_ascii() {
+                    return Err(EdidTypeConversionError::Value(String::from(
+                        "String must be ASCII.",
+                    )));
+                }
+
+                String::from(value)
+            }
+            EdidDescriptorStringEncodingPolicy::MapToLatin1 => {
+                if ISO_8859_1.encode(value, EncoderTrap::Strict).is_err() {
+                    return Err(EdidTypeConversionError::Value(String::from(
+                        "String must be ISO-8859-1.",
+                    )));
+                }
+
+                String::from(value)
+            }
+            EdidDescriptorStringEncodingPolicy::ReplaceWithQuestionMark => value
+                .chars()
+                .map(|c| if c.is_ascii() { c } else { '?' })
+                .collect(),
+        };
+
+        // Every character we allowed is encoded on a single byte.
+        let string = match length {
+            EdidDescriptorStringLengthPolicy::Reject => {
+                if string.chars().count() > Self::MAX_LEN {
+                    return Err(EdidTypeConversionError::Value(String::from(
+                        "String is too long.",
+                    )));
+                }
+
+                string
+            }
+            EdidDescriptorStringLengthPolicy::Truncate => {
+                string.chars().take(Self::MAX_LEN).collect()
+            }
+        };
+
+        Ok(Self {
+            string,
+            options: EdidDescriptorStringOptions::default(),
+        })
+    }
+
+    /// Splits an ASCII string into as many Descriptor Strings as needed to hold it.
+    ///
+    /// ```
+    /// use redid::EdidDescriptorString;
+    ///
+    /// let strings = EdidDescriptorString::split("redid Reference Monitor").unwrap();
+    /// assert_eq!(strings.len(), 2);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the string isn't ASCII.
+    pub fn split(value: &str) -> Result<Vec<Self>, EdidTypeConversionError<String>> {
+        if !value.is_ascii() {
+            return Err(EdidTypeConversionError::Value(String::from(
+                "String must be ASCII.",
+            )));
+        }
 
-        Self(String::from(value))
+        if value.is_empty() {
+            return Ok(vec![Self::try_from(value)?]);
+        }
+
+        value
+            .as_bytes()
+            .chunks(Self::MAX_LEN)
+            .map(|chunk| {
+                let chunk = core::str::from_utf8(chunk).map_err(|_e| {
+                    EdidTypeConversionError::Value(String::from("String must be ASCII."))
+                })?;
+
+                Self::try_from(chunk)
+            })
+            .collect()
     }
 }
 
@@ -161,7 +561,10 @@ impl TryFrom<String> for EdidDescriptorString {
             )));
         }
 
-        Ok(Self(value))
+        Ok(Self {
+            string: value,
+            options: EdidDescriptorStringOptions::default(),
+        })
     }
 }
 
@@ -181,15 +584,20 @@ impl IntoBytes for EdidDescriptorString {
         // deviate from that so we still need to output an ASCII-ish bytes array, but without the
         // Unicode leading bytes. ISO-8859-1 seems like a good enough guess at the moment.
         let iso_bytes = ISO_8859_1
-            .encode(&self.0, EncoderTrap::Strict)
+            .encode(&self.string, EncoderTrap::Strict)
             .expect("String Encoding failed.");
         bytes.extend_from_slice(&iso_bytes);
 
-        if bytes.len() < EDID_DESCRIPTOR_PAYLOAD_LEN {
+        if self.is_padded() && self.options.termination == EdidDescriptorStringTermination::LineFeed
+        {
             bytes.push(0x0a);
         }
 
-        bytes.resize(EDID_DESCRIPTOR_PAYLOAD_LEN, 0x20);
+        let padding = match self.options.padding {
+            EdidDescriptorStringPadding::Space => 0x20,
+            EdidDescriptorStringPadding::Null => 0x00,
+        };
+        bytes.resize(EDID_DESCRIPTOR_PAYLOAD_LEN, padding);
 
         assert!(
             bytes.len() == EDID_DESCRIPTOR_PAYLOAD_LEN,
@@ -205,10 +613,185 @@ impl IntoBytes for EdidDescriptorString {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_descriptor_string {
+    use super::{
+        EdidDescriptorString, EdidDescriptorStringEncodingPolicy, EdidDescriptorStringLengthPolicy,
+        EdidDescriptorStringOptions, EdidDescriptorStringPadding, EdidDescriptorStringTermination,
+        EdidR4Descriptor,
+    };
+    use crate::IntoBytes;
+
+    #[test]
+    fn test_binary_spec() {
+        // Taken from the EDID 1.4 Specification, Section 6.1
+        let string = EdidDescriptorString::try_from("ABC LCD21").unwrap();
+        assert_eq!(
+            string.into_bytes(),
+            &[0x41, 0x42, 0x43, 0x20, 0x4c, 0x43, 0x44, 0x32, 0x31, 0x0a, 0x20, 0x20, 0x20]
+        );
+    }
+
+    #[test]
+    fn test_binary_full() {
+        let string = EdidDescriptorString::try_from("ABCDEFGHIJKLM").unwrap();
+        assert_eq!(string.into_bytes(), b"ABCDEFGHIJKLM");
+    }
+
+    #[test]
+    fn test_binary_options() {
+        let string = EdidDescriptorString::try_from("ABC").unwrap();
+
+        assert_eq!(
+            string
+                .clone()
+                .with_options(
+                    EdidDescriptorStringOptions::builder()
+                        .padding(EdidDescriptorStringPadding::Null)
+                        .build()
+                )
+                .into_bytes(),
+            &[0x41, 0x42, 0x43, 0x0a, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+
+        assert_eq!(
+            string
+                .with_options(
+                    EdidDescriptorStringOptions::builder()
+                        .termination(EdidDescriptorStringTermination::None)
+                        .padding(EdidDescriptorStringPadding::Null)
+                        .build()
+                )
+                .into_bytes(),
+            &[0x41, 0x42, 0x43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_encoding_policy() {
+        assert!(EdidDescriptorString::from_str_with_policy(
+            "\u{c9}cran",
+            EdidDescriptorStringEncodingPolicy::Strict
+        )
+        .is_err());
+
+        assert_eq!(
+            EdidDescriptorString::from_str_with_policy(
+                "\u{c9}cran",
+                EdidDescriptorStringEncodingPolicy::MapToLatin1
+            )
+            .unwrap()
+            .into_bytes(),
+            &[0xc9, 0x63, 0x72, 0x61, 0x6e, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20]
+        );
+        assert!(EdidDescriptorString::from_str_with_policy(
+            "\u{c9}cran \u{20ac}",
+            EdidDescriptorStringEncodingPolicy::MapToLatin1
+        )
+        .is_err());
+
+        assert_eq!(
+            EdidDescriptorString::from_str_with_policy(
+                "\u{c9}cran \u{20ac}",
+                EdidDescriptorStringEncodingPolicy::ReplaceWithQuestionMark
+            )
+            .unwrap()
+            .into_bytes(),
+            b"?cran ?\n     "
+        );
+    }
+
+    #[test]
+    fn test_length_policy() {
+        assert!(EdidDescriptorString::from_str_with_policies(
+            "ABCDEFGHIJKLMN",
+            EdidDescriptorStringEncodingPolicy::Strict,
+            EdidDescriptorStringLengthPolicy::Reject
+        )
+        .is_err());
+
+        assert_eq!(
+            EdidDescriptorString::from_str_with_policies(
+                "ABCDEFGHIJKLMN",
+                EdidDescriptorStringEncodingPolicy::Strict,
+                EdidDescriptorStringLengthPolicy::Truncate
+            )
+            .unwrap()
+            .into_bytes(),
+            b"ABCDEFGHIJKLM"
+        );
+
+        assert_eq!(
+            EdidDescriptorString::from_str_with_policies(
+                "\u{c9}cran de d\u{e9}monstration",
+                EdidDescriptorStringEncodingPolicy::MapToLatin1,
+                EdidDescriptorStringLengthPolicy::Truncate
+            )
+            .unwrap()
+            .into_bytes(),
+            b"\xc9cran de d\xe9mo"
+        );
+    }
+
+    #[test]
+    fn test_split() {
+        let strings = EdidDescriptorString::split("redid Reference Monitor").unwrap();
+        assert_eq!(
+            strings,
+            vec![
+                EdidDescriptorString::try_from("redid Referen").unwrap(),
+                EdidDescriptorString::try_from("ce Monitor").unwrap(),
+            ]
+        );
+
+        assert_eq!(
+            EdidDescriptorString::split("ABCDEFGHIJKLM").unwrap(),
+            vec![EdidDescriptorString::try_from("ABCDEFGHIJKLM").unwrap()]
+        );
+        assert_eq!(
+            EdidDescriptorString::split("").unwrap(),
+            vec![EdidDescriptorString::try_from("").unwrap()]
+        );
+        assert!(EdidDescriptorString::split("\u{c9}cran").is_err());
+
+        assert_eq!(
+            EdidR4Descriptor::product_name("redid Reference Monitor").unwrap(),
+            vec![
+                EdidR4Descriptor::ProductName(strings[0].clone()),
+                EdidR4Descriptor::DataString(strings[1].clone()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        assert!(EdidDescriptorString::try_from("ABCDEFGHIJKLM").is_ok());
+        assert!(EdidDescriptorString::try_from("ABCDEFGHIJKLMN").is_err());
+        assert!(EdidDescriptorString::from_str_with_policy(
+            "\u{c9}BCDEFGHIJKLM",
+            EdidDescriptorStringEncodingPolicy::MapToLatin1
+        )
+        .is_ok());
+        assert!(EdidDescriptorString::from_str_with_policy(
+            "\u{c9}BCDEFGHIJKLMN",
+            EdidDescriptorStringEncodingPolicy::ReplaceWithQuestionMark
+        )
+        .is_err());
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u32"))]
 pub struct EdidDetailedTimingPixelClock(u32);
 
 impl EdidDetailedTimingPixelClock {
+    /// Returns the pixel clock, in kHz, if it's not a multiple of 10kHz and will be truncated.
+    pub(crate) fn truncation(self) -> Option<u32> {
+        (self.0 % 10 != 0).then_some(self.0)
+    }
+
     fn into_raw(self) -> u16 {
         u16::try_from(self.0 / 10).expect("Detailed Timing Pixel clock would overflow our type")
     }
@@ -218,7 +801,11 @@ impl TryFrom<u32> for EdidDetailedTimingPixelClock {
     type Error = EdidTypeConversionError<u32>;
 
     fn try_from(value: u32) -> Result<Self, Self::Error> {
-        if !(10..=655_350).contains(&value) {
+        if value > 655_350 {
+            return Err(EdidTypeConversionError::PixelClockOverflow(value));
+        }
+
+        if value < 10 {
             return Err(EdidTypeConversionError::Range(
                 value,
                 Some(10),
@@ -231,8 +818,10 @@ impl TryFrom<u32> for EdidDetailedTimingPixelClock {
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
 mod test_descriptor_detailed_timing_pixel_clock {
     use super::EdidDetailedTimingPixelClock;
+    use crate::EdidTypeConversionError;
 
     #[test]
     fn test_binary_spec() {
@@ -247,52 +836,120 @@ mod test_descriptor_detailed_timing_pixel_clock {
         assert!(EdidDetailedTimingPixelClock::try_from(1).is_err());
         assert!(EdidDetailedTimingPixelClock::try_from(10).is_ok());
         assert!(EdidDetailedTimingPixelClock::try_from(655_350).is_ok());
-        assert!(EdidDetailedTimingPixelClock::try_from(655_351).is_err());
-        assert!(EdidDetailedTimingPixelClock::try_from(u32::MAX).is_err());
+        assert!(matches!(
+            EdidDetailedTimingPixelClock::try_from(655_351),
+            Err(EdidTypeConversionError::PixelClockOverflow(655_351))
+        ));
+        assert!(matches!(
+            EdidDetailedTimingPixelClock::try_from(u32::MAX),
+            Err(EdidTypeConversionError::PixelClockOverflow(u32::MAX))
+        ));
+    }
+
+    #[test]
+    fn test_truncation() {
+        assert_eq!(
+            EdidDetailedTimingPixelClock::try_from(148_500)
+                .unwrap()
+                .truncation(),
+            None
+        );
+        assert_eq!(
+            EdidDetailedTimingPixelClock::try_from(148_352)
+                .unwrap()
+                .truncation(),
+            Some(148_352)
+        );
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidDetailedTimingAnalogSync {
     BipolarComposite(bool, bool),
     Composite(bool, bool),
 }
 
-#[derive(Clone, Copy, Debug, TypedBuilder)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct EdidDetailedTimingDigitalCompositeSync {
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     serrations: bool,
 }
 
-#[derive(Clone, Copy, Debug, TypedBuilder)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct EdidDetailedTimingDigitalSeparateSync {
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     vsync_positive: bool,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidDetailedTimingDigitalSyncKind {
     Composite(EdidDetailedTimingDigitalCompositeSync),
     Separate(EdidDetailedTimingDigitalSeparateSync),
 }
 
-#[derive(Clone, Copy, Debug, TypedBuilder)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct EdidDetailedTimingDigitalSync {
     kind: EdidDetailedTimingDigitalSyncKind,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     hsync_positive: bool,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidDetailedTimingSync {
     Analog(EdidDetailedTimingAnalogSync),
     Digital(EdidDetailedTimingDigitalSync),
 }
 
-#[derive(Clone, Copy, Debug)]
+impl EdidDetailedTimingSync {
+    /// Creates a digital separate sync, with the given horizontal and vertical sync polarities.
+    #[must_use]
+    pub const fn digital_separate(hsync_positive: bool, vsync_positive: bool) -> Self {
+        Self::Digital(EdidDetailedTimingDigitalSync {
+            kind: EdidDetailedTimingDigitalSyncKind::Separate(
+                EdidDetailedTimingDigitalSeparateSync { vsync_positive },
+            ),
+            hsync_positive,
+        })
+    }
+
+    /// Creates a digital composite sync, on the horizontal sync line, with the given polarity
+    /// outside of the vertical sync.
+    #[must_use]
+    pub const fn digital_composite(serrations: bool, hsync_positive: bool) -> Self {
+        Self::Digital(EdidDetailedTimingDigitalSync {
+            kind: EdidDetailedTimingDigitalSyncKind::Composite(
+                EdidDetailedTimingDigitalCompositeSync { serrations },
+            ),
+            hsync_positive,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidDetailedTimingStereo {
+    #[default]
     None,
+
+    /// No stereo support, with the lower Stereo Viewing Support bit (Bit 0 of Byte 17) set.
+    ///
+    /// The bit is ignored when stereo isn't supported, and most EDIDs clear it as
+    /// [`EdidDetailedTimingStereo::None`] does, but some set it.
+    NoneWithLowBitSet,
+
     FieldSequentialRightOnSync,
     FieldSequentialLeftOnSync,
     TwoWayInterleavedRightOnEven,
@@ -301,19 +958,34 @@ pub enum EdidDetailedTimingStereo {
     SideBySideInterleaved,
 }
 
-#[derive(Clone, Copy, Debug)]
+/// How the borders of a Detailed Timing relate to its blanking and front porches.
+///
+/// The Detailed Timing Descriptor stores the border sizes next to the blanking and front porches,
+/// but EDID releases disagree on whether the latter include the former.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EdidDetailedTimingBorderPolicy {
+    /// The borders are outside of the blanking, and the front porch starts at the end of the
+    /// right or bottom border. The total is the addressable size, twice the border, and the
+    /// blanking. This is what EDID 1.4 mandates, and how `edid-decode` interprets timings.
+    #[default]
+    OutsideBlanking,
+
+    /// The borders are part of the blanking and of the front porch, which starts at the end of
+    /// the addressable area. The total is the addressable size and the blanking. This is how
+    /// EDID 1.3 timings are usually interpreted.
+    InsideBlanking,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct EdidDescriptorTiming<const N: usize, T: fmt::Display>(T);
 
 impl<const N: usize, T> EdidDescriptorTiming<N, T>
 where
-    T: Num + Bounded + CheckedShl + WrappingSub + PartialOrd + fmt::Display,
+    T: Copy + Into<u32> + TryFrom<u32> + fmt::Display,
 {
     fn try_from(value: T) -> Result<Self, EdidTypeConversionError<T>> {
-        let max = compute_max_value::<T>(N);
-
-        if value > max {
-            return Err(EdidTypeConversionError::Range(value, None, Some(max)));
-        }
+        check_max_value(value.into(), N)
+            .map_err(|max| EdidTypeConversionError::Range(value, None, T::try_from(max).ok()))?;
 
         Ok(Self(value))
     }
@@ -323,6 +995,19 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de, const N: usize, T> serde::Deserialize<'de> for EdidDescriptorTiming<N, T>
+where
+    T: Copy + Into<u32> + TryFrom<u32> + fmt::Display + serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Self::try_from(T::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
 pub type EdidDescriptor6BitsTiming = EdidDescriptorTiming<6, u8>;
 
 impl TryFrom<u8> for EdidDescriptor6BitsTiming {
@@ -334,6 +1019,7 @@ impl TryFrom<u8> for EdidDescriptor6BitsTiming {
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_result_states)]
 mod test_edid_detailed_timings_6bits_fields {
     use crate::EdidDescriptor6BitsTiming;
 
@@ -357,6 +1043,7 @@ impl TryFrom<u8> for EdidDescriptor8BitsTiming {
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_result_states)]
 mod test_edid_detailed_timings_8bits_fields {
     use crate::EdidDescriptor8BitsTiming;
 
@@ -378,6 +1065,7 @@ impl TryFrom<u16> for EdidDescriptor10BitsTiming {
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_result_states)]
 mod test_edid_detailed_timings_10bits_fields {
     use crate::EdidDescriptor10BitsTiming;
 
@@ -400,7 +1088,42 @@ impl TryFrom<u16> for EdidDescriptor12BitsTiming {
     }
 }
 
+impl EdidDescriptor12BitsTiming {
+    /// Converts a number of lines of an interlaced frame into the number of lines of a field, as
+    /// expected by the vertical fields of a Detailed Timing Descriptor.
+    ///
+    /// Odd numbers are rounded down, the extra half line being implied by the interlace flag.
+    ///
+    /// # Errors
+    ///
+    /// If the number of lines of a field doesn't fit in 12 bits.
+    pub fn from_interlaced_frame(lines: u16) -> Result<Self, EdidTypeConversionError<u16>> {
+        Self::try_from(lines / 2)
+            .map_err(|_e| EdidTypeConversionError::Range(lines, None, Some(8191)))
+    }
+
+    /// Computes the blanking expected by a Detailed Timing Descriptor from a porch-based
+    /// description of the timing, as found in modelines or display datasheets.
+    ///
+    /// # Errors
+    ///
+    /// If the resulting blanking doesn't fit in 12 bits.
+    pub fn from_porches(
+        front_porch: u16,
+        sync_pulse: u16,
+        back_porch: u16,
+    ) -> Result<Self, EdidTypeConversionError<u16>> {
+        let blanking = front_porch
+            .checked_add(sync_pulse)
+            .and_then(|v| v.checked_add(back_porch))
+            .ok_or(EdidTypeConversionError::Range(u16::MAX, None, Some(4095)))?;
+
+        Self::try_from(blanking)
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
 mod test_edid_detailed_timings_12bits_fields {
     use crate::EdidDescriptor12BitsTiming;
 
@@ -411,11 +1134,38 @@ mod test_edid_detailed_timings_12bits_fields {
         assert!(EdidDescriptor12BitsTiming::try_from(4096).is_err());
         assert!(EdidDescriptor12BitsTiming::try_from(u16::MAX).is_err());
     }
+
+    #[test]
+    fn test_interlaced_frame() {
+        assert_eq!(
+            EdidDescriptor12BitsTiming::from_interlaced_frame(1080).unwrap(),
+            EdidDescriptor12BitsTiming::try_from(540).unwrap()
+        );
+        assert_eq!(
+            EdidDescriptor12BitsTiming::from_interlaced_frame(45).unwrap(),
+            EdidDescriptor12BitsTiming::try_from(22).unwrap()
+        );
+        assert!(EdidDescriptor12BitsTiming::from_interlaced_frame(8191).is_ok());
+        assert!(EdidDescriptor12BitsTiming::from_interlaced_frame(8192).is_err());
+    }
+
+    #[test]
+    fn test_porches() {
+        // 1920x1080@60Hz, CTA-861 VIC 16
+        assert_eq!(
+            EdidDescriptor12BitsTiming::from_porches(88, 44, 148).unwrap(),
+            EdidDescriptor12BitsTiming::try_from(280).unwrap()
+        );
+        assert!(EdidDescriptor12BitsTiming::from_porches(4000, 95, 0).is_ok());
+        assert!(EdidDescriptor12BitsTiming::from_porches(4000, 96, 0).is_err());
+        assert!(EdidDescriptor12BitsTiming::from_porches(u16::MAX, 1, 0).is_err());
+    }
 }
 
 pub type EdidDetailedTimingSizeMm = EdidDescriptor12BitsTiming;
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_result_states)]
 mod test_edid_detailed_timings_size {
     use crate::EdidDetailedTimingSizeMm;
 
@@ -428,7 +1178,9 @@ mod test_edid_detailed_timings_size {
     }
 }
 
-#[derive(Clone, Copy, Debug, TypedBuilder)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct EdidDescriptorDetailedTiming {
     pixel_clock: EdidDetailedTimingPixelClock,
 
@@ -451,26 +1203,930 @@ pub struct EdidDescriptorDetailedTiming {
     vertical_border: EdidDescriptor8BitsTiming,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     interlace: bool,
 
+    /// Defaults to a digital separate sync, with both polarities positive.
+    #[builder(default = EdidDetailedTimingSync::digital_separate(true, true))]
+    #[cfg_attr(feature = "serde", serde(default = "default_sync_type"))]
     sync_type: EdidDetailedTimingSync,
+
+    #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     stereo: EdidDetailedTimingStereo,
 }
 
-impl IntoBytes for EdidDescriptorDetailedTiming {
-    #[allow(clippy::too_many_lines)]
-    fn into_bytes(self) -> Vec<u8> {
-        let mut data = Vec::with_capacity(EDID_DESCRIPTOR_LEN);
-
-        let freq = self.pixel_clock.into_raw();
-        let lo_freq = (freq & 0xff) as u8;
-        let hi_freq = ((freq >> 8) & 0xff) as u8;
+#[cfg(feature = "serde")]
+const fn default_sync_type() -> EdidDetailedTimingSync {
+    EdidDetailedTimingSync::digital_separate(true, true)
+}
 
-        data.extend_from_slice(&[lo_freq, hi_freq]);
+impl EdidDescriptorDetailedTiming {
+    fn cta_1080i(
+        horizontal_blanking: u16,
+        horizontal_front_porch: u16,
+        horizontal_size: EdidDetailedTimingSizeMm,
+        vertical_size: EdidDetailedTimingSizeMm,
+    ) -> Self {
+        Self::builder()
+            .pixel_clock(
+                EdidDetailedTimingPixelClock::try_from(74_250).expect("Pixel clock is invalid"),
+            )
+            .horizontal_addressable(
+                EdidDescriptor12BitsTiming::try_from(1920).expect("Timing is invalid"),
+            )
+            .horizontal_blanking(
+                EdidDescriptor12BitsTiming::try_from(horizontal_blanking)
+                    .expect("Timing is invalid"),
+            )
+            .vertical_addressable(
+                EdidDescriptor12BitsTiming::from_interlaced_frame(1080).expect("Timing is invalid"),
+            )
+            .vertical_blanking(
+                EdidDescriptor12BitsTiming::from_interlaced_frame(45).expect("Timing is invalid"),
+            )
+            .horizontal_front_porch(
+                EdidDescriptor10BitsTiming::try_from(horizontal_front_porch)
+                    .expect("Timing is invalid"),
+            )
+            .horizontal_sync_pulse(
+                EdidDescriptor10BitsTiming::try_from(44).expect("Timing is invalid"),
+            )
+            .vertical_front_porch(
+                EdidDescriptor6BitsTiming::try_from(2).expect("Timing is invalid"),
+            )
+            .vertical_sync_pulse(EdidDescriptor6BitsTiming::try_from(5).expect("Timing is invalid"))
+            .horizontal_size(horizontal_size)
+            .vertical_size(vertical_size)
+            .horizontal_border(EdidDescriptor8BitsTiming::try_from(0).expect("Border is invalid"))
+            .vertical_border(EdidDescriptor8BitsTiming::try_from(0).expect("Border is invalid"))
+            .interlace(true)
+            .sync_type(EdidDetailedTimingSync::digital_separate(true, true))
+            .stereo(EdidDetailedTimingStereo::None)
+            .build()
+    }
 
-        let haddr = self.horizontal_addressable.into_raw();
-        let haddr_lo = (haddr & 0xff) as u8;
-        let haddr_hi = ((haddr >> 8) & 0xf) as u8;
+    /// Creates the Detailed Timing for the 1920x1080i, 60Hz, CTA-861 timing (VIC 5).
+    #[must_use]
+    pub fn cta_1080i_60hz(
+        horizontal_size: EdidDetailedTimingSizeMm,
+        vertical_size: EdidDetailedTimingSizeMm,
+    ) -> Self {
+        Self::cta_1080i(280, 88, horizontal_size, vertical_size)
+    }
+
+    /// Creates the Detailed Timing for the 1920x1080i, 50Hz, CTA-861 timing (VIC 20).
+    #[must_use]
+    pub fn cta_1080i_50hz(
+        horizontal_size: EdidDetailedTimingSizeMm,
+        vertical_size: EdidDetailedTimingSizeMm,
+    ) -> Self {
+        Self::cta_1080i(720, 528, horizontal_size, vertical_size)
+    }
+
+    // The horizontal and vertical timings are given as addressable, blanking, front porch and
+    // sync pulse.
+    fn cta_progressive(
+        pixel_clock: u32,
+        horizontal: [u16; 4],
+        vertical: [u16; 4],
+        sync_positive: bool,
+        horizontal_size: EdidDetailedTimingSizeMm,
+        vertical_size: EdidDetailedTimingSizeMm,
+    ) -> Self {
+        let [haddr, hblank, hfp, hsync] = horizontal;
+        let [vaddr, vblank, vfp, vsync] = vertical;
+
+        Self::builder()
+            .pixel_clock(pixel_clock.try_into().expect("Pixel clock is invalid"))
+            .horizontal_addressable(haddr.try_into().expect("Timing is invalid"))
+            .horizontal_blanking(hblank.try_into().expect("Timing is invalid"))
+            .vertical_addressable(vaddr.try_into().expect("Timing is invalid"))
+            .vertical_blanking(vblank.try_into().expect("Timing is invalid"))
+            .horizontal_front_porch(hfp.try_into().expect("Timing is invalid"))
+            .horizontal_sync_pulse(hsync.try_into().expect("Timing is invalid"))
+            .vertical_front_porch(
+                EdidDescriptor6BitsTiming::try_from(u8::try_from(vfp).expect("Timing is invalid"))
+                    .expect("Timing is invalid"),
+            )
+            .vertical_sync_pulse(
+                EdidDescriptor6BitsTiming::try_from(
+                    u8::try_from(vsync).expect("Timing is invalid"),
+                )
+                .expect("Timing is invalid"),
+            )
+            .horizontal_size(horizontal_size)
+            .vertical_size(vertical_size)
+            .horizontal_border(0.try_into().expect("Border is invalid"))
+            .vertical_border(0.try_into().expect("Border is invalid"))
+            .sync_type(EdidDetailedTimingSync::digital_separate(
+                sync_positive,
+                sync_positive,
+            ))
+            .stereo(EdidDetailedTimingStereo::None)
+            .build()
+    }
+
+    /// Creates the Detailed Timing for the 640x480, 60Hz, CTA-861 timing (VIC 1), also known as
+    /// DMT 0x04.
+    #[must_use]
+    pub fn cta_640x480p_60hz(
+        horizontal_size: EdidDetailedTimingSizeMm,
+        vertical_size: EdidDetailedTimingSizeMm,
+    ) -> Self {
+        Self::cta_progressive(
+            25_175,
+            [640, 160, 16, 96],
+            [480, 45, 10, 2],
+            false,
+            horizontal_size,
+            vertical_size,
+        )
+    }
+
+    /// Creates the Detailed Timing for the 1280x720, 60Hz, CTA-861 timing (VIC 4).
+    #[must_use]
+    pub fn cta_720p_60hz(
+        horizontal_size: EdidDetailedTimingSizeMm,
+        vertical_size: EdidDetailedTimingSizeMm,
+    ) -> Self {
+        Self::cta_progressive(
+            74_250,
+            [1280, 370, 110, 40],
+            [720, 30, 5, 5],
+            true,
+            horizontal_size,
+            vertical_size,
+        )
+    }
+
+    /// Creates the Detailed Timing for the 1920x1080, 60Hz, CTA-861 timing (VIC 16).
+    #[must_use]
+    pub fn cta_1080p_60hz(
+        horizontal_size: EdidDetailedTimingSizeMm,
+        vertical_size: EdidDetailedTimingSizeMm,
+    ) -> Self {
+        Self::cta_progressive(
+            148_500,
+            [1920, 280, 88, 44],
+            [1080, 45, 4, 5],
+            true,
+            horizontal_size,
+            vertical_size,
+        )
+    }
+
+    /// Creates the Detailed Timing of a progressive mode using the CVT Reduced Blanking v2
+    /// formula (VESA CVT 1.2), as most panels do.
+    ///
+    /// The horizontal blanking is fixed to 80 pixels, and the vertical blanking is the smallest
+    /// one lasting at least 460us.
+    ///
+    /// # Errors
+    ///
+    /// If the refresh rate is out of range, or if the resulting timing can't be expressed in a
+    /// Detailed Timing.
+    pub fn cvt_rb2(
+        hactive: u16,
+        vactive: u16,
+        refresh_rate: u16,
+        horizontal_size: EdidDetailedTimingSizeMm,
+        vertical_size: EdidDetailedTimingSizeMm,
+    ) -> Result<Self, EdidTypeConversionError<u32>> {
+        const HBLANK: u16 = 80;
+        const HFRONT_PORCH: u16 = 8;
+        const HSYNC: u16 = 32;
+        const VSYNC: u16 = 8;
+        const VBACK_PORCH: u16 = 6;
+        const MIN_VBLANK_US: u64 = 460;
+        const MIN_VBLANK_LINES: u64 = 15;
+        const US_PER_S: u64 = 1_000_000;
+
+        fn timing<V, T>(value: u64, name: &'static str) -> Result<T, EdidTypeConversionError<u32>>
+        where
+            V: TryFrom<u64, Error = core::num::TryFromIntError> + Into<u32> + fmt::Display,
+            T: TryFrom<V, Error = EdidTypeConversionError<V>>,
+        {
+            V::try_from(value)
+                .map_err(EdidTypeConversionError::from)
+                .and_then(T::try_from)
+                .map_err(|e| e.map_value(&Into::into).in_field(name))
+        }
+
+        let rate = u64::from(refresh_rate);
+        let frame_us = US_PER_S
+            .checked_sub(MIN_VBLANK_US * rate)
+            .filter(|us| rate > 0 && *us > 0)
+            .ok_or_else(|| {
+                EdidTypeConversionError::Range(u32::from(refresh_rate), Some(1), Some(2173))
+                    .in_field("refresh_rate")
+            })?;
+
+        // The estimated line duration is frame_us / (rate * vactive).
+        let vblank =
+            (MIN_VBLANK_US * rate * u64::from(vactive) / frame_us + 1).max(MIN_VBLANK_LINES);
+        let vtotal = u64::from(vactive) + vblank;
+        let htotal = u64::from(hactive) + u64::from(HBLANK);
+        // Clocks that don't even fit in a u32 are reported as u32::MAX.
+        let clock = u32::try_from(rate * vtotal * htotal / 1000).unwrap_or(u32::MAX);
+
+        Ok(Self {
+            pixel_clock: EdidDetailedTimingPixelClock::try_from(clock)
+                .map_err(|e| e.in_field("pixel_clock"))?,
+            horizontal_addressable: timing::<u16, _>(hactive.into(), "horizontal_addressable")?,
+            horizontal_blanking: timing::<u16, _>(HBLANK.into(), "horizontal_blanking")?,
+            vertical_addressable: timing::<u16, _>(vactive.into(), "vertical_addressable")?,
+            vertical_blanking: timing::<u16, _>(vblank, "vertical_blanking")?,
+            horizontal_front_porch: timing::<u16, _>(
+                HFRONT_PORCH.into(),
+                "horizontal_front_porch",
+            )?,
+            horizontal_sync_pulse: timing::<u16, _>(HSYNC.into(), "horizontal_sync_pulse")?,
+            vertical_front_porch: timing::<u8, _>(
+                vblank - u64::from(VSYNC + VBACK_PORCH),
+                "vertical_front_porch",
+            )?,
+            vertical_sync_pulse: timing::<u8, _>(VSYNC.into(), "vertical_sync_pulse")?,
+            horizontal_size,
+            vertical_size,
+            horizontal_border: timing::<u8, _>(0, "horizontal_border")?,
+            vertical_border: timing::<u8, _>(0, "vertical_border")?,
+            interlace: false,
+            sync_type: EdidDetailedTimingSync::digital_separate(true, false),
+            stereo: EdidDetailedTimingStereo::None,
+        })
+    }
+
+    pub(crate) fn horizontal_total(&self) -> u32 {
+        u32::from(self.horizontal_addressable.into_raw())
+            + u32::from(self.horizontal_blanking.into_raw())
+    }
+
+    /// Returns the total number of lines of a frame.
+    ///
+    /// The vertical fields of interlaced timings describe a single field, so a frame is made of
+    /// two fields and the extra half line of each field.
+    #[must_use]
+    pub fn vertical_frame_total(&self) -> u32 {
+        let field = u32::from(self.vertical_addressable.into_raw())
+            + u32::from(self.vertical_blanking.into_raw());
+
+        if self.interlace {
+            field * 2 + 1
+        } else {
+            field
+        }
+    }
+
+    /// Returns the frame rate, in Hz.
+    #[must_use]
+    pub fn frame_rate(&self) -> f64 {
+        f64::from(self.pixel_clock.0) * 1000.0
+            / (f64::from(self.horizontal_total()) * f64::from(self.vertical_frame_total()))
+    }
+
+    /// Returns the field rate, in Hz. It's twice the frame rate for interlaced timings, and the
+    /// frame rate otherwise.
+    #[must_use]
+    pub fn field_rate(&self) -> f64 {
+        if self.interlace {
+            self.frame_rate() * 2.0
+        } else {
+            self.frame_rate()
+        }
+    }
+
+    /// Returns the Detailed Timing of the same mode on a display rotated by 90 degrees.
+    ///
+    /// The image size is always swapped. If `swap_timing` is set, the addressable resolutions
+    /// are swapped as well, and the pixel clock is adjusted to preserve the refresh rate. The
+    /// blanking, porches and sync pulses are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// If `swap_timing` is set and the timing is interlaced, since its vertical fields describe
+    /// a single field and can't be swapped with the horizontal ones, or if the adjusted pixel
+    /// clock is out of range.
+    pub fn rotated(self, swap_timing: bool) -> Result<Self, EdidTypeConversionError<u32>> {
+        let mut dtd = self;
+
+        dtd.horizontal_size = self.vertical_size;
+        dtd.vertical_size = self.horizontal_size;
+
+        if swap_timing {
+            if self.interlace {
+                return Err(EdidTypeConversionError::Value(String::from(
+                    "Interlaced timings can't be rotated",
+                ))
+                .in_field("interlace"));
+            }
+
+            dtd.horizontal_addressable = self.vertical_addressable;
+            dtd.vertical_addressable = self.horizontal_addressable;
+
+            let total = |dtd: &Self| {
+                (u64::from(dtd.horizontal_addressable.into_raw())
+                    + u64::from(dtd.horizontal_blanking.into_raw()))
+                    * (u64::from(dtd.vertical_addressable.into_raw())
+                        + u64::from(dtd.vertical_blanking.into_raw()))
+            };
+
+            let old_total = total(&self).max(1);
+            let clock = u64::from(self.pixel_clock.0) * total(&dtd) / old_total;
+
+            // The pixel clock is stored with a 10kHz resolution. Clocks that don't even fit in a
+            // u32 are reported as u32::MAX.
+            let clock = u32::try_from(clock.div_ceil(10) * 10).unwrap_or(u32::MAX);
+            dtd.pixel_clock = EdidDetailedTimingPixelClock::try_from(clock)
+                .map_err(|e| e.in_field("pixel_clock"))?;
+        }
+
+        Ok(dtd)
+    }
+
+    pub(crate) fn size_mm(&self) -> (u16, u16) {
+        (
+            self.horizontal_size.into_raw(),
+            self.vertical_size.into_raw(),
+        )
+    }
+
+    /// Returns the addressable resolution of a frame, in pixels and lines.
+    pub(crate) fn frame_resolution(&self) -> (u16, u16) {
+        let lines = self.vertical_addressable.into_raw();
+
+        (
+            self.horizontal_addressable.into_raw(),
+            if self.interlace { lines * 2 } else { lines },
+        )
+    }
+
+    pub(crate) const fn pixel_clock(&self) -> EdidDetailedTimingPixelClock {
+        self.pixel_clock
+    }
+
+    /// Returns the pixel clock, in kHz.
+    #[must_use]
+    pub const fn pixel_clock_khz(&self) -> u32 {
+        self.pixel_clock.0
+    }
+
+    /// Returns the number of horizontal addressable pixels.
+    #[must_use]
+    pub fn hactive(&self) -> u16 {
+        self.horizontal_addressable.into_raw()
+    }
+
+    /// Returns the pixel the horizontal sync pulse starts at.
+    #[must_use]
+    pub fn hsync_start(&self) -> u16 {
+        self.hactive() + self.horizontal_front_porch.into_raw()
+    }
+
+    /// Returns the pixel the horizontal sync pulse ends at.
+    #[must_use]
+    pub fn hsync_end(&self) -> u16 {
+        self.hsync_start() + self.horizontal_sync_pulse.into_raw()
+    }
+
+    /// Returns the total number of pixels of a line. The borders aren't taken into account, see
+    /// [`EdidDescriptorDetailedTiming::htotal_with_borders`].
+    #[must_use]
+    pub fn htotal(&self) -> u16 {
+        self.hactive() + self.horizontal_blanking.into_raw()
+    }
+
+    /// Returns the number of vertical addressable lines. For interlaced timings, it's the number
+    /// of lines of a field.
+    #[must_use]
+    pub fn vactive(&self) -> u16 {
+        self.vertical_addressable.into_raw()
+    }
+
+    /// Returns the line the vertical sync pulse starts at. For interlaced timings, it's relative
+    /// to a field.
+    #[must_use]
+    pub fn vsync_start(&self) -> u16 {
+        self.vactive() + u16::from(self.vertical_front_porch.into_raw())
+    }
+
+    /// Returns the line the vertical sync pulse ends at. For interlaced timings, it's relative
+    /// to a field.
+    #[must_use]
+    pub fn vsync_end(&self) -> u16 {
+        self.vsync_start() + u16::from(self.vertical_sync_pulse.into_raw())
+    }
+
+    /// Returns the total number of lines. For interlaced timings, it's the number of lines of a
+    /// field, see [`EdidDescriptorDetailedTiming::vertical_frame_total`] for the frame.
+    #[must_use]
+    pub fn vtotal(&self) -> u16 {
+        self.vactive() + self.vertical_blanking.into_raw()
+    }
+
+    /// Returns the total number of pixels of a line, interpreting the borders according to
+    /// `policy`.
+    #[must_use]
+    pub fn htotal_with_borders(&self, policy: EdidDetailedTimingBorderPolicy) -> u16 {
+        match policy {
+            EdidDetailedTimingBorderPolicy::OutsideBlanking => {
+                self.htotal() + 2 * u16::from(self.horizontal_border.into_raw())
+            }
+            EdidDetailedTimingBorderPolicy::InsideBlanking => self.htotal(),
+        }
+    }
+
+    /// Returns the total number of lines, interpreting the borders according to `policy`. For
+    /// interlaced timings, it's the number of lines of a field.
+    #[must_use]
+    pub fn vtotal_with_borders(&self, policy: EdidDetailedTimingBorderPolicy) -> u16 {
+        match policy {
+            EdidDetailedTimingBorderPolicy::OutsideBlanking => {
+                self.vtotal() + 2 * u16::from(self.vertical_border.into_raw())
+            }
+            EdidDetailedTimingBorderPolicy::InsideBlanking => self.vtotal(),
+        }
+    }
+
+    /// Returns the same timing with borders, the pixel clock, totals and sync pulse positions
+    /// being preserved.
+    ///
+    /// With [`EdidDetailedTimingBorderPolicy::OutsideBlanking`], the blanking is reduced by twice
+    /// the border, and the front porch by the border. With
+    /// [`EdidDetailedTimingBorderPolicy::InsideBlanking`], only the borders are set.
+    ///
+    /// # Errors
+    ///
+    /// If the borders don't fit in the blanking or in the front porches.
+    pub fn with_borders(
+        self,
+        horizontal: u8,
+        vertical: u8,
+        policy: EdidDetailedTimingBorderPolicy,
+    ) -> Result<Self, EdidTypeConversionError<u16>> {
+        let mut dtd = self;
+
+        dtd.horizontal_border = EdidDescriptor8BitsTiming::try_from(horizontal)
+            .map_err(|e| e.map_value(&u16::from).in_field("horizontal_border"))?;
+        dtd.vertical_border = EdidDescriptor8BitsTiming::try_from(vertical)
+            .map_err(|e| e.map_value(&u16::from).in_field("vertical_border"))?;
+
+        match policy {
+            EdidDetailedTimingBorderPolicy::OutsideBlanking => {
+                let shrink = |value: u16, by: u16, name: &'static str| {
+                    value.checked_sub(by).ok_or_else(|| {
+                        EdidTypeConversionError::Range(value, Some(by), None).in_field(name)
+                    })
+                };
+
+                let (horizontal, vertical) = (u16::from(horizontal), u16::from(vertical));
+                dtd.horizontal_blanking = EdidDescriptor12BitsTiming::try_from(shrink(
+                    self.horizontal_blanking.into_raw(),
+                    2 * horizontal,
+                    "horizontal_blanking",
+                )?)?;
+                dtd.horizontal_front_porch = EdidDescriptor10BitsTiming::try_from(shrink(
+                    self.horizontal_front_porch.into_raw(),
+                    horizontal,
+                    "horizontal_front_porch",
+                )?)?;
+                dtd.vertical_blanking = EdidDescriptor12BitsTiming::try_from(shrink(
+                    self.vertical_blanking.into_raw(),
+                    2 * vertical,
+                    "vertical_blanking",
+                )?)?;
+
+                let vfp = shrink(
+                    u16::from(self.vertical_front_porch.into_raw()),
+                    vertical,
+                    "vertical_front_porch",
+                )?;
+                dtd.vertical_front_porch = EdidDescriptor6BitsTiming::try_from(
+                    u8::try_from(vfp).map_err(EdidTypeConversionError::from)?,
+                )
+                .map_err(|e| e.map_value(&u16::from))?;
+            }
+            EdidDetailedTimingBorderPolicy::InsideBlanking => {}
+        }
+
+        Ok(dtd)
+    }
+
+    /// Returns whether the timing is interlaced.
+    #[must_use]
+    pub const fn is_interlaced(&self) -> bool {
+        self.interlace
+    }
+
+    /// Returns whether the horizontal sync pulse is positive. For digital composite syncs, it's
+    /// the polarity of the composite sync outside of the vertical sync. Analog syncs don't have
+    /// a horizontal sync polarity, and are reported as negative.
+    #[must_use]
+    pub const fn hsync_positive(&self) -> bool {
+        match self.sync_type {
+            EdidDetailedTimingSync::Digital(sync) => sync.hsync_positive,
+            EdidDetailedTimingSync::Analog(_) => false,
+        }
+    }
+
+    /// Returns whether the vertical sync pulse is positive. Only digital separate syncs have a
+    /// vertical sync polarity, the others are reported as negative.
+    #[must_use]
+    pub const fn vsync_positive(&self) -> bool {
+        match self.sync_type {
+            EdidDetailedTimingSync::Digital(EdidDetailedTimingDigitalSync {
+                kind: EdidDetailedTimingDigitalSyncKind::Separate(sync),
+                ..
+            }) => sync.vsync_positive,
+            EdidDetailedTimingSync::Digital(EdidDetailedTimingDigitalSync {
+                kind: EdidDetailedTimingDigitalSyncKind::Composite(_),
+                ..
+            })
+            | EdidDetailedTimingSync::Analog(_) => false,
+        }
+    }
+
+    pub(crate) fn vertical_sync_in_blanking(&self) -> bool {
+        u16::from(self.vertical_front_porch.into_raw())
+            + u16::from(self.vertical_sync_pulse.into_raw())
+            <= self.vertical_blanking.into_raw()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_descriptor_detailed_timing_interlaced {
+    use crate::{EdidDescriptorDetailedTiming, EdidDetailedTimingSizeMm, IntoBytes};
+
+    #[test]
+    fn test_binary_1080i() {
+        let dtd = EdidDescriptorDetailedTiming::cta_1080i_60hz(
+            EdidDetailedTimingSizeMm::try_from(531).unwrap(),
+            EdidDetailedTimingSizeMm::try_from(299).unwrap(),
+        );
+
+        assert_eq!(
+            dtd.into_bytes(),
+            &[
+                0x01, 0x1d, 0x80, 0x18, 0x71, 0x1c, 0x16, 0x20, 0x58, 0x2c, 0x25, 0x00, 0x13, 0x2b,
+                0x21, 0x00, 0x00, 0x9e
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rates() {
+        let size = EdidDetailedTimingSizeMm::try_from(0).unwrap();
+
+        let dtd = EdidDescriptorDetailedTiming::cta_1080i_60hz(size, size);
+        assert_eq!(dtd.vertical_frame_total(), 1125);
+        assert!((dtd.frame_rate() - 30.0).abs() < 0.001);
+        assert!((dtd.field_rate() - 60.0).abs() < 0.001);
+        assert!(dtd.vertical_sync_in_blanking());
+
+        let dtd = EdidDescriptorDetailedTiming::cta_1080i_50hz(size, size);
+        assert!((dtd.frame_rate() - 25.0).abs() < 0.001);
+        assert!((dtd.field_rate() - 50.0).abs() < 0.001);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_descriptor_detailed_timing {
+    use crate::{
+        descriptors::EdidDetailedTimingPixelClock, EdidDescriptor10BitsTiming,
+        EdidDescriptor12BitsTiming, EdidDescriptor6BitsTiming, EdidDescriptor8BitsTiming,
+        EdidDescriptorDetailedTiming, EdidDetailedTimingBorderPolicy,
+        EdidDetailedTimingDigitalCompositeSync, EdidDetailedTimingDigitalSeparateSync,
+        EdidDetailedTimingDigitalSync, EdidDetailedTimingDigitalSyncKind, EdidDetailedTimingSizeMm,
+        EdidDetailedTimingStereo, EdidDetailedTimingSync, IntoBytes, EDID_CTA_VIDEO_FORMATS,
+    };
+
+    #[test]
+    fn test_sync_helpers() {
+        assert_eq!(
+            EdidDetailedTimingSync::digital_separate(true, false),
+            EdidDetailedTimingSync::Digital(
+                EdidDetailedTimingDigitalSync::builder()
+                    .kind(EdidDetailedTimingDigitalSyncKind::Separate(
+                        EdidDetailedTimingDigitalSeparateSync::builder()
+                            .vsync_positive(false)
+                            .build(),
+                    ))
+                    .hsync_positive(true)
+                    .build(),
+            )
+        );
+        assert_eq!(
+            EdidDetailedTimingSync::digital_composite(true, false),
+            EdidDetailedTimingSync::Digital(
+                EdidDetailedTimingDigitalSync::builder()
+                    .kind(EdidDetailedTimingDigitalSyncKind::Composite(
+                        EdidDetailedTimingDigitalCompositeSync::builder()
+                            .serrations(true)
+                            .build(),
+                    ))
+                    .build(),
+            )
+        );
+
+        let flags = |sync| {
+            EdidDescriptorDetailedTiming::builder()
+                .pixel_clock(EdidDetailedTimingPixelClock::try_from(25_175).unwrap())
+                .horizontal_addressable(EdidDescriptor12BitsTiming::try_from(640).unwrap())
+                .horizontal_blanking(EdidDescriptor12BitsTiming::try_from(160).unwrap())
+                .vertical_addressable(EdidDescriptor12BitsTiming::try_from(480).unwrap())
+                .vertical_blanking(EdidDescriptor12BitsTiming::try_from(45).unwrap())
+                .horizontal_front_porch(EdidDescriptor10BitsTiming::try_from(16).unwrap())
+                .horizontal_sync_pulse(EdidDescriptor10BitsTiming::try_from(96).unwrap())
+                .vertical_front_porch(EdidDescriptor6BitsTiming::try_from(10).unwrap())
+                .vertical_sync_pulse(EdidDescriptor6BitsTiming::try_from(2).unwrap())
+                .horizontal_size(EdidDetailedTimingSizeMm::try_from(0).unwrap())
+                .vertical_size(EdidDetailedTimingSizeMm::try_from(0).unwrap())
+                .horizontal_border(EdidDescriptor8BitsTiming::try_from(0).unwrap())
+                .vertical_border(EdidDescriptor8BitsTiming::try_from(0).unwrap())
+                .sync_type(sync)
+                .build()
+                .into_bytes()[17]
+        };
+
+        assert_eq!(
+            flags(EdidDetailedTimingSync::digital_separate(true, true)),
+            0x1e
+        );
+        assert_eq!(
+            flags(EdidDetailedTimingSync::digital_separate(false, false)),
+            0x18
+        );
+        assert_eq!(
+            flags(EdidDetailedTimingSync::digital_composite(true, false)),
+            0x14
+        );
+    }
+
+    #[test]
+    fn test_builder_defaults() {
+        let dtd = EdidDescriptorDetailedTiming::builder()
+            .pixel_clock(EdidDetailedTimingPixelClock::try_from(25_175).unwrap())
+            .horizontal_addressable(EdidDescriptor12BitsTiming::try_from(640).unwrap())
+            .horizontal_blanking(EdidDescriptor12BitsTiming::try_from(160).unwrap())
+            .vertical_addressable(EdidDescriptor12BitsTiming::try_from(480).unwrap())
+            .vertical_blanking(EdidDescriptor12BitsTiming::try_from(45).unwrap())
+            .horizontal_front_porch(EdidDescriptor10BitsTiming::try_from(16).unwrap())
+            .horizontal_sync_pulse(EdidDescriptor10BitsTiming::try_from(96).unwrap())
+            .vertical_front_porch(EdidDescriptor6BitsTiming::try_from(10).unwrap())
+            .vertical_sync_pulse(EdidDescriptor6BitsTiming::try_from(2).unwrap())
+            .horizontal_size(EdidDetailedTimingSizeMm::try_from(0).unwrap())
+            .vertical_size(EdidDetailedTimingSizeMm::try_from(0).unwrap())
+            .horizontal_border(EdidDescriptor8BitsTiming::try_from(0).unwrap())
+            .vertical_border(EdidDescriptor8BitsTiming::try_from(0).unwrap())
+            .build();
+
+        assert!(!dtd.is_interlaced());
+        assert_eq!(
+            dtd.sync_type,
+            EdidDetailedTimingSync::digital_separate(true, true)
+        );
+        assert_eq!(dtd.stereo, EdidDetailedTimingStereo::None);
+    }
+
+    #[test]
+    fn test_binary_stereo_low_bit() {
+        let size = EdidDetailedTimingSizeMm::try_from(0).unwrap();
+
+        let mut dtd = EdidDescriptorDetailedTiming::cta_1080i_60hz(size, size);
+        dtd.stereo = EdidDetailedTimingStereo::NoneWithLowBitSet;
+
+        assert_eq!(dtd.into_bytes()[17], 0x9f);
+    }
+
+    #[test]
+    fn test_rotated() {
+        let dtd = EdidDescriptorDetailedTiming::cta_1080i_60hz(
+            EdidDetailedTimingSizeMm::try_from(531).unwrap(),
+            EdidDetailedTimingSizeMm::try_from(299).unwrap(),
+        );
+
+        let rotated = dtd.rotated(false).unwrap();
+        assert_eq!(rotated.size_mm(), (299, 531));
+        assert_eq!(
+            rotated.horizontal_addressable,
+            EdidDescriptor12BitsTiming::try_from(1920).unwrap()
+        );
+        assert_eq!(rotated.pixel_clock, dtd.pixel_clock);
+
+        // The vertical fields of an interlaced timing only describe a field.
+        assert!(dtd.rotated(true).is_err());
+
+        let dtd = EdidDescriptorDetailedTiming::cta_1080p_60hz(
+            EdidDetailedTimingSizeMm::try_from(531).unwrap(),
+            EdidDetailedTimingSizeMm::try_from(299).unwrap(),
+        );
+
+        let rotated = dtd.rotated(true).unwrap();
+        assert_eq!(rotated.size_mm(), (299, 531));
+        assert_eq!(
+            rotated.horizontal_addressable,
+            EdidDescriptor12BitsTiming::try_from(1080).unwrap()
+        );
+        assert_eq!(
+            rotated.vertical_addressable,
+            EdidDescriptor12BitsTiming::try_from(1920).unwrap()
+        );
+        assert!((rotated.frame_rate() - dtd.frame_rate()).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_accessors() {
+        let size = EdidDetailedTimingSizeMm::try_from(0).unwrap();
+
+        let dtd = EdidDescriptorDetailedTiming::cta_1080i_60hz(size, size);
+        assert_eq!(dtd.pixel_clock_khz(), 74_250);
+        assert_eq!(
+            (
+                dtd.hactive(),
+                dtd.hsync_start(),
+                dtd.hsync_end(),
+                dtd.htotal()
+            ),
+            (1920, 2008, 2052, 2200)
+        );
+        assert_eq!(
+            (
+                dtd.vactive(),
+                dtd.vsync_start(),
+                dtd.vsync_end(),
+                dtd.vtotal()
+            ),
+            (540, 542, 547, 562)
+        );
+        assert!(dtd.is_interlaced());
+        assert!(dtd.hsync_positive());
+        assert!(dtd.vsync_positive());
+    }
+
+    #[test]
+    fn test_cta_progressive() {
+        let size = EdidDetailedTimingSizeMm::try_from(0).unwrap();
+
+        for (dtd, vic) in [
+            (
+                EdidDescriptorDetailedTiming::cta_640x480p_60hz(size, size),
+                1,
+            ),
+            (EdidDescriptorDetailedTiming::cta_720p_60hz(size, size), 4),
+            (EdidDescriptorDetailedTiming::cta_1080p_60hz(size, size), 16),
+        ] {
+            let format = &EDID_CTA_VIDEO_FORMATS[vic - 1];
+
+            assert_eq!(
+                (dtd.hactive(), dtd.vactive(), dtd.htotal(), dtd.vtotal()),
+                (
+                    format.hactive(),
+                    format.vactive(),
+                    format.htotal(),
+                    format.vtotal()
+                ),
+                "VIC {vic} doesn't match"
+            );
+            assert!(!dtd.is_interlaced());
+
+            // Allow for the 1000 / 1001 variant of the pixel clock.
+            let clock = format.pixel_clock_khz();
+            assert!(dtd.pixel_clock_khz().abs_diff(clock) * 1001 <= clock);
+        }
+    }
+
+    #[test]
+    fn test_borders() {
+        let size = EdidDetailedTimingSizeMm::try_from(0).unwrap();
+        let dtd = EdidDescriptorDetailedTiming::cta_640x480p_60hz(size, size);
+
+        // DMT 0x04 is defined with 8 pixels and 8 lines borders, included in edid-decode totals.
+        let bordered = dtd
+            .with_borders(8, 8, EdidDetailedTimingBorderPolicy::OutsideBlanking)
+            .unwrap();
+        assert_eq!(
+            (
+                bordered.hsync_start(),
+                bordered.htotal(),
+                bordered.vsync_start(),
+                bordered.vtotal()
+            ),
+            (648, 784, 482, 509)
+        );
+        assert_eq!(
+            bordered.htotal_with_borders(EdidDetailedTimingBorderPolicy::OutsideBlanking),
+            800
+        );
+        assert_eq!(
+            bordered.vtotal_with_borders(EdidDetailedTimingBorderPolicy::OutsideBlanking),
+            525
+        );
+
+        let bytes = bordered.into_bytes();
+        // 144 pixels of blanking, 8 of front porch, 29 lines of blanking and 2 of front porch
+        assert_eq!(&bytes[2..8], &[0x80, 0x90, 0x20, 0xe0, 0x1d, 0x10]);
+        assert_eq!(&bytes[8..12], &[0x08, 0x60, 0x22, 0x00]);
+        assert_eq!(&bytes[15..17], &[8, 8]);
+
+        let bordered = dtd
+            .with_borders(8, 8, EdidDetailedTimingBorderPolicy::InsideBlanking)
+            .unwrap();
+        assert_eq!(
+            bordered.htotal_with_borders(EdidDetailedTimingBorderPolicy::InsideBlanking),
+            800
+        );
+        assert_eq!(
+            bordered.vtotal_with_borders(EdidDetailedTimingBorderPolicy::InsideBlanking),
+            525
+        );
+        assert_eq!(&bordered.into_bytes()[2..12], &dtd.into_bytes()[2..12]);
+
+        assert!(dtd
+            .with_borders(17, 0, EdidDetailedTimingBorderPolicy::OutsideBlanking)
+            .is_err());
+        assert!(dtd
+            .with_borders(0, 11, EdidDetailedTimingBorderPolicy::OutsideBlanking)
+            .is_err());
+        assert!(dtd
+            .with_borders(17, 11, EdidDetailedTimingBorderPolicy::InsideBlanking)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_cvt_rb2() {
+        let size = EdidDetailedTimingSizeMm::try_from(0).unwrap();
+
+        let dtd = EdidDescriptorDetailedTiming::cvt_rb2(1920, 1080, 60, size, size).unwrap();
+        assert_eq!(dtd.pixel_clock_khz(), 133_320);
+        assert_eq!(
+            (
+                dtd.hactive(),
+                dtd.hsync_start(),
+                dtd.hsync_end(),
+                dtd.htotal()
+            ),
+            (1920, 1928, 1960, 2000)
+        );
+        assert_eq!(
+            (
+                dtd.vactive(),
+                dtd.vsync_start(),
+                dtd.vsync_end(),
+                dtd.vtotal()
+            ),
+            (1080, 1097, 1105, 1111)
+        );
+        assert!(!dtd.is_interlaced());
+        assert!(dtd.hsync_positive());
+        assert!(!dtd.vsync_positive());
+
+        let dtd = EdidDescriptorDetailedTiming::cvt_rb2(2560, 1440, 60, size, size).unwrap();
+        assert_eq!(dtd.pixel_clock_khz(), 234_590);
+        assert_eq!(dtd.vtotal(), 1481);
+
+        assert!(EdidDescriptorDetailedTiming::cvt_rb2(1920, 1080, 0, size, size).is_err());
+        assert!(EdidDescriptorDetailedTiming::cvt_rb2(1920, 1080, 2174, size, size).is_err());
+        assert!(EdidDescriptorDetailedTiming::cvt_rb2(4096, 1080, 60, size, size).is_err());
+
+        // The error reports the pixel clock the timing would need.
+        let err = EdidDescriptorDetailedTiming::cvt_rb2(3840, 2160, 240, size, size).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "pixel_clock: Pixel clock of 2285203 kHz is too high for a Detailed Timing Descriptor, use a DisplayID Type VII Timing instead"
+        );
+    }
+
+    #[test]
+    fn test_composite_sync_polarities() {
+        let size = EdidDetailedTimingSizeMm::try_from(0).unwrap();
+        let mut dtd = EdidDescriptorDetailedTiming::cta_1080i_60hz(size, size);
+
+        dtd.sync_type = EdidDetailedTimingSync::digital_composite(false, true);
+        assert!(dtd.hsync_positive());
+        assert!(!dtd.vsync_positive());
+
+        dtd.sync_type = EdidDetailedTimingSync::digital_composite(true, false);
+        assert!(!dtd.hsync_positive());
+        assert!(!dtd.vsync_positive());
+    }
+}
+
+impl IntoBytes for EdidDescriptorDetailedTiming {
+    #[allow(clippy::too_many_lines)]
+    fn into_bytes(self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(EDID_DESCRIPTOR_LEN);
+
+        let freq = self.pixel_clock.into_raw();
+        let lo_freq = (freq & 0xff) as u8;
+        let hi_freq = ((freq >> 8) & 0xff) as u8;
+
+        data.extend_from_slice(&[lo_freq, hi_freq]);
+
+        let haddr = self.horizontal_addressable.into_raw();
+        let haddr_lo = (haddr & 0xff) as u8;
+        let haddr_hi = ((haddr >> 8) & 0xf) as u8;
 
         let hblank = self.horizontal_blanking.into_raw();
         let hblank_lo = (hblank & 0xff) as u8;
@@ -529,6 +2185,7 @@ impl IntoBytes for EdidDescriptorDetailedTiming {
 
         match self.stereo {
             EdidDetailedTimingStereo::None => flags |= 0,
+            EdidDetailedTimingStereo::NoneWithLowBitSet => flags |= 0b000_0001,
             EdidDetailedTimingStereo::FieldSequentialRightOnSync => flags |= 0b010_0000,
             EdidDetailedTimingStereo::FieldSequentialLeftOnSync => flags |= 0b100_0000,
             EdidDetailedTimingStereo::TwoWayInterleavedRightOnEven => flags |= 0b010_0001,
@@ -578,7 +2235,7 @@ impl IntoBytes for EdidDescriptorDetailedTiming {
                             flags |= 1 << 2;
                         }
                     }
-                };
+                }
 
                 if v.hsync_positive {
                     flags |= 1 << 1;
@@ -606,7 +2263,7 @@ impl IntoBytes for EdidDescriptorDetailedTiming {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct EdidDisplayRangeHorizontalFreq(u8);
 
 impl TryFrom<u8> for EdidDisplayRangeHorizontalFreq {
@@ -621,7 +2278,23 @@ impl TryFrom<u8> for EdidDisplayRangeHorizontalFreq {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+impl TryFrom<u16> for EdidDisplayRangeHorizontalFreq {
+    type Error = EdidTypeConversionError<u16>;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        // EDID 1.3 can only store frequencies up to 255. EDID 1.4 relies on an offset to store
+        // larger values.
+        if !(1..=255).contains(&value) {
+            return Err(EdidTypeConversionError::Range(value, Some(1), Some(255)));
+        }
+
+        Ok(Self(u8::try_from(value)?))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16"))]
 pub struct EdidDisplayRangeVerticalFreq(u8);
 
 impl TryFrom<u8> for EdidDisplayRangeVerticalFreq {
@@ -636,10 +2309,50 @@ impl TryFrom<u8> for EdidDisplayRangeVerticalFreq {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+impl TryFrom<u16> for EdidDisplayRangeVerticalFreq {
+    type Error = EdidTypeConversionError<u16>;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        // EDID 1.3 can only store frequencies up to 255. EDID 1.4 relies on an offset to store
+        // larger values.
+        if !(1..=255).contains(&value) {
+            return Err(EdidTypeConversionError::Range(value, Some(1), Some(255)));
+        }
+
+        Ok(Self(u8::try_from(value)?))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states)]
+mod test_descriptor_display_range_r3_freqs {
+    use super::{EdidDisplayRangeHorizontalFreq, EdidDisplayRangeVerticalFreq};
+
+    #[test]
+    fn test_range() {
+        assert!(EdidDisplayRangeHorizontalFreq::try_from(0u16).is_err());
+        assert!(EdidDisplayRangeHorizontalFreq::try_from(1u16).is_ok());
+        assert!(EdidDisplayRangeHorizontalFreq::try_from(255u16).is_ok());
+        assert!(EdidDisplayRangeHorizontalFreq::try_from(256u16).is_err());
+
+        assert!(EdidDisplayRangeVerticalFreq::try_from(0u16).is_err());
+        assert!(EdidDisplayRangeVerticalFreq::try_from(1u16).is_ok());
+        assert!(EdidDisplayRangeVerticalFreq::try_from(255u16).is_ok());
+        assert!(EdidDisplayRangeVerticalFreq::try_from(256u16).is_err());
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16"))]
 pub struct EdidDisplayRangePixelClock(u16);
 
 impl EdidDisplayRangePixelClock {
+    /// Returns the pixel clock, in MHz, if it's not a multiple of 10MHz and will be rounded up.
+    pub(crate) fn rounding(self) -> Option<u16> {
+        (self.round() != self.0).then_some(self.0)
+    }
+
     fn round(self) -> u16 {
         round_up(&self.0, &10)
     }
@@ -663,6 +2376,7 @@ impl TryFrom<u16> for EdidDisplayRangePixelClock {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod test_descriptor_display_range_pixel_clock {
     use super::EdidDisplayRangePixelClock;
 
@@ -684,15 +2398,26 @@ mod test_descriptor_display_range_pixel_clock {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+/// The horizontal frequency, in kHz, the secondary GTF curve starts at.
+///
+/// It's stored divided by 2 in a single byte, so it must be an even number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16"))]
 pub struct EdidDisplayRangeVideoTimingsGTFStartFrequency(u16);
 
 impl TryFrom<u16> for EdidDisplayRangeVideoTimingsGTFStartFrequency {
     type Error = EdidTypeConversionError<u16>;
 
     fn try_from(value: u16) -> Result<Self, Self::Error> {
-        if !(1..=510).contains(&value) {
-            return Err(EdidTypeConversionError::Range(value, Some(1), Some(510)));
+        if !(2..=510).contains(&value) {
+            return Err(EdidTypeConversionError::Range(value, Some(2), Some(510)));
+        }
+
+        if value % 2 != 0 {
+            return Err(EdidTypeConversionError::Value(format!(
+                "GTF Start Frequency {value}kHz isn't a multiple of 2kHz."
+            )));
         }
 
         Ok(Self(value))
@@ -705,23 +2430,220 @@ impl EdidDisplayRangeVideoTimingsGTFStartFrequency {
     }
 }
 
-#[derive(Clone, Copy, Debug, TypedBuilder)]
+/// The GTF Blanking Offset, or C, in percents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
+pub struct EdidDisplayRangeVideoTimingsGTFBlankingOffset(u8);
+
+impl EdidDisplayRangeVideoTimingsGTFBlankingOffset {
+    fn into_raw(self) -> u8 {
+        // The value is at most 127, so it can't overflow.
+        self.0 * 2
+    }
+}
+
+impl TryFrom<u8> for EdidDisplayRangeVideoTimingsGTFBlankingOffset {
+    type Error = EdidTypeConversionError<u8>;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        // The value is stored multiplied by 2 in a single byte.
+        if value > 127 {
+            return Err(EdidTypeConversionError::Range(value, Some(0), Some(127)));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+/// The GTF Blanking Gradient, or M, in %/kHz.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "u16"))]
+pub struct EdidDisplayRangeVideoTimingsGTFBlankingGradient(u16);
+
+impl EdidDisplayRangeVideoTimingsGTFBlankingGradient {
+    fn into_raw(self) -> [u8; 2] {
+        self.0.to_le_bytes()
+    }
+}
+
+impl From<u16> for EdidDisplayRangeVideoTimingsGTFBlankingGradient {
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+
+/// The GTF Blanking Scaling Factor, or K.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "u8"))]
+pub struct EdidDisplayRangeVideoTimingsGTFBlankingScalingFactor(u8);
+
+impl EdidDisplayRangeVideoTimingsGTFBlankingScalingFactor {
+    fn into_raw(self) -> u8 {
+        self.0
+    }
+}
+
+impl From<u8> for EdidDisplayRangeVideoTimingsGTFBlankingScalingFactor {
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+/// The GTF Blanking Scaling Factor Weighting, or J, in percents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
+pub struct EdidDisplayRangeVideoTimingsGTFBlankingScalingFactorWeighting(u8);
+
+impl EdidDisplayRangeVideoTimingsGTFBlankingScalingFactorWeighting {
+    fn into_raw(self) -> u8 {
+        // The value is at most 127, so it can't overflow.
+        self.0 * 2
+    }
+}
+
+impl TryFrom<u8> for EdidDisplayRangeVideoTimingsGTFBlankingScalingFactorWeighting {
+    type Error = EdidTypeConversionError<u8>;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        // The value is stored multiplied by 2 in a single byte.
+        if value > 127 {
+            return Err(EdidTypeConversionError::Range(value, Some(0), Some(127)));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_descriptor_display_range_gtf_coefficients {
+    use super::{
+        EdidDisplayRangeVideoTimingsGTFBlankingGradient,
+        EdidDisplayRangeVideoTimingsGTFBlankingOffset,
+        EdidDisplayRangeVideoTimingsGTFBlankingScalingFactorWeighting,
+        EdidDisplayRangeVideoTimingsGTFStartFrequency,
+    };
+
+    #[test]
+    fn test_binary_spec() {
+        // VESA GTF Standard, Section 1.3, default values
+        assert_eq!(
+            EdidDisplayRangeVideoTimingsGTFBlankingOffset::try_from(40)
+                .unwrap()
+                .into_raw(),
+            0x50
+        );
+        assert_eq!(
+            EdidDisplayRangeVideoTimingsGTFBlankingGradient::from(600).into_raw(),
+            [0x58, 0x02]
+        );
+        assert_eq!(
+            EdidDisplayRangeVideoTimingsGTFBlankingScalingFactorWeighting::try_from(20)
+                .unwrap()
+                .into_raw(),
+            0x28
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        assert!(EdidDisplayRangeVideoTimingsGTFBlankingOffset::try_from(0).is_ok());
+        assert!(EdidDisplayRangeVideoTimingsGTFBlankingOffset::try_from(127).is_ok());
+        assert!(EdidDisplayRangeVideoTimingsGTFBlankingOffset::try_from(128).is_err());
+
+        assert!(EdidDisplayRangeVideoTimingsGTFBlankingScalingFactorWeighting::try_from(0).is_ok());
+        assert!(
+            EdidDisplayRangeVideoTimingsGTFBlankingScalingFactorWeighting::try_from(127).is_ok()
+        );
+        assert!(
+            EdidDisplayRangeVideoTimingsGTFBlankingScalingFactorWeighting::try_from(128).is_err()
+        );
+        assert_eq!(
+            EdidDisplayRangeVideoTimingsGTFBlankingOffset::try_from(127)
+                .unwrap()
+                .into_raw(),
+            0xfe
+        );
+        assert_eq!(
+            EdidDisplayRangeVideoTimingsGTFBlankingScalingFactorWeighting::try_from(127)
+                .unwrap()
+                .into_raw(),
+            0xfe
+        );
+    }
+
+    #[test]
+    fn test_start_frequency() {
+        assert!(EdidDisplayRangeVideoTimingsGTFStartFrequency::try_from(0).is_err());
+        assert!(EdidDisplayRangeVideoTimingsGTFStartFrequency::try_from(1).is_err());
+        assert!(EdidDisplayRangeVideoTimingsGTFStartFrequency::try_from(61).is_err());
+        assert!(EdidDisplayRangeVideoTimingsGTFStartFrequency::try_from(512).is_err());
+
+        assert_eq!(
+            EdidDisplayRangeVideoTimingsGTFStartFrequency::try_from(2)
+                .unwrap()
+                .into_raw(),
+            0x01
+        );
+        assert_eq!(
+            EdidDisplayRangeVideoTimingsGTFStartFrequency::try_from(510)
+                .unwrap()
+                .into_raw(),
+            0xff
+        );
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[builder(field_defaults(setter(into)))]
 pub struct EdidDisplayRangeVideoTimingsGTF {
-    #[builder(setter(into))]
     horizontal_start_frequency: EdidDisplayRangeVideoTimingsGTFStartFrequency,
-    blanking_offset: u8,
-    blanking_gradient: u16,
-    blanking_scaling_factor: u8,
-    blanking_scaling_factor_weighting: u8,
+    blanking_offset: EdidDisplayRangeVideoTimingsGTFBlankingOffset,
+    blanking_gradient: EdidDisplayRangeVideoTimingsGTFBlankingGradient,
+    blanking_scaling_factor: EdidDisplayRangeVideoTimingsGTFBlankingScalingFactor,
+    blanking_scaling_factor_weighting:
+        EdidDisplayRangeVideoTimingsGTFBlankingScalingFactorWeighting,
+}
+
+impl EdidDisplayRangeVideoTimingsGTF {
+    fn into_raw(self) -> [u8; 6] {
+        let [blank_grad_lo, blank_grad_hi] = self.blanking_gradient.into_raw();
+
+        [
+            self.horizontal_start_frequency.into_raw(),
+            self.blanking_offset.into_raw(),
+            blank_grad_lo,
+            blank_grad_hi,
+            self.blanking_scaling_factor.into_raw(),
+            self.blanking_scaling_factor_weighting.into_raw(),
+        ]
+    }
 }
 
-#[derive(Clone, Debug)]
+/// The timings an EDID 1.3 display supports within its Display Range Limits.
+///
+/// Unlike EDID 1.4, EDID 1.3 has no Range Limits Only encoding: whether the display supports
+/// the default GTF formula is reported by the `default_gtf_supported` feature, and the
+/// descriptor only tells whether a secondary GTF curve is used on top of it. A fixed frequency
+/// display that doesn't support GTF thus uses [`EdidR3DisplayRangeVideoTimingsSupport::DefaultGTF`]
+/// with `default_gtf_supported` unset.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum EdidR3DisplayRangeVideoTimingsSupport {
+    /// No secondary GTF curve. GTF is only supported if the `default_gtf_supported` feature is
+    /// set.
     DefaultGTF,
+
+    /// Secondary GTF curve. It requires the `default_gtf_supported` feature to be set.
     SecondaryGTF(EdidDisplayRangeVideoTimingsGTF),
 }
 
-#[derive(Clone, Debug, TypedBuilder)]
+#[derive(Clone, Debug, PartialEq, Eq, TypedBuilder)]
 pub struct EdidR3DisplayRangeLimits {
     min_hfreq: EdidDisplayRangeHorizontalFreq,
     max_hfreq: EdidDisplayRangeHorizontalFreq,
@@ -732,6 +2654,50 @@ pub struct EdidR3DisplayRangeLimits {
     timings_support: EdidR3DisplayRangeVideoTimingsSupport,
 }
 
+impl EdidR3DisplayRangeLimits {
+    /// Creates Display Range Limits that tightly cover a single Detailed Timing, without a
+    /// secondary GTF curve.
+    ///
+    /// Along with an unset `default_gtf_supported` feature, this is the EDID 1.3 equivalent of
+    /// the EDID 1.4 Range Limits Only descriptor.
+    ///
+    /// # Errors
+    ///
+    /// If the rates of the timing can't be expressed in a Display Range Limits descriptor.
+    pub fn from_detailed_timing(
+        dtd: &EdidDescriptorDetailedTiming,
+    ) -> Result<Self, EdidTypeConversionError<u16>> {
+        fn field<T>(value: u32, name: &'static str) -> Result<T, EdidTypeConversionError<u16>>
+        where
+            T: TryFrom<u16, Error = EdidTypeConversionError<u16>>,
+        {
+            u16::try_from(value)
+                .map_err(EdidTypeConversionError::from)
+                .and_then(T::try_from)
+                .map_err(|e| e.in_field(name))
+        }
+
+        let clock = dtd.pixel_clock.0;
+        let htotal = dtd.horizontal_total();
+        let fields = if dtd.interlace { 2 } else { 1 };
+        let vclock = clock * 1000 * fields;
+        let vtotal = htotal * dtd.vertical_frame_total();
+
+        Ok(Self {
+            min_hfreq: field(clock / htotal, "min_hfreq")?,
+            max_hfreq: field(div_round_up(&clock, &htotal), "max_hfreq")?,
+            min_vfreq: field(vclock / vtotal, "min_vfreq")?,
+            max_vfreq: field(div_round_up(&vclock, &vtotal), "max_vfreq")?,
+            max_pixelclock: field(div_round_up(&clock, &1000), "max_pixelclock")?,
+            timings_support: EdidR3DisplayRangeVideoTimingsSupport::DefaultGTF,
+        })
+    }
+
+    pub(crate) const fn timings_support(&self) -> &EdidR3DisplayRangeVideoTimingsSupport {
+        &self.timings_support
+    }
+}
+
 impl IntoBytes for EdidR3DisplayRangeLimits {
     fn into_bytes(self) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(EDID_DESCRIPTOR_PAYLOAD_LEN);
@@ -747,21 +2713,10 @@ impl IntoBytes for EdidR3DisplayRangeLimits {
                 bytes.extend_from_slice(&[0x00, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20]);
             }
             EdidR3DisplayRangeVideoTimingsSupport::SecondaryGTF(g) => {
-                let blank_grad_lo = (g.blanking_gradient & 0xff) as u8;
-                let blank_grad_hi = (g.blanking_gradient >> 8) as u8;
-
-                bytes.extend_from_slice(&[
-                    0x02,
-                    0x00,
-                    g.horizontal_start_frequency.into_raw(),
-                    g.blanking_offset * 2,
-                    blank_grad_lo,
-                    blank_grad_hi,
-                    g.blanking_scaling_factor,
-                    g.blanking_scaling_factor_weighting * 2,
-                ]);
+                bytes.extend_from_slice(&[0x02, 0x00]);
+                bytes.extend_from_slice(&g.into_raw());
             }
-        };
+        }
 
         let len = bytes.len();
         assert_eq!(
@@ -777,7 +2732,53 @@ impl IntoBytes for EdidR3DisplayRangeLimits {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_descriptor_display_range_limits_r3 {
+    use crate::{
+        test_utils::{r3_range_limits, secondary_gtf},
+        EdidDescriptorDetailedTiming, EdidDetailedTimingSizeMm, EdidR3DisplayRangeLimits,
+        EdidR3DisplayRangeVideoTimingsSupport, IntoBytes,
+    };
+
+    #[test]
+    fn test_binary_spec_secondary_gtf() {
+        // VESA EDID 1.3, Section 3.10.3.4, with the GTF Standard default coefficients and a
+        // secondary curve starting at 60kHz.
+        let limits = r3_range_limits(EdidR3DisplayRangeVideoTimingsSupport::SecondaryGTF(
+            secondary_gtf(),
+        ));
+
+        assert_eq!(
+            limits.into_bytes(),
+            [50, 90, 30, 110, 23, 0x02, 0x00, 0x1e, 0x50, 0x58, 0x02, 0x80, 0x28]
+        );
+    }
+
+    #[test]
+    fn test_from_detailed_timing() {
+        let timing = EdidDescriptorDetailedTiming::cta_1080p_60hz(
+            EdidDetailedTimingSizeMm::try_from(600).unwrap(),
+            EdidDetailedTimingSizeMm::try_from(340).unwrap(),
+        );
+
+        let limits = EdidR3DisplayRangeLimits::from_detailed_timing(&timing).unwrap();
+        assert_eq!(
+            *limits.timings_support(),
+            EdidR3DisplayRangeVideoTimingsSupport::DefaultGTF
+        );
+
+        // 67.5kHz and 60Hz, 148.5MHz rounded up to 150MHz, and no secondary GTF curve.
+        assert_eq!(
+            limits.into_bytes(),
+            [60, 60, 67, 68, 15, 0x00, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20]
+        );
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16"))]
 pub struct EdidR4DisplayRangeHorizontalFreq(bool, u8);
 
 impl TryFrom<u16> for EdidR4DisplayRangeHorizontalFreq {
@@ -800,9 +2801,19 @@ impl TryFrom<u16> for EdidR4DisplayRangeHorizontalFreq {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16"))]
 pub struct EdidR4DisplayRangeVerticalFreq(bool, u8);
 
+impl EdidR4DisplayRangeVerticalFreq {
+    const fn hz(self) -> u16 {
+        let offset = if self.0 { 255 } else { 0 };
+
+        self.1 as u16 + offset
+    }
+}
+
 impl TryFrom<u16> for EdidR4DisplayRangeVerticalFreq {
     type Error = EdidTypeConversionError<u16>;
 
@@ -822,9 +2833,46 @@ impl TryFrom<u16> for EdidR4DisplayRangeVerticalFreq {
         Ok(Self(offset, u8::try_from(value)?))
     }
 }
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_descriptor_display_range_r4_freqs {
+    use super::{EdidR4DisplayRangeHorizontalFreq, EdidR4DisplayRangeVerticalFreq};
+
+    #[test]
+    fn test_range() {
+        assert!(EdidR4DisplayRangeHorizontalFreq::try_from(0).is_err());
+        assert!(EdidR4DisplayRangeHorizontalFreq::try_from(1).is_ok());
+        assert!(EdidR4DisplayRangeHorizontalFreq::try_from(510).is_ok());
+        assert!(EdidR4DisplayRangeHorizontalFreq::try_from(511).is_err());
+
+        assert!(EdidR4DisplayRangeVerticalFreq::try_from(0).is_err());
+        assert!(EdidR4DisplayRangeVerticalFreq::try_from(1).is_ok());
+        assert!(EdidR4DisplayRangeVerticalFreq::try_from(510).is_ok());
+        assert!(EdidR4DisplayRangeVerticalFreq::try_from(511).is_err());
+    }
+
+    #[test]
+    fn test_offset() {
+        // EDID 1.4 Specification, Section 3.10.3, Table 3.26
+        assert_eq!(
+            EdidR4DisplayRangeHorizontalFreq::try_from(255).unwrap(),
+            EdidR4DisplayRangeHorizontalFreq(false, 255)
+        );
+        assert_eq!(
+            EdidR4DisplayRangeHorizontalFreq::try_from(256).unwrap(),
+            EdidR4DisplayRangeHorizontalFreq(true, 1)
+        );
+        assert_eq!(
+            EdidR4DisplayRangeVerticalFreq::try_from(510).unwrap(),
+            EdidR4DisplayRangeVerticalFreq(true, 255)
+        );
+    }
+}
+
 #[repr(u8)]
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidR4DisplayRangeVideoTimingsAspectRatio {
     Ratio_4_3 = 0,
     Ratio_16_9,
@@ -833,32 +2881,126 @@ pub enum EdidR4DisplayRangeVideoTimingsAspectRatio {
     Ratio_15_9,
 }
 
-#[derive(Clone, Copy, Debug)]
+/// Additional Pixel Clock precision for CVT, in steps of 0.25 MHz to remove from the maximum
+/// pixel clock.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
 pub struct EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff(u8);
 
-impl TryFrom<EdidDisplayRangePixelClock> for EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff {
+impl TryFrom<u8> for EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff {
     type Error = EdidTypeConversionError<u8>;
 
-    fn try_from(value: EdidDisplayRangePixelClock) -> Result<Self, Self::Error> {
-        let rounded = value.round() - value.0;
-
-        if rounded >= 10 {
-            return Err(EdidTypeConversionError::Value(String::from(
-                "Computed Additional Precision is too large.",
-            )));
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if value > Self::MAX {
+            return Err(EdidTypeConversionError::Range(
+                value,
+                Some(0),
+                Some(Self::MAX),
+            ));
         }
 
-        Ok(Self(u8::try_from(rounded)?))
+        Ok(Self(value))
+    }
+}
+
+impl From<EdidDisplayRangePixelClock> for EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff {
+    fn from(value: EdidDisplayRangePixelClock) -> Self {
+        // The maximum pixel clock is rounded up to the next 10 MHz, so the difference is always
+        // between 0 and 9 MHz, which means at most 36 steps of 0.25 MHz.
+        let steps = (value.round() - value.0) * 4;
+
+        Self(u8::try_from(steps).unwrap_or(Self::MAX).min(Self::MAX))
     }
 }
 
 impl EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff {
+    // The precision is stored on 6 bits
+    const MAX: u8 = 63;
+
     fn into_raw(self) -> u8 {
-        self.0 * 4
+        self.0
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_descriptor_display_range_cvt_pixel_clock_diff {
+    use super::{EdidDisplayRangePixelClock, EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff};
+
+    #[test]
+    fn test_range() {
+        assert!(EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff::try_from(0).is_ok());
+        assert!(EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff::try_from(63).is_ok());
+        assert!(EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff::try_from(64).is_err());
+        assert!(EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff::try_from(u8::MAX).is_err());
+    }
+
+    #[test]
+    fn test_from_pixel_clock() {
+        for (pclk, raw) in [(1, 36), (130, 0), (148, 8), (2550, 0)] {
+            let pclk = EdidDisplayRangePixelClock::try_from(pclk).unwrap();
+
+            assert_eq!(
+                EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff::from(pclk).into_raw(),
+                raw
+            );
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16"))]
+pub struct EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels(u16);
+
+impl EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels {
+    fn into_raw(self) -> u16 {
+        div_round_up(&self.0, &8)
+    }
+}
+
+impl TryFrom<u16> for EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels {
+    type Error = EdidTypeConversionError<u16>;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        // The value is stored on 10 bits, in increments of 8 pixels.
+        if value > 8184 {
+            return Err(EdidTypeConversionError::Range(value, Some(0), Some(8184)));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_descriptor_display_range_cvt_max_active_pixels {
+    use super::EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels;
+
+    #[test]
+    fn test_binary_spec() {
+        // EDID 1.4 Specification, Section 3.10.3.3, Example 1
+        assert_eq!(
+            EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels::try_from(1600)
+                .unwrap()
+                .into_raw(),
+            0xc8
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        assert!(EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels::try_from(0).is_ok());
+        assert!(EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels::try_from(8184).is_ok());
+        assert!(EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels::try_from(8185).is_err());
+        assert!(EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels::try_from(u16::MAX).is_err());
     }
 }
 
-#[derive(Clone, Debug, TypedBuilder)]
+#[derive(Clone, Debug, PartialEq, Eq, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 #[builder(mutators(
     #[allow(unreachable_pub)]
     pub fn supported_aspect_ratios(&mut self, ar: Vec<EdidR4DisplayRangeVideoTimingsAspectRatio>) {
@@ -871,40 +3013,55 @@ impl EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff {
     }
 ))]
 pub struct EdidR4DisplayRangeVideoTimingsCVTR1 {
-    // FIXME: Max 8184 pixels
-    maximum_active_pixels_per_line: u16,
+    maximum_active_pixels_per_line: EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels,
     #[builder(via_mutators)]
+    #[cfg_attr(feature = "serde", serde(default))]
     supported_aspect_ratios: Vec<EdidR4DisplayRangeVideoTimingsAspectRatio>,
     preferred_aspect_ratio: EdidR4DisplayRangeVideoTimingsAspectRatio,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     standard_cvt_blanking_supported: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     reduced_cvt_blanking_supported: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     horizontal_shrink_supported: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     horizontal_stretch_supported: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     vertical_shrink_supported: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     vertical_stretch_supported: bool,
 
     #[builder(setter(into))]
     preferred_vertical_refresh_rate: EdidDisplayRangeVerticalFreq,
+
+    /// The maximum pixel clock is expressed as the Display Range Limits maximum pixel clock,
+    /// rounded up to the next 10 MHz, minus this additional precision. If not set, it's derived
+    /// from the maximum pixel clock so that the result matches it exactly.
+    #[builder(default, setter(strip_option))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    additional_pixel_clock_precision: Option<EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidR4DisplayRangeVideoTimingsCVT {
     R1(EdidR4DisplayRangeVideoTimingsCVTR1),
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidR4DisplayRangeVideoTimingsSupport {
     DefaultGTF,
     RangeLimitsOnly,
@@ -913,7 +3070,9 @@ pub enum EdidR4DisplayRangeVideoTimingsSupport {
     CVTSupported(EdidR4DisplayRangeVideoTimingsCVT),
 }
 
-#[derive(Clone, Debug, TypedBuilder)]
+#[derive(Clone, Debug, PartialEq, Eq, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct EdidR4DisplayRangeLimits {
     #[builder(setter(into))]
     min_hfreq: EdidR4DisplayRangeHorizontalFreq,
@@ -933,6 +3092,125 @@ pub struct EdidR4DisplayRangeLimits {
     timings_support: EdidR4DisplayRangeVideoTimingsSupport,
 }
 
+impl EdidR4DisplayRangeLimits {
+    /// Creates Range Limits Only Display Range Limits that tightly cover a single Detailed
+    /// Timing.
+    ///
+    /// # Errors
+    ///
+    /// If the rates of the timing can't be expressed in a Display Range Limits descriptor.
+    pub fn from_detailed_timing(
+        dtd: &EdidDescriptorDetailedTiming,
+    ) -> Result<Self, EdidTypeConversionError<u16>> {
+        fn field<T>(value: u32, name: &'static str) -> Result<T, EdidTypeConversionError<u16>>
+        where
+            T: TryFrom<u16, Error = EdidTypeConversionError<u16>>,
+        {
+            u16::try_from(value)
+                .map_err(EdidTypeConversionError::from)
+                .and_then(T::try_from)
+                .map_err(|e| e.in_field(name))
+        }
+
+        let clock = dtd.pixel_clock.0;
+        let htotal = dtd.horizontal_total();
+        let fields = if dtd.interlace { 2 } else { 1 };
+        let vclock = clock * 1000 * fields;
+        let vtotal = htotal * dtd.vertical_frame_total();
+
+        Ok(Self {
+            min_hfreq: field(clock / htotal, "min_hfreq")?,
+            max_hfreq: field(div_round_up(&clock, &htotal), "max_hfreq")?,
+            min_vfreq: field(vclock / vtotal, "min_vfreq")?,
+            max_vfreq: field(div_round_up(&vclock, &vtotal), "max_vfreq")?,
+            max_pixelclock: field(div_round_up(&clock, &1000), "max_pixelclock")?,
+            timings_support: EdidR4DisplayRangeVideoTimingsSupport::RangeLimitsOnly,
+        })
+    }
+
+    /// Widens the vertical rate range, if needed, so that it covers the given one, in Hz.
+    pub(crate) fn cover_vertical_range(
+        &mut self,
+        min: u16,
+        max: u16,
+    ) -> Result<(), EdidTypeConversionError<u16>> {
+        self.min_vfreq = EdidR4DisplayRangeVerticalFreq::try_from(min.min(self.min_vfreq.hz()))
+            .map_err(|e| e.in_field("min_vfreq"))?;
+        self.max_vfreq = EdidR4DisplayRangeVerticalFreq::try_from(max.max(self.max_vfreq.hz()))
+            .map_err(|e| e.in_field("max_vfreq"))?;
+
+        Ok(())
+    }
+
+    /// Creates Display Range Limits that tightly cover a single Detailed Timing, and advertise
+    /// CVT Reduced Blanking support with the timing as the preferred mode.
+    ///
+    /// The preferred aspect ratio is the one the closest to the timing resolution.
+    ///
+    /// # Errors
+    ///
+    /// If the rates or the resolution of the timing can't be expressed in a Display Range Limits
+    /// descriptor.
+    pub fn cvt_from_detailed_timing(
+        dtd: &EdidDescriptorDetailedTiming,
+    ) -> Result<Self, EdidTypeConversionError<u16>> {
+        const ASPECT_RATIOS: [(EdidR4DisplayRangeVideoTimingsAspectRatio, u32, u32); 5] = [
+            (EdidR4DisplayRangeVideoTimingsAspectRatio::Ratio_4_3, 4, 3),
+            (EdidR4DisplayRangeVideoTimingsAspectRatio::Ratio_16_9, 16, 9),
+            (
+                EdidR4DisplayRangeVideoTimingsAspectRatio::Ratio_16_10,
+                16,
+                10,
+            ),
+            (EdidR4DisplayRangeVideoTimingsAspectRatio::Ratio_5_4, 5, 4),
+            (EdidR4DisplayRangeVideoTimingsAspectRatio::Ratio_15_9, 15, 9),
+        ];
+
+        let mut limits = Self::from_detailed_timing(dtd)?;
+
+        let (hactive, vactive) = dtd.frame_resolution();
+        let aspect_ratio = ASPECT_RATIOS
+            .into_iter()
+            .min_by_key(|(_, h, v)| {
+                // Distance between hactive / vactive and h / v, scaled by vactive.
+                (u32::from(hactive) * v).abs_diff(u32::from(vactive) * h) * 1000 / v
+            })
+            .map_or(
+                EdidR4DisplayRangeVideoTimingsAspectRatio::Ratio_16_9,
+                |(ar, _, _)| ar,
+            );
+
+        let fields = if dtd.interlace { 2 } else { 1 };
+        let vclock = dtd.pixel_clock.0 * 1000 * fields;
+        let vtotal = dtd.horizontal_total() * dtd.vertical_frame_total();
+        let refresh = u16::try_from((vclock + vtotal / 2) / vtotal)?;
+
+        limits.timings_support = EdidR4DisplayRangeVideoTimingsSupport::CVTSupported(
+            EdidR4DisplayRangeVideoTimingsCVT::R1(
+                EdidR4DisplayRangeVideoTimingsCVTR1::builder()
+                    .maximum_active_pixels_per_line(
+                        EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels::try_from(hactive)
+                            .map_err(|e| e.in_field("maximum_active_pixels_per_line"))?,
+                    )
+                    .preferred_aspect_ratio(aspect_ratio)
+                    .reduced_cvt_blanking_supported(true)
+                    .preferred_vertical_refresh_rate(
+                        EdidDisplayRangeVerticalFreq::try_from(refresh)
+                            .map_err(|e| e.in_field("preferred_vertical_refresh_rate"))?,
+                    )
+                    .add_supported_aspect_ratio(aspect_ratio)
+                    .build(),
+            ),
+        );
+
+        Ok(limits)
+    }
+
+    pub(crate) fn timings_support(&self) -> &EdidR4DisplayRangeVideoTimingsSupport {
+        &self.timings_support
+    }
+}
+
 impl IntoBytes for EdidR4DisplayRangeLimits {
     fn into_bytes(self) -> Vec<u8> {
         // The Display Range Limits block has a header a byte shorter than other descriptors.
@@ -971,32 +3249,24 @@ impl IntoBytes for EdidR4DisplayRangeLimits {
             }
             #[allow(deprecated)]
             EdidR4DisplayRangeVideoTimingsSupport::SecondaryGTF(g) => {
-                let blank_grad_lo = (g.blanking_gradient & 0xff) as u8;
-                let blank_grad_hi = (g.blanking_gradient >> 8) as u8;
-
-                bytes.extend_from_slice(&[
-                    0x02,
-                    0x00,
-                    g.horizontal_start_frequency.into_raw(),
-                    g.blanking_offset * 2,
-                    blank_grad_lo,
-                    blank_grad_hi,
-                    g.blanking_scaling_factor,
-                    g.blanking_scaling_factor_weighting * 2,
-                ]);
+                bytes.extend_from_slice(&[0x02, 0x00]);
+                bytes.extend_from_slice(&g.into_raw());
             }
             EdidR4DisplayRangeVideoTimingsSupport::CVTSupported(v) => {
                 match v {
                     EdidR4DisplayRangeVideoTimingsCVT::R1(cvt) => {
                         bytes.extend_from_slice(&[0x04, 0x11]);
 
-                        // FIXME: Check that it fits in 6 bits.
-                        let pclk_diff = EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff::try_from(
-                            self.max_pixelclock,
-                        )
-                        .expect("Pixel Clock value would overflow our type")
-                        .into_raw();
-                        let raw_max_pix = div_round_up(&cvt.maximum_active_pixels_per_line, &8);
+                        // The precision always fits in 6 bits, see its conversions.
+                        let pclk_diff = cvt
+                            .additional_pixel_clock_precision
+                            .unwrap_or_else(|| {
+                                EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff::from(
+                                    self.max_pixelclock,
+                                )
+                            })
+                            .into_raw();
+                        let raw_max_pix = cvt.maximum_active_pixels_per_line.into_raw();
                         let max_pix_hi = ((raw_max_pix >> 8) & 0x3) as u8;
                         let max_pix_lo = (raw_max_pix & 0xff) as u8;
 
@@ -1039,7 +3309,7 @@ impl IntoBytes for EdidR4DisplayRangeLimits {
                     }
                 }
             }
-        };
+        }
 
         let len = bytes.len();
         assert_eq!(
@@ -1057,9 +3327,90 @@ impl IntoBytes for EdidR4DisplayRangeLimits {
     }
 }
 
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_descriptor_display_range_limits_r4 {
+    use crate::{
+        EdidDisplayRangePixelClock, EdidDisplayRangeVerticalFreq, EdidR4DisplayRangeHorizontalFreq,
+        EdidR4DisplayRangeLimits, EdidR4DisplayRangeVerticalFreq,
+        EdidR4DisplayRangeVideoTimingsAspectRatio, EdidR4DisplayRangeVideoTimingsCVT,
+        EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels,
+        EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff, EdidR4DisplayRangeVideoTimingsCVTR1,
+        EdidR4DisplayRangeVideoTimingsSupport, IntoBytes,
+    };
+
+    #[test]
+    fn test_binary_cvt_precision() {
+        let limits = |pclk, precision| {
+            let cvt = EdidR4DisplayRangeVideoTimingsCVTR1::builder()
+                .maximum_active_pixels_per_line(
+                    EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels::try_from(1920).unwrap(),
+                )
+                .supported_aspect_ratios(vec![
+                    EdidR4DisplayRangeVideoTimingsAspectRatio::Ratio_16_9,
+                ])
+                .preferred_aspect_ratio(EdidR4DisplayRangeVideoTimingsAspectRatio::Ratio_16_9)
+                .preferred_vertical_refresh_rate(
+                    EdidDisplayRangeVerticalFreq::try_from(60u8).unwrap(),
+                );
+
+            let cvt = match precision {
+                Some(p) => cvt
+                    .additional_pixel_clock_precision(
+                        EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff::try_from(p).unwrap(),
+                    )
+                    .build(),
+                None => cvt.build(),
+            };
+
+            EdidR4DisplayRangeLimits::builder()
+                .min_vfreq(EdidR4DisplayRangeVerticalFreq::try_from(50).unwrap())
+                .max_vfreq(EdidR4DisplayRangeVerticalFreq::try_from(60).unwrap())
+                .min_hfreq(EdidR4DisplayRangeHorizontalFreq::try_from(30).unwrap())
+                .max_hfreq(EdidR4DisplayRangeHorizontalFreq::try_from(80).unwrap())
+                .max_pixelclock(EdidDisplayRangePixelClock::try_from(pclk).unwrap())
+                .timings_support(EdidR4DisplayRangeVideoTimingsSupport::CVTSupported(
+                    EdidR4DisplayRangeVideoTimingsCVT::R1(cvt),
+                ))
+                .build()
+                .into_bytes()
+        };
+
+        // 148 MHz, derived from the maximum pixel clock.
+        assert_eq!(limits(148, None)[5..9], [15, 0x04, 0x11, 8 << 2]);
+
+        // 148.5 MHz
+        assert_eq!(limits(150, Some(6))[5..9], [15, 0x04, 0x11, 6 << 2]);
+    }
+
+    #[test]
+    fn test_binary_offsets() {
+        // EDID 1.4 Specification, Section 3.10.3, Table 3.26
+        let limits = |min_v, max_v, min_h, max_h| {
+            EdidR4DisplayRangeLimits::builder()
+                .min_vfreq(EdidR4DisplayRangeVerticalFreq::try_from(min_v).unwrap())
+                .max_vfreq(EdidR4DisplayRangeVerticalFreq::try_from(max_v).unwrap())
+                .min_hfreq(EdidR4DisplayRangeHorizontalFreq::try_from(min_h).unwrap())
+                .max_hfreq(EdidR4DisplayRangeHorizontalFreq::try_from(max_h).unwrap())
+                .max_pixelclock(EdidDisplayRangePixelClock::try_from(600).unwrap())
+                .timings_support(EdidR4DisplayRangeVideoTimingsSupport::RangeLimitsOnly)
+                .build()
+                .into_bytes()
+        };
+
+        assert_eq!(limits(48, 255, 30, 255)[..6], [0x00, 48, 255, 30, 255, 60]);
+        assert_eq!(limits(48, 256, 30, 255)[..6], [0x02, 48, 1, 30, 255, 60]);
+        assert_eq!(limits(256, 510, 30, 255)[..6], [0x03, 1, 255, 30, 255, 60]);
+        assert_eq!(limits(48, 255, 30, 300)[..6], [0x08, 48, 255, 30, 45, 60]);
+        assert_eq!(limits(48, 255, 256, 510)[..6], [0x0c, 48, 255, 1, 255, 60]);
+    }
+}
+
 #[allow(non_camel_case_types)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum EdidR4DescriptorEstablishedTimingsIII {
+    /// The VESA DMT 1152x864 mode. The Established Timings I & II only have the Apple 1152x870
+    /// mode, see [`crate::EdidEstablishedTiming::ET_1152_870_75hz`].
     ET_1152_864_75Hz = 0,
     ET_1024_768_85Hz,
     ET_800_600_85Hz,
@@ -1101,45 +3452,458 @@ pub enum EdidR4DescriptorEstablishedTimingsIII {
     ET_1600_1200_85Hz,
     ET_1600_1200_75Hz,
 
+    // Bits 40 to 43, the lower bits of the 6th byte, are reserved and must be 0.
     ET_1920_1440_75Hz = 44,
     ET_1920_1440_60Hz,
     ET_1920_1200_85Hz,
     ET_1920_1200_75Hz,
 }
 
-#[derive(Clone, Debug, TypedBuilder)]
-#[builder(mutators(
-    #[allow(unreachable_pub)]
-    pub fn established_timings(&mut self, et: Vec<EdidR4DescriptorEstablishedTimingsIII>) {
-        self.established_timings = et;
+impl EdidR4DescriptorEstablishedTimingsIII {
+    const ALL: [Self; 44] = [
+        Self::ET_1152_864_75Hz,
+        Self::ET_1024_768_85Hz,
+        Self::ET_800_600_85Hz,
+        Self::ET_848_480_60Hz,
+        Self::ET_640_480_85Hz,
+        Self::ET_720_400_85Hz,
+        Self::ET_640_400_85Hz,
+        Self::ET_640_350_85Hz,
+        Self::ET_1280_1024_85Hz,
+        Self::ET_1280_1024_60Hz,
+        Self::ET_1280_960_85Hz,
+        Self::ET_1280_960_60Hz,
+        Self::ET_1280_768_85Hz,
+        Self::ET_1280_768_75Hz,
+        Self::ET_1280_768_60Hz,
+        Self::ET_1280_768_60Hz_RB,
+        Self::ET_1400_1050_75Hz,
+        Self::ET_1400_1050_60Hz,
+        Self::ET_1400_1050_60Hz_RB,
+        Self::ET_1440_900_85Hz,
+        Self::ET_1440_900_75Hz,
+        Self::ET_1440_900_60Hz,
+        Self::ET_1440_900_60Hz_RB,
+        Self::ET_1360_768_60Hz,
+        Self::ET_1600_1200_70Hz,
+        Self::ET_1600_1200_65Hz,
+        Self::ET_1600_1200_60Hz,
+        Self::ET_1680_1050_85Hz,
+        Self::ET_1680_1050_75Hz,
+        Self::ET_1680_1050_60Hz,
+        Self::ET_1680_1050_60Hz_RB,
+        Self::ET_1400_1050_85Hz,
+        Self::ET_1920_1200_60Hz,
+        Self::ET_1920_1200_60Hz_RB,
+        Self::ET_1856_1392_75Hz,
+        Self::ET_1856_1392_60Hz,
+        Self::ET_1792_1344_75Hz,
+        Self::ET_1792_1344_60Hz,
+        Self::ET_1600_1200_85Hz,
+        Self::ET_1600_1200_75Hz,
+        Self::ET_1920_1440_75Hz,
+        Self::ET_1920_1440_60Hz,
+        Self::ET_1920_1200_85Hz,
+        Self::ET_1920_1200_75Hz,
+    ];
+
+    fn from_bit(bit: u32) -> Option<Self> {
+        Self::ALL.into_iter().find(|et| *et as u32 == bit)
+    }
+
+    const fn is_reduced_blanking(self) -> bool {
+        matches!(
+            self,
+            Self::ET_1280_768_60Hz_RB
+                | Self::ET_1400_1050_60Hz_RB
+                | Self::ET_1440_900_60Hz_RB
+                | Self::ET_1680_1050_60Hz_RB
+                | Self::ET_1920_1200_60Hz_RB
+        )
+    }
+
+    fn from_mode(mode: (u16, u16, u8), reduced_blanking: bool) -> Option<Self> {
+        let (horizontal, vertical, refresh_rate) = mode;
+
+        Self::ALL.into_iter().find(|et| {
+            let timing = timings::established_timing_iii(*et);
+
+            et.is_reduced_blanking() == reduced_blanking
+                && timing.horizontal() == horizontal
+                && timing.vertical() == vertical
+                && timing.refresh_rate() == u16::from(refresh_rate)
+        })
+    }
+}
+
+/// Maps a (horizontal, vertical, refresh rate) mode to its Established Timing III.
+///
+/// The reduced blanking timings share their mode with the regular ones, so they are never
+/// returned and must be picked explicitly.
+impl TryFrom<(u16, u16, u8)> for EdidR4DescriptorEstablishedTimingsIII {
+    type Error = EdidTypeConversionError<String>;
+
+    fn try_from(value: (u16, u16, u8)) -> Result<Self, Self::Error> {
+        if value == (1152, 870, 75) {
+            return Err(EdidTypeConversionError::Value(String::from(
+                "1152x870@75Hz isn't an Established Timing III, it's the Apple mode of the Established Timings I & II.",
+            )));
+        }
+
+        Self::from_mode(value, false).ok_or_else(|| {
+            let (h, v, r) = value;
+
+            EdidTypeConversionError::Value(format!(
+                "{h}x{v}@{r}Hz isn't an Established Timing III."
+            ))
+        })
+    }
+}
+
+/// Parses an Established Timing III from its mode, such as `1280x1024@60` or `1280x1024@60Hz`.
+///
+/// The reduced blanking timings take an `RB` suffix, such as `1280x768@60RB`.
+impl FromStr for EdidR4DescriptorEstablishedTimingsIII {
+    type Err = EdidTypeConversionError<String>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            || EdidTypeConversionError::Value(format!("{s} isn't a valid Established Timing III."));
+
+        let (horizontal, rest) = s.split_once('x').ok_or_else(invalid)?;
+        let (vertical, rate) = rest.split_once('@').ok_or_else(invalid)?;
+        let (rate, reduced_blanking) = rate
+            .strip_suffix("RB")
+            .map_or((rate, false), |rate| (rate.trim_end(), true));
+        let rate = rate.strip_suffix("Hz").unwrap_or(rate);
+
+        let mode: (u16, u16, u8) = (
+            horizontal.parse().map_err(|_e| invalid())?,
+            vertical.parse().map_err(|_e| invalid())?,
+            rate.parse().map_err(|_e| invalid())?,
+        );
+
+        if !reduced_blanking {
+            return Self::try_from(mode);
+        }
+
+        Self::from_mode(mode, true).ok_or_else(invalid)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EdidR4DescriptorEstablishedTimingsIII {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[builder(mutators(
+    #[allow(unreachable_pub)]
+    pub fn established_timings(&mut self, et: Vec<EdidR4DescriptorEstablishedTimingsIII>) {
+        self.established_timings = et;
+    }
+
+    #[allow(unreachable_pub)]
+    pub fn add_established_timing(&mut self, et: EdidR4DescriptorEstablishedTimingsIII) {
+        self.established_timings.push(et);
+    }
+))]
+pub struct EdidR4DescriptorEstablishedTimings {
+    #[builder(via_mutators)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    established_timings: Vec<EdidR4DescriptorEstablishedTimingsIII>,
+
+    // The timing bits set in a parsed descriptor that don't match any known timing, and its
+    // reserved bytes, kept so that it serializes back to the same bytes.
+    #[builder(default, setter(skip))]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    reserved_bits: [u8; EDID_DESCRIPTOR_ESTABLISHED_TIMINGS_III_BITS_LEN],
+
+    #[builder(default, setter(skip))]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    reserved: [u8; EDID_DESCRIPTOR_ESTABLISHED_TIMINGS_III_RESERVED_LEN],
+}
+
+impl EdidR4DescriptorEstablishedTimings {
+    /// Creates an Established Timings III descriptor from a list of modes, parsed like
+    /// [`EdidR4DescriptorEstablishedTimingsIII::from_str`] does.
+    ///
+    /// # Errors
+    ///
+    /// If any of the modes isn't an Established Timing III.
+    pub fn from_modes<S: AsRef<str>>(modes: &[S]) -> Result<Self, EdidTypeConversionError<String>> {
+        Ok(Self {
+            established_timings: modes
+                .iter()
+                .map(|mode| mode.as_ref().parse())
+                .collect::<Result<_, _>>()?,
+            reserved_bits: [0; EDID_DESCRIPTOR_ESTABLISHED_TIMINGS_III_BITS_LEN],
+            reserved: [0; EDID_DESCRIPTOR_ESTABLISHED_TIMINGS_III_RESERVED_LEN],
+        })
+    }
+
+    pub(crate) fn timings(&self) -> &[EdidR4DescriptorEstablishedTimingsIII] {
+        &self.established_timings
+    }
+}
+
+/// Parses an Established Timings III descriptor.
+///
+/// The reserved bits and bytes are accepted, so that descriptors written by sinks following a
+/// later revision of the standard still parse. They are kept as is, and serialized back.
+impl TryFrom<&[u8; EDID_DESCRIPTOR_LEN]> for EdidR4DescriptorEstablishedTimings {
+    type Error = EdidTypeConversionError<u8>;
+
+    fn try_from(value: &[u8; EDID_DESCRIPTOR_LEN]) -> Result<Self, Self::Error> {
+        if value[..5] != [0, 0, 0, EDID_DESCRIPTOR_TAG_ESTABLISHED_TIMINGS, 0] {
+            return Err(EdidTypeConversionError::Value(String::from(
+                "Descriptor isn't an Established Timings III descriptor.",
+            )));
+        }
+
+        let bits = &value[6..12];
+        let mut established_timings = Vec::new();
+        let mut reserved_bits = [0; EDID_DESCRIPTOR_ESTABLISHED_TIMINGS_III_BITS_LEN];
+        for bit in 0..48 {
+            let idx = (bit / 8) as usize;
+            let mask = 1 << (bit % 8);
+            if bits[idx] & mask == 0 {
+                continue;
+            }
+
+            match EdidR4DescriptorEstablishedTimingsIII::from_bit(bit) {
+                Some(et) => established_timings.push(et),
+                None => reserved_bits[idx] |= mask,
+            }
+        }
+
+        let mut reserved = [0; EDID_DESCRIPTOR_ESTABLISHED_TIMINGS_III_RESERVED_LEN];
+        reserved.copy_from_slice(&value[12..]);
+
+        Ok(Self {
+            established_timings,
+            reserved_bits,
+            reserved,
+        })
+    }
+}
+
+impl IntoBytes for EdidR4DescriptorEstablishedTimings {
+    fn into_bytes(self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(EDID_DESCRIPTOR_PAYLOAD_LEN);
+        bytes.push(0x0a);
+
+        let mut array = self.reserved_bits;
+        for timing in self.established_timings {
+            let id = timing as u32;
+            let idx = (id / 8) as usize;
+            let shift = id % 8;
+
+            array[idx] |= 1 << shift;
+        }
+
+        bytes.extend_from_slice(&array);
+        bytes.extend_from_slice(&self.reserved);
+
+        let len = bytes.len();
+        assert_eq!(
+            len, EDID_DESCRIPTOR_PAYLOAD_LEN,
+            "Descriptor Payload is larger than it should ({len} vs expected {EDID_DESCRIPTOR_PAYLOAD_LEN} bytes)",
+        );
+
+        bytes
+    }
+
+    fn size(&self) -> usize {
+        EDID_DESCRIPTOR_PAYLOAD_LEN
+    }
+}
+
+/// An additional white point, and its gamma, of a Color Point descriptor.
+///
+/// The coordinates and gamma are stored as they are encoded in the EDID, so that the white
+/// points of an existing EDID can be reproduced exactly.
+///
+/// With the `serde` feature, it's deserialized from an array of the index, the coordinates and
+/// the gamma, as they are encoded in the EDID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "(u8, u16, u16, u8)"))]
+pub struct EdidDescriptorWhitePoint {
+    index: u8,
+    x: u16,
+    y: u16,
+    gamma: u8,
+}
+
+impl EdidDescriptorWhitePoint {
+    /// Creates a white point from its chromaticity coordinates and gamma.
+    ///
+    /// # Errors
+    ///
+    /// If the index is 0, which marks an unused white point.
+    pub fn new(
+        index: u8,
+        point: EdidChromaticityPoint,
+        gamma: EdidDisplayTransferCharacteristics,
+    ) -> Result<Self, EdidTypeConversionError<u16>> {
+        Self::from_raw(
+            index,
+            point.0.into_raw(),
+            point.1.into_raw(),
+            gamma.into_raw(),
+        )
+    }
+
+    /// Creates a white point from the values stored in the EDID: the 10 bits chromaticity
+    /// coordinates, and the gamma byte.
+    ///
+    /// # Errors
+    ///
+    /// If the index is 0, which marks an unused white point, or if a coordinate doesn't fit in
+    /// 10 bits.
+    pub fn from_raw(
+        index: u8,
+        x: u16,
+        y: u16,
+        gamma: u8,
+    ) -> Result<Self, EdidTypeConversionError<u16>> {
+        if index == 0 {
+            return Err(EdidTypeConversionError::Range(0, Some(1), Some(255)).in_field("index"));
+        }
+
+        for (value, name) in [(x, "x"), (y, "y")] {
+            if value > 0x3ff {
+                return Err(
+                    EdidTypeConversionError::Range(value, Some(0), Some(0x3ff)).in_field(name)
+                );
+            }
+        }
+
+        Ok(Self { index, x, y, gamma })
+    }
+
+    pub(crate) const fn index(self) -> u8 {
+        self.index
+    }
+
+    pub(crate) fn coordinates(self) -> (f32, f32) {
+        (f32::from(self.x) / 1024.0, f32::from(self.y) / 1024.0)
+    }
+
+    pub(crate) fn gamma(self) -> EdidDisplayTransferCharacteristics {
+        EdidDisplayTransferCharacteristics::from_raw(self.gamma)
+    }
+
+    fn into_raw(self) -> [u8; 5] {
+        let lo = u8::try_from((self.x & 0b11) << 2 | (self.y & 0b11))
+            .expect("Low bits would overflow our type");
+        let x_hi = u8::try_from(self.x >> 2).expect("Coordinate would overflow our type");
+        let y_hi = u8::try_from(self.y >> 2).expect("Coordinate would overflow our type");
+
+        [self.index, lo, x_hi, y_hi, self.gamma]
+    }
+
+    fn from_raw_bytes(bytes: [u8; 5]) -> Result<Option<Self>, EdidTypeConversionError<u16>> {
+        let [index, lo, x_hi, y_hi, gamma] = bytes;
+        if index == 0 {
+            return Ok(None);
+        }
+
+        let x = u16::from(x_hi) << 2 | u16::from(lo >> 2 & 0b11);
+        let y = u16::from(y_hi) << 2 | u16::from(lo & 0b11);
+
+        Self::from_raw(index, x, y, gamma).map(Some)
+    }
+}
+
+/// Creates a white point from its index and the values stored in the EDID, like
+/// [`EdidDescriptorWhitePoint::from_raw`].
+impl TryFrom<(u8, u16, u16, u8)> for EdidDescriptorWhitePoint {
+    type Error = EdidTypeConversionError<u16>;
+
+    fn try_from(value: (u8, u16, u16, u8)) -> Result<Self, Self::Error> {
+        let (index, x, y, gamma) = value;
+
+        Self::from_raw(index, x, y, gamma)
+    }
+}
+
+/// A Color Point descriptor, holding one or two white points in addition to the one of the
+/// base block.
+///
+/// The descriptor only holds chromaticity coordinates and gammas, there's no room for the
+/// luminance of the display. It's described by an
+/// [`EdidExtensionCTA861HdrStaticMetadataDataBlock`](crate::EdidExtensionCTA861HdrStaticMetadataDataBlock)
+/// instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct EdidDescriptorColorPoint {
+    first: EdidDescriptorWhitePoint,
+
+    #[builder(default, setter(strip_option))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    second: Option<EdidDescriptorWhitePoint>,
+}
+
+impl EdidDescriptorColorPoint {
+    pub(crate) fn white_points(&self) -> impl Iterator<Item = &EdidDescriptorWhitePoint> {
+        core::iter::once(&self.first).chain(self.second.as_ref())
     }
+}
 
-    #[allow(unreachable_pub)]
-    pub fn add_established_timing(&mut self, et: EdidR4DescriptorEstablishedTimingsIII) {
-        self.established_timings.push(et);
+/// Parses a Color Point descriptor, for example to turn a descriptor captured from an existing
+/// EDID into a typed one.
+impl TryFrom<&[u8; EDID_DESCRIPTOR_LEN]> for EdidDescriptorColorPoint {
+    type Error = EdidTypeConversionError<u16>;
+
+    fn try_from(value: &[u8; EDID_DESCRIPTOR_LEN]) -> Result<Self, Self::Error> {
+        if value[..5] != [0, 0, 0, EDID_DESCRIPTOR_TAG_COLOR_POINT, 0] {
+            return Err(EdidTypeConversionError::Value(String::from(
+                "Descriptor isn't a Color Point descriptor.",
+            )));
+        }
+
+        let white_point = |offset: usize| {
+            let mut bytes = [0; 5];
+            bytes.copy_from_slice(&value[offset..offset + 5]);
+            EdidDescriptorWhitePoint::from_raw_bytes(bytes)
+        };
+
+        let first = white_point(5)
+            .map_err(|e| e.in_field("first"))?
+            .ok_or_else(|| {
+                EdidTypeConversionError::Value(String::from(
+                    "Color Point descriptor has no white point.",
+                ))
+            })?;
+        let second = white_point(10).map_err(|e| e.in_field("second"))?;
+
+        Ok(Self { first, second })
     }
-))]
-pub struct EdidR4DescriptorEstablishedTimings {
-    #[builder(via_mutators)]
-    established_timings: Vec<EdidR4DescriptorEstablishedTimingsIII>,
 }
 
-impl IntoBytes for EdidR4DescriptorEstablishedTimings {
+impl IntoBytes for EdidDescriptorColorPoint {
     fn into_bytes(self) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(EDID_DESCRIPTOR_PAYLOAD_LEN);
-        bytes.push(0x0a);
-
-        let mut array: [u8; 6] = [0; 6];
-        for timing in self.established_timings {
-            let id = timing as u32;
-            let idx = (id / 8) as usize;
-            let shift = id % 8;
-
-            array[idx] |= 1 << shift;
-        }
 
-        bytes.extend_from_slice(&array);
-        bytes.extend_from_slice(&[0; 6]);
+        bytes.extend_from_slice(&self.first.into_raw());
+        bytes.extend_from_slice(
+            &self
+                .second
+                .map_or([0; 5], EdidDescriptorWhitePoint::into_raw),
+        );
+        bytes.extend_from_slice(&[0x0a, 0x20, 0x20]);
 
         let len = bytes.len();
         assert_eq!(
@@ -1155,31 +3919,147 @@ impl IntoBytes for EdidR4DescriptorEstablishedTimings {
     }
 }
 
-#[derive(Clone, Debug)]
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_descriptor_color_point {
+    use crate::{
+        EdidChromaticityPoint, EdidDescriptorColorPoint, EdidDescriptorWhitePoint,
+        EdidDisplayTransferCharacteristics, EdidR4Descriptor, IntoBytes,
+    };
+
+    fn d65() -> EdidDescriptorWhitePoint {
+        EdidDescriptorWhitePoint::new(
+            1,
+            EdidChromaticityPoint::try_from((0.3127, 0.3290)).unwrap(),
+            EdidDisplayTransferCharacteristics::try_from(2.2).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_binary() {
+        let bytes = EdidR4Descriptor::ColorPointData(
+            EdidDescriptorColorPoint::builder().first(d65()).build(),
+        )
+        .into_bytes();
+
+        // 0.3127 and 0.3290 are encoded as 0x140 and 0x151.
+        assert_eq!(
+            bytes,
+            [
+                0x00, 0x00, 0x00, 0xfb, 0x00, 0x01, 0x01, 0x50, 0x54, 0x78, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x0a, 0x20, 0x20
+            ]
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let color_point = EdidDescriptorColorPoint::builder()
+            .first(d65())
+            .second(EdidDescriptorWhitePoint::from_raw(2, 0x3ff, 0x155, 0xff).unwrap())
+            .build();
+
+        let mut bytes = [0; 18];
+        bytes.copy_from_slice(&EdidR4Descriptor::ColorPointData(color_point).into_bytes());
+
+        assert_eq!(bytes[10..15], [0x02, 0x0d, 0xff, 0x55, 0xff]);
+        assert_eq!(
+            EdidDescriptorColorPoint::try_from(&bytes).unwrap(),
+            color_point
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(EdidDescriptorWhitePoint::from_raw(0, 0, 0, 0).is_err());
+        assert!(EdidDescriptorWhitePoint::from_raw(1, 0x400, 0, 0).is_err());
+        assert!(EdidDescriptorWhitePoint::from_raw(1, 0, 0x400, 0).is_err());
+
+        let mut bytes = [0; 18];
+        bytes[3] = 0xfb;
+        assert!(EdidDescriptorColorPoint::try_from(&bytes).is_err());
+
+        bytes[3] = 0xfc;
+        bytes[5] = 1;
+        assert!(EdidDescriptorColorPoint::try_from(&bytes).is_err());
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum EdidR3Descriptor {
     DetailedTiming(EdidDescriptorDetailedTiming),
     Custom(EdidDescriptorCustom),
     Dummy,
     StandardTimings(()),
-    ColorPointData(()),
+    ColorPointData(EdidDescriptorColorPoint),
     ProductName(EdidDescriptorString),
     DisplayRangeLimits(EdidR3DisplayRangeLimits),
     DataString(EdidDescriptorString),
     ProductSerialNumber(EdidDescriptorString),
 }
 
+impl EdidR3Descriptor {
+    /// Creates a Product Name descriptor, followed by as many Data String descriptors as needed
+    /// to hold the rest of `name`.
+    ///
+    /// # Errors
+    ///
+    /// If the name isn't ASCII.
+    pub fn product_name(name: &str) -> Result<Vec<Self>, EdidTypeConversionError<String>> {
+        Ok(EdidDescriptorString::split(name)?
+            .into_iter()
+            .enumerate()
+            .map(|(idx, string)| {
+                if idx == 0 {
+                    Self::ProductName(string)
+                } else {
+                    Self::DataString(string)
+                }
+            })
+            .collect())
+    }
+
+    // Returns the Display Descriptor Tag, or None for a Detailed Timing Descriptor.
+    pub(crate) fn tag(&self) -> Option<u8> {
+        match self {
+            Self::DetailedTiming(_) => None,
+            Self::Custom(c) => Some(c.tag.into_raw()),
+            Self::Dummy => Some(EDID_DESCRIPTOR_TAG_DUMMY),
+            Self::StandardTimings(()) => Some(EDID_DESCRIPTOR_TAG_STANDARD_TIMINGS),
+            Self::ColorPointData(_) => Some(EDID_DESCRIPTOR_TAG_COLOR_POINT),
+            Self::ProductName(_) => Some(EDID_DESCRIPTOR_TAG_PRODUCT_NAME),
+            Self::DisplayRangeLimits(_) => Some(EDID_DESCRIPTOR_TAG_RANGE_LIMITS),
+            Self::DataString(_) => Some(EDID_DESCRIPTOR_TAG_DATA_STRING),
+            Self::ProductSerialNumber(_) => Some(EDID_DESCRIPTOR_TAG_PRODUCT_SERIAL_NUMBER),
+        }
+    }
+}
+
 impl IntoBytes for EdidR3Descriptor {
     fn into_bytes(self) -> Vec<u8> {
         let bytes = match self {
             Self::DetailedTiming(dtd) => dtd.into_bytes(),
             Self::Custom(c) => c.into_bytes(),
-            Self::Dummy => Vec::from(&[0, 0, 0, 0x10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+            Self::Dummy => {
+                let mut bytes = vec![0; EDID_DESCRIPTOR_LEN];
+                bytes[3] = EDID_DESCRIPTOR_TAG_DUMMY;
+
+                bytes
+            }
             Self::StandardTimings(()) => unimplemented!(),
-            Self::ColorPointData(()) => unimplemented!(),
+            Self::ColorPointData(c) => {
+                let mut bytes: Vec<u8> = Vec::with_capacity(EDID_DESCRIPTOR_LEN);
+
+                bytes.extend_from_slice(&[0, 0, 0, EDID_DESCRIPTOR_TAG_COLOR_POINT, 0]);
+                bytes.extend_from_slice(&c.into_bytes());
+
+                bytes
+            }
             Self::ProductName(v) => {
                 let mut bytes: Vec<u8> = Vec::with_capacity(EDID_DESCRIPTOR_LEN);
 
-                bytes.extend_from_slice(&[0, 0, 0, 0xfc, 0]);
+                bytes.extend_from_slice(&[0, 0, 0, EDID_DESCRIPTOR_TAG_PRODUCT_NAME, 0]);
                 bytes.extend_from_slice(&v.into_bytes());
 
                 bytes
@@ -1187,7 +4067,7 @@ impl IntoBytes for EdidR3Descriptor {
             Self::DisplayRangeLimits(drl) => {
                 let mut bytes: Vec<u8> = Vec::with_capacity(EDID_DESCRIPTOR_LEN);
 
-                bytes.extend_from_slice(&[0, 0, 0, 0xfd, 0]);
+                bytes.extend_from_slice(&[0, 0, 0, EDID_DESCRIPTOR_TAG_RANGE_LIMITS, 0]);
                 bytes.extend_from_slice(&drl.into_bytes());
 
                 bytes
@@ -1195,7 +4075,7 @@ impl IntoBytes for EdidR3Descriptor {
             Self::DataString(v) => {
                 let mut bytes: Vec<u8> = Vec::with_capacity(EDID_DESCRIPTOR_LEN);
 
-                bytes.extend_from_slice(&[0, 0, 0, 0xfe, 0]);
+                bytes.extend_from_slice(&[0, 0, 0, EDID_DESCRIPTOR_TAG_DATA_STRING, 0]);
                 bytes.extend_from_slice(&v.into_bytes());
 
                 bytes
@@ -1203,7 +4083,7 @@ impl IntoBytes for EdidR3Descriptor {
             Self::ProductSerialNumber(v) => {
                 let mut bytes: Vec<u8> = Vec::with_capacity(EDID_DESCRIPTOR_LEN);
 
-                bytes.extend_from_slice(&[0, 0, 0, 0xff, 0]);
+                bytes.extend_from_slice(&[0, 0, 0, EDID_DESCRIPTOR_TAG_PRODUCT_SERIAL_NUMBER, 0]);
                 bytes.extend_from_slice(&v.into_bytes());
 
                 bytes
@@ -1226,7 +4106,8 @@ impl IntoBytes for EdidR3Descriptor {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidR4Descriptor {
     DetailedTiming(EdidDescriptorDetailedTiming),
     Custom(EdidDescriptorCustom),
@@ -1235,13 +4116,58 @@ pub enum EdidR4Descriptor {
     CVT(()),
     DisplayColorManagement(()),
     StandardTimings(()),
-    ColorPointData(()),
+    ColorPointData(EdidDescriptorColorPoint),
     ProductName(EdidDescriptorString),
     DisplayRangeLimits(EdidR4DisplayRangeLimits),
     DataString(EdidDescriptorString),
     ProductSerialNumber(EdidDescriptorString),
 }
 
+impl EdidR4Descriptor {
+    /// Creates a Product Name descriptor, followed by as many Data String descriptors as needed
+    /// to hold the rest of `name`.
+    ///
+    /// # Errors
+    ///
+    /// If the name isn't ASCII.
+    pub fn product_name(name: &str) -> Result<Vec<Self>, EdidTypeConversionError<String>> {
+        Ok(EdidR3Descriptor::product_name(name)?
+            .into_iter()
+            .map(|desc| match desc {
+                EdidR3Descriptor::ProductName(string) => Self::ProductName(string),
+                EdidR3Descriptor::DataString(string) => Self::DataString(string),
+                EdidR3Descriptor::DetailedTiming(_)
+                | EdidR3Descriptor::Custom(_)
+                | EdidR3Descriptor::Dummy
+                | EdidR3Descriptor::StandardTimings(())
+                | EdidR3Descriptor::ColorPointData(_)
+                | EdidR3Descriptor::DisplayRangeLimits(_)
+                | EdidR3Descriptor::ProductSerialNumber(_) => {
+                    unreachable!("Only string descriptors are created")
+                }
+            })
+            .collect())
+    }
+
+    // Returns the Display Descriptor Tag, or None for a Detailed Timing Descriptor.
+    pub(crate) fn tag(&self) -> Option<u8> {
+        match self {
+            Self::DetailedTiming(_) => None,
+            Self::Custom(c) => Some(c.tag.into_raw()),
+            Self::Dummy => Some(EDID_DESCRIPTOR_TAG_DUMMY),
+            Self::EstablishedTimings(_) => Some(EDID_DESCRIPTOR_TAG_ESTABLISHED_TIMINGS),
+            Self::CVT(()) => Some(EDID_DESCRIPTOR_TAG_CVT),
+            Self::DisplayColorManagement(()) => Some(EDID_DESCRIPTOR_TAG_DISPLAY_COLOR_MANAGEMENT),
+            Self::StandardTimings(()) => Some(EDID_DESCRIPTOR_TAG_STANDARD_TIMINGS),
+            Self::ColorPointData(_) => Some(EDID_DESCRIPTOR_TAG_COLOR_POINT),
+            Self::ProductName(_) => Some(EDID_DESCRIPTOR_TAG_PRODUCT_NAME),
+            Self::DisplayRangeLimits(_) => Some(EDID_DESCRIPTOR_TAG_RANGE_LIMITS),
+            Self::DataString(_) => Some(EDID_DESCRIPTOR_TAG_DATA_STRING),
+            Self::ProductSerialNumber(_) => Some(EDID_DESCRIPTOR_TAG_PRODUCT_SERIAL_NUMBER),
+        }
+    }
+}
+
 impl IntoBytes for EdidR4Descriptor {
     fn into_bytes(self) -> Vec<u8> {
         let bytes = match self {
@@ -1251,7 +4177,7 @@ impl IntoBytes for EdidR4Descriptor {
             Self::EstablishedTimings(et) => {
                 let mut bytes = Vec::with_capacity(EDID_DESCRIPTOR_LEN);
 
-                bytes.extend_from_slice(&[0, 0, 0, 0xf7, 0]);
+                bytes.extend_from_slice(&[0, 0, 0, EDID_DESCRIPTOR_TAG_ESTABLISHED_TIMINGS, 0]);
                 bytes.extend_from_slice(&et.into_bytes());
 
                 bytes
@@ -1259,12 +4185,12 @@ impl IntoBytes for EdidR4Descriptor {
             Self::CVT(()) => unimplemented!(),
             Self::DisplayColorManagement(()) => unimplemented!(),
             Self::StandardTimings(()) => unimplemented!(),
-            Self::ColorPointData(()) => unimplemented!(),
+            Self::ColorPointData(c) => EdidR3Descriptor::ColorPointData(c).into_bytes(),
             Self::ProductName(v) => EdidR3Descriptor::ProductName(v).into_bytes(),
             Self::DisplayRangeLimits(drl) => {
                 let mut bytes = Vec::with_capacity(EDID_DESCRIPTOR_LEN);
 
-                bytes.extend_from_slice(&[0, 0, 0, 0xfd]);
+                bytes.extend_from_slice(&[0, 0, 0, EDID_DESCRIPTOR_TAG_RANGE_LIMITS]);
                 bytes.extend_from_slice(&drl.into_bytes());
 
                 bytes
@@ -1290,6 +4216,7 @@ impl IntoBytes for EdidR4Descriptor {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use crate::{EdidR4Descriptor, IntoBytes};
 
@@ -1329,43 +4256,669 @@ mod tests {
     }
 }
 
-#[derive(Clone, Debug)]
+/// A descriptor of the base block.
+///
+/// More kinds of descriptors might be added, so matches outside of redid need a wildcard arm.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum EdidDescriptor {
     R3(EdidR3Descriptor),
     R4(EdidR4Descriptor),
+
+    /// A descriptor serialized verbatim, to carry descriptors that aren't modelled, or are
+    /// malformed, unchanged.
+    Raw([u8; EDID_DESCRIPTOR_LEN]),
 }
 
-impl IntoBytes for Vec<EdidDescriptor> {
-    fn into_bytes(self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(EDID_DESCRIPTORS_NUM * EDID_DESCRIPTOR_LEN);
+fn slots_into_descriptors<D>(
+    preferred: EdidDescriptor,
+    slots: [Option<D>; EDID_DESCRIPTORS_NUM - 1],
+    wrap: fn(D) -> EdidDescriptor,
+    dummy: D,
+) -> Vec<EdidDescriptor>
+where
+    D: Clone,
+{
+    let used = slots
+        .iter()
+        .rposition(Option::is_some)
+        .map_or(0, |pos| pos + 1);
+
+    // Unset slots followed by a set one are filled with Dummy Descriptors to keep the positions.
+    core::iter::once(preferred)
+        .chain(
+            slots
+                .into_iter()
+                .take(used)
+                .map(|slot| wrap(slot.unwrap_or_else(|| dummy.clone()))),
+        )
+        .collect()
+}
+
+fn slots_from_vec<D>(
+    descriptors: Vec<D>,
+    preferred: fn(D) -> Option<EdidDescriptorDetailedTiming>,
+) -> Result<
+    (
+        EdidDescriptorDetailedTiming,
+        [Option<D>; EDID_DESCRIPTORS_NUM - 1],
+    ),
+    EdidTypeConversionError<usize>,
+> {
+    let len = descriptors.len();
+    if len > EDID_DESCRIPTORS_NUM {
+        return Err(EdidTypeConversionError::Range(
+            len,
+            None,
+            Some(EDID_DESCRIPTORS_NUM),
+        ));
+    }
+
+    let mut iter = descriptors.into_iter();
+    let Some(dtd) = iter.next().and_then(preferred) else {
+        return Err(EdidTypeConversionError::Value(String::from(
+            "The first descriptor must be a Detailed Timing.",
+        )));
+    };
+
+    Ok((dtd, [iter.next(), iter.next(), iter.next()]))
+}
+
+/// The descriptors of an EDID 1.3 base block, by position. The first one is always the preferred
+/// timing.
+#[derive(Clone, Debug, PartialEq, Eq, TypedBuilder)]
+pub struct EdidR3DescriptorSlots {
+    preferred: EdidDescriptorDetailedTiming,
+
+    #[builder(default, setter(strip_option))]
+    slot2: Option<EdidR3Descriptor>,
+
+    #[builder(default, setter(strip_option))]
+    slot3: Option<EdidR3Descriptor>,
+
+    #[builder(default, setter(strip_option))]
+    slot4: Option<EdidR3Descriptor>,
+}
+
+impl EdidR3DescriptorSlots {
+    /// Creates the slots from a list of descriptors, the first one being the preferred timing.
+    ///
+    /// # Errors
+    ///
+    /// If the first descriptor isn't a Detailed Timing, or if there's more descriptors than
+    /// slots.
+    pub fn from_vec(
+        descriptors: Vec<EdidR3Descriptor>,
+    ) -> Result<Self, EdidTypeConversionError<usize>> {
+        let (preferred, [slot2, slot3, slot4]) = slots_from_vec(descriptors, |desc| {
+            let EdidR3Descriptor::DetailedTiming(dtd) = desc else {
+                return None;
+            };
+
+            Some(dtd)
+        })?;
+
+        Ok(Self {
+            preferred,
+            slot2,
+            slot3,
+            slot4,
+        })
+    }
+
+    pub(crate) fn into_descriptors(self) -> Vec<EdidDescriptor> {
+        slots_into_descriptors(
+            EdidDescriptor::R3(EdidR3Descriptor::DetailedTiming(self.preferred)),
+            [self.slot2, self.slot3, self.slot4],
+            EdidDescriptor::R3,
+            EdidR3Descriptor::Dummy,
+        )
+    }
+}
+
+/// The descriptors of an EDID 1.4 base block, by position. The first one is always the preferred
+/// timing.
+#[derive(Clone, Debug, PartialEq, Eq, TypedBuilder)]
+pub struct EdidR4DescriptorSlots {
+    preferred: EdidDescriptorDetailedTiming,
+
+    #[builder(default, setter(strip_option))]
+    slot2: Option<EdidR4Descriptor>,
+
+    #[builder(default, setter(strip_option))]
+    slot3: Option<EdidR4Descriptor>,
 
-        let mut last_idx = 0;
-        for (idx, desc) in self.into_iter().enumerate() {
-            let desc_bytes = match desc {
-                EdidDescriptor::R3(e) => e.into_bytes(),
-                EdidDescriptor::R4(e) => e.into_bytes(),
+    #[builder(default, setter(strip_option))]
+    slot4: Option<EdidR4Descriptor>,
+}
+
+impl EdidR4DescriptorSlots {
+    /// Creates the slots from a list of descriptors, the first one being the preferred timing.
+    ///
+    /// # Errors
+    ///
+    /// If the first descriptor isn't a Detailed Timing, or if there's more descriptors than
+    /// slots.
+    pub fn from_vec(
+        descriptors: Vec<EdidR4Descriptor>,
+    ) -> Result<Self, EdidTypeConversionError<usize>> {
+        let (preferred, [slot2, slot3, slot4]) = slots_from_vec(descriptors, |desc| {
+            let EdidR4Descriptor::DetailedTiming(dtd) = desc else {
+                return None;
             };
 
-            last_idx = idx;
-            bytes.extend_from_slice(&desc_bytes);
+            Some(dtd)
+        })?;
+
+        Ok(Self {
+            preferred,
+            slot2,
+            slot3,
+            slot4,
+        })
+    }
+
+    pub(crate) fn into_descriptors(self) -> Vec<EdidDescriptor> {
+        slots_into_descriptors(
+            EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(self.preferred)),
+            [self.slot2, self.slot3, self.slot4],
+            EdidDescriptor::R4,
+            EdidR4Descriptor::Dummy,
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_descriptor_established_timings_iii {
+    use crate::{
+        timings::established_timing_iii, EdidEstablishedTiming, EdidR4Descriptor,
+        EdidR4DescriptorEstablishedTimings, EdidR4DescriptorEstablishedTimingsIII, IntoBytes,
+        EDID_DESCRIPTOR_LEN,
+    };
+
+    #[test]
+    fn test_from_mode() {
+        assert_eq!(
+            EdidR4DescriptorEstablishedTimingsIII::try_from((1152, 864, 75)).unwrap(),
+            EdidR4DescriptorEstablishedTimingsIII::ET_1152_864_75Hz
+        );
+        assert_eq!(
+            EdidR4DescriptorEstablishedTimingsIII::try_from((1440, 900, 60)).unwrap(),
+            EdidR4DescriptorEstablishedTimingsIII::ET_1440_900_60Hz
+        );
+        assert!(EdidR4DescriptorEstablishedTimingsIII::try_from((640, 480, 60)).is_err());
+
+        // Each timing is the only one with its mode and blanking.
+        for et in EdidR4DescriptorEstablishedTimingsIII::ALL {
+            let timing = established_timing_iii(et);
+            let suffix = if et.is_reduced_blanking() { "RB" } else { "" };
+            let mode = format!(
+                "{}x{}@{}{suffix}",
+                timing.horizontal(),
+                timing.vertical(),
+                timing.refresh_rate()
+            );
+
+            assert_eq!(
+                mode.parse::<EdidR4DescriptorEstablishedTimingsIII>()
+                    .unwrap(),
+                et
+            );
         }
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "1280x1024@60"
+                .parse::<EdidR4DescriptorEstablishedTimingsIII>()
+                .unwrap(),
+            EdidR4DescriptorEstablishedTimingsIII::ET_1280_1024_60Hz
+        );
+        assert_eq!(
+            "1920x1440@75Hz"
+                .parse::<EdidR4DescriptorEstablishedTimingsIII>()
+                .unwrap(),
+            EdidR4DescriptorEstablishedTimingsIII::ET_1920_1440_75Hz
+        );
+        assert_eq!(
+            "1280x768@60RB"
+                .parse::<EdidR4DescriptorEstablishedTimingsIII>()
+                .unwrap(),
+            EdidR4DescriptorEstablishedTimingsIII::ET_1280_768_60Hz_RB
+        );
+        assert_eq!(
+            "1920x1200@60Hz RB"
+                .parse::<EdidR4DescriptorEstablishedTimingsIII>()
+                .unwrap(),
+            EdidR4DescriptorEstablishedTimingsIII::ET_1920_1200_60Hz_RB
+        );
 
-        for _ in (last_idx + 1)..EDID_DESCRIPTORS_NUM {
-            bytes.extend_from_slice(&EdidR3Descriptor::Dummy.into_bytes());
+        for mode in [
+            "1280x1024@85RB",
+            "640x480@60",
+            "1280x1024",
+            "1280x@60",
+            "foo",
+        ] {
+            assert!(
+                mode.parse::<EdidR4DescriptorEstablishedTimingsIII>()
+                    .is_err(),
+                "{mode} should be rejected"
+            );
         }
+    }
 
+    #[test]
+    fn test_from_modes() {
         assert_eq!(
-            bytes.len(),
-            EDID_DESCRIPTOR_LEN * EDID_DESCRIPTORS_NUM,
-            "Descriptor Size has a different size than it should ({} vs expected {})",
-            bytes.len(),
-            EDID_DESCRIPTOR_LEN * EDID_DESCRIPTORS_NUM
+            EdidR4DescriptorEstablishedTimings::from_modes(&["1280x1024@60", "1440x900@60RB"])
+                .unwrap(),
+            EdidR4DescriptorEstablishedTimings::builder()
+                .add_established_timing(EdidR4DescriptorEstablishedTimingsIII::ET_1280_1024_60Hz)
+                .add_established_timing(EdidR4DescriptorEstablishedTimingsIII::ET_1440_900_60Hz_RB)
+                .build()
+        );
+        assert!(
+            EdidR4DescriptorEstablishedTimings::from_modes(&["1280x1024@60", "640x480@60"])
+                .is_err()
         );
+    }
 
-        bytes
+    #[test]
+    fn test_reserved_bits() {
+        // EDID 1.4 Specification, Section 3.10.3.9: bits 40 to 43 are reserved.
+        assert!(EdidR4DescriptorEstablishedTimingsIII::ALL
+            .iter()
+            .all(|et| !(40..44).contains(&(*et as u32))));
+
+        let bytes = EdidR4Descriptor::EstablishedTimings(
+            EdidR4DescriptorEstablishedTimings::builder()
+                .established_timings(EdidR4DescriptorEstablishedTimingsIII::ALL.to_vec())
+                .build(),
+        )
+        .into_bytes();
+        assert_eq!(&bytes[6..12], &[0xff, 0xff, 0xff, 0xff, 0xff, 0xf0]);
+        assert_eq!(&bytes[12..], &[0; 6]);
+    }
+
+    #[test]
+    fn test_parse() {
+        let et = EdidR4DescriptorEstablishedTimings::builder()
+            .add_established_timing(EdidR4DescriptorEstablishedTimingsIII::ET_1152_864_75Hz)
+            .add_established_timing(EdidR4DescriptorEstablishedTimingsIII::ET_1600_1200_75Hz)
+            .add_established_timing(EdidR4DescriptorEstablishedTimingsIII::ET_1920_1200_75Hz)
+            .build();
+        let bytes: [u8; EDID_DESCRIPTOR_LEN] = EdidR4Descriptor::EstablishedTimings(et.clone())
+            .into_bytes()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            EdidR4DescriptorEstablishedTimings::try_from(&bytes).unwrap(),
+            et
+        );
+
+        // The reserved bits and bytes are kept, and serialized back.
+        let mut reserved = bytes;
+        reserved[11] |= 0x0f;
+        reserved[12] = 0xff;
+        let parsed = EdidR4DescriptorEstablishedTimings::try_from(&reserved).unwrap();
+        assert_eq!(parsed.timings(), et.timings());
+        assert_eq!(
+            EdidR4Descriptor::EstablishedTimings(parsed).into_bytes(),
+            reserved
+        );
+
+        let mut other = bytes;
+        other[3] = 0xfa;
+        assert!(EdidR4DescriptorEstablishedTimings::try_from(&other).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize() {
+        let timings: Vec<EdidR4DescriptorEstablishedTimingsIII> =
+            serde_json::from_str(r#"["1280x1024@60", "1680x1050@60RB"]"#).unwrap();
+
+        assert_eq!(
+            timings,
+            vec![
+                EdidR4DescriptorEstablishedTimingsIII::ET_1280_1024_60Hz,
+                EdidR4DescriptorEstablishedTimingsIII::ET_1680_1050_60Hz_RB
+            ]
+        );
+    }
+
+    #[test]
+    fn test_1152_modes() {
+        // The Apple and VESA 1152 modes only exist in one table each, and trying to put them in
+        // the other one points to the right place.
+        assert_eq!(
+            EdidEstablishedTiming::try_from((1152, 870, 75)).unwrap(),
+            EdidEstablishedTiming::ET_1152_870_75hz
+        );
+        assert!(EdidEstablishedTiming::try_from((1152, 864, 75))
+            .unwrap_err()
+            .to_string()
+            .contains("Established Timing III"));
+        assert!("1152x864@75"
+            .parse::<EdidEstablishedTiming>()
+            .unwrap_err()
+            .to_string()
+            .contains("Established Timing III"));
+        assert!(
+            EdidR4DescriptorEstablishedTimingsIII::try_from((1152, 870, 75))
+                .unwrap_err()
+                .to_string()
+                .contains("Established Timings I & II")
+        );
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_descriptor_slots {
+    use crate::{
+        test_utils::edid_r4_builder, EdidDescriptor, EdidDescriptorDetailedTiming,
+        EdidDescriptorString, EdidDetailedTimingSizeMm, EdidR4Descriptor, EdidR4DescriptorSlots,
+        EdidValidationError,
+    };
+
+    fn dtd() -> EdidDescriptorDetailedTiming {
+        EdidDescriptorDetailedTiming::cta_1080i_60hz(
+            EdidDetailedTimingSizeMm::try_from(0).unwrap(),
+            EdidDetailedTimingSizeMm::try_from(0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_positions() {
+        let name = EdidR4Descriptor::ProductName(EdidDescriptorString::try_from("ABC").unwrap());
+
+        let slots = EdidR4DescriptorSlots::builder()
+            .preferred(dtd())
+            .slot3(name.clone())
+            .build();
+
+        assert_eq!(
+            slots.into_descriptors(),
+            vec![
+                EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(dtd())),
+                EdidDescriptor::R4(EdidR4Descriptor::Dummy),
+                EdidDescriptor::R4(name),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let name = EdidR4Descriptor::ProductName(EdidDescriptorString::try_from("ABC").unwrap());
+
+        assert_eq!(
+            EdidR4DescriptorSlots::from_vec(vec![
+                EdidR4Descriptor::DetailedTiming(dtd()),
+                name.clone()
+            ])
+            .unwrap(),
+            EdidR4DescriptorSlots::builder()
+                .preferred(dtd())
+                .slot2(name.clone())
+                .build()
+        );
+
+        assert!(EdidR4DescriptorSlots::from_vec(Vec::new()).is_err());
+        assert!(EdidR4DescriptorSlots::from_vec(vec![name]).is_err());
+        assert!(
+            EdidR4DescriptorSlots::from_vec(vec![EdidR4Descriptor::DetailedTiming(dtd()); 5])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_mixed_setters() {
+        let slots = || EdidR4DescriptorSlots::builder().preferred(dtd()).build();
+
+        assert!(edid_r4_builder!().descriptor_slots(slots()).build().is_ok());
+        assert_eq!(
+            edid_r4_builder!()
+                .descriptor_slots(slots())
+                .add_descriptor(EdidR4Descriptor::Dummy)
+                .build()
+                .unwrap_err(),
+            EdidValidationError::MixedDescriptorSetters
+        );
+        assert_eq!(
+            edid_r4_builder!()
+                .add_descriptor(EdidR4Descriptor::Dummy)
+                .descriptor_slots(slots())
+                .build()
+                .unwrap_err(),
+            EdidValidationError::MixedDescriptorSetters
+        );
+    }
+}
+
+/// How to fill the descriptor slots that haven't been set.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum EdidDescriptorPadding {
+    /// Use Dummy Descriptors, as recommended by the specification.
+    #[default]
+    Dummy,
+
+    /// Use Manufacturer Specified Descriptors with the given tag, and an empty payload.
+    Custom(EdidDescriptorCustomTag),
+
+    /// All the descriptors must be set.
+    Forbidden,
+}
+
+impl EdidDescriptorPadding {
+    // Returns the tag of the padding descriptors, or None if padding is forbidden.
+    pub(crate) fn tag(self) -> Option<u8> {
+        match self {
+            Self::Dummy => Some(EDID_DESCRIPTOR_TAG_DUMMY),
+            Self::Custom(tag) => Some(tag.into_raw()),
+            Self::Forbidden => None,
+        }
+    }
+}
+
+// Returns the value of the descriptor at the given index that can't be serialized exactly.
+fn serialization_warning(idx: usize, desc: &EdidDescriptor) -> Option<EdidSerializationWarning> {
+    match desc {
+        EdidDescriptor::R3(EdidR3Descriptor::DetailedTiming(dtd))
+        | EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(dtd)) => dtd
+            .pixel_clock
+            .truncation()
+            .map(|clock| EdidSerializationWarning::DetailedTimingPixelClockTruncated(idx, clock)),
+        EdidDescriptor::R3(EdidR3Descriptor::DisplayRangeLimits(limits)) => limits
+            .max_pixelclock
+            .rounding()
+            .map(|clock| EdidSerializationWarning::RangeLimitsPixelClockRounded(idx, clock)),
+        // The CVT support carries the additional precision to express the exact clock.
+        EdidDescriptor::R4(EdidR4Descriptor::DisplayRangeLimits(limits))
+            if !matches!(
+                limits.timings_support,
+                EdidR4DisplayRangeVideoTimingsSupport::CVTSupported(_)
+            ) =>
+        {
+            limits
+                .max_pixelclock
+                .rounding()
+                .map(|clock| EdidSerializationWarning::RangeLimitsPixelClockRounded(idx, clock))
+        }
+        EdidDescriptor::R3(
+            EdidR3Descriptor::ProductName(s)
+            | EdidR3Descriptor::DataString(s)
+            | EdidR3Descriptor::ProductSerialNumber(s),
+        )
+        | EdidDescriptor::R4(
+            EdidR4Descriptor::ProductName(s)
+            | EdidR4Descriptor::DataString(s)
+            | EdidR4Descriptor::ProductSerialNumber(s),
+        ) => s
+            .is_padded()
+            .then_some(EdidSerializationWarning::DescriptorStringPadded(idx)),
+        EdidDescriptor::R3(_) | EdidDescriptor::R4(_) | EdidDescriptor::Raw(_) => None,
+    }
+}
+
+pub(crate) fn descriptors_into_bytes(
+    descriptors: Vec<EdidDescriptor>,
+    padding: EdidDescriptorPadding,
+    warnings: &mut Vec<EdidSerializationWarning>,
+    fields: &mut FieldMap,
+) -> Vec<u8> {
+    // The builders reject the descriptors that don't fit, and the missing ones when padding is
+    // forbidden, see Edid::check_layout().
+    debug_assert!(
+        descriptors.len() <= EDID_DESCRIPTORS_NUM,
+        "Too many descriptors ({} vs at most {EDID_DESCRIPTORS_NUM})",
+        descriptors.len()
+    );
+    debug_assert!(
+        padding != EdidDescriptorPadding::Forbidden || descriptors.len() == EDID_DESCRIPTORS_NUM,
+        "Padding is forbidden, but only {} descriptors are set",
+        descriptors.len()
+    );
+
+    let mut bytes = Vec::with_capacity(EDID_DESCRIPTORS_NUM * EDID_DESCRIPTOR_LEN);
+
+    let num_padding = EDID_DESCRIPTORS_NUM.saturating_sub(descriptors.len());
+    for (idx, desc) in descriptors
+        .into_iter()
+        .take(EDID_DESCRIPTORS_NUM)
+        .enumerate()
+    {
+        warnings.extend(serialization_warning(idx, &desc));
+
+        let desc_bytes = match desc {
+            EdidDescriptor::R3(e) => e.into_bytes(),
+            EdidDescriptor::R4(e) => e.into_bytes(),
+            EdidDescriptor::Raw(bytes) => Vec::from(bytes),
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            index = bytes.len() / EDID_DESCRIPTOR_LEN,
+            bytes = ?desc_bytes,
+            "Descriptor serialized"
+        );
+
+        fields.push(&mut bytes, format!("descriptors[{idx}]"), &desc_bytes);
+    }
+
+    let padding_start = bytes.len();
+    for _ in 0..num_padding {
+        let desc_bytes = match padding {
+            // Padding is never needed when it's forbidden, see above.
+            EdidDescriptorPadding::Dummy | EdidDescriptorPadding::Forbidden => {
+                EdidR3Descriptor::Dummy.into_bytes()
+            }
+            EdidDescriptorPadding::Custom(tag) => EdidR3Descriptor::Custom(EdidDescriptorCustom {
+                tag,
+                payload: EdidDescriptorCustomPayload(Vec::new()),
+                padding: 0,
+            })
+            .into_bytes(),
+        };
+
+        bytes.extend_from_slice(&desc_bytes);
+    }
+
+    if num_padding > 0 {
+        fields.record("descriptor_padding", padding_start..bytes.len());
+    }
+
+    assert_eq!(
+        bytes.len(),
+        EDID_DESCRIPTOR_LEN * EDID_DESCRIPTORS_NUM,
+        "Descriptor Size has a different size than it should ({} vs expected {})",
+        bytes.len(),
+        EDID_DESCRIPTOR_LEN * EDID_DESCRIPTORS_NUM
+    );
+
+    bytes
+}
+
+impl IntoBytes for Vec<EdidDescriptor> {
+    fn into_bytes(self) -> Vec<u8> {
+        descriptors_into_bytes(
+            self,
+            EdidDescriptorPadding::Dummy,
+            &mut Vec::new(),
+            &mut FieldMap::default(),
+        )
     }
 
     fn size(&self) -> usize {
         EDID_DESCRIPTOR_LEN * EDID_DESCRIPTORS_NUM
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_descriptors_padding {
+    use super::{descriptors_into_bytes, EdidDescriptorPadding};
+    use crate::{
+        field_map::FieldMap, EdidDescriptor, EdidDescriptorCustomTag, EdidDescriptorString,
+        EdidR3Descriptor, EdidSerializationWarning, IntoBytes,
+    };
+
+    #[test]
+    fn test_dummy() {
+        let bytes = Vec::<EdidDescriptor>::new().into_bytes();
+
+        assert_eq!(bytes.len(), 72);
+        for desc in bytes.chunks(18) {
+            assert_eq!(
+                desc,
+                &[0, 0, 0, 0x10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+            );
+        }
+    }
+
+    #[test]
+    fn test_custom() {
+        let name = EdidDescriptor::R3(EdidR3Descriptor::ProductName(
+            EdidDescriptorString::try_from("Monitor").unwrap(),
+        ));
+
+        let mut warnings = Vec::new();
+        let bytes = descriptors_into_bytes(
+            vec![name],
+            EdidDescriptorPadding::Custom(EdidDescriptorCustomTag::try_from(0).unwrap()),
+            &mut warnings,
+            &mut FieldMap::default(),
+        );
+
+        assert_eq!(
+            warnings,
+            vec![EdidSerializationWarning::DescriptorStringPadded(0)]
+        );
+        assert_eq!(bytes.len(), 72);
+        assert_eq!(&bytes[0..5], &[0, 0, 0, 0xfc, 0]);
+        assert_eq!(&bytes[18..], &[0; 54]);
+    }
+
+    #[test]
+    fn test_raw() {
+        let raw = [
+            0x00, 0x00, 0x00, 0x0f, 0x00, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x00,
+            0x00, 0x00, 0x00, 0xff,
+        ];
+
+        let bytes = descriptors_into_bytes(
+            vec![EdidDescriptor::Raw(raw)],
+            EdidDescriptorPadding::Dummy,
+            &mut Vec::new(),
+            &mut FieldMap::default(),
+        );
+
+        assert_eq!(bytes.len(), 72);
+        assert_eq!(&bytes[0..18], &raw);
+        assert_eq!(&bytes[18..22], &[0, 0, 0, 0x10]);
+    }
+}
@@ -0,0 +1,948 @@
+//! `DisplayID` 2.0 sections, stored in an EDID extension block.
+//!
+//! Only the timings that can't be described by a Detailed Timing Descriptor are supported, through
+//! the Type VII Timing Data Block, along with the Tiled Display Topology Data Block describing
+//! the tiles of a display driven through several connectors.
+
+use typed_builder::TypedBuilder;
+
+use crate::{
+    edid_block_checksum, field_map::FieldMap, EdidExtensionCTA861VendorOui, EdidProductCode,
+    EdidSerialNumber, EdidTypeConversionError, IntoBytes,
+};
+
+const EDID_EXTENSION_DISPLAYID_LEN: usize = 128;
+const EDID_EXTENSION_DISPLAYID_TAG: u8 = 0x70;
+
+const DISPLAYID_VERSION: u8 = 0x20;
+const DISPLAYID_SECTION_HEADER_LEN: usize = 4;
+const DISPLAYID_SECTION_CHECKSUM_LEN: usize = 1;
+
+// The extension tag, the section header and checksum, and the block checksum.
+const EDID_EXTENSION_DISPLAYID_OVERHEAD: usize =
+    1 + DISPLAYID_SECTION_HEADER_LEN + DISPLAYID_SECTION_CHECKSUM_LEN + 1;
+
+const DISPLAYID_DATA_BLOCK_HEADER_LEN: usize = 3;
+const DISPLAYID_TYPE_VII_TIMING_TAG: u8 = 0x22;
+const DISPLAYID_TYPE_VII_TIMING_LEN: usize = 20;
+const DISPLAYID_TILED_DISPLAY_TOPOLOGY_TAG: u8 = 0x28;
+const DISPLAYID_TILED_DISPLAY_TOPOLOGY_LEN: usize = 22;
+
+/// The pixel clock of a `DisplayID` Type VII Timing, in kHz.
+///
+/// Unlike the Detailed Timing Descriptor one, it's stored with a 1 kHz precision on 24 bits, so
+/// it goes up to 16.7 GHz.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u32"))]
+pub struct EdidExtensionDisplayIDPixelClock(u32);
+
+impl EdidExtensionDisplayIDPixelClock {
+    const MAX: u32 = 1 << 24;
+
+    fn into_raw(self) -> [u8; 3] {
+        let [b0, b1, b2, _] = (self.0 - 1).to_le_bytes();
+
+        [b0, b1, b2]
+    }
+}
+
+impl TryFrom<u32> for EdidExtensionDisplayIDPixelClock {
+    type Error = EdidTypeConversionError<u32>;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if !(1..=Self::MAX).contains(&value) {
+            return Err(EdidTypeConversionError::Range(
+                value,
+                Some(1),
+                Some(Self::MAX),
+            ));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+/// A timing parameter of a `DisplayID` Type VII Timing, stored minus one on N bits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16"))]
+pub struct EdidExtensionDisplayIDTiming<const N: usize>(u16);
+
+impl<const N: usize> EdidExtensionDisplayIDTiming<N> {
+    fn into_raw(self) -> u16 {
+        self.0 - 1
+    }
+}
+
+impl<const N: usize> TryFrom<u16> for EdidExtensionDisplayIDTiming<N> {
+    type Error = EdidTypeConversionError<u16>;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        let max = u16::try_from(1u32 << N).unwrap_or(u16::MAX);
+
+        if !(1..=max).contains(&value) {
+            return Err(EdidTypeConversionError::Range(value, Some(1), Some(max)));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+pub type EdidExtensionDisplayID15BitsTiming = EdidExtensionDisplayIDTiming<15>;
+pub type EdidExtensionDisplayID16BitsTiming = EdidExtensionDisplayIDTiming<16>;
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_displayid_timing_fields {
+    use crate::{
+        EdidExtensionDisplayID15BitsTiming, EdidExtensionDisplayID16BitsTiming,
+        EdidExtensionDisplayIDPixelClock,
+    };
+
+    #[test]
+    fn test_range() {
+        assert!(EdidExtensionDisplayIDPixelClock::try_from(0).is_err());
+        assert!(EdidExtensionDisplayIDPixelClock::try_from(1).is_ok());
+        assert!(EdidExtensionDisplayIDPixelClock::try_from(0x0100_0000).is_ok());
+        assert!(EdidExtensionDisplayIDPixelClock::try_from(0x0100_0001).is_err());
+
+        assert!(EdidExtensionDisplayID15BitsTiming::try_from(0).is_err());
+        assert!(EdidExtensionDisplayID15BitsTiming::try_from(0x8000).is_ok());
+        assert!(EdidExtensionDisplayID15BitsTiming::try_from(0x8001).is_err());
+
+        assert!(EdidExtensionDisplayID16BitsTiming::try_from(0).is_err());
+        assert!(EdidExtensionDisplayID16BitsTiming::try_from(u16::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_binary() {
+        assert_eq!(
+            EdidExtensionDisplayIDPixelClock::try_from(1_188_000)
+                .unwrap()
+                .into_raw(),
+            [0x9f, 0x20, 0x12]
+        );
+        assert_eq!(
+            EdidExtensionDisplayID16BitsTiming::try_from(3840)
+                .unwrap()
+                .into_raw(),
+            3839
+        );
+    }
+}
+
+/// The aspect ratio of a `DisplayID` Type VII Timing.
+#[repr(u8)]
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum EdidExtensionDisplayIDAspectRatio {
+    Ratio_1_1 = 0,
+    Ratio_5_4,
+    Ratio_4_3,
+    Ratio_15_9,
+    Ratio_16_9,
+    Ratio_16_10,
+    Ratio_64_27,
+    Ratio_256_135,
+
+    /// The aspect ratio is computed from the addressable resolution.
+    #[default]
+    Undefined,
+}
+
+/// A `DisplayID` 2.0 Type VII Timing, for the timings whose pixel clock is too high for a
+/// Detailed Timing Descriptor.
+///
+/// The vertical parameters of interlaced timings are the ones of a field, like for a Detailed
+/// Timing Descriptor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct EdidExtensionDisplayIDTypeVIITiming {
+    pixel_clock: EdidExtensionDisplayIDPixelClock,
+
+    #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    preferred: bool,
+
+    #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    interlace: bool,
+
+    #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    aspect_ratio: EdidExtensionDisplayIDAspectRatio,
+
+    horizontal_addressable: EdidExtensionDisplayID16BitsTiming,
+    horizontal_blanking: EdidExtensionDisplayID16BitsTiming,
+    horizontal_front_porch: EdidExtensionDisplayID15BitsTiming,
+    horizontal_sync_pulse: EdidExtensionDisplayID16BitsTiming,
+
+    #[builder(default = true)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    hsync_positive: bool,
+
+    vertical_addressable: EdidExtensionDisplayID16BitsTiming,
+    vertical_blanking: EdidExtensionDisplayID16BitsTiming,
+    vertical_front_porch: EdidExtensionDisplayID15BitsTiming,
+    vertical_sync_pulse: EdidExtensionDisplayID16BitsTiming,
+
+    #[builder(default = true)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    vsync_positive: bool,
+}
+
+impl EdidExtensionDisplayIDTypeVIITiming {
+    /// Returns the addressable resolution of a frame, both fields included for interlaced
+    /// timings.
+    #[must_use]
+    pub fn frame_resolution(&self) -> (u16, u16) {
+        let vertical = self.vertical_addressable.0;
+
+        (
+            self.horizontal_addressable.0,
+            if self.interlace {
+                vertical.saturating_mul(2)
+            } else {
+                vertical
+            },
+        )
+    }
+
+    /// Returns the pixel clock, in kHz.
+    #[must_use]
+    pub const fn pixel_clock_khz(&self) -> u32 {
+        self.pixel_clock.0
+    }
+
+    /// Returns the total number of pixels of a line.
+    #[must_use]
+    pub fn horizontal_total(&self) -> u32 {
+        u32::from(self.horizontal_addressable.0) + u32::from(self.horizontal_blanking.0)
+    }
+
+    /// Returns the field rate, in Hz. It's twice the frame rate for interlaced timings, and the
+    /// frame rate otherwise.
+    #[must_use]
+    pub fn field_rate(&self) -> f64 {
+        let field = u32::from(self.vertical_addressable.0) + u32::from(self.vertical_blanking.0);
+        // The second field of an interlaced frame has an extra half line.
+        let lines = if self.interlace {
+            f64::from(field) + 0.5
+        } else {
+            f64::from(field)
+        };
+
+        f64::from(self.pixel_clock.0) * 1000.0 / (f64::from(self.horizontal_total()) * lines)
+    }
+
+    /// Returns whether the timing is interlaced.
+    #[must_use]
+    pub const fn is_interlaced(&self) -> bool {
+        self.interlace
+    }
+
+    /// Returns whether the timing is the preferred one.
+    #[must_use]
+    pub const fn is_preferred(&self) -> bool {
+        self.preferred
+    }
+}
+
+impl IntoBytes for EdidExtensionDisplayIDTypeVIITiming {
+    fn into_bytes(self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(DISPLAYID_TYPE_VII_TIMING_LEN);
+
+        bytes.extend_from_slice(&self.pixel_clock.into_raw());
+
+        let mut byte = self.aspect_ratio as u8;
+        if self.preferred {
+            byte |= 1 << 7;
+        }
+
+        if self.interlace {
+            byte |= 1 << 4;
+        }
+        bytes.push(byte);
+
+        let polarity = |positive| if positive { 1 << 15 } else { 0 };
+
+        for value in [
+            self.horizontal_addressable.into_raw(),
+            self.horizontal_blanking.into_raw(),
+            self.horizontal_front_porch.into_raw() | polarity(self.hsync_positive),
+            self.horizontal_sync_pulse.into_raw(),
+            self.vertical_addressable.into_raw(),
+            self.vertical_blanking.into_raw(),
+            self.vertical_front_porch.into_raw() | polarity(self.vsync_positive),
+            self.vertical_sync_pulse.into_raw(),
+        ] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        bytes
+    }
+
+    fn size(&self) -> usize {
+        DISPLAYID_TYPE_VII_TIMING_LEN
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_displayid_type_vii_timing {
+    use crate::{
+        EdidExtensionDisplayIDAspectRatio, EdidExtensionDisplayIDTypeVIITiming, IntoBytes,
+    };
+
+    pub(crate) fn timing_4k120() -> EdidExtensionDisplayIDTypeVIITiming {
+        EdidExtensionDisplayIDTypeVIITiming::builder()
+            .pixel_clock(1_188_000.try_into().unwrap())
+            .preferred(true)
+            .aspect_ratio(EdidExtensionDisplayIDAspectRatio::Ratio_16_9)
+            .horizontal_addressable(3840.try_into().unwrap())
+            .horizontal_blanking(560.try_into().unwrap())
+            .horizontal_front_porch(176.try_into().unwrap())
+            .horizontal_sync_pulse(88.try_into().unwrap())
+            .vertical_addressable(2160.try_into().unwrap())
+            .vertical_blanking(90.try_into().unwrap())
+            .vertical_front_porch(8.try_into().unwrap())
+            .vertical_sync_pulse(10.try_into().unwrap())
+            .build()
+    }
+
+    #[test]
+    fn test_binary() {
+        let timing = timing_4k120();
+
+        assert_eq!(timing.frame_resolution(), (3840, 2160));
+        assert!((timing.field_rate() - 120.0).abs() < 0.001);
+        assert_eq!(
+            timing.into_bytes(),
+            &[
+                0x9f, 0x20, 0x12, 0x84, 0xff, 0x0e, 0x2f, 0x02, 0xaf, 0x80, 0x57, 0x00, 0x6f, 0x08,
+                0x59, 0x00, 0x07, 0x80, 0x09, 0x00,
+            ]
+        );
+    }
+}
+
+/// The number of tiles of a tiled display, in one direction, from 1 to 64.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
+pub struct EdidExtensionDisplayIDTileCount(u8);
+
+impl EdidExtensionDisplayIDTileCount {
+    const MAX: u8 = 64;
+
+    fn into_raw(self) -> u8 {
+        self.0 - 1
+    }
+}
+
+impl TryFrom<u8> for EdidExtensionDisplayIDTileCount {
+    type Error = EdidTypeConversionError<u8>;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if !(1..=Self::MAX).contains(&value) {
+            return Err(EdidTypeConversionError::Range(
+                value,
+                Some(1),
+                Some(Self::MAX),
+            ));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+/// The location of a tile in a tiled display, in one direction, from 0 to 63.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
+pub struct EdidExtensionDisplayIDTileLocation(u8);
+
+impl EdidExtensionDisplayIDTileLocation {
+    const MAX: u8 = 63;
+}
+
+impl TryFrom<u8> for EdidExtensionDisplayIDTileLocation {
+    type Error = EdidTypeConversionError<u8>;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if value > Self::MAX {
+            return Err(EdidTypeConversionError::Range(
+                value,
+                Some(0),
+                Some(Self::MAX),
+            ));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+/// How a tiled display behaves when only its tile is driven.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum EdidExtensionDisplayIDTileSingleBehavior {
+    #[default]
+    Undefined = 0,
+
+    /// The image is displayed at the tile location.
+    TileLocation,
+
+    /// The image is scaled to fit the whole display.
+    ScaledToFit,
+
+    /// The image is cloned to all the other tiles.
+    Cloned,
+}
+
+/// How a tiled display behaves when more than one, but not all, of its tiles are driven.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum EdidExtensionDisplayIDTileMultipleBehavior {
+    #[default]
+    Undefined = 0,
+
+    /// The images are displayed at their tile location.
+    TileLocation,
+}
+
+/// The bezels around a tile, expressed in pixels times the pixel multiplier, divided by 10.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct EdidExtensionDisplayIDTileBezel {
+    pixel_multiplier: u8,
+    top: u8,
+    bottom: u8,
+    right: u8,
+    left: u8,
+}
+
+/// A `DisplayID` 2.0 Tiled Display Topology, describing a tile of a display made of several
+/// tiles, each driven by its own connector with its own EDID.
+///
+/// All the tiles of a display share the same topology identifier, made of the vendor OUI, the
+/// product code and the serial number, and only differ by their location.
+#[derive(Clone, Copy, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct EdidExtensionDisplayIDTiledDisplayTopology {
+    /// Whether all the tiles are in a single physical enclosure.
+    #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    single_enclosure: bool,
+
+    #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    single_tile_behavior: EdidExtensionDisplayIDTileSingleBehavior,
+
+    #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    multiple_tiles_behavior: EdidExtensionDisplayIDTileMultipleBehavior,
+
+    horizontal_tiles: EdidExtensionDisplayIDTileCount,
+    vertical_tiles: EdidExtensionDisplayIDTileCount,
+    horizontal_location: EdidExtensionDisplayIDTileLocation,
+    vertical_location: EdidExtensionDisplayIDTileLocation,
+
+    /// The addressable resolution of the tile.
+    horizontal_size: EdidExtensionDisplayID16BitsTiming,
+    vertical_size: EdidExtensionDisplayID16BitsTiming,
+
+    #[builder(default, setter(strip_option))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    bezel: Option<EdidExtensionDisplayIDTileBezel>,
+
+    vendor: EdidExtensionCTA861VendorOui,
+    product_code: EdidProductCode,
+    serial_number: EdidSerialNumber,
+}
+
+impl EdidExtensionDisplayIDTiledDisplayTopology {
+    /// Returns the number of horizontal and vertical tiles of the display.
+    #[must_use]
+    pub const fn tiles(&self) -> (u8, u8) {
+        (self.horizontal_tiles.0, self.vertical_tiles.0)
+    }
+
+    /// Returns the horizontal and vertical location of the tile, starting from the top left one.
+    #[must_use]
+    pub const fn location(&self) -> (u8, u8) {
+        (self.horizontal_location.0, self.vertical_location.0)
+    }
+
+    /// Returns the addressable resolution of the tile.
+    #[must_use]
+    pub const fn tile_resolution(&self) -> (u16, u16) {
+        (self.horizontal_size.0, self.vertical_size.0)
+    }
+
+    /// Returns whether the tile location is within the tiles grid.
+    pub(crate) fn location_is_valid(&self) -> bool {
+        self.horizontal_location.0 < self.horizontal_tiles.0
+            && self.vertical_location.0 < self.vertical_tiles.0
+    }
+}
+
+impl IntoBytes for EdidExtensionDisplayIDTiledDisplayTopology {
+    fn into_bytes(self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(DISPLAYID_TILED_DISPLAY_TOPOLOGY_LEN);
+
+        let mut byte =
+            ((self.multiple_tiles_behavior as u8) << 3) | self.single_tile_behavior as u8;
+        if self.single_enclosure {
+            byte |= 1 << 7;
+        }
+
+        if self.bezel.is_some() {
+            byte |= 1 << 6;
+        }
+        bytes.push(byte);
+
+        // The tile counts and locations are split in their 4 low bits, and their 2 high bits
+        // stored together in the fourth byte.
+        let htiles = self.horizontal_tiles.into_raw();
+        let vtiles = self.vertical_tiles.into_raw();
+        let hloc = self.horizontal_location.0;
+        let vloc = self.vertical_location.0;
+
+        bytes.extend_from_slice(&[
+            ((htiles & 0xf) << 4) | (vtiles & 0xf),
+            ((hloc & 0xf) << 4) | (vloc & 0xf),
+            ((htiles >> 4) << 6) | ((vtiles >> 4) << 4) | ((hloc >> 4) << 2) | (vloc >> 4),
+        ]);
+
+        bytes.extend_from_slice(&self.horizontal_size.into_raw().to_le_bytes());
+        bytes.extend_from_slice(&self.vertical_size.into_raw().to_le_bytes());
+
+        let bezel = self.bezel.unwrap_or_default();
+        bytes.extend_from_slice(&[
+            bezel.pixel_multiplier,
+            bezel.top,
+            bezel.bottom,
+            bezel.right,
+            bezel.left,
+        ]);
+
+        // Unlike in the CTA-861 Vendor-Specific Data Blocks, the OUI is stored big-endian.
+        bytes.extend_from_slice(&self.vendor.into_be_raw());
+        bytes.extend_from_slice(&self.product_code.into_raw());
+        bytes.extend_from_slice(&self.serial_number.0.to_le_bytes());
+
+        bytes
+    }
+
+    fn size(&self) -> usize {
+        DISPLAYID_TILED_DISPLAY_TOPOLOGY_LEN
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_displayid_tiled_display_topology {
+    use crate::{
+        EdidExtensionCTA861VendorOui, EdidExtensionDisplayIDTileBezel,
+        EdidExtensionDisplayIDTileCount, EdidExtensionDisplayIDTileLocation,
+        EdidExtensionDisplayIDTileMultipleBehavior, EdidExtensionDisplayIDTileSingleBehavior,
+        EdidExtensionDisplayIDTiledDisplayTopology, EdidProductCode, EdidSerialNumber, IntoBytes,
+    };
+
+    #[test]
+    fn test_range() {
+        assert!(EdidExtensionDisplayIDTileCount::try_from(0).is_err());
+        assert!(EdidExtensionDisplayIDTileCount::try_from(64).is_ok());
+        assert!(EdidExtensionDisplayIDTileCount::try_from(65).is_err());
+
+        assert!(EdidExtensionDisplayIDTileLocation::try_from(63).is_ok());
+        assert!(EdidExtensionDisplayIDTileLocation::try_from(64).is_err());
+    }
+
+    #[test]
+    fn test_binary() {
+        let topology = EdidExtensionDisplayIDTiledDisplayTopology::builder()
+            .single_enclosure(true)
+            .single_tile_behavior(EdidExtensionDisplayIDTileSingleBehavior::ScaledToFit)
+            .multiple_tiles_behavior(EdidExtensionDisplayIDTileMultipleBehavior::TileLocation)
+            .horizontal_tiles(EdidExtensionDisplayIDTileCount::try_from(20).unwrap())
+            .vertical_tiles(EdidExtensionDisplayIDTileCount::try_from(2).unwrap())
+            .horizontal_location(EdidExtensionDisplayIDTileLocation::try_from(17).unwrap())
+            .vertical_location(EdidExtensionDisplayIDTileLocation::try_from(1).unwrap())
+            .horizontal_size(3840.try_into().unwrap())
+            .vertical_size(4320.try_into().unwrap())
+            .bezel(
+                EdidExtensionDisplayIDTileBezel::builder()
+                    .pixel_multiplier(10)
+                    .top(1)
+                    .bottom(2)
+                    .right(3)
+                    .left(4)
+                    .build(),
+            )
+            .vendor(EdidExtensionCTA861VendorOui::try_from(0x3a_0292).unwrap())
+            .product_code(EdidProductCode::new(0x1234))
+            .serial_number(EdidSerialNumber::from(0x0102_0304))
+            .build();
+
+        assert!(topology.location_is_valid());
+        assert_eq!(
+            topology.into_bytes(),
+            &[
+                0xca, 0x31, 0x11, 0x44, 0xff, 0x0e, 0xdf, 0x10, 0x0a, 0x01, 0x02, 0x03, 0x04, 0x3a,
+                0x02, 0x92, 0x34, 0x12, 0x04, 0x03, 0x02, 0x01,
+            ]
+        );
+    }
+}
+
+/// A `DisplayID` 2.0 data block.
+///
+/// Only a few of the `DisplayID` data blocks are supported, so more variants are expected.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[non_exhaustive]
+pub enum EdidExtensionDisplayIDDataBlock {
+    /// A Type VII Timing Data Block, listing the given timings.
+    TypeVIITimings(Vec<EdidExtensionDisplayIDTypeVIITiming>),
+
+    /// A Tiled Display Topology Data Block.
+    TiledDisplayTopology(EdidExtensionDisplayIDTiledDisplayTopology),
+}
+
+impl EdidExtensionDisplayIDDataBlock {
+    /// Returns the Data Block Tag.
+    pub(crate) const fn tag(&self) -> u8 {
+        match self {
+            Self::TypeVIITimings(_) => DISPLAYID_TYPE_VII_TIMING_TAG,
+            Self::TiledDisplayTopology(_) => DISPLAYID_TILED_DISPLAY_TOPOLOGY_TAG,
+        }
+    }
+}
+
+impl IntoBytes for EdidExtensionDisplayIDDataBlock {
+    fn into_bytes(self) -> Vec<u8> {
+        let size = self.size();
+        let mut bytes = Vec::with_capacity(size);
+
+        match self {
+            Self::TypeVIITimings(timings) => {
+                // Revision 0, 20 bytes descriptors, and no DSC pass-through.
+                bytes.extend_from_slice(&[
+                    DISPLAYID_TYPE_VII_TIMING_TAG,
+                    0x00,
+                    u8::try_from(size - DISPLAYID_DATA_BLOCK_HEADER_LEN).unwrap_or(u8::MAX),
+                ]);
+
+                for timing in timings {
+                    bytes.extend_from_slice(&timing.into_bytes());
+                }
+            }
+            Self::TiledDisplayTopology(topology) => {
+                // Revision 0.
+                bytes.extend_from_slice(&[
+                    DISPLAYID_TILED_DISPLAY_TOPOLOGY_TAG,
+                    0x00,
+                    u8::try_from(size - DISPLAYID_DATA_BLOCK_HEADER_LEN).unwrap_or(u8::MAX),
+                ]);
+                bytes.extend_from_slice(&topology.into_bytes());
+            }
+        }
+
+        bytes
+    }
+
+    fn size(&self) -> usize {
+        DISPLAYID_DATA_BLOCK_HEADER_LEN
+            + match self {
+                Self::TypeVIITimings(timings) => timings.len() * DISPLAYID_TYPE_VII_TIMING_LEN,
+                Self::TiledDisplayTopology(topology) => topology.size(),
+            }
+    }
+}
+
+/// A `DisplayID` 2.0 Extension, holding a single `DisplayID` section.
+///
+/// ```
+/// use redid::{
+///     EdidExtensionDisplayID, EdidExtensionDisplayIDDataBlock,
+///     EdidExtensionDisplayIDTypeVIITiming,
+/// };
+///
+/// // 3840x2160 at 120Hz, with a 1188 MHz pixel clock.
+/// let timing = EdidExtensionDisplayIDTypeVIITiming::builder()
+///     .pixel_clock(1_188_000.try_into().unwrap())
+///     .horizontal_addressable(3840.try_into().unwrap())
+///     .horizontal_blanking(560.try_into().unwrap())
+///     .horizontal_front_porch(176.try_into().unwrap())
+///     .horizontal_sync_pulse(88.try_into().unwrap())
+///     .vertical_addressable(2160.try_into().unwrap())
+///     .vertical_blanking(90.try_into().unwrap())
+///     .vertical_front_porch(8.try_into().unwrap())
+///     .vertical_sync_pulse(10.try_into().unwrap())
+///     .build();
+///
+/// let ext = EdidExtensionDisplayID::builder()
+///     .add_data_block(EdidExtensionDisplayIDDataBlock::TypeVIITimings(vec![timing]))
+///     .build();
+/// ```
+#[derive(Clone, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[builder(mutators(
+    #[allow(unreachable_pub)]
+    pub fn data_blocks(&mut self, blocks: Vec<EdidExtensionDisplayIDDataBlock>) {
+        self.data_blocks = blocks;
+    }
+
+    #[allow(unreachable_pub)]
+    pub fn add_data_block(&mut self, block: EdidExtensionDisplayIDDataBlock) {
+        self.data_blocks.push(block);
+    }
+))]
+pub struct EdidExtensionDisplayID {
+    #[builder(via_mutators)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    data_blocks: Vec<EdidExtensionDisplayIDDataBlock>,
+}
+
+impl EdidExtensionDisplayID {
+    pub(crate) fn data_blocks(&self) -> &[EdidExtensionDisplayIDDataBlock] {
+        &self.data_blocks
+    }
+
+    /// Lists the Type VII Timings of all the data blocks.
+    pub(crate) fn type_vii_timings(
+        &self,
+    ) -> impl Iterator<Item = &EdidExtensionDisplayIDTypeVIITiming> {
+        self.data_blocks.iter().flat_map(|block| match block {
+            EdidExtensionDisplayIDDataBlock::TypeVIITimings(timings) => timings.iter(),
+            EdidExtensionDisplayIDDataBlock::TiledDisplayTopology(_) => [].iter(),
+        })
+    }
+
+    /// Lists the Tiled Display Topologies of all the data blocks.
+    pub(crate) fn tiled_display_topologies(
+        &self,
+    ) -> impl Iterator<Item = &EdidExtensionDisplayIDTiledDisplayTopology> {
+        self.data_blocks.iter().filter_map(|block| match block {
+            EdidExtensionDisplayIDDataBlock::TiledDisplayTopology(topology) => Some(topology),
+            EdidExtensionDisplayIDDataBlock::TypeVIITimings(_) => None,
+        })
+    }
+
+    pub(crate) fn used_bytes(&self) -> usize {
+        self.data_blocks.iter().map(IntoBytes::size).sum::<usize>()
+            + EDID_EXTENSION_DISPLAYID_OVERHEAD
+    }
+
+    /// Serializes the extension, and records the bytes each part of it has been serialized to.
+    pub(crate) fn into_bytes_with_field_map(self, fields: &mut FieldMap) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::with_capacity(EDID_EXTENSION_DISPLAYID_LEN);
+
+        // The data blocks that don't fit are left out, and reported by the validation.
+        let mut available = EDID_EXTENSION_DISPLAYID_LEN - EDID_EXTENSION_DISPLAYID_OVERHEAD;
+        let mut section = Vec::with_capacity(available);
+        let mut section_fields = FieldMap::default();
+        for (idx, block) in self.data_blocks.into_iter().enumerate() {
+            let Some(left) = available.checked_sub(block.size()) else {
+                break;
+            };
+
+            available = left;
+            section_fields.push(
+                &mut section,
+                format!("data_blocks[{idx}]"),
+                &block.into_bytes(),
+            );
+        }
+
+        fields.push(&mut data, "header", &[EDID_EXTENSION_DISPLAYID_TAG]);
+
+        // The section header: version, number of payload bytes, primary use case (the same as
+        // the base section), and number of extension sections.
+        fields.push(
+            &mut data,
+            "section_header",
+            &[
+                DISPLAYID_VERSION,
+                u8::try_from(section.len()).unwrap_or(u8::MAX),
+                0x00,
+                0x00,
+            ],
+        );
+        fields.extend("", data.len(), section_fields);
+        data.extend_from_slice(&section);
+
+        // The section checksum covers the section, from its header on.
+        let checksum = edid_block_checksum(&data[1..]);
+        fields.push(&mut data, "section_checksum", &[checksum]);
+
+        let padding_start = data.len();
+        data.resize(EDID_EXTENSION_DISPLAYID_LEN - 1, 0);
+        if data.len() > padding_start {
+            fields.record("padding", padding_start..data.len());
+        }
+
+        let checksum = edid_block_checksum(&data);
+        fields.push(&mut data, "checksum", &[checksum]);
+
+        data
+    }
+
+    /// Returns the number of bytes still available in the extension block after the data
+    /// blocks.
+    ///
+    /// Returns 0 if the content doesn't fit.
+    #[must_use]
+    pub fn remaining_bytes(&self) -> usize {
+        EDID_EXTENSION_DISPLAYID_LEN.saturating_sub(self.used_bytes())
+    }
+
+    /// Returns whether the data blocks fit in the extension block.
+    #[must_use]
+    pub fn fits(&self) -> bool {
+        self.used_bytes() <= EDID_EXTENSION_DISPLAYID_LEN
+    }
+}
+
+impl IntoBytes for EdidExtensionDisplayID {
+    fn into_bytes(self) -> Vec<u8> {
+        self.into_bytes_with_field_map(&mut FieldMap::default())
+    }
+
+    fn size(&self) -> usize {
+        EDID_EXTENSION_DISPLAYID_LEN
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_displayid {
+    use super::test_displayid_type_vii_timing::timing_4k120;
+    use crate::{
+        edid_preset_avr, CecAddress, EdidExtension, EdidExtensionCTA861VendorOui,
+        EdidExtensionDisplayID, EdidExtensionDisplayIDDataBlock, EdidExtensionDisplayIDTileCount,
+        EdidExtensionDisplayIDTileLocation, EdidExtensionDisplayIDTiledDisplayTopology,
+        EdidManufacturer, EdidProductCode, EdidSerialNumber, EdidValidationError, IntoBytes,
+    };
+
+    #[test]
+    fn test_binary() {
+        let ext = EdidExtensionDisplayID::builder()
+            .add_data_block(EdidExtensionDisplayIDDataBlock::TypeVIITimings(vec![
+                timing_4k120(),
+            ]))
+            .build();
+
+        assert!(ext.fits());
+        assert_eq!(ext.remaining_bytes(), 128 - 7 - 23);
+
+        let bytes = ext.into_bytes();
+        assert_eq!(bytes.len(), 128);
+        assert_eq!(&bytes[..8], &[0x70, 0x20, 23, 0x00, 0x00, 0x22, 0x00, 20]);
+        assert_eq!(bytes[8], 0x9f);
+        assert_eq!(
+            bytes[1..=28]
+                .iter()
+                .fold(0u8, |sum, byte| sum.wrapping_add(*byte)),
+            0
+        );
+        assert!(bytes[29..127].iter().all(|b| *b == 0));
+        assert_eq!(
+            bytes.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte)),
+            0
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let ext = EdidExtensionDisplayID::builder()
+            .add_data_block(EdidExtensionDisplayIDDataBlock::TypeVIITimings(vec![
+                timing_4k120();
+                5
+            ]))
+            .add_data_block(EdidExtensionDisplayIDDataBlock::TypeVIITimings(vec![
+                timing_4k120(),
+            ]))
+            .build();
+
+        assert!(!ext.fits());
+        assert_eq!(ext.remaining_bytes(), 0);
+
+        // The last data block is left out.
+        let bytes = ext.into_bytes();
+        assert_eq!(bytes.len(), 128);
+        assert_eq!(bytes[2], 103);
+    }
+
+    #[test]
+    fn test_edid() {
+        let mut edid = edid_preset_avr(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        );
+        edid.extensions.push(EdidExtension::DisplayID(
+            EdidExtensionDisplayID::builder()
+                .add_data_block(EdidExtensionDisplayIDDataBlock::TypeVIITimings(vec![
+                    timing_4k120(),
+                ]))
+                .build(),
+        ));
+
+        assert_eq!(edid.validate(), Ok(()));
+        assert!(edid
+            .all_timings()
+            .any(|t| (t.horizontal(), t.vertical(), t.refresh_rate()) == (3840, 2160, 120)));
+
+        let layout = edid.layout();
+        assert!(layout.fits());
+        assert_eq!(layout.extensions()[1].data_blocks()[0].tag(), 0x22);
+
+        let bytes = edid.into_bytes();
+        assert_eq!(bytes.len(), 3 * 128);
+        assert_eq!(bytes[126], 2);
+        assert_eq!(bytes[256], 0x70);
+    }
+
+    #[test]
+    fn test_tile_outside_topology() {
+        let mut edid = edid_preset_avr(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        );
+        edid.extensions.push(EdidExtension::DisplayID(
+            EdidExtensionDisplayID::builder()
+                .add_data_block(EdidExtensionDisplayIDDataBlock::TiledDisplayTopology(
+                    EdidExtensionDisplayIDTiledDisplayTopology::builder()
+                        .horizontal_tiles(EdidExtensionDisplayIDTileCount::try_from(2).unwrap())
+                        .vertical_tiles(EdidExtensionDisplayIDTileCount::try_from(1).unwrap())
+                        .horizontal_location(
+                            EdidExtensionDisplayIDTileLocation::try_from(2).unwrap(),
+                        )
+                        .vertical_location(EdidExtensionDisplayIDTileLocation::try_from(0).unwrap())
+                        .horizontal_size(1920.try_into().unwrap())
+                        .vertical_size(2160.try_into().unwrap())
+                        .vendor(EdidExtensionCTA861VendorOui::try_from(0x3a_0292).unwrap())
+                        .product_code(EdidProductCode::new(0x1234))
+                        .serial_number(EdidSerialNumber::from(1))
+                        .build(),
+                ))
+                .build(),
+        ));
+
+        assert_eq!(
+            edid.validate(),
+            Err(EdidValidationError::TileOutsideTopology(1))
+        );
+    }
+}
@@ -0,0 +1,586 @@
+//! A textual description of an EDID, laid out like the edid-decode output.
+
+use core::fmt;
+
+use crate::{
+    edid_into_bytes, field_map::FieldMap, layout::edid_layout, timings, Edid, EdidCtaVideoFormat,
+    EdidDate, EdidDescriptor, EdidDescriptorCustomType, EdidDescriptorDetailedTiming,
+    EdidExtension, EdidExtensionCTA861, EdidExtensionCTA861Revision3,
+    EdidExtensionCTA861Revision3DataBlock, EdidExtensionCTA861VendorDataBlockType,
+    EdidExtensionDisplayID, EdidExtensionDisplayIDDataBlock, EdidManufactureDate, EdidR3Descriptor,
+    EdidR4Date, EdidR4Descriptor, EdidR4ManufactureDate, EdidRelease, EdidSerializationWarning,
+    EdidTimingSource, EDID_BASE_LEN,
+};
+
+const EDID_DUMP_SEPARATOR: &str = "----------------";
+const EDID_DUMP_HEX_LINE_LEN: usize = 16;
+
+/// A textual description of an EDID, laid out like the edid-decode output.
+///
+/// It's generated from the EDID description rather than decoded from its bytes, and only covers
+/// what tools usually scrape from the edid-decode output: the hexadecimal dump, the block
+/// headers, the vendor and product identification, the timings and display descriptors, the
+/// checksums, and the failures, warnings and conformity verdict of `--check`.
+///
+/// An EDID whose content doesn't fit in its blocks can't be serialized: its structure is then
+/// described without the hexadecimal dump and checksums, followed by the validation failure.
+#[derive(Clone, Debug)]
+pub struct EdidDump {
+    edid: Edid,
+    bytes: Option<Vec<u8>>,
+    serialization_warnings: Vec<EdidSerializationWarning>,
+}
+
+impl From<Edid> for EdidDump {
+    fn from(edid: Edid) -> Self {
+        let mut serialization_warnings = Vec::new();
+        let bytes = edid_layout(&edid).fits().then(|| {
+            edid_into_bytes(
+                edid.clone(),
+                &mut serialization_warnings,
+                &mut FieldMap::default(),
+            )
+        });
+
+        Self {
+            edid,
+            bytes,
+            serialization_warnings,
+        }
+    }
+}
+
+fn mode(horizontal: u16, vertical: u16, interlaced: bool) -> String {
+    let vertical = format!("{vertical}{}", if interlaced { "i" } else { "" });
+
+    format!("{horizontal:5}x{vertical:<6}")
+}
+
+fn write_detailed_timing(
+    f: &mut fmt::Formatter<'_>,
+    idx: usize,
+    dtd: &EdidDescriptorDetailedTiming,
+) -> fmt::Result {
+    let (horizontal, vertical) = dtd.frame_resolution();
+    let (width, height) = dtd.size_mm();
+    let clock = f64::from(dtd.pixel_clock_khz());
+
+    writeln!(
+        f,
+        "    DTD {idx}: {} {:10.6} Hz {:8.3} kHz {:13.6} MHz ({width} mm x {height} mm)",
+        mode(horizontal, vertical, dtd.is_interlaced()),
+        dtd.field_rate(),
+        clock / f64::from(dtd.horizontal_total()),
+        clock / 1000.0,
+    )
+}
+
+fn write_descriptor(
+    f: &mut fmt::Formatter<'_>,
+    desc: &EdidDescriptor,
+    dtd_idx: &mut usize,
+) -> fmt::Result {
+    match desc {
+        EdidDescriptor::R3(EdidR3Descriptor::DetailedTiming(dtd))
+        | EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(dtd)) => {
+            *dtd_idx += 1;
+            write_detailed_timing(f, *dtd_idx, dtd)
+        }
+        EdidDescriptor::R3(EdidR3Descriptor::ProductName(s))
+        | EdidDescriptor::R4(EdidR4Descriptor::ProductName(s)) => {
+            writeln!(f, "    Display Product Name: '{}'", s.as_str())
+        }
+        EdidDescriptor::R3(EdidR3Descriptor::ProductSerialNumber(s))
+        | EdidDescriptor::R4(EdidR4Descriptor::ProductSerialNumber(s)) => {
+            writeln!(f, "    Display Product Serial Number: '{}'", s.as_str())
+        }
+        EdidDescriptor::R3(EdidR3Descriptor::DataString(s))
+        | EdidDescriptor::R4(EdidR4Descriptor::DataString(s)) => {
+            writeln!(f, "    Alphanumeric Data String: '{}'", s.as_str())
+        }
+        EdidDescriptor::R3(EdidR3Descriptor::DisplayRangeLimits(_))
+        | EdidDescriptor::R4(EdidR4Descriptor::DisplayRangeLimits(_)) => {
+            writeln!(f, "    Display Range Limits")
+        }
+        EdidDescriptor::R3(EdidR3Descriptor::Custom(c))
+        | EdidDescriptor::R4(EdidR4Descriptor::Custom(c)) => writeln!(
+            f,
+            "    Manufacturer-Specified Display Descriptor (0x{:02x})",
+            c.tag().into_raw()
+        ),
+        EdidDescriptor::R3(EdidR3Descriptor::Dummy)
+        | EdidDescriptor::R4(EdidR4Descriptor::Dummy) => writeln!(f, "    Dummy Descriptor"),
+        EdidDescriptor::R3(EdidR3Descriptor::StandardTimings(()))
+        | EdidDescriptor::R4(EdidR4Descriptor::StandardTimings(())) => {
+            writeln!(f, "    Standard Timing Identifications")
+        }
+        EdidDescriptor::R3(EdidR3Descriptor::ColorPointData(c))
+        | EdidDescriptor::R4(EdidR4Descriptor::ColorPointData(c)) => {
+            writeln!(f, "    Display Color Point Descriptor:")?;
+            for point in c.white_points() {
+                let (x, y) = point.coordinates();
+                write!(f, "      Index: {} White: {x:.4}, {y:.4} ", point.index())?;
+                match point.gamma().encoded_gamma() {
+                    Some(gamma) => writeln!(f, "Gamma: {gamma:.2}")?,
+                    None => writeln!(f, "Gamma: Defined in an extension")?,
+                }
+            }
+
+            Ok(())
+        }
+        EdidDescriptor::R4(EdidR4Descriptor::EstablishedTimings(_)) => {
+            writeln!(f, "    Established timings III:")?;
+            for timing in timings::descriptor_timings(desc) {
+                writeln!(
+                    f,
+                    "      {} {:10.6} Hz",
+                    mode(
+                        timing.horizontal(),
+                        timing.vertical(),
+                        timing.is_interlaced()
+                    ),
+                    f64::from(timing.refresh_rate())
+                )?;
+            }
+
+            Ok(())
+        }
+        EdidDescriptor::R4(EdidR4Descriptor::CVT(())) => {
+            writeln!(f, "    CVT 3 Byte Timing Codes")
+        }
+        EdidDescriptor::R4(EdidR4Descriptor::DisplayColorManagement(())) => {
+            writeln!(f, "    Display Color Management Data")
+        }
+        EdidDescriptor::Raw(raw) => writeln!(f, "    Raw Descriptor (tag 0x{:02x})", raw[3]),
+    }
+}
+
+fn write_checksum(f: &mut fmt::Formatter<'_>, block: Option<&[u8]>) -> fmt::Result {
+    let Some(block) = block else {
+        return write!(f, "Checksum: not serialized");
+    };
+
+    write!(
+        f,
+        "Checksum: 0x{:02x}",
+        block.last().copied().unwrap_or_default()
+    )
+}
+
+impl EdidDump {
+    /// Returns the serialized EDID, if it fits in its blocks.
+    pub(crate) fn bytes(&self) -> Option<&[u8]> {
+        self.bytes.as_deref()
+    }
+
+    fn block(&self, idx: usize) -> Option<&[u8]> {
+        self.bytes
+            .as_ref()
+            .and_then(|bytes| bytes.chunks(EDID_BASE_LEN).nth(idx))
+    }
+
+    fn write_hex(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "edid-decode (hex):")?;
+        writeln!(f)?;
+
+        let Some(bytes) = &self.bytes else {
+            writeln!(
+                f,
+                "The EDID doesn't fit in its blocks and can't be serialized."
+            )?;
+            return writeln!(f);
+        };
+
+        for block in bytes.chunks(EDID_BASE_LEN) {
+            for line in block.chunks(EDID_DUMP_HEX_LINE_LEN) {
+                let line: Vec<_> = line.iter().map(|b| format!("{b:02x}")).collect();
+                writeln!(f, "{}", line.join(" "))?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_base_block(&self, f: &mut fmt::Formatter<'_>, dtd_idx: &mut usize) -> fmt::Result {
+        let edid = &self.edid;
+
+        writeln!(f, "Block 0, Base EDID:")?;
+        writeln!(
+            f,
+            "  EDID Structure Version & Revision: {}",
+            match edid.release {
+                EdidRelease::R3 => "1.3",
+                EdidRelease::R4 => "1.4",
+            }
+        )?;
+
+        writeln!(f, "  Vendor & Product Identification:")?;
+        writeln!(
+            f,
+            "    Manufacturer: {}",
+            String::from_utf8_lossy(&edid.manufacturer.0)
+        )?;
+        writeln!(f, "    Model: {}", edid.product_code.0)?;
+        if let Some(sn) = edid.serial_number {
+            writeln!(f, "    Serial Number: {}", sn.0)?;
+        }
+
+        match edid.date {
+            EdidDate::R3(EdidManufactureDate(Some(week), year)) => {
+                writeln!(f, "    Made in: week {} of {}", week.0, year.0)?;
+            }
+            EdidDate::R4(EdidR4Date::Manufacture(EdidR4ManufactureDate(Some(week), year))) => {
+                writeln!(f, "    Made in: week {} of {}", week.0, year.0)?;
+            }
+            EdidDate::R3(EdidManufactureDate(None, year))
+            | EdidDate::R4(EdidR4Date::Manufacture(EdidR4ManufactureDate(None, year))) => {
+                writeln!(f, "    Made in: {}", year.0)?;
+            }
+            EdidDate::R4(EdidR4Date::Model(model)) => {
+                writeln!(f, "    Model year: {}", model.0 .0)?;
+            }
+        }
+
+        let timings = timings::edid_timings(edid);
+        for (source, title) in [
+            (
+                EdidTimingSource::EstablishedTiming,
+                "Established Timings I & II",
+            ),
+            (EdidTimingSource::StandardTiming, "Standard Timings"),
+        ] {
+            let mut timings = timings.iter().filter(|t| t.source() == source).peekable();
+            if timings.peek().is_none() {
+                continue;
+            }
+
+            writeln!(f, "  {title}:")?;
+            for timing in timings {
+                writeln!(
+                    f,
+                    "    {} {:10.6} Hz",
+                    mode(
+                        timing.horizontal(),
+                        timing.vertical(),
+                        timing.is_interlaced()
+                    ),
+                    f64::from(timing.refresh_rate())
+                )?;
+            }
+        }
+
+        // Descriptors stored verbatim aren't modelled, so there's nothing to describe.
+        if edid.raw_descriptors.is_none() && !edid.descriptors.is_empty() {
+            writeln!(f, "  Detailed Timing Descriptors:")?;
+            for desc in &edid.descriptors {
+                write_descriptor(f, desc, dtd_idx)?;
+            }
+        }
+
+        if !edid.extensions.is_empty() {
+            writeln!(f, "  Extension blocks: {}", edid.extensions.len())?;
+        }
+
+        write_checksum(f, self.block(0))?;
+        writeln!(f)
+    }
+
+    fn write_cta_block(
+        f: &mut fmt::Formatter<'_>,
+        cta: &EdidExtensionCTA861Revision3,
+        block: Option<&[u8]>,
+        dtd_idx: &mut usize,
+    ) -> fmt::Result {
+        let (underscan, audio, native) = cta.header();
+        let (ycbcr_444, ycbcr_422) = cta.ycbcr_supported();
+
+        writeln!(f, "  Revision: {}", cta.revision() as u8)?;
+        for (set, flag) in [
+            (underscan, "Underscans IT Video Formats by default"),
+            (audio, "Basic audio support"),
+            (ycbcr_444, "Supports YCbCr 4:4:4"),
+            (ycbcr_422, "Supports YCbCr 4:2:2"),
+        ] {
+            if set {
+                writeln!(f, "  {flag}")?;
+            }
+        }
+        writeln!(f, "  Native detailed modes: {native}")?;
+
+        for data_block in cta.ordered_data_blocks() {
+            match data_block {
+                EdidExtensionCTA861Revision3DataBlock::Video(vdb) => {
+                    writeln!(f, "  Video Data Block:")?;
+                    for desc in vdb.descriptors() {
+                        let vic = desc.vic();
+                        let native = if desc.is_native() { " (native)" } else { "" };
+
+                        let Some(format) = EdidCtaVideoFormat::from_vic(vic) else {
+                            writeln!(f, "    VIC {vic:3}: Unknown{native}")?;
+                            continue;
+                        };
+
+                        let clock = f64::from(format.pixel_clock_khz());
+                        let fields = if format.is_interlaced() { 2.0 } else { 1.0 };
+                        let total = f64::from(format.htotal()) * f64::from(format.vtotal());
+
+                        writeln!(
+                            f,
+                            "    VIC {vic:3}: {} {:10.6} Hz {:8.3} kHz {:13.6} MHz{native}",
+                            mode(format.hactive(), format.vactive(), format.is_interlaced()),
+                            clock * 1000.0 * fields / total,
+                            clock / f64::from(format.htotal()),
+                            clock / 1000.0,
+                        )?;
+                    }
+                }
+                EdidExtensionCTA861Revision3DataBlock::Audio(_) => {
+                    writeln!(f, "  Audio Data Block")?;
+                }
+                EdidExtensionCTA861Revision3DataBlock::SpeakerAllocation(_) => {
+                    writeln!(f, "  Speaker Allocation Data Block")?;
+                }
+                EdidExtensionCTA861Revision3DataBlock::HDMI(hdmi) => {
+                    writeln!(f, "  Vendor-Specific Data Block (HDMI), OUI 00-0C-03:")?;
+                    writeln!(
+                        f,
+                        "    Source physical address: {}",
+                        hdmi.source_physical_address()
+                    )?;
+                }
+                EdidExtensionCTA861Revision3DataBlock::Vendor(vendor) => {
+                    writeln!(f, "  Vendor-Specific Data Block, OUI {}", vendor.oui())?;
+                }
+                EdidExtensionCTA861Revision3DataBlock::VideoCapability(_) => {
+                    writeln!(f, "  Video Capability Data Block")?;
+                }
+                EdidExtensionCTA861Revision3DataBlock::Colorimetry(_) => {
+                    writeln!(f, "  Colorimetry Data Block")?;
+                }
+                EdidExtensionCTA861Revision3DataBlock::HdrStaticMetadata(_) => {
+                    writeln!(f, "  HDR Static Metadata Data Block")?;
+                }
+            }
+        }
+
+        if !cta.detailed_timings().is_empty() {
+            writeln!(f, "  Detailed Timing Descriptors:")?;
+            for dtd in cta.detailed_timings() {
+                *dtd_idx += 1;
+                write_detailed_timing(f, *dtd_idx, dtd)?;
+            }
+        }
+
+        write_checksum(f, block)?;
+        writeln!(
+            f,
+            "  Unused space in Extension Block: {} bytes",
+            cta.remaining_bytes()
+        )
+    }
+
+    fn write_displayid_block(
+        f: &mut fmt::Formatter<'_>,
+        displayid: &EdidExtensionDisplayID,
+        block: Option<&[u8]>,
+    ) -> fmt::Result {
+        writeln!(f, "  Version: 2.0")?;
+
+        for data_block in displayid.data_blocks() {
+            match data_block {
+                EdidExtensionDisplayIDDataBlock::TypeVIITimings(timings) => {
+                    writeln!(
+                        f,
+                        "  Video Timing Modes Type 7 - Detailed Timings Data Block:"
+                    )?;
+                    for timing in timings {
+                        let (horizontal, vertical) = timing.frame_resolution();
+                        let clock = f64::from(timing.pixel_clock_khz());
+                        let preferred = if timing.is_preferred() {
+                            " (preferred)"
+                        } else {
+                            ""
+                        };
+
+                        writeln!(
+                            f,
+                            "    DTD: {} {:10.6} Hz {:8.3} kHz {:13.6} MHz{preferred}",
+                            mode(horizontal, vertical, timing.is_interlaced()),
+                            timing.field_rate(),
+                            clock / f64::from(timing.horizontal_total()),
+                            clock / 1000.0,
+                        )?;
+                    }
+                }
+                EdidExtensionDisplayIDDataBlock::TiledDisplayTopology(topology) => {
+                    let (horizontal_tiles, vertical_tiles) = topology.tiles();
+                    let (horizontal, vertical) = topology.location();
+                    let (width, height) = topology.tile_resolution();
+
+                    writeln!(f, "  Tiled Display Topology Data Block:")?;
+                    writeln!(
+                        f,
+                        "    Num horizontal tiles: {horizontal_tiles} Num vertical tiles: {vertical_tiles}"
+                    )?;
+                    writeln!(f, "    Tile location: {horizontal}, {vertical}")?;
+                    writeln!(f, "    Tile resolution: {width}x{height}")?;
+                }
+            }
+        }
+
+        write_checksum(f, block)
+    }
+
+    fn write_conformity(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let edid = &self.edid;
+
+        let mut warnings: Vec<_> = edid.warnings().iter().map(ToString::to_string).collect();
+        warnings.extend(self.serialization_warnings.iter().map(ToString::to_string));
+
+        if !warnings.is_empty() {
+            writeln!(f, "Warnings:")?;
+            writeln!(f)?;
+            writeln!(f, "EDID:")?;
+            for warning in warnings {
+                writeln!(f, "  {warning}")?;
+            }
+            writeln!(f)?;
+        }
+
+        let result = edid.validate();
+        if let Err(error) = &result {
+            writeln!(f, "Failures:")?;
+            writeln!(f)?;
+            writeln!(f, "EDID:")?;
+            writeln!(f, "  {error}")?;
+            writeln!(f)?;
+        }
+
+        writeln!(
+            f,
+            "EDID conformity: {}",
+            if result.is_ok() { "PASS" } else { "FAIL" }
+        )
+    }
+}
+
+impl fmt::Display for EdidDump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut dtd_idx = 0;
+
+        self.write_hex(f)?;
+        writeln!(f, "{EDID_DUMP_SEPARATOR}")?;
+        writeln!(f)?;
+
+        self.write_base_block(f, &mut dtd_idx)?;
+
+        for (idx, ext) in self.edid.extensions.iter().enumerate() {
+            let block = self.block(idx + 1);
+
+            writeln!(f)?;
+            writeln!(f, "{EDID_DUMP_SEPARATOR}")?;
+            writeln!(f)?;
+
+            match ext {
+                EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => {
+                    writeln!(f, "Block {}, CTA-861 Extension Block:", idx + 1)?;
+                    Self::write_cta_block(f, cta, block, &mut dtd_idx)?;
+                }
+                EdidExtension::DisplayID(displayid) => {
+                    writeln!(f, "Block {}, DisplayID Extension Block:", idx + 1)?;
+                    Self::write_displayid_block(f, displayid, block)?;
+                }
+            }
+        }
+
+        writeln!(f)?;
+        writeln!(f, "{EDID_DUMP_SEPARATOR}")?;
+        writeln!(f)?;
+
+        self.write_conformity(f)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_dump {
+    use crate::{
+        edid_preset_avr, CecAddress, EdidDescriptor, EdidEstablishedTiming, EdidManufacturer,
+        EdidR4Descriptor, EdidRelease4, IntoBytes,
+    };
+
+    #[test]
+    fn test_avr() {
+        let edid = edid_preset_avr(
+            EdidManufacturer::try_from("RDD").unwrap(),
+            0x1234.into(),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        );
+        let bytes = edid.clone().into_bytes();
+        let dump = edid.dump().to_string();
+
+        assert!(bytes.len() > 0xff);
+        assert!(dump.starts_with("edid-decode (hex):\n\n00 ff ff ff ff ff ff 00 "));
+        assert!(dump.contains("\n----------------\n\nBlock 0, Base EDID:\n"));
+        assert!(dump.contains("\n  EDID Structure Version & Revision: 1.4\n"));
+        assert!(dump.contains("\n    Manufacturer: RDD\n    Model: 4660\n"));
+        assert!(dump.contains("\n  Established Timings I & II:\n      640x480     60.000000 Hz\n"));
+        assert!(dump.contains(
+            "\n    DTD 1:   640x480     59.940476 Hz   31.469 kHz     25.175000 MHz (0 mm x 0 mm)\n"
+        ));
+        assert!(dump.contains(&format!("\nChecksum: 0x{:02x}\n", bytes[0x7f])));
+        assert!(dump.contains("\n----------------\n\nBlock 1, CTA-861 Extension Block:\n"));
+        assert!(dump.contains("\n  Revision: 3\n  Basic audio support\n"));
+        assert!(dump.contains(
+            "\n    VIC   1:   640x480     60.000000 Hz   31.500 kHz     25.200000 MHz (native)\n"
+        ));
+        assert!(dump.contains("\n    Source physical address: 1.0.0.0\n"));
+        assert!(dump.contains(&format!(
+            "\nChecksum: 0x{:02x}  Unused space in Extension Block: ",
+            bytes[0xff]
+        )));
+        assert!(dump.ends_with("\nEDID conformity: PASS\n"));
+    }
+
+    #[test]
+    fn test_failures() {
+        let edid = edid_preset_avr(
+            EdidManufacturer::try_from("RDD").unwrap(),
+            0x1234.into(),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        );
+        let edid = EdidRelease4 {
+            established_timings: vec![
+                EdidEstablishedTiming::ET_640_480_60hz,
+                EdidEstablishedTiming::ET_640_480_60hz,
+            ],
+            ..edid
+        };
+
+        let dump = edid.dump().to_string();
+        assert!(dump.contains("\nFailures:\n\nEDID:\n  "));
+        assert!(dump.ends_with("\nEDID conformity: FAIL\n"));
+    }
+
+    #[test]
+    fn test_too_many_descriptors() {
+        let mut edid = edid_preset_avr(
+            EdidManufacturer::try_from("RDD").unwrap(),
+            0x1234.into(),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        );
+        for _ in 0..3 {
+            edid.descriptors
+                .push(EdidDescriptor::R4(EdidR4Descriptor::Dummy));
+        }
+
+        let dump = edid.dump().to_string();
+        assert!(dump.starts_with("edid-decode (hex):\n\nThe EDID doesn't fit"));
+        assert!(dump.contains("\n    Dummy Descriptor\n"));
+        assert!(dump.contains("\nChecksum: not serialized\n"));
+        assert!(dump.contains("\nFailures:\n\nEDID:\n  "));
+        assert!(dump.ends_with("\nEDID conformity: FAIL\n"));
+    }
+}
@@ -1,8 +1,12 @@
+use alloc::collections::BTreeMap;
+use core::{cmp::Reverse, fmt};
+
 use num_traits::ToPrimitive;
 use typed_builder::TypedBuilder;
 
 use crate::{
-    utils::div_round_up, EdidDescriptorDetailedTiming, EdidTypeConversionError, IntoBytes,
+    edid_block_checksum, field_map::FieldMap, utils::div_round_up, EdidDescriptorDetailedTiming,
+    EdidExtensionDisplayID, EdidSerializationWarning, EdidTypeConversionError, IntoBytes,
 };
 
 const UNIT_KHZ: usize = 1000;
@@ -11,11 +15,18 @@ const UNIT_MHZ: usize = 1000 * UNIT_KHZ;
 const EDID_EXTENSION_CTA_861_LEN: usize = 128;
 
 const EDID_EXTENSION_CTA_861_DATA_BLOCK_HEADER_LEN: usize = 1;
+const EDID_EXTENSION_CTA_861_DATA_BLOCK_PAYLOAD_MAX_LEN: usize = 31;
 const EDID_EXTENSION_CTA_861_AUDIO_DESCRIPTOR_LEN: usize = 3;
 const EDID_EXTENSION_CTA_861_VIDEO_DESCRIPTOR_LEN: usize = 1;
+const EDID_EXTENSION_CTA_861_AUDIO_DESCRIPTORS_MAX: usize =
+    EDID_EXTENSION_CTA_861_DATA_BLOCK_PAYLOAD_MAX_LEN / EDID_EXTENSION_CTA_861_AUDIO_DESCRIPTOR_LEN;
+const EDID_EXTENSION_CTA_861_VIDEO_DESCRIPTORS_MAX: usize =
+    EDID_EXTENSION_CTA_861_DATA_BLOCK_PAYLOAD_MAX_LEN / EDID_EXTENSION_CTA_861_VIDEO_DESCRIPTOR_LEN;
 const EDID_EXTENSION_CTA_861_VENDOR_OUI_LEN: usize = 3;
 const EDID_EXTENSION_CTA_861_VENDOR_HEADER_LEN: usize =
     EDID_EXTENSION_CTA_861_DATA_BLOCK_HEADER_LEN + EDID_EXTENSION_CTA_861_VENDOR_OUI_LEN;
+const EDID_EXTENSION_CTA_861_VENDOR_PAYLOAD_MAX_LEN: usize =
+    EDID_EXTENSION_CTA_861_DATA_BLOCK_PAYLOAD_MAX_LEN - EDID_EXTENSION_CTA_861_VENDOR_OUI_LEN;
 const EDID_EXTENSION_CTA_861_SPEAKER_ALLOCATION_LEN: usize =
     EDID_EXTENSION_CTA_861_DATA_BLOCK_HEADER_LEN + 3;
 // TODO: VESA Display Transfer Characteristic Data Block
@@ -25,11 +36,58 @@ const EDID_EXTENSION_CTA_861_VIDEO_CAPABILITY_LEN: usize =
     EDID_EXTENSION_CTA_861_DATA_BLOCK_EXTENDED_HEADER_LEN + 1;
 const EDID_EXTENSION_CTA_861_COLORIMETRY_LEN: usize =
     EDID_EXTENSION_CTA_861_DATA_BLOCK_EXTENDED_HEADER_LEN + 2;
+const EDID_EXTENSION_CTA_861_HDR_STATIC_METADATA_HEADER_LEN: usize =
+    EDID_EXTENSION_CTA_861_DATA_BLOCK_EXTENDED_HEADER_LEN + 2;
 
 const EDID_EXTENSION_CTA_861_HDMI_HEADER_LEN: usize = EDID_EXTENSION_CTA_861_VENDOR_HEADER_LEN + 2;
 const EDID_EXTENSION_CTA_861_HDMI_VIDEO_HEADER_LEN: usize = 2;
+const EDID_EXTENSION_CTA_861_HDMI_VICS_MAX: usize = 7;
+
+/// Returns the first byte of a data block, holding its tag and the length of its payload.
+///
+/// The data blocks can't be built with more than 31 bytes of payload, so the length always
+/// fits in its 5 bits.
+fn data_block_header(tag: u8, size: usize) -> u8 {
+    let len = size - EDID_EXTENSION_CTA_861_DATA_BLOCK_HEADER_LEN;
+    debug_assert!(
+        len <= EDID_EXTENSION_CTA_861_DATA_BLOCK_PAYLOAD_MAX_LEN,
+        "Data Block payload is too large ({len} vs maximum {EDID_EXTENSION_CTA_861_DATA_BLOCK_PAYLOAD_MAX_LEN} bytes)"
+    );
+
+    tag << 5 | len.to_u8().expect("Size would overflow our type")
+}
+
+/// Makes sure a list of descriptors isn't longer than what its data block can hold.
+fn check_list_len<T>(
+    list: &[T],
+    max: usize,
+    field: &'static str,
+) -> Result<(), EdidTypeConversionError<usize>> {
+    if list.len() > max {
+        return Err(EdidTypeConversionError::Range(list.len(), None, Some(max)).in_field(field));
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_bounded_list<'de, D, T, const MAX: usize>(
+    deserializer: D,
+) -> Result<Vec<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    let list: Vec<T> = serde::Deserialize::deserialize(deserializer)?;
+
+    check_list_len(&list, MAX, "list").map_err(serde::de::Error::custom)?;
+
+    Ok(list)
+}
 
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
 pub struct EdidExtensionCTA861AudioDataBlockChannels(u8);
 
 impl TryFrom<u8> for EdidExtensionCTA861AudioDataBlockChannels {
@@ -47,6 +105,7 @@ impl TryFrom<u8> for EdidExtensionCTA861AudioDataBlockChannels {
 #[allow(clippy::enum_variant_names)]
 #[repr(u8)]
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidExtensionCTA861AudioDataBlockSamplingFrequency {
     Frequency32kHz = 0,
     Frequency44_1kHz,
@@ -60,6 +119,7 @@ pub enum EdidExtensionCTA861AudioDataBlockSamplingFrequency {
 #[allow(clippy::enum_variant_names)]
 #[repr(u8)]
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidExtensionCTA861AudioDataBlockSamplingRate {
     Rate16Bit = 0,
     Rate20Bit,
@@ -67,6 +127,8 @@ pub enum EdidExtensionCTA861AudioDataBlockSamplingRate {
 }
 
 #[derive(Clone, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 #[builder(mutators(
     #[allow(unreachable_pub)]
     pub fn sampling_frequencies(&mut self, freqs: Vec<EdidExtensionCTA861AudioDataBlockSamplingFrequency>) {
@@ -92,19 +154,100 @@ pub struct EdidExtensionCTA861AudioDataBlockLPCM {
     channels: EdidExtensionCTA861AudioDataBlockChannels,
 
     #[builder(via_mutators)]
+    #[cfg_attr(feature = "serde", serde(default))]
     sampling_frequencies: Vec<EdidExtensionCTA861AudioDataBlockSamplingFrequency>,
 
     #[builder(via_mutators)]
+    #[cfg_attr(feature = "serde", serde(default))]
     sampling_rates: Vec<EdidExtensionCTA861AudioDataBlockSamplingRate>,
 }
 
+/// The Audio Format Extension Type Code of a Short Audio Descriptor using the Audio Format Code
+/// 15.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
+pub struct EdidExtensionCTA861AudioDataBlockExtendedTypeCode(u8);
+
+impl TryFrom<u8> for EdidExtensionCTA861AudioDataBlockExtendedTypeCode {
+    type Error = EdidTypeConversionError<u8>;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        // Codes 0 to 3 are reserved, and the code is stored on 5 bits.
+        if !(4..=31).contains(&value) {
+            return Err(EdidTypeConversionError::Range(value, Some(4), Some(31)));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+/// The bits 0 to 2 of the third byte of an extended Short Audio Descriptor, whose meaning depends
+/// on the Audio Format Extension Type Code.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
+pub struct EdidExtensionCTA861AudioDataBlockExtendedFlags(u8);
+
+impl TryFrom<u8> for EdidExtensionCTA861AudioDataBlockExtendedFlags {
+    type Error = EdidTypeConversionError<u8>;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if value > 7 {
+            return Err(EdidTypeConversionError::Range(value, None, Some(7)));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+/// A Short Audio Descriptor using the Audio Format Code 15, where the format is identified by the
+/// Extension Type Code stored in the third byte.
+#[derive(Clone, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[builder(mutators(
+    #[allow(unreachable_pub)]
+    pub fn sampling_frequencies(&mut self, freqs: Vec<EdidExtensionCTA861AudioDataBlockSamplingFrequency>) {
+        self.sampling_frequencies = freqs;
+    }
+
+    #[allow(unreachable_pub)]
+    pub fn add_sampling_frequency(&mut self, freq: EdidExtensionCTA861AudioDataBlockSamplingFrequency) {
+        self.sampling_frequencies.push(freq);
+    }
+))]
+pub struct EdidExtensionCTA861AudioDataBlockExtended {
+    type_code: EdidExtensionCTA861AudioDataBlockExtendedTypeCode,
+    channels: EdidExtensionCTA861AudioDataBlockChannels,
+
+    #[builder(via_mutators)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    sampling_frequencies: Vec<EdidExtensionCTA861AudioDataBlockSamplingFrequency>,
+
+    #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    flags: EdidExtensionCTA861AudioDataBlockExtendedFlags,
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidExtensionCTA861AudioDataBlockDesc {
     #[allow(clippy::upper_case_acronyms)]
     LPCM(EdidExtensionCTA861AudioDataBlockLPCM),
+    Extended(EdidExtensionCTA861AudioDataBlockExtended),
 }
 
+/// A CTA-861 Audio Data Block.
+///
+/// It can hold up to 10 Short Audio Descriptors, the builder returns an error otherwise.
 #[derive(Clone, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[builder(build_method(
+    into = Result<EdidExtensionCTA861AudioDataBlock, EdidTypeConversionError<usize>>,
+    doc = "Builds the Audio Data Block.\n\n# Errors\n\nIf there are more Short Audio Descriptors than a data block can hold."
+))]
 #[builder(mutators(
     #[allow(unreachable_pub)]
     pub fn descriptors(&mut self, desc: Vec<EdidExtensionCTA861AudioDataBlockDesc>) {
@@ -118,17 +261,50 @@ pub enum EdidExtensionCTA861AudioDataBlockDesc {
 ))]
 pub struct EdidExtensionCTA861AudioDataBlock {
     #[builder(via_mutators)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            deserialize_with = "deserialize_bounded_list::<_, _, EDID_EXTENSION_CTA_861_AUDIO_DESCRIPTORS_MAX>"
+        )
+    )]
     desc: Vec<EdidExtensionCTA861AudioDataBlockDesc>,
 }
 
+impl From<EdidExtensionCTA861AudioDataBlock>
+    for Result<EdidExtensionCTA861AudioDataBlock, EdidTypeConversionError<usize>>
+{
+    fn from(value: EdidExtensionCTA861AudioDataBlock) -> Self {
+        check_list_len(
+            &value.desc,
+            EDID_EXTENSION_CTA_861_AUDIO_DESCRIPTORS_MAX,
+            "descriptors",
+        )?;
+
+        Ok(value)
+    }
+}
+
+impl EdidExtensionCTA861AudioDataBlock {
+    /// Returns the maximum number of channels of the LPCM Short Audio Descriptors, if any.
+    pub(crate) fn max_lpcm_channels(&self) -> Option<usize> {
+        self.desc
+            .iter()
+            .filter_map(|desc| match desc {
+                EdidExtensionCTA861AudioDataBlockDesc::LPCM(lpcm) => {
+                    Some(usize::from(lpcm.channels.0))
+                }
+                EdidExtensionCTA861AudioDataBlockDesc::Extended(_) => None,
+            })
+            .max()
+    }
+}
+
 impl IntoBytes for EdidExtensionCTA861AudioDataBlock {
     fn into_bytes(self) -> Vec<u8> {
         let mut data = Vec::with_capacity(self.size());
 
-        let size = (self.size() - 1)
-            .to_u8()
-            .expect("Size would overflow our type");
-        data.push(1 << 5 | size);
+        data.push(data_block_header(1, self.size()));
 
         for desc in &self.desc {
             match desc {
@@ -145,6 +321,18 @@ impl IntoBytes for EdidExtensionCTA861AudioDataBlock {
                         byte2 |= 1 << (*r as u8);
                     }
 
+                    data.extend_from_slice(&[byte0, byte1, byte2]);
+                }
+                EdidExtensionCTA861AudioDataBlockDesc::Extended(b) => {
+                    let byte0 = 15 << 3 | (b.channels.0 - 1);
+
+                    let mut byte1 = 0;
+                    for f in &b.sampling_frequencies {
+                        byte1 |= 1 << (*f as u8);
+                    }
+
+                    let byte2 = b.type_code.0 << 3 | b.flags.0;
+
                     data.extend_from_slice(&[byte0, byte1, byte2]);
                 }
             }
@@ -159,7 +347,57 @@ impl IntoBytes for EdidExtensionCTA861AudioDataBlock {
     }
 }
 
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_cta861_audio {
+    use crate::{
+        EdidExtensionCTA861AudioDataBlock, EdidExtensionCTA861AudioDataBlockChannels,
+        EdidExtensionCTA861AudioDataBlockDesc, EdidExtensionCTA861AudioDataBlockExtended,
+        EdidExtensionCTA861AudioDataBlockExtendedFlags,
+        EdidExtensionCTA861AudioDataBlockExtendedTypeCode,
+        EdidExtensionCTA861AudioDataBlockSamplingFrequency, IntoBytes,
+    };
+
+    #[test]
+    fn test_binary_extended() {
+        // MPEG-H 3D Audio, 8 channels, at 48 and 96 kHz.
+        let adb = EdidExtensionCTA861AudioDataBlock::builder()
+            .add_short_audio_descriptor(EdidExtensionCTA861AudioDataBlockDesc::Extended(
+                EdidExtensionCTA861AudioDataBlockExtended::builder()
+                    .type_code(
+                        EdidExtensionCTA861AudioDataBlockExtendedTypeCode::try_from(11).unwrap(),
+                    )
+                    .channels(EdidExtensionCTA861AudioDataBlockChannels::try_from(8).unwrap())
+                    .add_sampling_frequency(
+                        EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency48kHz,
+                    )
+                    .add_sampling_frequency(
+                        EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency96kHz,
+                    )
+                    .flags(EdidExtensionCTA861AudioDataBlockExtendedFlags::try_from(2).unwrap())
+                    .build(),
+            ))
+            .build()
+            .unwrap();
+
+        assert_eq!(adb.into_bytes(), &[0x23, 0x7f, 0x14, 0x5a]);
+    }
+
+    #[test]
+    fn test_range() {
+        assert!(EdidExtensionCTA861AudioDataBlockExtendedTypeCode::try_from(3).is_err());
+        assert!(EdidExtensionCTA861AudioDataBlockExtendedTypeCode::try_from(4).is_ok());
+        assert!(EdidExtensionCTA861AudioDataBlockExtendedTypeCode::try_from(31).is_ok());
+        assert!(EdidExtensionCTA861AudioDataBlockExtendedTypeCode::try_from(32).is_err());
+
+        assert!(EdidExtensionCTA861AudioDataBlockExtendedFlags::try_from(7).is_ok());
+        assert!(EdidExtensionCTA861AudioDataBlockExtendedFlags::try_from(8).is_err());
+    }
+}
+
 #[derive(Clone, Copy, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 #[builder(field_defaults(setter(strip_bool)))]
 pub struct EdidExtensionCTA861SpeakerAllocationDataBlock {
     front_left_front_right: bool,
@@ -184,14 +422,44 @@ pub struct EdidExtensionCTA861SpeakerAllocationDataBlock {
     top_left_surround_top_right_surround: bool,
 }
 
+impl EdidExtensionCTA861SpeakerAllocationDataBlock {
+    /// Returns the number of speakers described by the block.
+    pub(crate) fn num_speakers(&self) -> usize {
+        let pairs = [
+            self.front_left_front_right,
+            self.back_left_back_right,
+            self.front_left_of_center_front_right_of_center,
+            self.rear_left_of_center_rear_right_of_center,
+            self.front_left_wide_front_right_wide,
+            self.top_front_left_top_front_right,
+            self.left_surround_right_surround,
+            self.side_left_side_right,
+            self.top_side_left_top_side_right,
+            self.top_back_left_top_back_right,
+            self.bottom_from_left_bottom_front_right,
+            self.top_left_surround_top_right_surround,
+        ];
+
+        let singles = [
+            self.low_frequency_effects,
+            self.front_center,
+            self.back_center,
+            self.top_center,
+            self.top_front_center,
+            self.low_frequency_effects_2,
+            self.top_back_center,
+            self.bottom_front_center,
+        ];
+
+        pairs.iter().filter(|s| **s).count() * 2 + singles.iter().filter(|s| **s).count()
+    }
+}
+
 impl IntoBytes for EdidExtensionCTA861SpeakerAllocationDataBlock {
     fn into_bytes(self) -> Vec<u8> {
         let mut data = Vec::with_capacity(EDID_EXTENSION_CTA_861_SPEAKER_ALLOCATION_LEN);
 
-        let size = (self.size() - 1)
-            .to_u8()
-            .expect("Size would overflow our type");
-        data.push(4 << 5 | size);
+        data.push(data_block_header(4, self.size()));
 
         let mut byte = 0;
         if self.front_left_wide_front_right_wide {
@@ -288,6 +556,8 @@ impl IntoBytes for EdidExtensionCTA861SpeakerAllocationDataBlock {
 }
 
 #[derive(Clone, Copy, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 #[builder(field_defaults(default))]
 pub struct EdidExtensionCTA861ColorimetryDataBlock {
     xv_ycc_601: bool,
@@ -299,16 +569,26 @@ pub struct EdidExtensionCTA861ColorimetryDataBlock {
     bt_2020_ycc: bool,
     bt_2020_rgb: bool,
     dci_p3: bool,
+    ictcp: bool,
+
+    /// Introduced by CTA-861.6. It's set by default since some EDID checkers require it, but
+    /// must be cleared to reproduce EDIDs predating it.
+    #[builder(default = true)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    st2113_rgb: bool,
+
+    /// Gamut-related metadata profiles supported, MD0 to MD3.
+    md0: bool,
+    md1: bool,
+    md2: bool,
+    md3: bool,
 }
 
 impl IntoBytes for EdidExtensionCTA861ColorimetryDataBlock {
     fn into_bytes(self) -> Vec<u8> {
         let mut data = Vec::with_capacity(EDID_EXTENSION_CTA_861_COLORIMETRY_LEN);
 
-        let size = (self.size() - 1)
-            .to_u8()
-            .expect("Size would overflow our type");
-        data.push(7 << 5 | size);
+        data.push(data_block_header(7, self.size()));
         data.push(5);
 
         let mut byte = 0;
@@ -346,12 +626,35 @@ impl IntoBytes for EdidExtensionCTA861ColorimetryDataBlock {
 
         data.push(byte);
 
-        // FIXME: introduced by CTA-861.6 and made mandatory by edid-check
-        let mut byte = 1 << 5;
+        let mut byte = 0;
         if self.dci_p3 {
             byte |= 1 << 7;
         }
 
+        if self.ictcp {
+            byte |= 1 << 6;
+        }
+
+        if self.st2113_rgb {
+            byte |= 1 << 5;
+        }
+
+        if self.md3 {
+            byte |= 1 << 3;
+        }
+
+        if self.md2 {
+            byte |= 1 << 2;
+        }
+
+        if self.md1 {
+            byte |= 1 << 1;
+        }
+
+        if self.md0 {
+            byte |= 1 << 0;
+        }
+
         data.push(byte);
 
         data
@@ -362,13 +665,299 @@ impl IntoBytes for EdidExtensionCTA861ColorimetryDataBlock {
     }
 }
 
+#[cfg(test)]
+mod test_cta861_colorimetry {
+    use crate::{EdidExtensionCTA861ColorimetryDataBlock, IntoBytes};
+
+    #[test]
+    fn test_binary() {
+        let cdb = EdidExtensionCTA861ColorimetryDataBlock::builder()
+            .bt_2020_rgb(true)
+            .bt_2020_ycc(true)
+            .dci_p3(true)
+            .ictcp(true)
+            .md0(true)
+            .md3(true)
+            .build();
+
+        assert_eq!(cdb.into_bytes(), &[0xe3, 0x05, 0xc0, 0xe9]);
+    }
+
+    #[test]
+    fn test_binary_st2113() {
+        let cdb = EdidExtensionCTA861ColorimetryDataBlock::builder().build();
+        assert_eq!(cdb.into_bytes(), &[0xe3, 0x05, 0x00, 0x20]);
+
+        let cdb = EdidExtensionCTA861ColorimetryDataBlock::builder()
+            .st2113_rgb(false)
+            .build();
+        assert_eq!(cdb.into_bytes(), &[0xe3, 0x05, 0x00, 0x00]);
+    }
+}
+
+/// An HDR Static Metadata Data Block, listing the Electro-Optical Transfer Functions supported
+/// by the display, and the luminance of the content it's been designed for.
+///
+/// The luminance values are stored coded, as described by CTA-861-G, Section 7.5.13. They are
+/// optional, but are stored in order, so any value left unset before a set one is stored as 0.
+#[derive(Clone, Copy, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[builder(field_defaults(default))]
+pub struct EdidExtensionCTA861HdrStaticMetadataDataBlock {
+    traditional_sdr: bool,
+    traditional_hdr: bool,
+    smpte_st2084: bool,
+    hlg: bool,
+
+    /// Whether the Static Metadata Type 1 is supported.
+    static_metadata_type1: bool,
+
+    /// The coded Desired Content Max Luminance.
+    #[builder(setter(strip_option))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    max_luminance: Option<u8>,
+
+    /// The coded Desired Content Max Frame-average Luminance.
+    #[builder(setter(strip_option))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    max_frame_average_luminance: Option<u8>,
+
+    /// The coded Desired Content Min Luminance.
+    #[builder(setter(strip_option))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    min_luminance: Option<u8>,
+}
+
+impl EdidExtensionCTA861HdrStaticMetadataDataBlock {
+    fn luminance(&self) -> impl Iterator<Item = u8> {
+        let luminance = [
+            self.max_luminance,
+            self.max_frame_average_luminance,
+            self.min_luminance,
+        ];
+        let len = luminance
+            .iter()
+            .rposition(Option::is_some)
+            .map_or(0, |idx| idx + 1);
+
+        luminance.into_iter().take(len).map(|v| v.unwrap_or(0))
+    }
+}
+
+impl IntoBytes for EdidExtensionCTA861HdrStaticMetadataDataBlock {
+    fn into_bytes(self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(self.size());
+
+        data.push(data_block_header(7, self.size()));
+        data.push(6);
+
+        let mut byte = 0;
+        if self.hlg {
+            byte |= 1 << 3;
+        }
+
+        if self.smpte_st2084 {
+            byte |= 1 << 2;
+        }
+
+        if self.traditional_hdr {
+            byte |= 1 << 1;
+        }
+
+        if self.traditional_sdr {
+            byte |= 1 << 0;
+        }
+
+        data.push(byte);
+        data.push(u8::from(self.static_metadata_type1));
+        data.extend(self.luminance());
+
+        data
+    }
+
+    fn size(&self) -> usize {
+        EDID_EXTENSION_CTA_861_HDR_STATIC_METADATA_HEADER_LEN + self.luminance().count()
+    }
+}
+
+#[cfg(test)]
+mod test_cta861_hdr_static_metadata {
+    use crate::{EdidExtensionCTA861HdrStaticMetadataDataBlock, IntoBytes};
+
+    #[test]
+    fn test_binary() {
+        let hdr = EdidExtensionCTA861HdrStaticMetadataDataBlock::builder()
+            .traditional_sdr(true)
+            .smpte_st2084(true)
+            .hlg(true)
+            .static_metadata_type1(true)
+            .build();
+        assert_eq!(hdr.into_bytes(), &[0xe3, 0x06, 0x0d, 0x01]);
+
+        let hdr = EdidExtensionCTA861HdrStaticMetadataDataBlock::builder()
+            .traditional_sdr(true)
+            .smpte_st2084(true)
+            .static_metadata_type1(true)
+            .max_luminance(0x78)
+            .max_frame_average_luminance(0x5a)
+            .min_luminance(0x34)
+            .build();
+        assert_eq!(
+            hdr.into_bytes(),
+            &[0xe6, 0x06, 0x05, 0x01, 0x78, 0x5a, 0x34]
+        );
+    }
+
+    #[test]
+    fn test_binary_partial_luminance() {
+        let hdr = EdidExtensionCTA861HdrStaticMetadataDataBlock::builder()
+            .traditional_sdr(true)
+            .static_metadata_type1(true)
+            .max_frame_average_luminance(0x5a)
+            .build();
+        assert_eq!(hdr.into_bytes(), &[0xe5, 0x06, 0x01, 0x01, 0x00, 0x5a]);
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidExtensionCTA861VideoDataBlockDesc {
     Low(bool, u8),
     High(u8),
 }
 
+impl EdidExtensionCTA861VideoDataBlockDesc {
+    /// Creates a non-native Short Video Descriptor in a const context.
+    ///
+    /// # Panics
+    ///
+    /// If the VIC is 0, or between 128 and 192 since those codes hold the native VICs.
+    #[must_use]
+    pub const fn from_vic(vic: u8) -> Self {
+        assert!(
+            vic != 0 && !(vic >= 128 && vic <= 192),
+            "VIC must be between 1 and 127, or between 193 and 255."
+        );
+
+        if vic < 64 {
+            Self::Low(false, vic)
+        } else {
+            Self::High(vic)
+        }
+    }
+
+    /// Creates a native Short Video Descriptor in a const context.
+    ///
+    /// # Panics
+    ///
+    /// If the VIC isn't between 1 and 64, the only ones that can be marked as native.
+    #[must_use]
+    pub const fn native(vic: u8) -> Self {
+        assert!(
+            vic >= 1 && vic <= 64,
+            "Native VIC must be between 1 and 64."
+        );
+
+        Self::Low(true, vic)
+    }
+
+    /// Returns the Video Identification Code of the Short Video Descriptor.
+    #[must_use]
+    pub const fn vic(self) -> u8 {
+        match self {
+            Self::Low(_, vic) | Self::High(vic) => vic,
+        }
+    }
+
+    /// Returns whether the Short Video Descriptor is marked as native.
+    #[must_use]
+    pub const fn is_native(self) -> bool {
+        matches!(self, Self::Low(true, _))
+    }
+
+    /// Returns the Short Video Descriptor encoded as it will be found in the EDID.
+    #[must_use]
+    pub const fn into_raw(self) -> u8 {
+        match self {
+            Self::Low(native, vic) => {
+                if native {
+                    1 << 7 | vic
+                } else {
+                    vic
+                }
+            }
+            Self::High(vic) => vic,
+        }
+    }
+
+    /// Returns a list of Short Video Descriptors encoded as they will be found in the EDID.
+    /// This can be called in a const context.
+    #[must_use]
+    pub const fn list_into_raw<const N: usize>(descs: &[Self; N]) -> [u8; N] {
+        let mut bytes = [0; N];
+
+        let mut idx = 0;
+        while idx < N {
+            bytes[idx] = descs[idx].into_raw();
+            idx += 1;
+        }
+
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod test_cta861_video_data_block_desc {
+    use crate::EdidExtensionCTA861VideoDataBlockDesc;
+
+    #[test]
+    fn test_raw() {
+        const DESCS: [u8; 4] = EdidExtensionCTA861VideoDataBlockDesc::list_into_raw(&[
+            EdidExtensionCTA861VideoDataBlockDesc::native(16),
+            EdidExtensionCTA861VideoDataBlockDesc::native(64),
+            EdidExtensionCTA861VideoDataBlockDesc::from_vic(97),
+            EdidExtensionCTA861VideoDataBlockDesc::from_vic(193),
+        ]);
+
+        assert_eq!(DESCS, [0x90, 0xc0, 97, 193]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Native VIC must be between 1 and 64.")]
+    fn test_native_out_of_range() {
+        let _desc = EdidExtensionCTA861VideoDataBlockDesc::native(97);
+    }
+
+    #[test]
+    #[should_panic(expected = "Native VIC must be between 1 and 64.")]
+    fn test_native_zero() {
+        let _desc = EdidExtensionCTA861VideoDataBlockDesc::native(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "VIC must be between 1 and 127, or between 193 and 255.")]
+    fn test_vic_zero() {
+        let _desc = EdidExtensionCTA861VideoDataBlockDesc::from_vic(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "VIC must be between 1 and 127, or between 193 and 255.")]
+    fn test_vic_reserved() {
+        let _desc = EdidExtensionCTA861VideoDataBlockDesc::from_vic(144);
+    }
+}
+
+/// A CTA-861 Video Data Block.
+///
+/// It can hold up to 31 Short Video Descriptors, the builder returns an error otherwise.
 #[derive(Clone, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[builder(build_method(
+    into = Result<EdidExtensionCTA861VideoDataBlock, EdidTypeConversionError<usize>>,
+    doc = "Builds the Video Data Block.\n\n# Errors\n\nIf there are more Short Video Descriptors than a data block can hold."
+))]
 #[builder(mutators(
     #[allow(unreachable_pub)]
     pub fn descriptors(&mut self, desc: Vec<EdidExtensionCTA861VideoDataBlockDesc>) {
@@ -377,45 +966,56 @@ pub enum EdidExtensionCTA861VideoDataBlockDesc {
 
     #[allow(unreachable_pub)]
     pub fn add_short_video_descriptor(&mut self, vic: u8) {
-        self.desc.push(if vic < 64 {
-            EdidExtensionCTA861VideoDataBlockDesc::Low(false, vic)
-        } else {
-            EdidExtensionCTA861VideoDataBlockDesc::High(vic)
-        });
+        self.desc
+            .push(EdidExtensionCTA861VideoDataBlockDesc::from_vic(vic));
     }
 
     #[allow(unreachable_pub)]
     pub fn add_native_short_video_descriptor(&mut self, vic: u8) {
         self.desc
-            .push(EdidExtensionCTA861VideoDataBlockDesc::Low(true, vic));
+            .push(EdidExtensionCTA861VideoDataBlockDesc::native(vic));
     }
 ))]
 pub struct EdidExtensionCTA861VideoDataBlock {
     #[builder(via_mutators)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            deserialize_with = "deserialize_bounded_list::<_, _, EDID_EXTENSION_CTA_861_VIDEO_DESCRIPTORS_MAX>"
+        )
+    )]
     desc: Vec<EdidExtensionCTA861VideoDataBlockDesc>,
 }
 
+impl From<EdidExtensionCTA861VideoDataBlock>
+    for Result<EdidExtensionCTA861VideoDataBlock, EdidTypeConversionError<usize>>
+{
+    fn from(value: EdidExtensionCTA861VideoDataBlock) -> Self {
+        check_list_len(
+            &value.desc,
+            EDID_EXTENSION_CTA_861_VIDEO_DESCRIPTORS_MAX,
+            "descriptors",
+        )?;
+
+        Ok(value)
+    }
+}
+
+impl EdidExtensionCTA861VideoDataBlock {
+    pub(crate) fn descriptors(&self) -> &[EdidExtensionCTA861VideoDataBlockDesc] {
+        &self.desc
+    }
+}
+
 impl IntoBytes for EdidExtensionCTA861VideoDataBlock {
     fn into_bytes(self) -> Vec<u8> {
         let mut data = Vec::with_capacity(self.size());
 
-        let size = (self.size() - 1)
-            .to_u8()
-            .expect("Size would overflow our type");
-
-        data.push(2 << 5 | size);
+        data.push(data_block_header(2, self.size()));
 
         for desc in &self.desc {
-            match desc {
-                EdidExtensionCTA861VideoDataBlockDesc::Low(native, vic) => {
-                    let byte = if *native { 1 << 7 | vic } else { *vic };
-
-                    data.push(byte);
-                }
-                EdidExtensionCTA861VideoDataBlockDesc::High(vic) => {
-                    data.push(*vic);
-                }
-            }
+            data.push(desc.into_raw());
         }
 
         data
@@ -427,7 +1027,12 @@ impl IntoBytes for EdidExtensionCTA861VideoDataBlock {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// A CEC physical address, such as 1.0.0.0.
+///
+/// With the `serde` feature, it's deserialized from an array of the four components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "[u8; 4]"))]
 pub struct CecAddress(u8, u8, u8, u8);
 
 impl TryFrom<[u8; 4]> for CecAddress {
@@ -440,11 +1045,20 @@ impl TryFrom<[u8; 4]> for CecAddress {
             }
         }
 
-        Ok(Self(value[0], value[1], value[2], value[2]))
+        Ok(Self(value[0], value[1], value[2], value[3]))
+    }
+}
+
+impl fmt::Display for CecAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:x}.{:x}.{:x}.{:x}", self.0, self.1, self.2, self.3)
     }
 }
 
+/// The maximum TMDS character rate, in MHz.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16"))]
 pub struct EdidExtensionCTA861Hdmi14bTmdsRate(u16);
 
 impl TryFrom<u16> for EdidExtensionCTA861Hdmi14bTmdsRate {
@@ -459,7 +1073,14 @@ impl TryFrom<u16> for EdidExtensionCTA861Hdmi14bTmdsRate {
     }
 }
 
+/// The HDMI 1.4b video capabilities of an HDMI Vendor-Specific Data Block.
+///
+/// It can hold up to 7 HDMI VICs, the builder returns an error otherwise.
 #[derive(Clone, Debug, TypedBuilder)]
+#[builder(build_method(
+    into = Result<EdidExtensionCTA861Hdmi14bDataBlockVideo, EdidTypeConversionError<usize>>,
+    doc = "Builds the HDMI video capabilities.\n\n# Errors\n\nIf there are more HDMI VICs than its 3 bits length field can describe."
+))]
 #[builder(mutators(
     #[allow(unreachable_pub)]
     pub fn add_vic(&mut self, vic: u8) {
@@ -471,60 +1092,187 @@ impl TryFrom<u16> for EdidExtensionCTA861Hdmi14bTmdsRate {
         self.vics = vics;
     }
 ))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct EdidExtensionCTA861Hdmi14bDataBlockVideo {
     #[builder(via_mutators)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            deserialize_with = "deserialize_bounded_list::<_, _, EDID_EXTENSION_CTA_861_HDMI_VICS_MAX>"
+        )
+    )]
     vics: Vec<u8>,
+
+    /// Sets the `3D_present` bit, advertising the support of the 3D formats mandatory for HDMI
+    /// 1.4b sinks: Frame Packing and Top-and-Bottom for 1080p24 and 720p, and Side-by-Side (Half)
+    /// for 1080i.
+    #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    three_d_present: bool,
     // FIXME: Handle Image Size attributes
-    // FIXME: Handle 3d
+    // FIXME: Handle 3D_Multi_present and the 3D structures
+}
+
+impl From<EdidExtensionCTA861Hdmi14bDataBlockVideo>
+    for Result<EdidExtensionCTA861Hdmi14bDataBlockVideo, EdidTypeConversionError<usize>>
+{
+    fn from(value: EdidExtensionCTA861Hdmi14bDataBlockVideo) -> Self {
+        check_list_len(&value.vics, EDID_EXTENSION_CTA_861_HDMI_VICS_MAX, "vics")?;
+
+        Ok(value)
+    }
+}
+
+/// A video or audio latency reported in the HDMI Vendor-Specific Data Block.
+///
+/// With the `serde` feature, it's deserialized from a latency in milliseconds, or from a null
+/// value if the output isn't supported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Option<u16>"))]
+pub struct EdidExtensionCTA861HdmiLatency(Option<u16>);
+
+impl EdidExtensionCTA861HdmiLatency {
+    /// The video or audio output isn't supported.
+    pub const UNSUPPORTED: Self = Self(None);
+
+    // The raw value 0 means the latency is unknown, so it's never emitted: the latency is either
+    // provided, or the latency fields are omitted altogether.
+    fn into_raw(self) -> u8 {
+        match self.0 {
+            Some(ms) => (ms / 2 + 1)
+                .to_u8()
+                .expect("Latency would overflow our type"),
+            None => 255,
+        }
+    }
+}
+
+impl TryFrom<u16> for EdidExtensionCTA861HdmiLatency {
+    type Error = EdidTypeConversionError<u16>;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        if value > 500 {
+            return Err(EdidTypeConversionError::Range(value, None, Some(500)));
+        }
+
+        Ok(Self(Some(value)))
+    }
 }
 
+impl TryFrom<Option<u16>> for EdidExtensionCTA861HdmiLatency {
+    type Error = EdidTypeConversionError<u16>;
+
+    fn try_from(value: Option<u16>) -> Result<Self, Self::Error> {
+        value.map_or(Ok(Self::UNSUPPORTED), Self::try_from)
+    }
+}
+
+#[derive(Clone, Copy, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct EdidExtensionCTA861HdmiLatencyPair {
+    video: EdidExtensionCTA861HdmiLatency,
+    audio: EdidExtensionCTA861HdmiLatency,
+}
+
+impl EdidExtensionCTA861HdmiLatencyPair {
+    fn into_raw(self) -> [u8; 2] {
+        [self.video.into_raw(), self.audio.into_raw()]
+    }
+}
+
+/// The latencies reported in the HDMI Vendor-Specific Data Block.
+///
+/// The interlaced latencies can only be reported along with the progressive ones.
+#[derive(Clone, Copy, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct EdidExtensionCTA861HdmiLatencies {
+    progressive: EdidExtensionCTA861HdmiLatencyPair,
+
+    #[builder(default, setter(strip_option))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    interlaced: Option<EdidExtensionCTA861HdmiLatencyPair>,
+}
+
+/// The HDMI 1.4 Vendor-Specific Data Block.
+///
+/// With the `serde` feature, it can be deserialized, all fields but the physical address being
+/// optional.
 #[derive(Clone, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct EdidExtensionCTA861HdmiDataBlock {
     source_physical_address: CecAddress,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     deep_color_30_bits: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     deep_color_36_bits: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     deep_color_48_bits: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     deep_color_ycbcr_444: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     dvi_dual: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     acp_isrc: bool,
 
     #[builder(default, setter(strip_option))]
+    #[cfg_attr(feature = "serde", serde(default))]
     max_tmds_rate: Option<EdidExtensionCTA861Hdmi14bTmdsRate>,
 
     #[builder(default, setter(strip_option))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    latencies: Option<EdidExtensionCTA861HdmiLatencies>,
+
+    #[builder(default, setter(strip_option))]
+    #[cfg_attr(feature = "serde", serde(default))]
     video: Option<EdidExtensionCTA861Hdmi14bDataBlockVideo>,
     // FIXME: Handle CNC
-    // FIXME: Handle latencies
+}
+
+impl EdidExtensionCTA861HdmiDataBlock {
+    pub(crate) const fn source_physical_address(&self) -> CecAddress {
+        self.source_physical_address
+    }
+
+    pub(crate) fn set_source_physical_address(&mut self, address: CecAddress) {
+        self.source_physical_address = address;
+    }
+
+    /// Returns whether the sink supports more than 8 bits per color component.
+    pub(crate) const fn deep_color(&self) -> bool {
+        self.deep_color_30_bits || self.deep_color_36_bits || self.deep_color_48_bits
+    }
 }
 
 impl IntoBytes for EdidExtensionCTA861HdmiDataBlock {
     fn into_bytes(self) -> Vec<u8> {
         let mut data = Vec::with_capacity(self.size());
 
-        let size = (self.size() - 1)
-            .to_u8()
-            .expect("Size would overflow our type");
-
-        data.push(3 << 5 | size);
-        data.extend_from_slice(&[0x03u8, 0x0cu8, 0x00u8]);
+        data.push(data_block_header(3, self.size()));
+        data.extend_from_slice(&EdidExtensionCTA861VendorOui::HDMI_LLC.into_raw());
 
         data.push(self.source_physical_address.0 << 4 | self.source_physical_address.1);
         data.push(self.source_physical_address.2 << 4 | self.source_physical_address.3);
 
-        // FIXME: Handle latencies and CNC
-        if self.video.is_some() {
+        // FIXME: Handle CNC
+        if self.video.is_some() || self.latencies.is_some() {
             data.resize(9, 0);
         } else if self.max_tmds_rate.is_some() {
             data.resize(8, 0);
@@ -587,20 +1335,32 @@ impl IntoBytes for EdidExtensionCTA861HdmiDataBlock {
         if data.len() > 8 {
             let mut byte = 0;
 
+            if let Some(latencies) = self.latencies {
+                byte |= 1 << 7;
+
+                if latencies.interlaced.is_some() {
+                    byte |= 1 << 6;
+                }
+            }
+
             if self.video.is_some() {
                 byte |= 1 << 5;
             }
 
-            // FIXME: Handle latencies
-
             data[8] = byte;
         }
 
-        // FIXME: Handle latencies
+        if let Some(latencies) = self.latencies {
+            data.extend_from_slice(&latencies.progressive.into_raw());
+
+            if let Some(interlaced) = latencies.interlaced {
+                data.extend_from_slice(&interlaced.into_raw());
+            }
+        }
 
         if let Some(val) = self.video {
-            // FIXME: Handle 3D and Image Size attributes
-            data.push(0);
+            // FIXME: Handle 3D_Multi_present and Image Size attributes
+            data.push(if val.three_d_present { 1 << 7 } else { 0 });
 
             let vics = val
                 .vics
@@ -622,8 +1382,8 @@ impl IntoBytes for EdidExtensionCTA861HdmiDataBlock {
     fn size(&self) -> usize {
         let mut size = EDID_EXTENSION_CTA_861_HDMI_HEADER_LEN;
 
-        // FIXME: Handle latencies and CNC
-        if self.video.is_some() {
+        // FIXME: Handle CNC
+        if self.video.is_some() || self.latencies.is_some() {
             size += 3;
         } else if self.max_tmds_rate.is_some() {
             size += 2;
@@ -637,22 +1397,113 @@ impl IntoBytes for EdidExtensionCTA861HdmiDataBlock {
             size += 1;
         }
 
-        // FIXME: Handle latencies
+        if let Some(latencies) = &self.latencies {
+            size += 2;
+
+            if latencies.interlaced.is_some() {
+                size += 2;
+            }
+        }
 
         if let Some(val) = &self.video {
             size += EDID_EXTENSION_CTA_861_HDMI_VIDEO_HEADER_LEN;
             size += val.vics.len();
 
             // FIXME: Handle 3d
-        };
+        }
 
         size
     }
 }
 
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_cta861_hdmi_latencies {
+    use crate::{
+        CecAddress, EdidExtensionCTA861Hdmi14bDataBlockVideo, EdidExtensionCTA861HdmiDataBlock,
+        EdidExtensionCTA861HdmiLatencies, EdidExtensionCTA861HdmiLatency,
+        EdidExtensionCTA861HdmiLatencyPair, IntoBytes,
+    };
+
+    fn pair(
+        video: EdidExtensionCTA861HdmiLatency,
+        audio: u16,
+    ) -> EdidExtensionCTA861HdmiLatencyPair {
+        EdidExtensionCTA861HdmiLatencyPair::builder()
+            .video(video)
+            .audio(EdidExtensionCTA861HdmiLatency::try_from(audio).unwrap())
+            .build()
+    }
+
+    #[test]
+    fn test_binary() {
+        let hdmi = EdidExtensionCTA861HdmiDataBlock::builder()
+            .source_physical_address(CecAddress::try_from([1, 0, 0, 0]).unwrap())
+            .latencies(
+                EdidExtensionCTA861HdmiLatencies::builder()
+                    .progressive(pair(
+                        EdidExtensionCTA861HdmiLatency::try_from(20).unwrap(),
+                        0,
+                    ))
+                    .build(),
+            )
+            .build();
+
+        let size = hdmi.size();
+        let bytes = hdmi.into_bytes();
+        assert_eq!(bytes.len(), size);
+        assert_eq!(&bytes[6..], &[0x00, 0x00, 0x80, 11, 1]);
+
+        let hdmi = EdidExtensionCTA861HdmiDataBlock::builder()
+            .source_physical_address(CecAddress::try_from([1, 0, 0, 0]).unwrap())
+            .latencies(
+                EdidExtensionCTA861HdmiLatencies::builder()
+                    .progressive(pair(
+                        EdidExtensionCTA861HdmiLatency::try_from(500).unwrap(),
+                        10,
+                    ))
+                    .interlaced(pair(EdidExtensionCTA861HdmiLatency::UNSUPPORTED, 10))
+                    .build(),
+            )
+            .build();
+
+        let size = hdmi.size();
+        let bytes = hdmi.into_bytes();
+        assert_eq!(bytes.len(), size);
+        assert_eq!(&bytes[6..], &[0x00, 0x00, 0xc0, 251, 6, 255, 6]);
+    }
+
+    #[test]
+    fn test_binary_3d() {
+        let hdmi = EdidExtensionCTA861HdmiDataBlock::builder()
+            .source_physical_address(CecAddress::try_from([1, 0, 0, 0]).unwrap())
+            .video(
+                EdidExtensionCTA861Hdmi14bDataBlockVideo::builder()
+                    .three_d_present(true)
+                    .build()
+                    .unwrap(),
+            )
+            .build();
+
+        let size = hdmi.size();
+        let bytes = hdmi.into_bytes();
+        assert_eq!(bytes.len(), size);
+        assert_eq!(&bytes[6..], &[0x00, 0x00, 0x20, 0x80, 0x00]);
+    }
+
+    #[test]
+    fn test_range() {
+        assert!(EdidExtensionCTA861HdmiLatency::try_from(0).is_ok());
+        assert!(EdidExtensionCTA861HdmiLatency::try_from(500).is_ok());
+        assert!(EdidExtensionCTA861HdmiLatency::try_from(501).is_err());
+    }
+}
+
 #[repr(u8)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidExtensionCTA861VideoCapabilityQuantization {
+    #[default]
     NoData,
     Selectable,
 }
@@ -666,24 +1517,24 @@ impl EdidExtensionCTA861VideoCapabilityQuantization {
     }
 }
 
-impl Default for EdidExtensionCTA861VideoCapabilityQuantization {
-    fn default() -> Self {
-        Self::NoData
-    }
-}
-
 #[repr(u8)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidExtensionCTA861VideoCapabilityScanBehavior {
+    #[default]
     NotSupported,
     Overscanned,
     Underscanned,
     Both,
 }
 
-impl Default for EdidExtensionCTA861VideoCapabilityScanBehavior {
-    fn default() -> Self {
-        Self::NotSupported
+impl From<bool> for EdidExtensionCTA861VideoCapabilityQuantization {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::Selectable
+        } else {
+            Self::NoData
+        }
     }
 }
 
@@ -699,9 +1550,18 @@ impl EdidExtensionCTA861VideoCapabilityScanBehavior {
 }
 
 #[derive(Clone, Copy, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 #[builder(field_defaults(default))]
 pub struct EdidExtensionCTA861VideoCapabilityDataBlock {
+    /// Whether the YCbCr quantization range can be selected through the AVI `InfoFrame`. Setting it
+    /// from a bool is supported.
+    #[builder(setter(into))]
     qy_quant: EdidExtensionCTA861VideoCapabilityQuantization,
+
+    /// Whether the RGB quantization range can be selected through the AVI `InfoFrame`. Setting it
+    /// from a bool is supported.
+    #[builder(setter(into))]
     qs_quant: EdidExtensionCTA861VideoCapabilityQuantization,
     pt_scan: EdidExtensionCTA861VideoCapabilityScanBehavior,
     it_scan: EdidExtensionCTA861VideoCapabilityScanBehavior,
@@ -712,11 +1572,7 @@ impl IntoBytes for EdidExtensionCTA861VideoCapabilityDataBlock {
     fn into_bytes(self) -> Vec<u8> {
         let mut data = Vec::with_capacity(EDID_EXTENSION_CTA_861_VIDEO_CAPABILITY_LEN);
 
-        let size = (self.size() - 1)
-            .to_u8()
-            .expect("Size would overflow our type");
-
-        data.push(7 << 5 | size);
+        data.push(data_block_header(7, self.size()));
         data.push(0);
 
         let mut byte = 0;
@@ -736,24 +1592,494 @@ impl IntoBytes for EdidExtensionCTA861VideoCapabilityDataBlock {
     }
 }
 
+/// The IEEE OUI identifying the vendor of a Vendor-Specific Data Block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u32"))]
+pub struct EdidExtensionCTA861VendorOui(u32);
+
+impl TryFrom<u32> for EdidExtensionCTA861VendorOui {
+    type Error = EdidTypeConversionError<u32>;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if value > 0x00ff_ffff {
+            return Err(EdidTypeConversionError::Range(
+                value,
+                Some(0),
+                Some(0x00ff_ffff),
+            ));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl EdidExtensionCTA861VendorOui {
+    /// The OUI of HDMI Licensing, LLC, used by the HDMI 1.4b Vendor-Specific Data Block.
+    ///
+    /// The HDMI Vendor-Specific Data Block is described by
+    /// [`EdidExtensionCTA861HdmiDataBlock`], which always uses this OUI.
+    pub const HDMI_LLC: Self = Self(0x00_0c_03);
+
+    /// The OUI of the HDMI Forum, used by the HDMI Forum Vendor-Specific Data Block.
+    pub const HDMI_FORUM: Self = Self(0xc4_5d_d8);
+
+    fn into_raw(self) -> [u8; EDID_EXTENSION_CTA_861_VENDOR_OUI_LEN] {
+        let [lo, mid, hi, _] = self.0.to_le_bytes();
+
+        [lo, mid, hi]
+    }
+
+    /// Returns the OUI in the big-endian order used by `DisplayID`.
+    pub(crate) fn into_be_raw(self) -> [u8; EDID_EXTENSION_CTA_861_VENDOR_OUI_LEN] {
+        let [_, hi, mid, lo] = self.0.to_be_bytes();
+
+        [hi, mid, lo]
+    }
+}
+
+impl fmt::Display for EdidExtensionCTA861VendorOui {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [_, hi, mid, lo] = self.0.to_be_bytes();
+
+        write!(f, "{hi:02X}-{mid:02X}-{lo:02X}")
+    }
+}
+
+/// The payload of a Vendor-Specific Data Block, following the OUI.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EdidExtensionCTA861VendorPayload(Vec<u8>);
+
+impl TryFrom<Vec<u8>> for EdidExtensionCTA861VendorPayload {
+    type Error = EdidTypeConversionError<u8>;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        if value.len() > EDID_EXTENSION_CTA_861_VENDOR_PAYLOAD_MAX_LEN {
+            return Err(EdidTypeConversionError::Value(format!(
+                "Vendor-Specific Data Block Payload must be at most \
+                 {EDID_EXTENSION_CTA_861_VENDOR_PAYLOAD_MAX_LEN} bytes long."
+            )));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+/// A Vendor-Specific Data Block for a vendor redid doesn't know about.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "(EdidExtensionCTA861VendorOui, Vec<u8>)")
+)]
+pub struct EdidExtensionCTA861VendorDataBlock {
+    oui: EdidExtensionCTA861VendorOui,
+    payload: EdidExtensionCTA861VendorPayload,
+}
+
+impl EdidExtensionCTA861VendorDataBlock {
+    /// Creates a Vendor-Specific Data Block from its typed representation.
+    #[must_use]
+    pub fn from_typed<T: EdidExtensionCTA861VendorDataBlockType + ?Sized>(block: &T) -> Self {
+        Self {
+            oui: block.oui(),
+            payload: block.payload(),
+        }
+    }
+}
+
+/// Creates a Vendor-Specific Data Block from its OUI and payload.
+///
+/// The HDMI OUIs are rejected, since their payload has a layout defined by the HDMI
+/// specifications. HDMI Vendor-Specific Data Blocks must use
+/// [`EdidExtensionCTA861HdmiDataBlock`] instead, which takes care of the OUI, and HDMI Forum
+/// ones must be described by a type implementing [`EdidExtensionCTA861VendorDataBlockType`],
+/// converted with [`EdidExtensionCTA861VendorDataBlock::from_typed`].
+impl TryFrom<(EdidExtensionCTA861VendorOui, Vec<u8>)> for EdidExtensionCTA861VendorDataBlock {
+    type Error = EdidTypeConversionError<u8>;
+
+    fn try_from(value: (EdidExtensionCTA861VendorOui, Vec<u8>)) -> Result<Self, Self::Error> {
+        let (oui, payload) = value;
+        if oui == EdidExtensionCTA861VendorOui::HDMI_LLC
+            || oui == EdidExtensionCTA861VendorOui::HDMI_FORUM
+        {
+            return Err(EdidTypeConversionError::Value(format!(
+                "OUI {oui} is reserved to the HDMI Vendor-Specific Data Blocks."
+            )));
+        }
+
+        let payload = EdidExtensionCTA861VendorPayload::try_from(payload)?;
+
+        Ok(Self { oui, payload })
+    }
+}
+
+impl IntoBytes for EdidExtensionCTA861VendorDataBlock {
+    fn into_bytes(self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(self.size());
+
+        data.push(data_block_header(3, self.size()));
+        data.extend_from_slice(&self.oui.into_raw());
+        data.extend_from_slice(&self.payload.0);
+
+        data
+    }
+
+    fn size(&self) -> usize {
+        EDID_EXTENSION_CTA_861_VENDOR_HEADER_LEN + self.payload.0.len()
+    }
+}
+
+/// A Vendor-Specific Data Block with a known layout.
+///
+/// Implementing this trait allows downstream crates to describe a proprietary data block with a
+/// dedicated type, and to convert it to an [`EdidExtensionCTA861VendorDataBlock`] through
+/// [`EdidExtensionCTA861VendorDataBlock::from_typed`].
+/// [`EdidExtensionCTA861VendorDataBlock`] implements it too, and serves as the raw fallback.
+pub trait EdidExtensionCTA861VendorDataBlockType: fmt::Debug {
+    /// The OUI identifying the vendor.
+    fn oui(&self) -> EdidExtensionCTA861VendorOui;
+
+    /// The payload of the data block.
+    fn payload(&self) -> EdidExtensionCTA861VendorPayload;
+}
+
+impl EdidExtensionCTA861VendorDataBlockType for EdidExtensionCTA861VendorDataBlock {
+    fn oui(&self) -> EdidExtensionCTA861VendorOui {
+        self.oui
+    }
+
+    fn payload(&self) -> EdidExtensionCTA861VendorPayload {
+        self.payload.clone()
+    }
+}
+
+/// Decodes the payload of a Vendor-Specific Data Block into its typed representation.
+///
+/// Returns `None` if the payload isn't valid for that type.
+pub type EdidExtensionCTA861VendorDataBlockDecoder =
+    fn(&[u8]) -> Option<Box<dyn EdidExtensionCTA861VendorDataBlockType>>;
+
+/// Maps OUIs to the types describing their Vendor-Specific Data Blocks.
+#[derive(Clone, Debug, Default)]
+pub struct EdidExtensionCTA861VendorDataBlockRegistry {
+    decoders: BTreeMap<EdidExtensionCTA861VendorOui, EdidExtensionCTA861VendorDataBlockDecoder>,
+}
+
+impl EdidExtensionCTA861VendorDataBlockRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the decoder for the given OUI, replacing any previous one.
+    #[must_use]
+    pub fn register(
+        mut self,
+        oui: EdidExtensionCTA861VendorOui,
+        decoder: EdidExtensionCTA861VendorDataBlockDecoder,
+    ) -> Self {
+        self.decoders.insert(oui, decoder);
+        self
+    }
+
+    /// Decodes a Vendor-Specific Data Block using the registered decoders.
+    ///
+    /// If its OUI isn't registered, or if the decoder rejects the payload, the raw data block is
+    /// returned.
+    #[must_use]
+    pub fn decode(
+        &self,
+        block: &EdidExtensionCTA861VendorDataBlock,
+    ) -> Box<dyn EdidExtensionCTA861VendorDataBlockType> {
+        self.decoders
+            .get(&block.oui)
+            .and_then(|decoder| decoder(&block.payload.0))
+            .unwrap_or_else(|| Box::new(block.clone()))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_cta861_vendor_data_block {
+    use super::{
+        EdidExtensionCTA861VendorDataBlock, EdidExtensionCTA861VendorDataBlockRegistry,
+        EdidExtensionCTA861VendorDataBlockType, EdidExtensionCTA861VendorOui,
+        EdidExtensionCTA861VendorPayload,
+    };
+    use crate::IntoBytes;
+
+    const OUI: u32 = 0x00_1a_2b;
+
+    #[derive(Debug)]
+    struct Dimming(u8);
+
+    impl EdidExtensionCTA861VendorDataBlockType for Dimming {
+        fn oui(&self) -> EdidExtensionCTA861VendorOui {
+            EdidExtensionCTA861VendorOui::try_from(OUI).unwrap()
+        }
+
+        fn payload(&self) -> EdidExtensionCTA861VendorPayload {
+            EdidExtensionCTA861VendorPayload::try_from(vec![0x01, self.0]).unwrap()
+        }
+    }
+
+    fn decode_dimming(payload: &[u8]) -> Option<Box<dyn EdidExtensionCTA861VendorDataBlockType>> {
+        match payload {
+            [0x01, zones] => Some(Box::new(Dimming(*zones))),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_binary() {
+        let block = EdidExtensionCTA861VendorDataBlock::from_typed(&Dimming(16));
+
+        assert_eq!(block.into_bytes(), &[0x65, 0x2b, 0x1a, 0x00, 0x01, 0x10]);
+    }
+
+    #[test]
+    fn test_range() {
+        assert!(EdidExtensionCTA861VendorOui::try_from(0x00ff_ffff).is_ok());
+        assert!(EdidExtensionCTA861VendorOui::try_from(0x0100_0000).is_err());
+
+        let oui = EdidExtensionCTA861VendorOui::try_from(OUI).unwrap();
+        assert!(EdidExtensionCTA861VendorDataBlock::try_from((oui, vec![0; 28])).is_ok());
+        assert!(EdidExtensionCTA861VendorDataBlock::try_from((oui, vec![0; 29])).is_err());
+
+        assert!(EdidExtensionCTA861VendorDataBlock::try_from((
+            EdidExtensionCTA861VendorOui::HDMI_LLC,
+            vec![0x10, 0x00]
+        ))
+        .is_err());
+        assert!(EdidExtensionCTA861VendorDataBlock::try_from((
+            EdidExtensionCTA861VendorOui::HDMI_FORUM,
+            vec![0x01, 0x78, 0x80]
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn test_hdmi_oui() {
+        assert_eq!(
+            EdidExtensionCTA861VendorOui::HDMI_LLC,
+            EdidExtensionCTA861VendorOui::try_from(0x00_0c_03).unwrap()
+        );
+        assert_eq!(
+            EdidExtensionCTA861VendorOui::HDMI_LLC.to_string(),
+            "00-0C-03"
+        );
+        assert_eq!(
+            EdidExtensionCTA861VendorOui::HDMI_LLC.into_raw(),
+            [0x03, 0x0c, 0x00]
+        );
+    }
+
+    #[test]
+    fn test_registry() {
+        let registry = EdidExtensionCTA861VendorDataBlockRegistry::new().register(
+            EdidExtensionCTA861VendorOui::try_from(OUI).unwrap(),
+            decode_dimming,
+        );
+
+        let known = EdidExtensionCTA861VendorDataBlock::from_typed(&Dimming(16));
+        let decoded = registry.decode(&known);
+        assert_eq!(
+            EdidExtensionCTA861VendorDataBlock::from_typed(decoded.as_ref()),
+            known
+        );
+        assert_eq!(format!("{decoded:?}"), "Dimming(16)");
+
+        let unknown = EdidExtensionCTA861VendorDataBlock::try_from((
+            EdidExtensionCTA861VendorOui::try_from(0x00_3c_4d).unwrap(),
+            vec![0x01],
+        ))
+        .unwrap();
+        assert_eq!(
+            EdidExtensionCTA861VendorDataBlock::from_typed(registry.decode(&unknown).as_ref()),
+            unknown
+        );
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidExtensionCTA861Revision3DataBlock {
     Audio(EdidExtensionCTA861AudioDataBlock),
     SpeakerAllocation(EdidExtensionCTA861SpeakerAllocationDataBlock),
     Colorimetry(EdidExtensionCTA861ColorimetryDataBlock),
+    HdrStaticMetadata(EdidExtensionCTA861HdrStaticMetadataDataBlock),
     Video(EdidExtensionCTA861VideoDataBlock),
     HDMI(EdidExtensionCTA861HdmiDataBlock),
+    Vendor(EdidExtensionCTA861VendorDataBlock),
     VideoCapability(EdidExtensionCTA861VideoCapabilityDataBlock),
 }
 
+impl EdidExtensionCTA861Revision3DataBlock {
+    // Most EDIDs list the Video and Audio related blocks first, then the Vendor-Specific Data
+    // Blocks, starting with the HDMI one, and finally the blocks using the Extended Tag.
+    fn canonical_rank(&self) -> usize {
+        match self {
+            Self::Video(_) => 0,
+            Self::Audio(_) => 1,
+            Self::SpeakerAllocation(_) => 2,
+            Self::HDMI(_) => 3,
+            Self::Vendor(_) => 4,
+            Self::VideoCapability(_) => 5,
+            Self::Colorimetry(_) => 6,
+            Self::HdrStaticMetadata(_) => 7,
+        }
+    }
+
+    // The order data blocks are dropped in when an extension has to shrink, lowest first. The
+    // blocks describing the timings are kept the longest, since they are what a source needs to
+    // drive the display at all.
+    fn strip_priority(&self) -> usize {
+        match self {
+            Self::HdrStaticMetadata(_) => 0,
+            Self::Colorimetry(_) => 1,
+            Self::VideoCapability(_) => 2,
+            Self::Vendor(_) => 3,
+            Self::SpeakerAllocation(_) => 4,
+            Self::Audio(_) => 5,
+            Self::HDMI(_) => 7,
+            Self::Video(_) => 8,
+        }
+    }
+
+    // CTA-861 allows a single Speaker Allocation, Colorimetry, HDR Static Metadata and Video
+    // Capability Data Block, and a single Vendor-Specific Data Block per OUI, HDMI's included.
+    // Video and Audio Data Blocks can be repeated.
+    fn duplicates(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::SpeakerAllocation(_), Self::SpeakerAllocation(_))
+            | (Self::Colorimetry(_), Self::Colorimetry(_))
+            | (Self::HdrStaticMetadata(_), Self::HdrStaticMetadata(_))
+            | (Self::HDMI(_), Self::HDMI(_))
+            | (Self::VideoCapability(_), Self::VideoCapability(_)) => true,
+            (Self::Vendor(a), Self::Vendor(b)) => a.oui == b.oui,
+            (Self::Vendor(vendor), Self::HDMI(_)) | (Self::HDMI(_), Self::Vendor(vendor)) => {
+                vendor.oui == EdidExtensionCTA861VendorOui::HDMI_LLC
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the Data Block Tag Code stored in the upper bits of the header byte.
+    ///
+    /// Blocks using the Extended Tag all share the Tag Code 7, their Extended Tag Code being
+    /// the first byte of their payload.
+    #[must_use]
+    pub const fn tag(&self) -> u8 {
+        match self {
+            Self::Audio(_) => 1,
+            Self::Video(_) => 2,
+            Self::HDMI(_) | Self::Vendor(_) => 3,
+            Self::SpeakerAllocation(_) => 4,
+            Self::Colorimetry(_) | Self::HdrStaticMetadata(_) | Self::VideoCapability(_) => 7,
+        }
+    }
+
+    /// Serializes the data block without its header byte.
+    ///
+    /// The [`IntoBytes`] implementation returns the full data block, header included, as it's
+    /// found in a CTA-861 Extension. This only returns the payload, which is useful to check a
+    /// Vendor-Specific payload on its own, or to embed the block in a container with its own
+    /// framing. For blocks using the Extended Tag, the payload starts with the Extended Tag
+    /// Code.
+    #[must_use]
+    pub fn into_payload_bytes(self) -> Vec<u8> {
+        let mut bytes = self.into_bytes();
+        bytes.remove(0);
+        bytes
+    }
+}
+
+/// How the data blocks of a CTA-861 Extension are laid out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum EdidExtensionCTA861DataBlockOrdering {
+    /// The data blocks are stored in the order they were added.
+    #[default]
+    Preserve,
+
+    /// The data blocks are sorted in the conventional order: Video, Audio, Speaker Allocation,
+    /// HDMI Vendor-Specific, other Vendor-Specific, Video Capability, Colorimetry and HDR Static
+    /// Metadata. Blocks of
+    /// the same kind keep the order they were added in.
+    Canonical,
+
+    /// The data blocks are stored in the order they were added, and the validation reports the
+    /// ones that aren't in the canonical order.
+    Strict,
+}
+
+/// The revision of the CTA-861 Extension, stored in its second byte.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum EdidExtensionCTA861RevisionNumber {
+    /// CEA-861. The extension only holds Detailed Timing Descriptors.
+    Revision1 = 1,
+
+    /// CEA-861-A. Adds the underscan, basic audio and YCbCr support flags.
+    Revision2,
+
+    /// CEA-861-B and later. Adds the data blocks and the number of native formats.
+    #[default]
+    Revision3,
+}
+
+impl TryFrom<u8> for EdidExtensionCTA861RevisionNumber {
+    type Error = EdidTypeConversionError<u8>;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            1 => Self::Revision1,
+            2 => Self::Revision2,
+            3 => Self::Revision3,
+            _ => return Err(EdidTypeConversionError::Range(value, Some(1), Some(3))),
+        })
+    }
+}
+
+/// The number of native Detailed Timing Descriptors of a CTA-861 Extension. It's stored in 4
+/// bits, so it can't be larger than 15.
+///
+/// With the `serde` feature, it's deserialized from an integer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
+pub struct EdidExtensionCTA861NativeFormats(u8);
+
+impl TryFrom<u8> for EdidExtensionCTA861NativeFormats {
+    type Error = EdidTypeConversionError<u8>;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if value > 0x0f {
+            return Err(EdidTypeConversionError::Range(value, Some(0), Some(0x0f)));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl EdidExtensionCTA861NativeFormats {
+    const fn into_raw(self) -> u8 {
+        self.0
+    }
+}
+
 impl IntoBytes for EdidExtensionCTA861Revision3DataBlock {
     fn into_bytes(self) -> Vec<u8> {
         match self {
             Self::Audio(v) => v.into_bytes(),
             Self::SpeakerAllocation(v) => v.into_bytes(),
             Self::Colorimetry(v) => v.into_bytes(),
+            Self::HdrStaticMetadata(v) => v.into_bytes(),
             Self::Video(v) => v.into_bytes(),
             Self::HDMI(v) => v.into_bytes(),
+            Self::Vendor(v) => v.into_bytes(),
             Self::VideoCapability(v) => v.into_bytes(),
         }
     }
@@ -763,14 +2089,18 @@ impl IntoBytes for EdidExtensionCTA861Revision3DataBlock {
             Self::Audio(v) => v.size(),
             Self::SpeakerAllocation(v) => v.size(),
             Self::Colorimetry(v) => v.size(),
+            Self::HdrStaticMetadata(v) => v.size(),
             Self::Video(v) => v.size(),
             Self::HDMI(v) => v.size(),
+            Self::Vendor(v) => v.size(),
             Self::VideoCapability(v) => v.size(),
         }
     }
 }
 
 #[derive(Clone, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 #[builder(mutators(
     #[allow(unreachable_pub)]
     pub fn data_blocks(&mut self, blocks: Vec<EdidExtensionCTA861Revision3DataBlock>) {
@@ -793,43 +2123,396 @@ impl IntoBytes for EdidExtensionCTA861Revision3DataBlock {
     }
 ))]
 pub struct EdidExtensionCTA861Revision3 {
+    /// Whether the display supports YCbCr 4:2:2. For EDID 1.4, it must match the Color Encoding
+    /// Formats of the base block.
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     ycbcr_422_supported: bool,
 
+    /// Whether the display supports YCbCr 4:4:4. For EDID 1.4, it must match the Color Encoding
+    /// Formats of the base block.
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     ycbcr_444_supported: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     audio_supported: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     underscan_it_formats_by_default: bool,
 
-    native_formats: u8,
+    /// The number of native Detailed Timing Descriptors. There's none by default.
+    #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    native_formats: EdidExtensionCTA861NativeFormats,
 
     #[builder(via_mutators)]
+    #[cfg_attr(feature = "serde", serde(default))]
     data_blocks: Vec<EdidExtensionCTA861Revision3DataBlock>,
 
+    #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    data_block_ordering: EdidExtensionCTA861DataBlockOrdering,
+
     #[builder(via_mutators)]
+    #[cfg_attr(feature = "serde", serde(default))]
     timings: Vec<EdidDescriptorDetailedTiming>,
+
+    /// The revision reported by the extension, to emulate older sinks. Only the revision 3 can
+    /// hold data blocks and native formats, and the revision 1 can't report any support flag.
+    #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    revision: EdidExtensionCTA861RevisionNumber,
 }
 
-impl IntoBytes for EdidExtensionCTA861Revision3 {
-    fn into_bytes(self) -> Vec<u8> {
+impl EdidExtensionCTA861Revision3 {
+    pub(crate) fn short_video_descriptors(
+        &self,
+    ) -> impl Iterator<Item = &EdidExtensionCTA861VideoDataBlockDesc> {
+        self.data_blocks.iter().flat_map(|b| match b {
+            EdidExtensionCTA861Revision3DataBlock::Video(vdb) => vdb.desc.as_slice(),
+            EdidExtensionCTA861Revision3DataBlock::Audio(_)
+            | EdidExtensionCTA861Revision3DataBlock::SpeakerAllocation(_)
+            | EdidExtensionCTA861Revision3DataBlock::Colorimetry(_)
+            | EdidExtensionCTA861Revision3DataBlock::HdrStaticMetadata(_)
+            | EdidExtensionCTA861Revision3DataBlock::HDMI(_)
+            | EdidExtensionCTA861Revision3DataBlock::Vendor(_)
+            | EdidExtensionCTA861Revision3DataBlock::VideoCapability(_) => &[],
+        })
+    }
+
+    pub(crate) fn data_blocks(&self) -> &[EdidExtensionCTA861Revision3DataBlock] {
+        &self.data_blocks
+    }
+
+    /// Returns the indices of the data blocks, in the order they will be serialized.
+    pub(crate) fn data_block_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.data_blocks.len()).collect();
+        if self.data_block_ordering == EdidExtensionCTA861DataBlockOrdering::Canonical {
+            order.sort_by_key(|idx| self.data_blocks[*idx].canonical_rank());
+        }
+
+        order
+    }
+
+    /// Returns the data blocks in the order they will be serialized.
+    pub(crate) fn ordered_data_blocks(&self) -> Vec<&EdidExtensionCTA861Revision3DataBlock> {
+        self.data_block_order()
+            .into_iter()
+            .map(|idx| &self.data_blocks[idx])
+            .collect()
+    }
+
+    pub(crate) const fn revision(&self) -> EdidExtensionCTA861RevisionNumber {
+        self.revision
+    }
+
+    /// Returns the underscan and basic audio support flags, and the number of native formats.
+    pub(crate) const fn header(&self) -> (bool, bool, u8) {
+        (
+            self.underscan_it_formats_by_default,
+            self.audio_supported,
+            self.native_formats.into_raw(),
+        )
+    }
+
+    pub(crate) fn detailed_timings(&self) -> &[EdidDescriptorDetailedTiming] {
+        &self.timings
+    }
+
+    /// Returns the first VIC listed more than once in the Video Data Blocks, if any.
+    pub(crate) fn duplicate_vic(&self) -> Option<u8> {
+        let mut vics = Vec::new();
+
+        for desc in self.short_video_descriptors() {
+            let vic = desc.vic();
+            if vics.contains(&vic) {
+                return Some(vic);
+            }
+
+            vics.push(vic);
+        }
+
+        None
+    }
+
+    /// Returns the number of Short Video Descriptors marked as native.
+    pub(crate) fn num_native_vics(&self) -> usize {
+        self.short_video_descriptors()
+            .filter(|desc| desc.is_native())
+            .count()
+    }
+
+    /// Returns whether an HDMI Vendor-Specific Data Block advertises deep color support.
+    pub(crate) fn deep_color(&self) -> bool {
+        self.data_blocks.iter().any(|block| match block {
+            EdidExtensionCTA861Revision3DataBlock::HDMI(hdmi) => hdmi.deep_color(),
+            EdidExtensionCTA861Revision3DataBlock::Audio(_)
+            | EdidExtensionCTA861Revision3DataBlock::SpeakerAllocation(_)
+            | EdidExtensionCTA861Revision3DataBlock::Colorimetry(_)
+            | EdidExtensionCTA861Revision3DataBlock::HdrStaticMetadata(_)
+            | EdidExtensionCTA861Revision3DataBlock::Video(_)
+            | EdidExtensionCTA861Revision3DataBlock::Vendor(_)
+            | EdidExtensionCTA861Revision3DataBlock::VideoCapability(_) => false,
+        })
+    }
+
+    /// Returns the maximum number of channels of the LPCM Short Audio Descriptors, if any.
+    pub(crate) fn max_lpcm_channels(&self) -> Option<usize> {
+        self.data_blocks
+            .iter()
+            .filter_map(|block| match block {
+                EdidExtensionCTA861Revision3DataBlock::Audio(audio) => audio.max_lpcm_channels(),
+                EdidExtensionCTA861Revision3DataBlock::SpeakerAllocation(_)
+                | EdidExtensionCTA861Revision3DataBlock::Colorimetry(_)
+                | EdidExtensionCTA861Revision3DataBlock::HdrStaticMetadata(_)
+                | EdidExtensionCTA861Revision3DataBlock::Video(_)
+                | EdidExtensionCTA861Revision3DataBlock::HDMI(_)
+                | EdidExtensionCTA861Revision3DataBlock::Vendor(_)
+                | EdidExtensionCTA861Revision3DataBlock::VideoCapability(_) => None,
+            })
+            .max()
+    }
+
+    /// Returns whether the extension has an HDMI Vendor Specific Data Block.
+    pub(crate) fn has_hdmi_block(&self) -> bool {
+        self.data_blocks
+            .iter()
+            .any(|block| matches!(block, EdidExtensionCTA861Revision3DataBlock::HDMI(_)))
+    }
+
+    /// Returns the number of speakers and the maximum number of LPCM channels, if the speakers
+    /// can't all be driven by the audio formats supported.
+    pub(crate) fn speakers_channels_mismatch(&self) -> Option<(usize, usize)> {
+        let mut max_channels = None;
+        let mut num_speakers = None;
+
+        for block in &self.data_blocks {
+            match block {
+                EdidExtensionCTA861Revision3DataBlock::Audio(audio) => {
+                    max_channels = max_channels.max(audio.max_lpcm_channels());
+                }
+                EdidExtensionCTA861Revision3DataBlock::SpeakerAllocation(speakers) => {
+                    num_speakers = num_speakers.max(Some(speakers.num_speakers()));
+                }
+                EdidExtensionCTA861Revision3DataBlock::Colorimetry(_)
+                | EdidExtensionCTA861Revision3DataBlock::HdrStaticMetadata(_)
+                | EdidExtensionCTA861Revision3DataBlock::Video(_)
+                | EdidExtensionCTA861Revision3DataBlock::HDMI(_)
+                | EdidExtensionCTA861Revision3DataBlock::Vendor(_)
+                | EdidExtensionCTA861Revision3DataBlock::VideoCapability(_) => {}
+            }
+        }
+
+        let (Some(num_speakers), Some(max_channels)) = (num_speakers, max_channels) else {
+            return None;
+        };
+
+        (num_speakers > max_channels).then_some((num_speakers, max_channels))
+    }
+
+    /// Returns the source physical addresses of the HDMI Vendor-Specific Data Blocks.
+    pub(crate) fn physical_addresses(&self) -> impl Iterator<Item = CecAddress> + '_ {
+        self.data_blocks.iter().filter_map(|b| match b {
+            EdidExtensionCTA861Revision3DataBlock::HDMI(hdmi) => {
+                Some(hdmi.source_physical_address())
+            }
+            EdidExtensionCTA861Revision3DataBlock::Video(_)
+            | EdidExtensionCTA861Revision3DataBlock::Audio(_)
+            | EdidExtensionCTA861Revision3DataBlock::SpeakerAllocation(_)
+            | EdidExtensionCTA861Revision3DataBlock::Colorimetry(_)
+            | EdidExtensionCTA861Revision3DataBlock::HdrStaticMetadata(_)
+            | EdidExtensionCTA861Revision3DataBlock::Vendor(_)
+            | EdidExtensionCTA861Revision3DataBlock::VideoCapability(_) => None,
+        })
+    }
+
+    /// Sets the source physical address of every HDMI Vendor-Specific Data Block.
+    pub(crate) fn set_physical_address(&mut self, address: CecAddress) {
+        for block in &mut self.data_blocks {
+            match block {
+                EdidExtensionCTA861Revision3DataBlock::HDMI(hdmi) => {
+                    hdmi.set_source_physical_address(address);
+                }
+                EdidExtensionCTA861Revision3DataBlock::Video(_)
+                | EdidExtensionCTA861Revision3DataBlock::Audio(_)
+                | EdidExtensionCTA861Revision3DataBlock::SpeakerAllocation(_)
+                | EdidExtensionCTA861Revision3DataBlock::Colorimetry(_)
+                | EdidExtensionCTA861Revision3DataBlock::HdrStaticMetadata(_)
+                | EdidExtensionCTA861Revision3DataBlock::Vendor(_)
+                | EdidExtensionCTA861Revision3DataBlock::VideoCapability(_) => {}
+            }
+        }
+    }
+
+    /// Drops data blocks and Detailed Timing Descriptors, lowest priority and last added first,
+    /// until the extension fits in its block.
+    pub(crate) fn strip(&mut self) {
+        // Detailed Timing Descriptors rank between the Audio and HDMI Data Blocks.
+        const DTD_PRIORITY: usize = 6;
+
+        while !self.fits() {
+            let lowest = self
+                .data_blocks
+                .iter()
+                .enumerate()
+                .min_by_key(|(idx, block)| (block.strip_priority(), Reverse(*idx)))
+                .map(|(idx, block)| (idx, block.strip_priority()));
+
+            match lowest {
+                Some((idx, priority)) if priority < DTD_PRIORITY || self.timings.is_empty() => {
+                    self.data_blocks.remove(idx);
+                }
+                Some(_) | None => {
+                    if self.timings.pop().is_none() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns whether the extension uses features its revision doesn't support.
+    pub(crate) fn revision_mismatch(&self) -> bool {
+        match self.revision {
+            EdidExtensionCTA861RevisionNumber::Revision1 => {
+                self.underscan_it_formats_by_default
+                    || self.audio_supported
+                    || self.ycbcr_444_supported
+                    || self.ycbcr_422_supported
+                    || self.native_formats.into_raw() != 0
+                    || !self.data_blocks.is_empty()
+            }
+            EdidExtensionCTA861RevisionNumber::Revision2 => {
+                self.native_formats.into_raw() != 0 || !self.data_blocks.is_empty()
+            }
+            EdidExtensionCTA861RevisionNumber::Revision3 => false,
+        }
+    }
+
+    /// Returns whether a Video Capability Data Block reports a selectable YCbCr quantization range
+    /// while YCbCr isn't supported.
+    pub(crate) fn ycc_quantization_without_ycbcr(&self) -> bool {
+        let ycbcr = self.ycbcr_444_supported || self.ycbcr_422_supported;
+
+        !ycbcr && self.data_blocks.iter().any(|b| {
+            matches!(
+                b,
+                EdidExtensionCTA861Revision3DataBlock::VideoCapability(vcdb)
+                    if vcdb.qy_quant == EdidExtensionCTA861VideoCapabilityQuantization::Selectable
+            )
+        })
+    }
+
+    /// Returns whether a Video Capability Data Block contradicts the underscan bit of the header
+    /// for IT Video Formats.
+    pub(crate) fn underscan_mismatch(&self) -> bool {
+        self.data_blocks.iter().any(|b| {
+            let EdidExtensionCTA861Revision3DataBlock::VideoCapability(vcdb) = b else {
+                return false;
+            };
+
+            matches!(
+                (vcdb.it_scan, self.underscan_it_formats_by_default),
+                (
+                    EdidExtensionCTA861VideoCapabilityScanBehavior::Overscanned,
+                    true
+                ) | (
+                    EdidExtensionCTA861VideoCapabilityScanBehavior::Underscanned,
+                    false
+                )
+            )
+        })
+    }
+
+    /// Returns whether YCbCr 4:4:4 and YCbCr 4:2:2 are supported, in that order.
+    pub(crate) fn ycbcr_supported(&self) -> (bool, bool) {
+        (self.ycbcr_444_supported, self.ycbcr_422_supported)
+    }
+
+    /// Returns whether an Audio Data Block is listed while the Basic Audio bit is cleared.
+    pub(crate) fn missing_basic_audio(&self) -> bool {
+        !self.audio_supported
+            && self
+                .data_blocks
+                .iter()
+                .any(|b| matches!(b, EdidExtensionCTA861Revision3DataBlock::Audio(_)))
+    }
+
+    /// Returns the index of the first data block that isn't in the canonical order, if strict
+    /// ordering was requested.
+    pub(crate) fn misordered_data_block(&self) -> Option<usize> {
+        if self.data_block_ordering != EdidExtensionCTA861DataBlockOrdering::Strict {
+            return None;
+        }
+
+        self.data_blocks
+            .iter()
+            .zip(self.data_blocks.iter().skip(1))
+            .position(|(prev, next)| prev.canonical_rank() > next.canonical_rank())
+            .map(|idx| idx + 1)
+    }
+
+    /// Returns the index of the first data block that can only be listed once, but is already
+    /// listed by a previous data block.
+    pub(crate) fn duplicate_data_block(&self) -> Option<usize> {
+        self.data_blocks
+            .iter()
+            .enumerate()
+            .find_map(|(idx, block)| {
+                self.data_blocks[..idx]
+                    .iter()
+                    .any(|prev| prev.duplicates(block))
+                    .then_some(idx)
+            })
+    }
+
+    pub(crate) fn used_bytes(&self) -> usize {
+        // The tag, revision, DTD offset and flags bytes, and the checksum.
+        let overhead = 5;
+
+        self.data_blocks.iter().map(IntoBytes::size).sum::<usize>()
+            + self.timings.iter().map(IntoBytes::size).sum::<usize>()
+            + overhead
+    }
+
+    /// Serializes the extension, and records the bytes each part of it has been serialized to.
+    /// Data blocks and Detailed Timing Descriptors are identified by the index they were added
+    /// at, whatever the order they are serialized in.
+    pub(crate) fn into_bytes_with_field_map(self, fields: &mut FieldMap) -> Vec<u8> {
         let mut data: Vec<u8> = Vec::with_capacity(EDID_EXTENSION_CTA_861_LEN);
 
-        data.extend_from_slice(&[0x02, 0x03]);
+        fields.push(&mut data, "header", &[0x02]);
+        fields.push(&mut data, "revision", &[self.revision as u8]);
+
+        let order = self.data_block_order();
+        let mut blocks: Vec<_> = self.data_blocks.into_iter().map(Some).collect();
+        let data_blocks = order
+            .into_iter()
+            .filter_map(|idx| blocks[idx].take().map(|block| (idx, block)))
+            .collect();
+
+        // The content that doesn't fit is left out, and reported by the validation. The tag,
+        // revision, DTD offset and flags bytes, and the checksum, take 5 bytes.
+        let mut available = EDID_EXTENSION_CTA_861_LEN - 5;
+        let data_blocks = take_fitting(data_blocks, &mut available);
+        let timings = take_fitting(
+            self.timings.into_iter().enumerate().collect(),
+            &mut available,
+        );
 
-        let dtd_offset = if self.data_blocks.is_empty() && self.timings.is_empty() {
+        let dtd_offset = if data_blocks.is_empty() && timings.is_empty() {
             0
         } else {
-            self.data_blocks
+            data_blocks
                 .iter()
-                .fold(4, |acc, b| acc + b.size())
+                .fold(4, |acc, (_, b)| acc + b.size())
                 .to_u8()
                 .expect("The number of data blocks would overflow our type")
         };
-        data.push(dtd_offset);
+        fields.push(&mut data, "detailed_timing_offset", &[dtd_offset]);
 
         let mut byte = 0;
         if self.underscan_it_formats_by_default {
@@ -848,26 +2531,43 @@ impl IntoBytes for EdidExtensionCTA861Revision3 {
             byte |= 1 << 4;
         }
 
-        byte |= self.native_formats;
-        data.push(byte);
+        byte |= self.native_formats.into_raw();
+        fields.push(&mut data, "flags", &[byte]);
+
+        for (idx, block) in data_blocks {
+            let block_bytes = block.into_bytes();
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(offset = data.len(), bytes = ?block_bytes, "Data block serialized");
 
-        for block in self.data_blocks {
-            data.extend_from_slice(&block.into_bytes());
+            fields.push(&mut data, format!("data_blocks[{idx}]"), &block_bytes);
         }
 
-        for timing in self.timings {
-            data.extend_from_slice(&timing.into_bytes());
+        for (idx, timing) in timings {
+            let timing_bytes = timing.into_bytes();
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                offset = data.len(),
+                bytes = ?timing_bytes,
+                "Detailed Timing Descriptor serialized"
+            );
+
+            fields.push(
+                &mut data,
+                format!("detailed_timing_descriptors[{idx}]"),
+                &timing_bytes,
+            );
         }
 
+        let padding_start = data.len();
         data.resize(EDID_EXTENSION_CTA_861_LEN - 1, 0);
-
-        let mut sum: u8 = 0;
-        for byte in &data {
-            sum = sum.wrapping_add(*byte);
+        if data.len() > padding_start {
+            fields.record("padding", padding_start..data.len());
         }
 
-        let checksum = 0u8.wrapping_sub(sum);
-        data.push(checksum);
+        let checksum = edid_block_checksum(&data);
+        fields.push(&mut data, "checksum", &[checksum]);
 
         assert_eq!(
             data.len(),
@@ -880,12 +2580,538 @@ impl IntoBytes for EdidExtensionCTA861Revision3 {
         data
     }
 
+    /// Returns the number of bytes still available in the extension block after the data blocks
+    /// and Detailed Timing Descriptors.
+    ///
+    /// Returns 0 if the content doesn't fit.
+    #[must_use]
+    pub fn remaining_bytes(&self) -> usize {
+        EDID_EXTENSION_CTA_861_LEN.saturating_sub(self.used_bytes())
+    }
+
+    /// Returns whether the data blocks and Detailed Timing Descriptors fit in the extension block.
+    #[must_use]
+    pub fn fits(&self) -> bool {
+        self.used_bytes() <= EDID_EXTENSION_CTA_861_LEN
+    }
+}
+
+/// Returns the first items that fit in the available bytes, and updates it accordingly.
+fn take_fitting<T: IntoBytes>(items: Vec<(usize, T)>, available: &mut usize) -> Vec<(usize, T)> {
+    items
+        .into_iter()
+        .take_while(|(_, item)| {
+            let Some(left) = available.checked_sub(item.size()) else {
+                return false;
+            };
+
+            *available = left;
+            true
+        })
+        .collect()
+}
+
+impl IntoBytes for EdidExtensionCTA861Revision3 {
+    fn into_bytes(self) -> Vec<u8> {
+        self.into_bytes_with_field_map(&mut FieldMap::default())
+    }
+
     fn size(&self) -> usize {
         EDID_EXTENSION_CTA_861_LEN
     }
 }
 
+#[cfg(test)]
+#[cfg(feature = "serde")]
+#[allow(clippy::unwrap_used)]
+mod test_cta861_hdmi_serde {
+    use crate::{EdidExtensionCTA861HdmiDataBlock, IntoBytes};
+
+    #[test]
+    fn test_deserialize() {
+        let hdmi: EdidExtensionCTA861HdmiDataBlock = serde_json::from_str(
+            r#"{
+                "source_physical_address": [1, 0, 0, 0],
+                "deep_color_30_bits": true,
+                "max_tmds_rate": 300,
+                "latencies": {
+                    "progressive": { "video": null, "audio": 20 }
+                },
+                "video": { "vics": [1, 2] }
+            }"#,
+        )
+        .unwrap();
+
+        let bytes = hdmi.into_bytes();
+        assert_eq!(&bytes[1..6], &[0x03, 0x0c, 0x00, 0x10, 0x00]);
+        assert_eq!(bytes[6], 1 << 4);
+        assert_eq!(&bytes[9..11], &[0xff, 11]);
+        assert_eq!(&bytes[bytes.len() - 2..], &[1, 2]);
+    }
+
+    #[test]
+    fn test_deserialize_minimal() {
+        let hdmi: EdidExtensionCTA861HdmiDataBlock =
+            serde_json::from_str(r#"{ "source_physical_address": [2, 0, 0, 0] }"#).unwrap();
+
+        assert_eq!(hdmi.into_bytes(), &[0x65, 0x03, 0x0c, 0x00, 0x20, 0x00]);
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        for json in [
+            r#"{ "source_physical_address": [16, 0, 0, 0] }"#,
+            r#"{ "source_physical_address": [1, 0, 0, 0], "max_tmds_rate": 400 }"#,
+            r#"{ "source_physical_address": [1, 0, 0, 0], "unknown": true }"#,
+            r#"{ "deep_color_30_bits": true }"#,
+            r#"{
+                "source_physical_address": [1, 0, 0, 0],
+                "video": { "vics": [1, 2, 3, 4, 5, 6, 7, 8] }
+            }"#,
+        ] {
+            assert!(
+                serde_json::from_str::<EdidExtensionCTA861HdmiDataBlock>(json).is_err(),
+                "{json} should be rejected"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_cta861_revision3_size {
+    use crate::{
+        EdidExtensionCTA861Revision3, EdidExtensionCTA861Revision3DataBlock,
+        EdidExtensionCTA861VideoDataBlock, IntoBytes,
+    };
+
+    fn video_block(num_vics: u8) -> EdidExtensionCTA861Revision3DataBlock {
+        let mut block = EdidExtensionCTA861VideoDataBlock::builder();
+
+        for vic in 1..=num_vics {
+            block = block.add_short_video_descriptor(vic);
+        }
+
+        EdidExtensionCTA861Revision3DataBlock::Video(block.build().unwrap())
+    }
+
+    #[test]
+    fn test_empty() {
+        let ext = EdidExtensionCTA861Revision3::builder().build();
+
+        assert!(ext.fits());
+        assert_eq!(ext.remaining_bytes(), 123);
+    }
+
+    #[test]
+    fn test_full() {
+        let ext = EdidExtensionCTA861Revision3::builder()
+            .data_blocks(vec![
+                video_block(30),
+                video_block(30),
+                video_block(30),
+                video_block(29),
+            ])
+            .build();
+
+        assert!(ext.fits());
+        assert_eq!(ext.remaining_bytes(), 0);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let ext = EdidExtensionCTA861Revision3::builder()
+            .data_blocks(vec![
+                video_block(30),
+                video_block(30),
+                video_block(30),
+                video_block(30),
+            ])
+            .build();
+
+        assert!(!ext.fits());
+        assert_eq!(ext.remaining_bytes(), 0);
+
+        // The last data block is left out.
+        let bytes = ext.into_bytes();
+        assert_eq!(bytes.len(), 128);
+        assert_eq!(bytes[2], 4 + 31 * 3);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_cta861_strip {
+    use crate::{
+        edid_preset_avr, CecAddress, EdidDescriptorDetailedTiming, EdidDetailedTimingSizeMm,
+        EdidExtensionCTA861ColorimetryDataBlock, EdidExtensionCTA861HdmiDataBlock,
+        EdidExtensionCTA861Revision3, EdidExtensionCTA861Revision3DataBlock,
+        EdidExtensionCTA861VideoDataBlock, EdidManufacturer, EdidProductCode, EdidSizeBudget,
+        IntoBytes, EDID_BASE_LEN,
+    };
+
+    fn video_block(first: u8) -> EdidExtensionCTA861Revision3DataBlock {
+        let mut block = EdidExtensionCTA861VideoDataBlock::builder();
+
+        for vic in first..first + 30 {
+            block = block.add_short_video_descriptor(vic);
+        }
+
+        EdidExtensionCTA861Revision3DataBlock::Video(block.build().unwrap())
+    }
+
+    fn dtd() -> EdidDescriptorDetailedTiming {
+        EdidDescriptorDetailedTiming::cta_720p_60hz(
+            EdidDetailedTimingSizeMm::try_from(0).unwrap(),
+            EdidDetailedTimingSizeMm::try_from(0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_priority() {
+        let mut ext = EdidExtensionCTA861Revision3::builder()
+            .data_blocks(vec![
+                video_block(1),
+                EdidExtensionCTA861Revision3DataBlock::Colorimetry(
+                    EdidExtensionCTA861ColorimetryDataBlock::builder().build(),
+                ),
+                EdidExtensionCTA861Revision3DataBlock::HDMI(
+                    EdidExtensionCTA861HdmiDataBlock::builder()
+                        .source_physical_address(CecAddress::try_from([1, 0, 0, 0]).unwrap())
+                        .build(),
+                ),
+                video_block(31),
+                video_block(61),
+            ])
+            .add_detailed_timing_descriptor(dtd())
+            .add_detailed_timing_descriptor(dtd())
+            .build();
+        assert!(!ext.fits());
+
+        ext.strip();
+        assert!(ext.fits());
+        assert_eq!(ext.timings.len(), 1);
+        assert!(matches!(
+            ext.data_blocks.as_slice(),
+            [
+                EdidExtensionCTA861Revision3DataBlock::Video(_),
+                EdidExtensionCTA861Revision3DataBlock::HDMI(_),
+                EdidExtensionCTA861Revision3DataBlock::Video(_),
+                EdidExtensionCTA861Revision3DataBlock::Video(_),
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_last_added_first() {
+        let mut ext = EdidExtensionCTA861Revision3::builder()
+            .data_blocks(vec![
+                video_block(1),
+                video_block(31),
+                video_block(61),
+                video_block(91),
+            ])
+            .build();
+        assert!(!ext.fits());
+
+        ext.strip();
+        assert!(ext.fits());
+        assert_eq!(ext.data_blocks.len(), 3);
+        assert_eq!(
+            ext.short_video_descriptors().last().unwrap().vic(),
+            90,
+            "The last Video Data Block should have been dropped"
+        );
+    }
+
+    #[test]
+    fn test_budget() {
+        let edid = edid_preset_avr(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        );
+
+        assert_eq!(
+            edid.clone()
+                .strip_to(EdidSizeBudget::Extensions(1))
+                .into_bytes()
+                .len(),
+            2 * EDID_BASE_LEN
+        );
+
+        let bytes = edid.strip_to(EdidSizeBudget::BaseBlock).into_bytes();
+        assert_eq!(bytes.len(), EDID_BASE_LEN);
+        assert_eq!(bytes[EDID_BASE_LEN - 2], 0);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_cta861_data_block_length {
+    use crate::{
+        EdidExtensionCTA861AudioDataBlock, EdidExtensionCTA861AudioDataBlockChannels,
+        EdidExtensionCTA861AudioDataBlockDesc, EdidExtensionCTA861AudioDataBlockLPCM,
+        EdidExtensionCTA861Hdmi14bDataBlockVideo, EdidExtensionCTA861VideoDataBlock,
+        EdidExtensionCTA861VideoDataBlockDesc, IntoBytes,
+    };
+
+    fn lpcm() -> EdidExtensionCTA861AudioDataBlockDesc {
+        EdidExtensionCTA861AudioDataBlockDesc::LPCM(
+            EdidExtensionCTA861AudioDataBlockLPCM::builder()
+                .channels(EdidExtensionCTA861AudioDataBlockChannels::try_from(2).unwrap())
+                .build(),
+        )
+    }
+
+    #[test]
+    fn test_video() {
+        let vdb = EdidExtensionCTA861VideoDataBlock::builder()
+            .descriptors(
+                (1..=31)
+                    .map(EdidExtensionCTA861VideoDataBlockDesc::from_vic)
+                    .collect(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(vdb.into_bytes()[0], 0x5f);
+
+        assert!(EdidExtensionCTA861VideoDataBlock::builder()
+            .descriptors(
+                (1..=32)
+                    .map(EdidExtensionCTA861VideoDataBlockDesc::from_vic)
+                    .collect()
+            )
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_audio() {
+        let adb = EdidExtensionCTA861AudioDataBlock::builder()
+            .descriptors(vec![lpcm(); 10])
+            .build()
+            .unwrap();
+        assert_eq!(adb.into_bytes()[0], 0x3e);
+
+        assert!(EdidExtensionCTA861AudioDataBlock::builder()
+            .descriptors(vec![lpcm(); 11])
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_hdmi_vics() {
+        assert!(EdidExtensionCTA861Hdmi14bDataBlockVideo::builder()
+            .vics((1..=7).collect())
+            .build()
+            .is_ok());
+
+        assert!(EdidExtensionCTA861Hdmi14bDataBlockVideo::builder()
+            .vics((1..=8).collect())
+            .build()
+            .is_err());
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_cta861_data_block_bytes {
+    use crate::{
+        EdidExtensionCTA861ColorimetryDataBlock, EdidExtensionCTA861Revision3DataBlock,
+        EdidExtensionCTA861VendorDataBlock, EdidExtensionCTA861VendorOui,
+        EdidExtensionCTA861VideoDataBlock, IntoBytes,
+    };
+
+    #[test]
+    fn test_header() {
+        let vdb = EdidExtensionCTA861Revision3DataBlock::Video(
+            EdidExtensionCTA861VideoDataBlock::builder()
+                .add_short_video_descriptor(16)
+                .add_short_video_descriptor(4)
+                .build()
+                .unwrap(),
+        );
+
+        assert_eq!(vdb.tag(), 2);
+        assert_eq!(vdb.clone().into_bytes(), [0x42, 16, 4]);
+        assert_eq!(vdb.into_payload_bytes(), [16, 4]);
+    }
+
+    #[test]
+    fn test_vendor_payload() {
+        let vsdb = EdidExtensionCTA861Revision3DataBlock::Vendor(
+            EdidExtensionCTA861VendorDataBlock::try_from((
+                EdidExtensionCTA861VendorOui::try_from(0x00_d0_46).unwrap(),
+                vec![0xaa, 0x55],
+            ))
+            .unwrap(),
+        );
+
+        assert_eq!(vsdb.tag(), 3);
+        assert_eq!(vsdb.size(), 6);
+        assert_eq!(vsdb.into_payload_bytes(), [0x46, 0xd0, 0x00, 0xaa, 0x55]);
+    }
+
+    #[test]
+    fn test_extended_tag() {
+        let cdb = EdidExtensionCTA861Revision3DataBlock::Colorimetry(
+            EdidExtensionCTA861ColorimetryDataBlock::builder().build(),
+        );
+
+        assert_eq!(cdb.tag(), 7);
+
+        let payload = cdb.into_payload_bytes();
+        assert_eq!(payload.len(), 3);
+        assert_eq!(payload[0], 0x05);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_cta861_revision {
+    use crate::{
+        EdidDescriptorDetailedTiming, EdidDetailedTimingSizeMm, EdidExtensionCTA861NativeFormats,
+        EdidExtensionCTA861Revision3, EdidExtensionCTA861Revision3DataBlock,
+        EdidExtensionCTA861RevisionNumber, EdidExtensionCTA861VideoDataBlock, IntoBytes,
+    };
+
+    #[test]
+    fn test_binary() {
+        let bytes = EdidExtensionCTA861Revision3::builder()
+            .revision(EdidExtensionCTA861RevisionNumber::Revision1)
+            .add_detailed_timing_descriptor(EdidDescriptorDetailedTiming::cta_720p_60hz(
+                EdidDetailedTimingSizeMm::try_from(0).unwrap(),
+                EdidDetailedTimingSizeMm::try_from(0).unwrap(),
+            ))
+            .build()
+            .into_bytes();
+
+        assert_eq!(bytes[..4], [0x02, 0x01, 0x04, 0x00]);
+
+        let bytes = EdidExtensionCTA861Revision3::builder().build().into_bytes();
+
+        assert_eq!(bytes[..2], [0x02, 0x03]);
+    }
+
+    #[test]
+    fn test_dtd_offset() {
+        let dtd = || {
+            EdidDescriptorDetailedTiming::cta_720p_60hz(
+                EdidDetailedTimingSizeMm::try_from(0).unwrap(),
+                EdidDetailedTimingSizeMm::try_from(0).unwrap(),
+            )
+        };
+        let video = || {
+            EdidExtensionCTA861Revision3DataBlock::Video(
+                EdidExtensionCTA861VideoDataBlock::builder()
+                    .add_short_video_descriptor(16)
+                    .add_short_video_descriptor(4)
+                    .build()
+                    .unwrap(),
+            )
+        };
+
+        // No data block and no Detailed Timing.
+        let bytes = EdidExtensionCTA861Revision3::builder().build().into_bytes();
+        assert_eq!(bytes[2], 0);
+        assert!(bytes[4..127].iter().all(|b| *b == 0));
+
+        // Detailed Timings only, they start right after the header.
+        let bytes = EdidExtensionCTA861Revision3::builder()
+            .native_formats(EdidExtensionCTA861NativeFormats::try_from(1).unwrap())
+            .add_detailed_timing_descriptor(dtd())
+            .build()
+            .into_bytes();
+        assert_eq!(bytes[2..4], [4, 0x01]);
+        assert!(bytes[22..127].iter().all(|b| *b == 0));
+
+        // Data blocks only, the offset points right after them.
+        let bytes = EdidExtensionCTA861Revision3::builder()
+            .add_data_block(video())
+            .build()
+            .into_bytes();
+        assert_eq!(bytes[2], 7);
+        assert_eq!(bytes[4..7], [0x42, 16, 4]);
+        assert!(bytes[7..127].iter().all(|b| *b == 0));
+
+        let bytes = EdidExtensionCTA861Revision3::builder()
+            .add_data_block(video())
+            .add_detailed_timing_descriptor(dtd())
+            .add_detailed_timing_descriptor(dtd())
+            .build()
+            .into_bytes();
+        assert_eq!(bytes[2], 7);
+        assert_eq!(bytes[7..25], bytes[25..43]);
+        assert!(bytes[43..127].iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn test_range() {
+        assert!(EdidExtensionCTA861NativeFormats::try_from(15).is_ok());
+        assert!(EdidExtensionCTA861NativeFormats::try_from(16).is_err());
+
+        assert!(EdidExtensionCTA861RevisionNumber::try_from(0).is_err());
+        assert_eq!(
+            EdidExtensionCTA861RevisionNumber::try_from(2).ok(),
+            Some(EdidExtensionCTA861RevisionNumber::Revision2)
+        );
+        assert!(EdidExtensionCTA861RevisionNumber::try_from(4).is_err());
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_cta861_revision3_ordering {
+    use crate::{
+        CecAddress, EdidExtensionCTA861DataBlockOrdering, EdidExtensionCTA861HdmiDataBlock,
+        EdidExtensionCTA861Revision3, EdidExtensionCTA861Revision3DataBlock,
+        EdidExtensionCTA861VideoDataBlock, IntoBytes,
+    };
+
+    fn block_tags(ordering: EdidExtensionCTA861DataBlockOrdering) -> [u8; 2] {
+        let video = EdidExtensionCTA861Revision3DataBlock::Video(
+            EdidExtensionCTA861VideoDataBlock::builder()
+                .add_short_video_descriptor(16)
+                .build()
+                .unwrap(),
+        );
+        let hdmi = EdidExtensionCTA861Revision3DataBlock::HDMI(
+            EdidExtensionCTA861HdmiDataBlock::builder()
+                .source_physical_address(CecAddress::try_from([1, 0, 0, 0]).unwrap())
+                .build(),
+        );
+        let hdmi_len = hdmi.size();
+
+        let bytes = EdidExtensionCTA861Revision3::builder()
+            .data_blocks(vec![hdmi, video])
+            .data_block_ordering(ordering)
+            .build()
+            .into_bytes();
+
+        let second = if bytes[4] >> 5 == 3 { 4 + hdmi_len } else { 6 };
+        [bytes[4] >> 5, bytes[second] >> 5]
+    }
+
+    #[test]
+    fn test_binary() {
+        assert_eq!(
+            block_tags(EdidExtensionCTA861DataBlockOrdering::Preserve),
+            [3, 2]
+        );
+        assert_eq!(
+            block_tags(EdidExtensionCTA861DataBlockOrdering::Strict),
+            [3, 2]
+        );
+        assert_eq!(
+            block_tags(EdidExtensionCTA861DataBlockOrdering::Canonical),
+            [2, 3]
+        );
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidExtensionCTA861 {
     Revision3(EdidExtensionCTA861Revision3),
 }
@@ -905,20 +3131,165 @@ impl IntoBytes for EdidExtensionCTA861 {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[non_exhaustive]
 pub enum EdidExtension {
     CTA861(EdidExtensionCTA861),
+    DisplayID(EdidExtensionDisplayID),
+}
+
+/// How many blocks an EDID can take, for [`EdidRelease3::strip_to`] and
+/// [`EdidRelease4::strip_to`].
+///
+/// The extensions that don't fit in the budget are dropped, last first. Then, the CTA-861
+/// Extensions that are too large to fit in their block lose data blocks and Detailed Timing
+/// Descriptors, last added first, in the following order:
+///
+/// 1. HDR Static Metadata Data Blocks
+/// 2. Colorimetry Data Blocks
+/// 3. Video Capability Data Blocks
+/// 4. Vendor-Specific Data Blocks, except the HDMI one
+/// 5. Speaker Allocation Data Blocks
+/// 6. Audio Data Blocks
+/// 7. Detailed Timing Descriptors
+/// 8. HDMI Vendor-Specific Data Blocks
+/// 9. Video Data Blocks
+///
+/// [`EdidRelease3::strip_to`]: crate::EdidRelease3::strip_to
+/// [`EdidRelease4::strip_to`]: crate::EdidRelease4::strip_to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EdidSizeBudget {
+    /// The base block only, without any extension.
+    BaseBlock,
+
+    /// The base block, and up to the given number of extension blocks.
+    Extensions(usize),
+}
+
+/// Returns the Variable Refresh Rate range, in Hz, advertised by the first HDMI Forum
+/// Vendor-Specific Data Block that sets both `VRRmin` and `VRRmax`.
+pub(crate) fn vrr_range(extensions: &[EdidExtension]) -> Option<(u16, u16)> {
+    extensions
+        .iter()
+        .flat_map(|ext| match ext {
+            EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => cta.data_blocks.iter(),
+            EdidExtension::DisplayID(_) => [].iter(),
+        })
+        .find_map(|block| match block {
+            EdidExtensionCTA861Revision3DataBlock::Vendor(vendor)
+                if vendor.oui == EdidExtensionCTA861VendorOui::HDMI_FORUM =>
+            {
+                // HDMI 2.1 Specification, Section 10.3.2, Table 10-6. VRRmin is stored in the
+                // lower 6 bits of the 6th byte following the OUI, and VRRmax in the upper 2 bits
+                // of the same byte and the whole following one.
+                let payload = &vendor.payload.0;
+                let (Some(&low), Some(&high)) = (payload.get(5), payload.get(6)) else {
+                    return None;
+                };
+
+                let min = u16::from(low & 0x3f);
+                let max = (u16::from(low & 0xc0) << 2) | u16::from(high);
+
+                (min != 0 && max != 0).then_some((min, max))
+            }
+            EdidExtensionCTA861Revision3DataBlock::Audio(_)
+            | EdidExtensionCTA861Revision3DataBlock::SpeakerAllocation(_)
+            | EdidExtensionCTA861Revision3DataBlock::Colorimetry(_)
+            | EdidExtensionCTA861Revision3DataBlock::HdrStaticMetadata(_)
+            | EdidExtensionCTA861Revision3DataBlock::Video(_)
+            | EdidExtensionCTA861Revision3DataBlock::HDMI(_)
+            | EdidExtensionCTA861Revision3DataBlock::Vendor(_)
+            | EdidExtensionCTA861Revision3DataBlock::VideoCapability(_) => None,
+        })
+}
+
+/// Drops the extensions, data blocks and Detailed Timing Descriptors that don't fit in the
+/// budget.
+pub(crate) fn strip_extensions(extensions: &mut Vec<EdidExtension>, budget: EdidSizeBudget) {
+    let max = match budget {
+        EdidSizeBudget::BaseBlock => 0,
+        EdidSizeBudget::Extensions(num) => num,
+    };
+
+    extensions.truncate(max);
+
+    for ext in extensions {
+        match ext {
+            EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => cta.strip(),
+            EdidExtension::DisplayID(_) => {}
+        }
+    }
+}
+
+/// Lists the source physical addresses found in the extensions, along with the index of the
+/// extension they were found in.
+pub(crate) fn physical_addresses(
+    extensions: &[EdidExtension],
+) -> impl Iterator<Item = (usize, CecAddress)> + '_ {
+    extensions
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, ext)| match ext {
+            EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => Some((idx, cta)),
+            EdidExtension::DisplayID(_) => None,
+        })
+        .flat_map(|(idx, cta)| cta.physical_addresses().map(move |address| (idx, address)))
+}
+
+/// Sets the source physical address of every HDMI Vendor-Specific Data Block, in every
+/// extension.
+pub(crate) fn set_physical_address(extensions: &mut [EdidExtension], address: CecAddress) {
+    for ext in extensions {
+        match ext {
+            EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => {
+                cta.set_physical_address(address);
+            }
+            EdidExtension::DisplayID(_) => {}
+        }
+    }
+}
+
+/// Serializes the extension at the given index, and lists the values that couldn't be
+/// represented exactly.
+pub(crate) fn extension_into_bytes(
+    ext_idx: usize,
+    ext: EdidExtension,
+    warnings: &mut Vec<EdidSerializationWarning>,
+    fields: &mut FieldMap,
+) -> Vec<u8> {
+    match &ext {
+        EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => {
+            warnings.extend(cta.timings.iter().enumerate().filter_map(|(idx, dtd)| {
+                dtd.pixel_clock().truncation().map(|clock| {
+                    EdidSerializationWarning::ExtensionDetailedTimingPixelClockTruncated(
+                        ext_idx, idx, clock,
+                    )
+                })
+            }));
+        }
+        EdidExtension::DisplayID(_) => {}
+    }
+
+    match ext {
+        EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => {
+            cta.into_bytes_with_field_map(fields)
+        }
+        EdidExtension::DisplayID(displayid) => displayid.into_bytes_with_field_map(fields),
+    }
 }
 
 impl IntoBytes for EdidExtension {
     fn into_bytes(self) -> Vec<u8> {
         match self {
             EdidExtension::CTA861(v) => v.into_bytes(),
+            EdidExtension::DisplayID(v) => v.into_bytes(),
         }
     }
 
     fn size(&self) -> usize {
         match self {
             EdidExtension::CTA861(v) => v.size(),
+            EdidExtension::DisplayID(v) => v.size(),
         }
     }
 }
@@ -0,0 +1,123 @@
+//! Maps the fields of an EDID to the bytes they are serialized to.
+
+use core::{fmt, ops::Range};
+
+/// The path to a field of an EDID, such as `manufacturer`, `descriptors[1]` or
+/// `extensions[0].data_blocks[2]`.
+///
+/// The names follow the builders ones, and items of a list are identified by the index they
+/// were added at.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EdidFieldPath(String);
+
+impl EdidFieldPath {
+    /// Returns the path as a string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for EdidFieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Records the bytes each field is serialized to, as the serialization goes.
+#[derive(Debug, Default)]
+pub(crate) struct FieldMap {
+    fields: Vec<(EdidFieldPath, Range<usize>)>,
+}
+
+impl FieldMap {
+    /// Records that the field has been serialized to the given bytes.
+    pub(crate) fn record(&mut self, path: impl Into<String>, range: Range<usize>) {
+        self.fields.push((EdidFieldPath(path.into()), range));
+    }
+
+    /// Appends the bytes of a field, and records where they have been stored.
+    pub(crate) fn push(&mut self, bytes: &mut Vec<u8>, path: impl Into<String>, field: &[u8]) {
+        let start = bytes.len();
+        bytes.extend_from_slice(field);
+        self.record(path, start..bytes.len());
+    }
+
+    /// Records the fields of a part serialized on its own, and then stored at the given offset.
+    pub(crate) fn extend(&mut self, prefix: &str, offset: usize, other: Self) {
+        for (path, range) in other.fields {
+            self.fields.push((
+                EdidFieldPath(format!("{prefix}{path}")),
+                range.start + offset..range.end + offset,
+            ));
+        }
+    }
+
+    pub(crate) fn into_fields(self) -> Vec<(EdidFieldPath, Range<usize>)> {
+        self.fields
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_field_map {
+    use crate::{
+        edid_preset_avr, CecAddress, EdidManufacturer, EdidProductCode, IntoBytes, EDID_BASE_LEN,
+    };
+
+    #[test]
+    fn test_contiguous() {
+        let edid = edid_preset_avr(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        );
+        let (bytes, fields) = edid.into_bytes_with_field_map();
+
+        let mut end = 0;
+        for (path, range) in &fields {
+            assert_eq!(range.start, end, "{path} isn't contiguous");
+            assert!(range.start < range.end, "{path} is empty");
+            end = range.end;
+        }
+        assert_eq!(end, bytes.len());
+    }
+
+    #[test]
+    fn test_fields() {
+        let edid = edid_preset_avr(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        );
+        let (bytes, fields) = edid.clone().into_bytes_with_field_map();
+        assert_eq!(bytes, edid.into_bytes());
+
+        let range = |name: &str| {
+            fields
+                .iter()
+                .find(|(path, _)| path.as_str() == name)
+                .map(|(_, range)| range.clone())
+                .unwrap()
+        };
+
+        assert_eq!(range("manufacturer"), 8..10);
+        assert_eq!(bytes[range("product_code")], [0x34, 0x12]);
+        assert_eq!(range("descriptors[0]"), 54..72);
+        assert_eq!(range("checksum"), 127..128);
+        assert_eq!(
+            range("extensions[0].header"),
+            EDID_BASE_LEN..EDID_BASE_LEN + 1
+        );
+        assert_eq!(bytes[range("extensions[0].revision")], [0x03]);
+        assert_eq!(
+            usize::from(bytes[range("extensions[0].detailed_timing_offset").start]),
+            range("extensions[0].padding").start - EDID_BASE_LEN
+        );
+        assert_eq!(
+            range("extensions[0].checksum"),
+            2 * EDID_BASE_LEN - 1..2 * EDID_BASE_LEN
+        );
+        assert_eq!(bytes[range("extensions[0].data_blocks[0]").start] >> 5, 2);
+    }
+}
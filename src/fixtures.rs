@@ -0,0 +1,497 @@
+//! A set of EDIDs meant to be used in the test suites of EDID consumers.
+//!
+//! The set is a matrix of a few display modes, with and without audio and wide color gamut, plus
+//! a few corrupted EDIDs. The names and contents of the fixtures are stable, so they can be
+//! referred to by name and compared against between releases.
+
+use crate::{
+    CecAddress, EdidChromaticityPoint, EdidChromaticityPoints, EdidDescriptor10BitsTiming,
+    EdidDescriptor12BitsTiming, EdidDescriptor6BitsTiming, EdidDescriptor8BitsTiming,
+    EdidDescriptorDetailedTiming, EdidDescriptorString, EdidDetailedTimingPixelClock,
+    EdidDetailedTimingStereo, EdidDetailedTimingSync, EdidDisplayTransferCharacteristics,
+    EdidExtension, EdidExtensionCTA861, EdidExtensionCTA861AudioDataBlock,
+    EdidExtensionCTA861AudioDataBlockChannels, EdidExtensionCTA861AudioDataBlockDesc,
+    EdidExtensionCTA861AudioDataBlockLPCM, EdidExtensionCTA861AudioDataBlockSamplingFrequency,
+    EdidExtensionCTA861AudioDataBlockSamplingRate, EdidExtensionCTA861ColorimetryDataBlock,
+    EdidExtensionCTA861HdmiDataBlock, EdidExtensionCTA861NativeFormats,
+    EdidExtensionCTA861Revision3, EdidExtensionCTA861Revision3DataBlock,
+    EdidExtensionCTA861SpeakerAllocationDataBlock, EdidExtensionCTA861VideoDataBlock,
+    EdidFilterChromaticity, EdidManufacturer, EdidProductCode,
+    EdidR4BasicDisplayParametersFeatures, EdidR4Date, EdidR4Descriptor, EdidR4DigitalColorDepth,
+    EdidR4DigitalInterface, EdidR4DigitalVideoInputDefinition, EdidR4DisplayColor,
+    EdidR4DisplayColorEncoding, EdidR4FeatureSupport, EdidR4ImageSize, EdidR4ManufactureDate,
+    EdidR4VideoInputDefinition, EdidRelease4, EdidScreenSize, EdidScreenSizeLength,
+    EdidSerialNumber, IntoBytes, EDID_BASE_LEN,
+};
+
+/// An EDID from the fixtures set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EdidFixture {
+    name: String,
+    bytes: Vec<u8>,
+}
+
+impl EdidFixture {
+    /// The name of the fixture, unique in the set.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The serialized EDID.
+    #[must_use]
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+struct FixtureMode {
+    hactive: u16,
+    hfp: u16,
+    hsync: u16,
+    hblank: u16,
+    vactive: u16,
+    vfp: u8,
+    vsync: u8,
+    vblank: u16,
+    refresh: u8,
+    pixel_clock_khz: u32,
+    width_mm: u16,
+    height_mm: u16,
+    vics: &'static [u8],
+}
+
+const FIXTURE_MODES: [FixtureMode; 5] = [
+    // CTA-861 VIC 4
+    FixtureMode {
+        hactive: 1280,
+        hfp: 110,
+        hsync: 40,
+        hblank: 370,
+        vactive: 720,
+        vfp: 5,
+        vsync: 5,
+        vblank: 30,
+        refresh: 60,
+        pixel_clock_khz: 74_250,
+        width_mm: 344,
+        height_mm: 194,
+        vics: &[4],
+    },
+    // CTA-861 VIC 16
+    FixtureMode {
+        hactive: 1920,
+        hfp: 88,
+        hsync: 44,
+        hblank: 280,
+        vactive: 1080,
+        vfp: 4,
+        vsync: 5,
+        vblank: 45,
+        refresh: 60,
+        pixel_clock_khz: 148_500,
+        width_mm: 531,
+        height_mm: 299,
+        vics: &[16, 4],
+    },
+    // CVT Reduced Blanking
+    FixtureMode {
+        hactive: 2560,
+        hfp: 48,
+        hsync: 32,
+        hblank: 160,
+        vactive: 1440,
+        vfp: 3,
+        vsync: 5,
+        vblank: 41,
+        refresh: 60,
+        pixel_clock_khz: 241_500,
+        width_mm: 597,
+        height_mm: 336,
+        vics: &[16, 4],
+    },
+    // CTA-861 VIC 95
+    FixtureMode {
+        hactive: 3840,
+        hfp: 176,
+        hsync: 88,
+        hblank: 560,
+        vactive: 2160,
+        vfp: 8,
+        vsync: 10,
+        vblank: 90,
+        refresh: 30,
+        pixel_clock_khz: 297_000,
+        width_mm: 698,
+        height_mm: 393,
+        vics: &[95, 16, 4],
+    },
+    // A panel mounted in portrait orientation
+    FixtureMode {
+        hactive: 1080,
+        hfp: 48,
+        hsync: 32,
+        hblank: 160,
+        vactive: 1920,
+        vfp: 3,
+        vsync: 5,
+        vblank: 40,
+        refresh: 60,
+        pixel_clock_khz: 145_820,
+        width_mm: 68,
+        height_mm: 121,
+        vics: &[],
+    },
+];
+
+impl FixtureMode {
+    fn name(&self) -> String {
+        format!("{}x{}_{}hz", self.hactive, self.vactive, self.refresh)
+    }
+
+    fn detailed_timing(&self) -> EdidDescriptorDetailedTiming {
+        EdidDescriptorDetailedTiming::builder()
+            .pixel_clock(
+                EdidDetailedTimingPixelClock::try_from(self.pixel_clock_khz)
+                    .expect("Fixture pixel clock is invalid"),
+            )
+            .horizontal_addressable(
+                EdidDescriptor12BitsTiming::try_from(self.hactive)
+                    .expect("Fixture timing is invalid"),
+            )
+            .horizontal_blanking(
+                EdidDescriptor12BitsTiming::try_from(self.hblank)
+                    .expect("Fixture timing is invalid"),
+            )
+            .vertical_addressable(
+                EdidDescriptor12BitsTiming::try_from(self.vactive)
+                    .expect("Fixture timing is invalid"),
+            )
+            .vertical_blanking(
+                EdidDescriptor12BitsTiming::try_from(self.vblank)
+                    .expect("Fixture timing is invalid"),
+            )
+            .horizontal_front_porch(
+                EdidDescriptor10BitsTiming::try_from(self.hfp).expect("Fixture timing is invalid"),
+            )
+            .horizontal_sync_pulse(
+                EdidDescriptor10BitsTiming::try_from(self.hsync)
+                    .expect("Fixture timing is invalid"),
+            )
+            .vertical_front_porch(
+                EdidDescriptor6BitsTiming::try_from(self.vfp).expect("Fixture timing is invalid"),
+            )
+            .vertical_sync_pulse(
+                EdidDescriptor6BitsTiming::try_from(self.vsync).expect("Fixture timing is invalid"),
+            )
+            .horizontal_size(
+                EdidDescriptor12BitsTiming::try_from(self.width_mm)
+                    .expect("Fixture size is invalid"),
+            )
+            .vertical_size(
+                EdidDescriptor12BitsTiming::try_from(self.height_mm)
+                    .expect("Fixture size is invalid"),
+            )
+            .horizontal_border(
+                EdidDescriptor8BitsTiming::try_from(0).expect("Fixture border is invalid"),
+            )
+            .vertical_border(
+                EdidDescriptor8BitsTiming::try_from(0).expect("Fixture border is invalid"),
+            )
+            .stereo(EdidDetailedTimingStereo::None)
+            .sync_type(EdidDetailedTimingSync::digital_separate(true, true))
+            .build()
+    }
+
+    fn screen_size(&self) -> EdidScreenSize {
+        let cm = |mm: u16| {
+            let cm = u8::try_from(mm.div_ceil(10)).expect("Fixture size is invalid");
+            EdidScreenSizeLength::try_from(cm).expect("Fixture size is invalid")
+        };
+
+        EdidScreenSize::builder()
+            .horizontal_cm(cm(self.width_mm))
+            .vertical_cm(cm(self.height_mm))
+            .build()
+    }
+}
+
+fn chromaticity_point(x: f32, y: f32) -> EdidChromaticityPoint {
+    EdidChromaticityPoint::try_from((x, y)).expect("Fixture chromaticity point is invalid")
+}
+
+fn cta_extension(mode: &FixtureMode, audio: bool, wide_gamut: bool) -> EdidExtension {
+    let mut cta = EdidExtensionCTA861Revision3::builder()
+        .native_formats(
+            EdidExtensionCTA861NativeFormats::try_from(1)
+                .expect("Fixture native formats count is invalid"),
+        )
+        .audio_supported(audio);
+
+    if !mode.vics.is_empty() {
+        let mut vdb = EdidExtensionCTA861VideoDataBlock::builder();
+        for (idx, vic) in mode.vics.iter().enumerate() {
+            // Only the VICs up to 64 can be marked as native.
+            vdb = if idx == 0 && *vic <= 64 {
+                vdb.add_native_short_video_descriptor(*vic)
+            } else {
+                vdb.add_short_video_descriptor(*vic)
+            };
+        }
+
+        cta = cta.add_data_block(EdidExtensionCTA861Revision3DataBlock::Video(
+            vdb.build().expect("Fixture VICs are invalid"),
+        ));
+    }
+
+    if audio {
+        cta = cta
+            .add_data_block(EdidExtensionCTA861Revision3DataBlock::Audio(
+                EdidExtensionCTA861AudioDataBlock::builder()
+                    .add_short_audio_descriptor(EdidExtensionCTA861AudioDataBlockDesc::LPCM(
+                        EdidExtensionCTA861AudioDataBlockLPCM::builder()
+                            .channels(
+                                EdidExtensionCTA861AudioDataBlockChannels::try_from(2)
+                                    .expect("Fixture channels count is invalid"),
+                            )
+                            .sampling_frequencies(vec![
+                                EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency32kHz,
+                                EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency44_1kHz,
+                                EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency48kHz,
+                            ])
+                            .sampling_rates(vec![
+                                EdidExtensionCTA861AudioDataBlockSamplingRate::Rate16Bit,
+                                EdidExtensionCTA861AudioDataBlockSamplingRate::Rate20Bit,
+                                EdidExtensionCTA861AudioDataBlockSamplingRate::Rate24Bit,
+                            ])
+                            .build(),
+                    ))
+                    .build()
+                    .expect("Fixture Audio Data Block is invalid"),
+            ))
+            .add_data_block(EdidExtensionCTA861Revision3DataBlock::SpeakerAllocation(
+                EdidExtensionCTA861SpeakerAllocationDataBlock::builder()
+                    .front_left_front_right()
+                    .build(),
+            ));
+    }
+
+    cta = cta.add_data_block(EdidExtensionCTA861Revision3DataBlock::HDMI(
+        EdidExtensionCTA861HdmiDataBlock::builder()
+            .source_physical_address(
+                CecAddress::try_from([1, 0, 0, 0]).expect("Fixture address is invalid"),
+            )
+            .build(),
+    ));
+
+    if wide_gamut {
+        cta = cta.add_data_block(EdidExtensionCTA861Revision3DataBlock::Colorimetry(
+            EdidExtensionCTA861ColorimetryDataBlock::builder()
+                .bt_2020_rgb(true)
+                .build(),
+        ));
+    }
+
+    EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta.build()))
+}
+
+fn fixture_edid(idx: u16, mode: &FixtureMode, audio: bool, wide_gamut: bool) -> EdidRelease4 {
+    let chroma = if wide_gamut {
+        EdidChromaticityPoints::builder()
+            .red(chromaticity_point(0.708, 0.292))
+            .green(chromaticity_point(0.170, 0.797))
+            .blue(chromaticity_point(0.131, 0.046))
+            .white(chromaticity_point(0.3127, 0.3290))
+            .build()
+    } else {
+        EdidChromaticityPoints::srgb()
+    };
+
+    EdidRelease4::builder()
+        .manufacturer(EdidManufacturer::from_ascii(b"RED"))
+        .product_code(EdidProductCode::new(idx))
+        .serial_number(Some(EdidSerialNumber::from(u32::from(idx))))
+        .date(EdidR4Date::Manufacture(
+            EdidR4ManufactureDate::try_from((1, 2024)).expect("Fixture date is invalid"),
+        ))
+        .display_parameters_features(
+            EdidR4BasicDisplayParametersFeatures::builder()
+                .video_input(EdidR4VideoInputDefinition::Digital(
+                    EdidR4DigitalVideoInputDefinition::builder()
+                        .color_depth(if wide_gamut {
+                            EdidR4DigitalColorDepth::Depth10Bpc
+                        } else {
+                            EdidR4DigitalColorDepth::Depth8Bpc
+                        })
+                        .interface(EdidR4DigitalInterface::HDMIa)
+                        .build(),
+                ))
+                .size(EdidR4ImageSize::Size(mode.screen_size()))
+                .display_transfer_characteristic(
+                    EdidDisplayTransferCharacteristics::try_from(2.2)
+                        .expect("Fixture gamma is invalid"),
+                )
+                .feature_support(
+                    EdidR4FeatureSupport::builder()
+                        .color(EdidR4DisplayColor::Digital(
+                            EdidR4DisplayColorEncoding::RGB444,
+                        ))
+                        .srgb_default_color_space(!wide_gamut)
+                        .preferred_timing_mode_is_native(true)
+                        .build(),
+                )
+                .build(),
+        )
+        .filter_chromaticity(EdidFilterChromaticity::Color(chroma))
+        .descriptors(vec![
+            EdidR4Descriptor::DetailedTiming(mode.detailed_timing()),
+            EdidR4Descriptor::ProductName(
+                EdidDescriptorString::try_from("redid fixture")
+                    .expect("Fixture product name is invalid"),
+            ),
+        ])
+        .extensions(vec![cta_extension(mode, audio, wide_gamut)])
+        .build()
+        .expect("Fixture EDID is invalid")
+}
+
+/// Generates the fixtures set.
+///
+/// Every display mode is combined with audio support and wide color gamut (BT.2020 primaries,
+/// 10 bits per component and a Colorimetry Data Block) being enabled or not. There's no HDR
+/// fixture, none of them has an HDR Static Metadata Data Block.
+///
+/// The set also contains a fixture with a broken base block checksum, named
+/// `broken_checksum`.
+#[must_use]
+pub fn edid_fixtures() -> Vec<EdidFixture> {
+    let mut fixtures = Vec::new();
+    let mut idx = 0;
+
+    for mode in &FIXTURE_MODES {
+        for audio in [false, true] {
+            for wide_gamut in [false, true] {
+                let mut name = mode.name();
+                if audio {
+                    name.push_str("_audio");
+                }
+
+                if wide_gamut {
+                    name.push_str("_wide_gamut");
+                }
+
+                fixtures.push(EdidFixture {
+                    name,
+                    bytes: fixture_edid(idx, mode, audio, wide_gamut).into_bytes(),
+                });
+                idx += 1;
+            }
+        }
+    }
+
+    let mut bytes = fixture_edid(idx, &FIXTURE_MODES[1], false, false).into_bytes();
+    bytes[EDID_BASE_LEN - 1] = bytes[EDID_BASE_LEN - 1].wrapping_add(1);
+    fixtures.push(EdidFixture {
+        name: String::from("broken_checksum"),
+        bytes,
+    });
+
+    fixtures
+}
+
+#[cfg(test)]
+mod test_fixtures {
+    use super::{edid_fixtures, fixture_edid, EdidFixture, FIXTURE_MODES};
+    use crate::{
+        utils::assert_size_coherent, Edid, EdidDescriptor, EdidExtension, EdidExtensionCTA861,
+        EDID_BASE_LEN,
+    };
+
+    fn checksum_ok(block: &[u8]) -> bool {
+        block.iter().fold(0u8, |sum, b| sum.wrapping_add(*b)) == 0
+    }
+
+    #[test]
+    fn test_names() {
+        let fixtures = edid_fixtures();
+        assert_eq!(fixtures.len(), 21);
+
+        let names: Vec<&str> = fixtures.iter().map(EdidFixture::name).collect();
+        for (idx, name) in names.iter().enumerate() {
+            assert!(!names[..idx].contains(name), "{name} is a duplicate");
+        }
+
+        assert_eq!(names[0], "1280x720_60hz");
+        assert_eq!(names[7], "1920x1080_60hz_audio_wide_gamut");
+        assert_eq!(names[17], "1080x1920_60hz_wide_gamut");
+    }
+
+    #[test]
+    fn test_checksums() {
+        for fixture in edid_fixtures() {
+            let bytes = fixture.bytes();
+            assert_eq!(bytes.len(), 2 * EDID_BASE_LEN);
+
+            let broken = fixture.name() == "broken_checksum";
+            assert_eq!(checksum_ok(&bytes[..EDID_BASE_LEN]), !broken);
+            assert!(checksum_ok(&bytes[EDID_BASE_LEN..]));
+        }
+    }
+
+    #[test]
+    fn test_valid() {
+        for mode in &FIXTURE_MODES {
+            for audio in [false, true] {
+                for wide_gamut in [false, true] {
+                    assert_eq!(fixture_edid(0, mode, audio, wide_gamut).validate(), Ok(()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_size() {
+        for mode in &FIXTURE_MODES {
+            for audio in [false, true] {
+                for wide_gamut in [false, true] {
+                    let edid = fixture_edid(0, mode, audio, wide_gamut);
+                    assert_size_coherent(&edid);
+                    assert_size_coherent(&Edid::from(edid.clone()));
+
+                    assert_size_coherent(&edid.manufacturer);
+                    assert_size_coherent(&edid.product_code);
+                    assert_size_coherent(&edid.date);
+                    assert_size_coherent(&edid.display_parameters_features);
+                    assert_size_coherent(&edid.filter_chromaticity);
+                    assert_size_coherent(&edid.established_timings);
+                    assert_size_coherent(&edid.standard_timings);
+                    assert_size_coherent(&edid.descriptors);
+
+                    for desc in &edid.descriptors {
+                        let EdidDescriptor::R4(desc) = desc else {
+                            panic!("Fixture descriptors must be EDID 1.4 descriptors");
+                        };
+
+                        assert_size_coherent(desc);
+                    }
+
+                    for ext in &edid.extensions {
+                        assert_size_coherent(ext);
+
+                        let EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) = ext else {
+                            continue;
+                        };
+
+                        for block in cta.data_blocks() {
+                            assert_size_coherent(block);
+                        }
+
+                        for dtd in cta.detailed_timings() {
+                            assert_size_coherent(dtd);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,325 @@
+//! Describes how an EDID will be laid out once serialized, without serializing it.
+
+use crate::{
+    Edid, EdidDescriptor, EdidExtension, EdidExtensionCTA861, EdidExtensionCTA861Revision3,
+    EdidExtensionDisplayID, IntoBytes, EDID_DESCRIPTORS_NUM,
+};
+
+/// What a descriptor slot of the base block will hold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EdidDescriptorSlot {
+    /// The Detailed Timing Descriptor at the given index of the descriptors.
+    DetailedTiming(usize),
+
+    /// The Display Descriptor at the given index of the descriptors, with the given tag.
+    Display(usize, u8),
+
+    /// The raw descriptor at the given index of the descriptors.
+    Raw(usize),
+
+    /// A padding Display Descriptor, with the given tag.
+    Padding(u8),
+
+    /// Nothing, since padding is forbidden. The EDID can't be serialized.
+    Missing,
+
+    /// A part of the raw descriptors area.
+    RawArea,
+}
+
+/// A data block of a CTA-861 Extension, as it will be laid out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EdidDataBlockLayout {
+    index: usize,
+    tag: u8,
+    size: usize,
+}
+
+impl EdidDataBlockLayout {
+    /// Returns the index the data block was added at.
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the Data Block Tag Code, or the Data Block Tag for a `DisplayID` Extension.
+    #[must_use]
+    pub const fn tag(&self) -> u8 {
+        self.tag
+    }
+
+    /// Returns the size of the data block, in bytes, header included.
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// An extension block, as it will be laid out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EdidExtensionLayout {
+    data_blocks: Vec<EdidDataBlockLayout>,
+    detailed_timings: usize,
+    remaining_bytes: usize,
+    fits: bool,
+}
+
+impl EdidExtensionLayout {
+    fn cta861(cta: &EdidExtensionCTA861Revision3) -> Self {
+        let blocks = cta.data_blocks();
+
+        Self {
+            data_blocks: cta
+                .data_block_order()
+                .into_iter()
+                .map(|index| EdidDataBlockLayout {
+                    index,
+                    tag: blocks[index].tag(),
+                    size: blocks[index].size(),
+                })
+                .collect(),
+            detailed_timings: cta.detailed_timings().len(),
+            remaining_bytes: cta.remaining_bytes(),
+            fits: cta.fits(),
+        }
+    }
+
+    fn displayid(displayid: &EdidExtensionDisplayID) -> Self {
+        Self {
+            data_blocks: displayid
+                .data_blocks()
+                .iter()
+                .enumerate()
+                .map(|(index, block)| EdidDataBlockLayout {
+                    index,
+                    tag: block.tag(),
+                    size: block.size(),
+                })
+                .collect(),
+            detailed_timings: 0,
+            remaining_bytes: displayid.remaining_bytes(),
+            fits: displayid.fits(),
+        }
+    }
+
+    /// Returns the data blocks, in the order they will be serialized.
+    #[must_use]
+    pub fn data_blocks(&self) -> &[EdidDataBlockLayout] {
+        &self.data_blocks
+    }
+
+    /// Returns the number of Detailed Timing Descriptors, stored after the data blocks. It's
+    /// always 0 for a `DisplayID` Extension, whose timings are stored in data blocks.
+    #[must_use]
+    pub const fn detailed_timings(&self) -> usize {
+        self.detailed_timings
+    }
+
+    /// Returns the number of bytes left unused in the block. It's 0 if the content doesn't fit.
+    #[must_use]
+    pub const fn remaining_bytes(&self) -> usize {
+        self.remaining_bytes
+    }
+
+    /// Returns whether the content fits in the block.
+    #[must_use]
+    pub const fn fits(&self) -> bool {
+        self.fits
+    }
+}
+
+/// A description of the blocks an EDID will be serialized to, and of what they will hold.
+///
+/// It can be used to report configuration errors, such as too many descriptors or an
+/// overflowing extension, before serializing the EDID.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EdidLayout {
+    descriptors: Vec<EdidDescriptorSlot>,
+    extensions: Vec<EdidExtensionLayout>,
+    fits: bool,
+}
+
+impl EdidLayout {
+    /// Returns the number of 128 bytes blocks, base block included.
+    #[must_use]
+    pub fn num_blocks(&self) -> usize {
+        1 + self.extensions.len()
+    }
+
+    /// Returns what each descriptor slot of the base block will hold.
+    ///
+    /// If more than 4 descriptors have been set, the extra ones are listed as well, even though
+    /// they don't fit.
+    #[must_use]
+    pub fn descriptors(&self) -> &[EdidDescriptorSlot] {
+        &self.descriptors
+    }
+
+    /// Returns the layout of each extension block.
+    #[must_use]
+    pub fn extensions(&self) -> &[EdidExtensionLayout] {
+        &self.extensions
+    }
+
+    /// Returns whether the descriptors and the extensions all fit.
+    ///
+    /// The EDID can be serialized if they do. It doesn't mean that it is valid though, see
+    /// [`crate::EdidRelease4::validate`].
+    #[must_use]
+    pub const fn fits(&self) -> bool {
+        self.fits
+    }
+}
+
+fn descriptor_slot(index: usize, desc: &EdidDescriptor) -> EdidDescriptorSlot {
+    let tag = match desc {
+        EdidDescriptor::R3(desc) => desc.tag(),
+        EdidDescriptor::R4(desc) => desc.tag(),
+        EdidDescriptor::Raw(_) => return EdidDescriptorSlot::Raw(index),
+    };
+
+    tag.map_or(EdidDescriptorSlot::DetailedTiming(index), |tag| {
+        EdidDescriptorSlot::Display(index, tag)
+    })
+}
+
+pub(crate) fn edid_layout(edid: &Edid) -> EdidLayout {
+    let descriptors = if edid.raw_descriptors.is_some() {
+        vec![EdidDescriptorSlot::RawArea; EDID_DESCRIPTORS_NUM]
+    } else {
+        let mut slots: Vec<_> = edid
+            .descriptors
+            .iter()
+            .enumerate()
+            .map(|(idx, desc)| descriptor_slot(idx, desc))
+            .collect();
+
+        let padding = edid
+            .descriptor_padding
+            .tag()
+            .map_or(EdidDescriptorSlot::Missing, EdidDescriptorSlot::Padding);
+
+        if slots.len() < EDID_DESCRIPTORS_NUM {
+            slots.resize(EDID_DESCRIPTORS_NUM, padding);
+        }
+
+        slots
+    };
+
+    let extensions: Vec<_> = edid
+        .extensions
+        .iter()
+        .map(|ext| match ext {
+            EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => {
+                EdidExtensionLayout::cta861(cta)
+            }
+            EdidExtension::DisplayID(displayid) => EdidExtensionLayout::displayid(displayid),
+        })
+        .collect();
+
+    // The serialization relies on the checks the builders enforce, while the overflowing
+    // extensions are stripped to fit.
+    let fits = edid.check_layout().is_ok() && extensions.iter().all(EdidExtensionLayout::fits);
+
+    EdidLayout {
+        descriptors,
+        extensions,
+        fits,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_layout {
+    use crate::{
+        edid_preset_avr, CecAddress, EdidDescriptor, EdidDescriptorPadding, EdidDescriptorSlot,
+        EdidExtensionLayout, EdidManufacturer, EdidProductCode, EdidR4Descriptor,
+    };
+
+    #[test]
+    fn test_avr() {
+        let edid = edid_preset_avr(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+            CecAddress::try_from([2, 0, 0, 0]).unwrap(),
+        );
+
+        let layout = edid.layout();
+        assert!(layout.fits());
+        assert_eq!(layout.num_blocks(), 2);
+        assert_eq!(
+            layout.descriptors(),
+            &[
+                EdidDescriptorSlot::DetailedTiming(0),
+                EdidDescriptorSlot::Display(1, 0xfc),
+                EdidDescriptorSlot::Padding(0x10),
+                EdidDescriptorSlot::Padding(0x10),
+            ]
+        );
+
+        let ext = &layout.extensions()[0];
+        assert!(ext.fits());
+        assert_eq!(ext.detailed_timings(), 0);
+        assert_eq!(
+            ext.data_blocks()
+                .iter()
+                .map(|b| (b.index(), b.tag(), b.size()))
+                .collect::<Vec<_>>(),
+            vec![(0, 2, 2), (1, 1, 7), (2, 4, 4), (3, 3, 7)]
+        );
+        // The header, data blocks and checksum take 25 bytes.
+        assert_eq!(ext.remaining_bytes(), 128 - 25);
+    }
+
+    #[test]
+    fn test_descriptors() {
+        let mut edid = edid_preset_avr(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+            CecAddress::try_from([2, 0, 0, 0]).unwrap(),
+        );
+        edid.descriptor_padding = EdidDescriptorPadding::Forbidden;
+
+        let layout = edid.layout();
+        assert!(!layout.fits());
+        assert_eq!(layout.descriptors()[3], EdidDescriptorSlot::Missing);
+
+        edid.descriptor_padding = EdidDescriptorPadding::Dummy;
+        for _ in 0..3 {
+            edid.descriptors
+                .push(EdidDescriptor::R4(EdidR4Descriptor::Dummy));
+        }
+
+        let layout = edid.layout();
+        assert!(!layout.fits());
+        assert_eq!(layout.descriptors().len(), 5);
+        assert_eq!(
+            layout.descriptors()[4],
+            EdidDescriptorSlot::Display(4, 0x10)
+        );
+
+        edid.raw_descriptors = Some([0; 72]);
+        assert_eq!(
+            edid.layout().descriptors(),
+            &[EdidDescriptorSlot::RawArea; 4]
+        );
+    }
+
+    #[test]
+    fn test_extensions() {
+        let mut edid = edid_preset_avr(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+            CecAddress::try_from([2, 0, 0, 0]).unwrap(),
+        );
+        let ext = edid.extensions[0].clone();
+        edid.extensions.resize(255, ext.clone());
+        assert!(edid.layout().fits());
+
+        // The extensions all fit, but their count can't be stored.
+        edid.extensions.push(ext);
+        let layout = edid.layout();
+        assert!(layout.extensions().iter().all(EdidExtensionLayout::fits));
+        assert!(!layout.fits());
+    }
+}
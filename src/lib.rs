@@ -23,53 +23,146 @@
 )]
 #![doc = include_str!("../README.md")]
 
-use core::{array, fmt, num};
+extern crate alloc;
+
+use core::{array, fmt, num, ops::Range, str::FromStr};
 
 use num_traits::ToPrimitive;
 use static_assertions::const_assert_eq;
 use typed_builder::TypedBuilder;
 
+mod anonymize;
+
+pub use anonymize::anonymize;
+
 mod descriptors;
 
 pub use descriptors::{
     EdidDescriptor, EdidDescriptor10BitsTiming, EdidDescriptor12BitsTiming,
-    EdidDescriptor6BitsTiming, EdidDescriptor8BitsTiming, EdidDescriptorCustom,
-    EdidDescriptorCustomPayload, EdidDescriptorCustomTag, EdidDescriptorDetailedTiming,
-    EdidDescriptorString, EdidDescriptorTiming, EdidDetailedTimingAnalogSync,
-    EdidDetailedTimingDigitalCompositeSync, EdidDetailedTimingDigitalSeparateSync,
-    EdidDetailedTimingDigitalSync, EdidDetailedTimingDigitalSyncKind, EdidDetailedTimingPixelClock,
-    EdidDetailedTimingSizeMm, EdidDetailedTimingStereo, EdidDetailedTimingSync,
-    EdidDisplayRangeHorizontalFreq, EdidDisplayRangePixelClock, EdidDisplayRangeVerticalFreq,
-    EdidDisplayRangeVideoTimingsGTF, EdidDisplayRangeVideoTimingsGTFStartFrequency,
-    EdidR3Descriptor, EdidR3DisplayRangeLimits, EdidR3DisplayRangeVideoTimingsSupport,
-    EdidR4Descriptor, EdidR4DescriptorEstablishedTimings, EdidR4DescriptorEstablishedTimingsIII,
-    EdidR4DisplayRangeHorizontalFreq, EdidR4DisplayRangeLimits, EdidR4DisplayRangeVerticalFreq,
-    EdidR4DisplayRangeVideoTimingsAspectRatio, EdidR4DisplayRangeVideoTimingsCVT,
+    EdidDescriptor6BitsTiming, EdidDescriptor8BitsTiming, EdidDescriptorColorPoint,
+    EdidDescriptorCustom, EdidDescriptorCustomDecoder, EdidDescriptorCustomPayload,
+    EdidDescriptorCustomRegistry, EdidDescriptorCustomTag, EdidDescriptorCustomType,
+    EdidDescriptorDetailedTiming, EdidDescriptorPadding, EdidDescriptorString,
+    EdidDescriptorStringEncodingPolicy, EdidDescriptorStringLengthPolicy,
+    EdidDescriptorStringOptions, EdidDescriptorStringPadding, EdidDescriptorStringTermination,
+    EdidDescriptorTiming, EdidDescriptorWhitePoint, EdidDetailedTimingAnalogSync,
+    EdidDetailedTimingBorderPolicy, EdidDetailedTimingDigitalCompositeSync,
+    EdidDetailedTimingDigitalSeparateSync, EdidDetailedTimingDigitalSync,
+    EdidDetailedTimingDigitalSyncKind, EdidDetailedTimingPixelClock, EdidDetailedTimingSizeMm,
+    EdidDetailedTimingStereo, EdidDetailedTimingSync, EdidDisplayRangeHorizontalFreq,
+    EdidDisplayRangePixelClock, EdidDisplayRangeVerticalFreq, EdidDisplayRangeVideoTimingsGTF,
+    EdidDisplayRangeVideoTimingsGTFBlankingGradient, EdidDisplayRangeVideoTimingsGTFBlankingOffset,
+    EdidDisplayRangeVideoTimingsGTFBlankingScalingFactor,
+    EdidDisplayRangeVideoTimingsGTFBlankingScalingFactorWeighting,
+    EdidDisplayRangeVideoTimingsGTFStartFrequency, EdidR3Descriptor, EdidR3DescriptorSlots,
+    EdidR3DisplayRangeLimits, EdidR3DisplayRangeVideoTimingsSupport, EdidR4Descriptor,
+    EdidR4DescriptorEstablishedTimings, EdidR4DescriptorEstablishedTimingsIII,
+    EdidR4DescriptorSlots, EdidR4DisplayRangeHorizontalFreq, EdidR4DisplayRangeLimits,
+    EdidR4DisplayRangeVerticalFreq, EdidR4DisplayRangeVideoTimingsAspectRatio,
+    EdidR4DisplayRangeVideoTimingsCVT, EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels,
     EdidR4DisplayRangeVideoTimingsCVTPixelClockDiff, EdidR4DisplayRangeVideoTimingsCVTR1,
     EdidR4DisplayRangeVideoTimingsSupport,
 };
 
+mod displayid;
+
+pub use displayid::{
+    EdidExtensionDisplayID, EdidExtensionDisplayID15BitsTiming, EdidExtensionDisplayID16BitsTiming,
+    EdidExtensionDisplayIDAspectRatio, EdidExtensionDisplayIDDataBlock,
+    EdidExtensionDisplayIDPixelClock, EdidExtensionDisplayIDTileBezel,
+    EdidExtensionDisplayIDTileCount, EdidExtensionDisplayIDTileLocation,
+    EdidExtensionDisplayIDTileMultipleBehavior, EdidExtensionDisplayIDTileSingleBehavior,
+    EdidExtensionDisplayIDTiledDisplayTopology, EdidExtensionDisplayIDTiming,
+    EdidExtensionDisplayIDTypeVIITiming,
+};
+
+mod dump;
+
+pub use dump::EdidDump;
+
 mod extensions;
 
 pub use extensions::{
     CecAddress, EdidExtension, EdidExtensionCTA861, EdidExtensionCTA861AudioDataBlock,
     EdidExtensionCTA861AudioDataBlockChannels, EdidExtensionCTA861AudioDataBlockDesc,
-    EdidExtensionCTA861AudioDataBlockLPCM, EdidExtensionCTA861AudioDataBlockSamplingFrequency,
+    EdidExtensionCTA861AudioDataBlockExtended, EdidExtensionCTA861AudioDataBlockExtendedFlags,
+    EdidExtensionCTA861AudioDataBlockExtendedTypeCode, EdidExtensionCTA861AudioDataBlockLPCM,
+    EdidExtensionCTA861AudioDataBlockSamplingFrequency,
     EdidExtensionCTA861AudioDataBlockSamplingRate, EdidExtensionCTA861ColorimetryDataBlock,
-    EdidExtensionCTA861Hdmi14bDataBlockVideo, EdidExtensionCTA861Hdmi14bTmdsRate,
-    EdidExtensionCTA861HdmiDataBlock, EdidExtensionCTA861Revision3,
-    EdidExtensionCTA861Revision3DataBlock, EdidExtensionCTA861SpeakerAllocationDataBlock,
-    EdidExtensionCTA861VideoCapabilityDataBlock, EdidExtensionCTA861VideoCapabilityQuantization,
-    EdidExtensionCTA861VideoCapabilityScanBehavior, EdidExtensionCTA861VideoDataBlock,
-    EdidExtensionCTA861VideoDataBlockDesc,
+    EdidExtensionCTA861DataBlockOrdering, EdidExtensionCTA861Hdmi14bDataBlockVideo,
+    EdidExtensionCTA861Hdmi14bTmdsRate, EdidExtensionCTA861HdmiDataBlock,
+    EdidExtensionCTA861HdmiLatencies, EdidExtensionCTA861HdmiLatency,
+    EdidExtensionCTA861HdmiLatencyPair, EdidExtensionCTA861HdrStaticMetadataDataBlock,
+    EdidExtensionCTA861NativeFormats, EdidExtensionCTA861Revision3,
+    EdidExtensionCTA861Revision3DataBlock, EdidExtensionCTA861RevisionNumber,
+    EdidExtensionCTA861SpeakerAllocationDataBlock, EdidExtensionCTA861VendorDataBlock,
+    EdidExtensionCTA861VendorDataBlockDecoder, EdidExtensionCTA861VendorDataBlockRegistry,
+    EdidExtensionCTA861VendorDataBlockType, EdidExtensionCTA861VendorOui,
+    EdidExtensionCTA861VendorPayload, EdidExtensionCTA861VideoCapabilityDataBlock,
+    EdidExtensionCTA861VideoCapabilityQuantization, EdidExtensionCTA861VideoCapabilityScanBehavior,
+    EdidExtensionCTA861VideoDataBlock, EdidExtensionCTA861VideoDataBlockDesc, EdidSizeBudget,
 };
 
+mod field_map;
+
+pub use field_map::EdidFieldPath;
+
+mod fixtures;
+
+pub use fixtures::{edid_fixtures, EdidFixture};
+
+mod layout;
+
+pub use layout::{EdidDataBlockLayout, EdidDescriptorSlot, EdidExtensionLayout, EdidLayout};
+
+mod load;
+
+pub use load::{load_raw, parse_base64, parse_hex};
+
+mod presets;
+
+pub use presets::{edid_preset_3d_tv, edid_preset_avr, edid_preset_monochrome_medical};
+
+pub mod random;
+
+#[cfg(feature = "serde")]
+mod schema;
+
+#[cfg(feature = "serde")]
+pub use schema::{EdidSchema, EDID_SCHEMA_VERSION};
+
+mod sidecar;
+
+pub use sidecar::EdidSidecar;
+
+mod static_edid;
+
+pub use static_edid::{edid_block_checksum, edid_blocks, EdidBlock, EDID_HEADER};
+
+mod summary;
+
+pub use summary::EdidSummary;
+
+mod template;
+
+pub use template::{EdidTemplate, EdidTemplateOverrides};
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_utils;
+
+mod timings;
+
+pub use timings::{EdidCtaVideoFormat, EdidTiming, EdidTimingSource, EDID_CTA_VIDEO_FORMATS};
+
 mod utils;
 
+mod validation;
+
+pub use validation::{EdidSerializationWarning, EdidValidationError, EdidValidationWarning};
+
 const EDID_BASE_LEN: usize = 128;
 
-// It looks like const_assert! doesn't count as being used somehow.
-#[allow(dead_code)]
 const EDID_HEADER_LEN: usize = 8;
 
 // It looks like const_assert! doesn't count as being used somehow.
@@ -106,11 +199,18 @@ const EDID_ESTABLISHED_TIMINGS_LEN: usize = 3;
 const EDID_STANDARD_TIMINGS_LEN: usize = 16;
 const EDID_DESCRIPTOR_LEN: usize = 18;
 const EDID_DESCRIPTORS_NUM: usize = 4;
+const EDID_DESCRIPTOR_HEADER_LEN: usize = 5;
 const EDID_DESCRIPTOR_PAYLOAD_LEN: usize = 13;
 
+const_assert_eq!(
+    EDID_DESCRIPTOR_HEADER_LEN + EDID_DESCRIPTOR_PAYLOAD_LEN,
+    EDID_DESCRIPTOR_LEN
+);
+
 // It looks like const_assert! doesn't count as being used somehow.
 #[allow(dead_code)]
 const EDID_EXTENSION_NUM_LEN: usize = 1;
+const EDID_EXTENSIONS_MAX: usize = u8::MAX as usize;
 
 // It looks like const_assert! doesn't count as being used somehow.
 #[allow(dead_code)]
@@ -139,11 +239,76 @@ pub trait IntoBytes {
 }
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum EdidTypeConversionError<D: fmt::Display> {
     Int(num::TryFromIntError),
     Slice(array::TryFromSliceError),
     Range(D, Option<D>, Option<D>),
     Value(String),
+
+    /// The pixel clock, in kHz, is too high to be expressed in a Detailed Timing Descriptor. Such
+    /// a timing needs to be described by a [`EdidExtensionDisplayIDTypeVIITiming`] instead, in an
+    /// [`EdidExtension::DisplayID`] extension.
+    PixelClockOverflow(D),
+
+    /// The EDID the conversion starts from can't be converted, for the given reason.
+    Invalid(EdidValidationError),
+
+    /// The conversion of a given field failed.
+    Field(&'static str, Box<EdidTypeConversionError<D>>),
+
+    /// The conversion of the item at a given index of a list failed.
+    Index(usize, Box<EdidTypeConversionError<D>>),
+}
+
+impl<D: fmt::Display> EdidTypeConversionError<D> {
+    /// Wraps the error to report the field it happened in.
+    #[must_use]
+    pub fn in_field(self, field: &'static str) -> Self {
+        EdidTypeConversionError::Field(field, Box::new(self))
+    }
+
+    /// Wraps the error to report the index of the list item it happened in.
+    #[must_use]
+    pub fn at_index(self, index: usize) -> Self {
+        EdidTypeConversionError::Index(index, Box::new(self))
+    }
+
+    // Returns what separates the enclosing field or index from this error in the path.
+    const fn path_separator(&self) -> &'static str {
+        match self {
+            EdidTypeConversionError::Field(_, _) => ".",
+            EdidTypeConversionError::Index(_, _) => "",
+            EdidTypeConversionError::Int(_)
+            | EdidTypeConversionError::Slice(_)
+            | EdidTypeConversionError::Range(_, _, _)
+            | EdidTypeConversionError::Value(_)
+            | EdidTypeConversionError::PixelClockOverflow(_)
+            | EdidTypeConversionError::Invalid(_) => ": ",
+        }
+    }
+
+    /// Converts the values held by the error.
+    pub fn map_value<E: fmt::Display>(self, f: &impl Fn(D) -> E) -> EdidTypeConversionError<E> {
+        match self {
+            EdidTypeConversionError::Int(e) => EdidTypeConversionError::Int(e),
+            EdidTypeConversionError::Slice(e) => EdidTypeConversionError::Slice(e),
+            EdidTypeConversionError::Range(v, min, max) => {
+                EdidTypeConversionError::Range(f(v), min.map(f), max.map(f))
+            }
+            EdidTypeConversionError::Value(v) => EdidTypeConversionError::Value(v),
+            EdidTypeConversionError::PixelClockOverflow(v) => {
+                EdidTypeConversionError::PixelClockOverflow(f(v))
+            }
+            EdidTypeConversionError::Invalid(e) => EdidTypeConversionError::Invalid(e),
+            EdidTypeConversionError::Field(field, e) => {
+                EdidTypeConversionError::Field(field, Box::new(e.map_value(f)))
+            }
+            EdidTypeConversionError::Index(index, e) => {
+                EdidTypeConversionError::Index(index, Box::new(e.map_value(f)))
+            }
+        }
+    }
 }
 
 impl<D: fmt::Display> From<num::TryFromIntError> for EdidTypeConversionError<D> {
@@ -176,16 +341,33 @@ impl<D: fmt::Display> fmt::Display for EdidTypeConversionError<D> {
             }
             EdidTypeConversionError::Slice(_) => write!(f, "Couldn't convert to an array"),
             EdidTypeConversionError::Value(s) => write!(f, "Invalid Value: {s}"),
+            EdidTypeConversionError::PixelClockOverflow(v) => write!(
+                f,
+                "Pixel clock of {v} kHz is too high for a Detailed Timing Descriptor, use a DisplayID Type VII Timing instead"
+            ),
+            EdidTypeConversionError::Invalid(e) => write!(f, "Invalid EDID: {e}"),
+            EdidTypeConversionError::Field(field, e) => {
+                write!(f, "{field}{}{e}", e.path_separator())
+            }
+            EdidTypeConversionError::Index(index, e) => {
+                write!(f, "[{index}]{}{e}", e.path_separator())
+            }
         }
     }
 }
 
-impl<D: fmt::Display + fmt::Debug> std::error::Error for EdidTypeConversionError<D> {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl<D: fmt::Display + fmt::Debug> core::error::Error for EdidTypeConversionError<D> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self {
             EdidTypeConversionError::Int(e) => Some(e),
             EdidTypeConversionError::Slice(e) => Some(e),
-            EdidTypeConversionError::Range(_, _, _) | EdidTypeConversionError::Value(_) => None,
+            EdidTypeConversionError::Invalid(e) => Some(e),
+            EdidTypeConversionError::Range(_, _, _)
+            | EdidTypeConversionError::Value(_)
+            | EdidTypeConversionError::PixelClockOverflow(_) => None,
+            EdidTypeConversionError::Field(_, e) | EdidTypeConversionError::Index(_, e) => {
+                e.source()
+            }
         }
     }
 }
@@ -197,6 +379,8 @@ enum EdidRelease {
 }
 
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String"))]
 pub struct EdidManufacturer([u8; EDID_MANUFACTURER_CHAR_LEN]);
 
 impl TryFrom<&str> for EdidManufacturer {
@@ -225,18 +409,51 @@ impl TryFrom<&str> for EdidManufacturer {
     }
 }
 
+impl EdidManufacturer {
+    /// Creates a Manufacturer ID in a const context.
+    ///
+    /// # Panics
+    ///
+    /// If any of the characters isn't an upper-case ASCII letter. If called in a const context,
+    /// this will be reported as a compilation error.
+    #[must_use]
+    pub const fn from_ascii(id: &[u8; EDID_MANUFACTURER_CHAR_LEN]) -> Self {
+        let mut idx = 0;
+        while idx < EDID_MANUFACTURER_CHAR_LEN {
+            assert!(
+                id[idx].is_ascii_uppercase(),
+                "Manufacturer ID must be upper-cased ASCII only."
+            );
+            idx += 1;
+        }
+
+        Self(*id)
+    }
+
+    /// Returns the Manufacturer ID encoded as it will be found in the EDID.
+    #[must_use]
+    pub const fn into_raw(self) -> [u8; EDID_MANUFACTURER_LEN] {
+        let manufacturer = &self.0;
+        let byte0 = ((manufacturer[0] - b'@') << 2) | ((manufacturer[1] - b'@') >> 3);
+        let byte1 = ((manufacturer[1] - b'@') << 5) | (manufacturer[2] - b'@');
+
+        [byte0, byte1]
+    }
+}
+
+impl TryFrom<String> for EdidManufacturer {
+    type Error = EdidTypeConversionError<String>;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
 impl IntoBytes for EdidManufacturer {
     fn into_bytes(self) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(EDID_MANUFACTURER_LEN);
 
-        let manufacturer = &self.0;
-        let mut comp = (manufacturer[0] - b'@') << 2;
-        comp |= (manufacturer[1] - b'@') >> 3;
-        bytes.push(comp);
-
-        comp = (manufacturer[1] - b'@') << 5;
-        comp |= manufacturer[2] - b'@';
-        bytes.push(comp);
+        bytes.extend_from_slice(&self.into_raw());
 
         let len = bytes.len();
         assert_eq!(
@@ -253,11 +470,27 @@ impl IntoBytes for EdidManufacturer {
 }
 
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "u16"))]
 pub struct EdidProductCode(u16);
 
+impl EdidProductCode {
+    /// Creates a Product Code in a const context.
+    #[must_use]
+    pub const fn new(value: u16) -> Self {
+        Self(value)
+    }
+
+    /// Returns the Product Code encoded as it will be found in the EDID.
+    #[must_use]
+    pub const fn into_raw(self) -> [u8; EDID_PRODUCT_CODE_LEN] {
+        self.0.to_le_bytes()
+    }
+}
+
 impl From<u16> for EdidProductCode {
     fn from(value: u16) -> Self {
-        Self(value)
+        Self::new(value)
     }
 }
 
@@ -265,9 +498,7 @@ impl IntoBytes for EdidProductCode {
     fn into_bytes(self) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(EDID_PRODUCT_CODE_LEN);
 
-        let prod = &self.0;
-        bytes.push((prod & 0xff) as u8);
-        bytes.push((prod >> 8) as u8);
+        bytes.extend_from_slice(&self.into_raw());
 
         let len = bytes.len();
         assert_eq!(
@@ -284,8 +515,39 @@ impl IntoBytes for EdidProductCode {
 }
 
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "u32"))]
 pub struct EdidSerialNumber(u32);
 
+/// How to format the serial number when deriving the Product Serial Number descriptor from it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum EdidSerialNumberFormat {
+    /// Decimal, without any padding.
+    Decimal,
+
+    /// Upper-case hexadecimal, zero-padded to 8 digits and without any prefix.
+    Hexadecimal,
+}
+
+impl EdidSerialNumber {
+    /// Formats the serial number into a string suitable for a Product Serial Number descriptor.
+    ///
+    /// # Panics
+    ///
+    /// Never, the formatted serial number always fits in a descriptor.
+    #[must_use]
+    pub fn to_descriptor_string(self, format: EdidSerialNumberFormat) -> EdidDescriptorString {
+        let s = match format {
+            EdidSerialNumberFormat::Decimal => format!("{}", self.0),
+            EdidSerialNumberFormat::Hexadecimal => format!("{:08X}", self.0),
+        };
+
+        EdidDescriptorString::try_from(s.as_str())
+            .expect("Serial number string would overflow our descriptor")
+    }
+}
+
 impl From<u32> for EdidSerialNumber {
     fn from(value: u32) -> Self {
         Self(value)
@@ -316,6 +578,34 @@ impl IntoBytes for EdidSerialNumber {
     }
 }
 
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_edid_serial_number {
+    use crate::{EdidDescriptorString, EdidSerialNumber, EdidSerialNumberFormat};
+
+    #[test]
+    fn test_descriptor_string() {
+        let sn = EdidSerialNumber::from(0x1234_abcd);
+
+        assert_eq!(
+            sn.to_descriptor_string(EdidSerialNumberFormat::Decimal),
+            EdidDescriptorString::try_from("305441741").unwrap()
+        );
+        assert_eq!(
+            sn.to_descriptor_string(EdidSerialNumberFormat::Hexadecimal),
+            EdidDescriptorString::try_from("1234ABCD").unwrap()
+        );
+        assert_eq!(
+            EdidSerialNumber::from(u32::MAX).to_descriptor_string(EdidSerialNumberFormat::Decimal),
+            EdidDescriptorString::try_from("4294967295").unwrap()
+        );
+        assert_eq!(
+            EdidSerialNumber::from(42).to_descriptor_string(EdidSerialNumberFormat::Hexadecimal),
+            EdidDescriptorString::try_from("0000002A").unwrap()
+        );
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct EdidWeek(u8);
 
@@ -332,6 +622,7 @@ impl TryFrom<u8> for EdidWeek {
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_result_states)]
 mod test_edid_week {
     use crate::EdidWeek;
 
@@ -360,6 +651,7 @@ impl TryFrom<u16> for EdidYear {
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_result_states)]
 mod test_edid_year {
     use crate::EdidYear;
 
@@ -384,15 +676,11 @@ impl TryFrom<(u8, u16)> for EdidManufactureDate {
         let week = value
             .0
             .try_into()
-            .map_err(|e: EdidTypeConversionError<u8>| match e {
-                EdidTypeConversionError::Int(e) => EdidTypeConversionError::Int(e),
-                EdidTypeConversionError::Range(v, min, max) => {
-                    EdidTypeConversionError::Range(v.into(), min.map(u16::from), max.map(u16::from))
-                }
-                EdidTypeConversionError::Slice(e) => EdidTypeConversionError::Slice(e),
-                EdidTypeConversionError::Value(v) => EdidTypeConversionError::Value(v),
-            })?;
-        let year = value.1.try_into()?;
+            .map_err(|e: EdidTypeConversionError<u8>| e.map_value(&u16::from).in_field("week"))?;
+        let year = value
+            .1
+            .try_into()
+            .map_err(|e: EdidTypeConversionError<u16>| e.in_field("year"))?;
 
         Ok(Self(Some(week), year))
     }
@@ -431,6 +719,7 @@ impl IntoBytes for EdidManufactureDate {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod test_edid_manufacture_date {
     use crate::{EdidManufactureDate, IntoBytes};
 
@@ -441,6 +730,22 @@ mod test_edid_manufacture_date {
         let date = EdidManufactureDate::try_from(1997).unwrap();
         assert_eq!(date.into_bytes(), &[0x00, 0x07]);
     }
+
+    #[test]
+    fn test_error_field() {
+        assert_eq!(
+            EdidManufactureDate::try_from((54, 2024))
+                .unwrap_err()
+                .to_string(),
+            "week: Value out of range: 54 (Range: 1..=53)"
+        );
+        assert_eq!(
+            EdidManufactureDate::try_from((1, 1989))
+                .unwrap_err()
+                .to_string(),
+            "year: Value out of range: 1989 (Range: 1990..)"
+        );
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -459,6 +764,7 @@ impl TryFrom<u8> for EdidR4Week {
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_result_states)]
 mod test_edid_week_release_4 {
     use crate::EdidR4Week;
 
@@ -475,6 +781,8 @@ mod test_edid_week_release_4 {
 ///
 /// Contains a year, starting from 1990, and an optional week in the 1-54 range.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "(u8, u16)"))]
 pub struct EdidR4ManufactureDate(Option<EdidR4Week>, EdidYear);
 
 impl TryFrom<(u8, u16)> for EdidR4ManufactureDate {
@@ -484,15 +792,11 @@ impl TryFrom<(u8, u16)> for EdidR4ManufactureDate {
         let week = value
             .0
             .try_into()
-            .map_err(|e: EdidTypeConversionError<u8>| match e {
-                EdidTypeConversionError::Int(e) => EdidTypeConversionError::Int(e),
-                EdidTypeConversionError::Range(v, min, max) => {
-                    EdidTypeConversionError::Range(v.into(), min.map(u16::from), max.map(u16::from))
-                }
-                EdidTypeConversionError::Slice(e) => EdidTypeConversionError::Slice(e),
-                EdidTypeConversionError::Value(v) => EdidTypeConversionError::Value(v),
-            })?;
-        let year = value.1.try_into()?;
+            .map_err(|e: EdidTypeConversionError<u8>| e.map_value(&u16::from).in_field("week"))?;
+        let year = value
+            .1
+            .try_into()
+            .map_err(|e: EdidTypeConversionError<u16>| e.in_field("year"))?;
 
         Ok(Self(Some(week), year))
     }
@@ -531,6 +835,7 @@ impl IntoBytes for EdidR4ManufactureDate {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod test_edid_manufacture_date_release_4 {
     use crate::{EdidManufactureDate, IntoBytes};
 
@@ -547,6 +852,8 @@ mod test_edid_manufacture_date_release_4 {
 ///
 /// Contains a Year, starting from 1990.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16"))]
 pub struct EdidR4ModelDate(EdidYear);
 
 impl TryFrom<u16> for EdidR4ModelDate {
@@ -581,6 +888,7 @@ impl IntoBytes for EdidR4ModelDate {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod test_edid_model_date {
     use crate::{EdidR4ModelDate, IntoBytes};
 
@@ -595,11 +903,57 @@ mod test_edid_model_date {
 
 /// EDID 1.4 Date Representation.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidR4Date {
     Manufacture(EdidR4ManufactureDate),
     Model(EdidR4ModelDate),
 }
 
+/// Returns the year a number of days since the UNIX Epoch falls in.
+fn year_since_epoch(mut days: u64) -> u16 {
+    let mut year: u16 = 1970;
+    loop {
+        let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        let year_days = if leap { 366 } else { 365 };
+
+        if days < year_days {
+            break year;
+        }
+
+        days -= year_days;
+        year += 1;
+    }
+}
+
+/// Returns the current year, according to the system clock.
+fn current_year() -> u16 {
+    const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+    year_since_epoch(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs() / SECS_PER_DAY),
+    )
+}
+
+impl EdidR4Date {
+    /// Creates a Model Date for the current year, according to the system clock.
+    #[must_use]
+    pub fn model_year_now() -> Self {
+        Self::Model(EdidR4ModelDate(EdidYear(current_year().max(1990))))
+    }
+
+    /// Creates a Manufacture Date, without a week, for the current year according to the system
+    /// clock.
+    #[must_use]
+    pub fn manufacture_year_now() -> Self {
+        Self::Manufacture(EdidR4ManufactureDate(
+            None,
+            EdidYear(current_year().max(1990)),
+        ))
+    }
+}
+
 impl IntoBytes for EdidR4Date {
     fn into_bytes(self) -> Vec<u8> {
         match self {
@@ -617,7 +971,9 @@ impl IntoBytes for EdidR4Date {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod test_edid_date_release_4 {
+    use super::year_since_epoch;
     use crate::{EdidR4Date, EdidR4ManufactureDate, EdidR4ModelDate, IntoBytes};
 
     #[test]
@@ -630,6 +986,17 @@ mod test_edid_date_release_4 {
         let date = EdidR4Date::Model(EdidR4ModelDate::try_from(2006).unwrap());
         assert_eq!(date.into_bytes(), &[0xff, 0x10]);
     }
+
+    #[test]
+    fn test_year_since_epoch() {
+        assert_eq!(year_since_epoch(0), 1970);
+        // 2000 is a leap year, its last day is the 366th.
+        assert_eq!(year_since_epoch(11_322), 2000);
+        assert_eq!(year_since_epoch(11_323), 2001);
+        // 2100 isn't.
+        assert_eq!(year_since_epoch(47_846), 2100);
+        assert_eq!(year_since_epoch(47_847), 2101);
+    }
 }
 
 /// EDID Date Representation.
@@ -665,6 +1032,7 @@ impl IntoBytes for EdidDate {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod test_edid_date {
     use crate::{
         EdidDate, EdidManufactureDate, EdidR4Date, EdidR4ManufactureDate, EdidR4ModelDate,
@@ -692,6 +1060,7 @@ mod test_edid_date {
 #[repr(u8)]
 #[allow(non_camel_case_types)]
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidAnalogSignalLevelStandard {
     V_0_700_S_0_300_T_1_000 = 0,
     V_0_714_S_0_286_T_1_000,
@@ -701,26 +1070,33 @@ pub enum EdidAnalogSignalLevelStandard {
 
 #[repr(u8)]
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidAnalogVideoSetup {
     BlankLevelIsBlackLevel = 0,
     BlankToBlackSetupOrPedestal,
 }
 
 #[derive(Clone, Copy, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct EdidAnalogVideoInputDefinition {
     signal_level: EdidAnalogSignalLevelStandard,
     setup: EdidAnalogVideoSetup,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     separate_hv_sync_signals: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     composite_sync_signal_on_hsync: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     composite_sync_signal_on_green_video: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     serrations_on_vsync: bool,
 }
 
@@ -769,6 +1145,109 @@ pub struct EdidR3DigitalVideoInputDefinition {
     dfp1_compatible: bool,
 }
 
+impl EdidR3DigitalVideoInputDefinition {
+    /// Returns the color depth implied by the input definition.
+    ///
+    /// EDID 1.3 has no color depth field. A DFP 1.x compatible input uses 8 bits per color
+    /// component, and the color depth of any other digital input is undefined.
+    #[must_use]
+    pub const fn color_depth(&self) -> EdidR4DigitalColorDepth {
+        if self.dfp1_compatible {
+            EdidR4DigitalColorDepth::Depth8Bpc
+        } else {
+            EdidR4DigitalColorDepth::DepthUndefined
+        }
+    }
+}
+
+/// Converts an EDID 1.4 digital input definition to its EDID 1.3 counterpart.
+///
+/// EDID 1.3 can only tell whether a digital input is DFP 1.x compatible, that is an 8 bits per
+/// color component DVI input. Without a color depth, any interface converts to a generic
+/// digital input, but a defined color depth can only be expressed for an 8 bits per color
+/// component DVI input.
+impl TryFrom<EdidR4DigitalVideoInputDefinition> for EdidR3DigitalVideoInputDefinition {
+    type Error = EdidTypeConversionError<String>;
+
+    fn try_from(value: EdidR4DigitalVideoInputDefinition) -> Result<Self, Self::Error> {
+        let dfp1_compatible = match (value.color_depth, value.interface) {
+            (EdidR4DigitalColorDepth::Depth8Bpc, EdidR4DigitalInterface::DVI) => true,
+            (EdidR4DigitalColorDepth::DepthUndefined, _) => false,
+            (depth, _) => {
+                return Err(EdidTypeConversionError::Value(format!(
+                    "EDID 1.3 can't express a {depth:?} color depth on this interface, only EDID 1.4 can."
+                )))
+            }
+        };
+
+        Ok(Self { dfp1_compatible })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_r3_digital_input {
+    use crate::{
+        EdidR3DigitalVideoInputDefinition, EdidR4DigitalColorDepth, EdidR4DigitalInterface,
+        EdidR4DigitalVideoInputDefinition,
+    };
+
+    fn r4(
+        color_depth: EdidR4DigitalColorDepth,
+        interface: EdidR4DigitalInterface,
+    ) -> EdidR4DigitalVideoInputDefinition {
+        EdidR4DigitalVideoInputDefinition::builder()
+            .color_depth(color_depth)
+            .interface(interface)
+            .build()
+    }
+
+    #[test]
+    fn test_color_depth() {
+        assert_eq!(
+            EdidR3DigitalVideoInputDefinition::builder()
+                .build()
+                .color_depth(),
+            EdidR4DigitalColorDepth::DepthUndefined
+        );
+        assert_eq!(
+            EdidR3DigitalVideoInputDefinition::builder()
+                .dfp1_compatible(true)
+                .build()
+                .color_depth(),
+            EdidR4DigitalColorDepth::Depth8Bpc
+        );
+    }
+
+    #[test]
+    fn test_from_release_4() {
+        let r3 = EdidR3DigitalVideoInputDefinition::try_from(r4(
+            EdidR4DigitalColorDepth::Depth8Bpc,
+            EdidR4DigitalInterface::DVI,
+        ))
+        .unwrap();
+        assert!(r3.dfp1_compatible);
+
+        let r3 = EdidR3DigitalVideoInputDefinition::try_from(r4(
+            EdidR4DigitalColorDepth::DepthUndefined,
+            EdidR4DigitalInterface::HDMIa,
+        ))
+        .unwrap();
+        assert!(!r3.dfp1_compatible);
+
+        assert!(EdidR3DigitalVideoInputDefinition::try_from(r4(
+            EdidR4DigitalColorDepth::Depth10Bpc,
+            EdidR4DigitalInterface::DVI,
+        ))
+        .is_err());
+        assert!(EdidR3DigitalVideoInputDefinition::try_from(r4(
+            EdidR4DigitalColorDepth::Depth8Bpc,
+            EdidR4DigitalInterface::HDMIa,
+        ))
+        .is_err());
+    }
+}
+
 impl IntoBytes for EdidR3DigitalVideoInputDefinition {
     fn into_bytes(self) -> Vec<u8> {
         let mut byte = 0x80;
@@ -817,6 +1296,8 @@ impl IntoBytes for EdidR3VideoInputDefinition {
 }
 
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
 pub struct EdidScreenSizeLength(u8);
 
 impl TryFrom<u8> for EdidScreenSizeLength {
@@ -832,11 +1313,22 @@ impl TryFrom<u8> for EdidScreenSizeLength {
 }
 
 #[derive(Clone, Copy, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct EdidScreenSize {
     horizontal_cm: EdidScreenSizeLength,
     vertical_cm: EdidScreenSizeLength,
 }
 
+impl EdidScreenSize {
+    fn rotated(self) -> Self {
+        Self {
+            horizontal_cm: self.vertical_cm,
+            vertical_cm: self.horizontal_cm,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum EdidR3ImageSize {
     Size(EdidScreenSize),
@@ -866,6 +1358,7 @@ impl IntoBytes for EdidR3ImageSize {
 
 #[repr(u8)]
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidDisplayColorType {
     MonochromeGrayScale = 0,
     RGBColor,
@@ -874,7 +1367,12 @@ pub enum EdidDisplayColorType {
 }
 
 /// Display Transfer Characteristics (aka Gamma)
+///
+/// With the `serde` feature, it's deserialized from a gamma value, or from a null value if it's
+/// defined in an extension.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Option<f32>"))]
 pub enum EdidDisplayTransferCharacteristics {
     Gamma(f32),
     DisplayInformationExtension(()),
@@ -892,9 +1390,30 @@ impl TryFrom<f32> for EdidDisplayTransferCharacteristics {
     }
 }
 
-impl IntoBytes for EdidDisplayTransferCharacteristics {
-    fn into_bytes(self) -> Vec<u8> {
-        let stored = match self {
+impl TryFrom<Option<f32>> for EdidDisplayTransferCharacteristics {
+    type Error = EdidTypeConversionError<f32>;
+
+    fn try_from(value: Option<f32>) -> Result<Self, Self::Error> {
+        value.map_or(Ok(Self::DisplayInformationExtension(())), Self::try_from)
+    }
+}
+
+impl EdidDisplayTransferCharacteristics {
+    /// Creates the Display Transfer Characteristics from the byte stored in the EDID.
+    ///
+    /// This allows to reproduce exactly the gamma of an existing EDID, whatever the rounding
+    /// that led to it.
+    #[must_use]
+    pub fn from_raw(raw: u8) -> Self {
+        if raw == 0xff {
+            return Self::DisplayInformationExtension(());
+        }
+
+        Self::Gamma((f32::from(raw) + 100.0) / 100.0)
+    }
+
+    fn into_raw(self) -> u8 {
+        match self {
             EdidDisplayTransferCharacteristics::Gamma(v) => {
                 let raw = (v * 100.0) - 100.0;
 
@@ -903,9 +1422,35 @@ impl IntoBytes for EdidDisplayTransferCharacteristics {
                     .expect("Gamma binary representation would overflow.")
             }
             EdidDisplayTransferCharacteristics::DisplayInformationExtension(()) => 0xff,
+        }
+    }
+
+    /// Returns the gamma value as it will be encoded in the EDID, that is rounded to the nearest
+    /// hundredth, or `None` if the gamma is defined in an extension.
+    #[must_use]
+    pub fn encoded_gamma(self) -> Option<f32> {
+        match self {
+            EdidDisplayTransferCharacteristics::Gamma(_) => {
+                Some((f32::from(self.into_raw()) + 100.0) / 100.0)
+            }
+            EdidDisplayTransferCharacteristics::DisplayInformationExtension(()) => None,
+        }
+    }
+
+    /// Returns the gamma if it's not a multiple of a hundredth and will be rounded.
+    fn rounding(self) -> Option<f32> {
+        let EdidDisplayTransferCharacteristics::Gamma(value) = self else {
+            return None;
         };
 
-        let bytes = Vec::from(&[stored]);
+        let encoded = self.encoded_gamma()?;
+        ((value - encoded).abs() > 0.0005).then_some(value)
+    }
+}
+
+impl IntoBytes for EdidDisplayTransferCharacteristics {
+    fn into_bytes(self) -> Vec<u8> {
+        let bytes = Vec::from(&[self.into_raw()]);
         let len = bytes.len();
         assert_eq!(
             len, EDID_GAMMA_LEN,
@@ -921,18 +1466,41 @@ impl IntoBytes for EdidDisplayTransferCharacteristics {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod test_display_transfer_characteristics {
     use super::{EdidDisplayTransferCharacteristics, IntoBytes};
 
     #[test]
     fn test_binary_spec() {
         // These are taken from the EDID 1.4 Specification, Section 3.6.2
-        let gamma: EdidDisplayTransferCharacteristics = 2.2_f32.try_into().unwrap();
+        let gamma: EdidDisplayTransferCharacteristics = 2.2f32.try_into().unwrap();
         assert_eq!(gamma.into_bytes(), &[0x78]);
 
         let ext = EdidDisplayTransferCharacteristics::DisplayInformationExtension(());
         assert_eq!(ext.into_bytes(), &[0xff]);
     }
+
+    #[test]
+    fn test_raw() {
+        let gamma = EdidDisplayTransferCharacteristics::from_raw(0x77);
+        assert_eq!(gamma.into_bytes(), &[0x77]);
+        assert!((gamma.encoded_gamma().unwrap() - 2.19).abs() < f32::EPSILON);
+
+        for raw in 0..=0xfe {
+            let gamma = EdidDisplayTransferCharacteristics::from_raw(raw);
+            assert_eq!(gamma.into_bytes(), &[raw]);
+        }
+
+        let ext = EdidDisplayTransferCharacteristics::from_raw(0xff);
+        assert_eq!(ext.into_bytes(), &[0xff]);
+        assert!(ext.encoded_gamma().is_none());
+    }
+
+    #[test]
+    fn test_encoded_gamma() {
+        let gamma = EdidDisplayTransferCharacteristics::try_from(2.196).unwrap();
+        assert!((gamma.encoded_gamma().unwrap() - 2.2).abs() < f32::EPSILON);
+    }
 }
 
 #[derive(Clone, Copy, Debug, TypedBuilder)]
@@ -1031,7 +1599,8 @@ impl IntoBytes for EdidR3BasicDisplayParametersFeatures {
 }
 
 #[repr(u8)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidR4DigitalColorDepth {
     DepthUndefined = 0,
     Depth6Bpc,
@@ -1043,7 +1612,8 @@ pub enum EdidR4DigitalColorDepth {
 }
 
 #[repr(u8)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidR4DigitalInterface {
     Undefined = 0,
     DVI,
@@ -1054,6 +1624,8 @@ pub enum EdidR4DigitalInterface {
 }
 
 #[derive(Clone, Copy, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct EdidR4DigitalVideoInputDefinition {
     color_depth: EdidR4DigitalColorDepth,
     interface: EdidR4DigitalInterface,
@@ -1082,6 +1654,7 @@ impl IntoBytes for EdidR4DigitalVideoInputDefinition {
 }
 
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidR4VideoInputDefinition {
     Analog(EdidAnalogVideoInputDefinition),
     Digital(EdidR4DigitalVideoInputDefinition),
@@ -1113,6 +1686,8 @@ impl IntoBytes for EdidR4VideoInputDefinition {
 }
 
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "(f32, f32)"))]
 pub struct EdidR4ImageLandscapeAspectRatio(f32, f32);
 
 impl TryFrom<(f32, f32)> for EdidR4ImageLandscapeAspectRatio {
@@ -1132,6 +1707,8 @@ impl TryFrom<(f32, f32)> for EdidR4ImageLandscapeAspectRatio {
 }
 
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "(f32, f32)"))]
 pub struct EdidR4ImagePortraitAspectRatio(f32, f32);
 
 impl TryFrom<(f32, f32)> for EdidR4ImagePortraitAspectRatio {
@@ -1154,6 +1731,7 @@ impl TryFrom<(f32, f32)> for EdidR4ImagePortraitAspectRatio {
 ///
 /// For displays that pivot, the screen size is considered in landscape mode.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidR4ImageSize {
     LandscapeRatio(EdidR4ImageLandscapeAspectRatio),
     PortraitRatio(EdidR4ImagePortraitAspectRatio),
@@ -1161,6 +1739,76 @@ pub enum EdidR4ImageSize {
     Undefined,
 }
 
+const CM_PER_INCH: f32 = 2.54;
+
+fn screen_size_length(cm: f32) -> Result<EdidScreenSizeLength, EdidTypeConversionError<f32>> {
+    let Some(length) = cm.round().to_u8() else {
+        return Err(EdidTypeConversionError::Range(cm, Some(1.0), Some(255.0)));
+    };
+
+    EdidScreenSizeLength::try_from(length)
+        .map_err(|_e| EdidTypeConversionError::Range(cm, Some(1.0), Some(255.0)))
+}
+
+impl EdidR4ImageSize {
+    /// Creates a screen size from its diagonal, in inches, and its aspect ratio, so that a 27"
+    /// 16:9 display can be expressed with `from_diagonal(27.0, (16.0, 9.0))`.
+    ///
+    /// # Errors
+    ///
+    /// If the computed width or height doesn't fit in the 1 to 255 cm range.
+    pub fn from_diagonal(
+        diagonal: f32,
+        ratio: (f32, f32),
+    ) -> Result<Self, EdidTypeConversionError<f32>> {
+        let (width, height) = ratio;
+        let diagonal_cm = diagonal * CM_PER_INCH;
+        let ratio_diagonal = width.hypot(height);
+
+        Ok(Self::Size(
+            EdidScreenSize::builder()
+                .horizontal_cm(screen_size_length(diagonal_cm * width / ratio_diagonal)?)
+                .vertical_cm(screen_size_length(diagonal_cm * height / ratio_diagonal)?)
+                .build(),
+        ))
+    }
+
+    /// Converts a screen size into the matching landscape or portrait aspect ratio. Other
+    /// variants are returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// If the aspect ratio of the screen size can't be represented.
+    pub fn into_aspect_ratio(self) -> Result<Self, EdidTypeConversionError<f32>> {
+        let Self::Size(size) = self else {
+            return Ok(self);
+        };
+
+        let width = f32::from(size.horizontal_cm.0);
+        let height = f32::from(size.vertical_cm.0);
+
+        Ok(if width >= height {
+            Self::LandscapeRatio(EdidR4ImageLandscapeAspectRatio::try_from((width, height))?)
+        } else {
+            Self::PortraitRatio(EdidR4ImagePortraitAspectRatio::try_from((width, height))?)
+        })
+    }
+
+    /// Converts an aspect ratio into a screen size, given the diagonal of the screen in inches.
+    /// Other variants are returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// If the computed width or height doesn't fit in the 1 to 255 cm range.
+    pub fn into_size(self, diagonal: f32) -> Result<Self, EdidTypeConversionError<f32>> {
+        match self {
+            Self::LandscapeRatio(ratio) => Self::from_diagonal(diagonal, (ratio.0, ratio.1)),
+            Self::PortraitRatio(ratio) => Self::from_diagonal(diagonal, (ratio.0, ratio.1)),
+            Self::Size(_) | Self::Undefined => Ok(self),
+        }
+    }
+}
+
 impl IntoBytes for EdidR4ImageSize {
     fn into_bytes(self) -> Vec<u8> {
         let bytes = Vec::from(&match self {
@@ -1205,11 +1853,53 @@ impl IntoBytes for EdidR4ImageSize {
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
 mod test_size_release_4 {
     use super::{
         EdidR4ImageLandscapeAspectRatio, EdidR4ImagePortraitAspectRatio, EdidR4ImageSize, IntoBytes,
     };
 
+    #[test]
+    fn test_diagonal() {
+        let size = EdidR4ImageSize::from_diagonal(27.0, (16.0, 9.0)).unwrap();
+        assert_eq!(size.into_bytes(), &[60, 34]);
+
+        let size = EdidR4ImageSize::from_diagonal(15.6, (9.0, 16.0)).unwrap();
+        assert_eq!(size.into_bytes(), &[19, 35]);
+
+        assert!(EdidR4ImageSize::from_diagonal(0.1, (16.0, 9.0)).is_err());
+        assert!(EdidR4ImageSize::from_diagonal(200.0, (16.0, 9.0)).is_err());
+    }
+
+    #[test]
+    fn test_aspect_ratio() {
+        // The screen size is rounded to the centimeter, so we end up with a 1.76 ratio.
+        let size = EdidR4ImageSize::from_diagonal(27.0, (16.0, 9.0)).unwrap();
+        let ratio = size.into_aspect_ratio().unwrap();
+        assert_eq!(ratio.into_bytes(), &[0x4d, 0x00]);
+
+        let size = EdidR4ImageSize::from_diagonal(27.0, (9.0, 16.0)).unwrap();
+        let ratio = size.into_aspect_ratio().unwrap();
+        assert_eq!(ratio.into_bytes(), &[0x00, 0x4d]);
+
+        assert!(matches!(
+            EdidR4ImageSize::Undefined.into_aspect_ratio(),
+            Ok(EdidR4ImageSize::Undefined)
+        ));
+
+        let ratio: EdidR4ImageLandscapeAspectRatio = (16.0, 9.0).try_into().unwrap();
+        let size = EdidR4ImageSize::LandscapeRatio(ratio)
+            .into_size(27.0)
+            .unwrap();
+        assert_eq!(size.into_bytes(), &[60, 34]);
+
+        let ratio: EdidR4ImagePortraitAspectRatio = (9.0, 16.0).try_into().unwrap();
+        let size = EdidR4ImageSize::PortraitRatio(ratio)
+            .into_size(27.0)
+            .unwrap();
+        assert_eq!(size.into_bytes(), &[34, 60]);
+    }
+
     #[test]
     fn test_binary_spec() {
         // These are taken from the EDID 1.4 Specification, Section 3.6.2
@@ -1252,6 +1942,7 @@ mod test_size_release_4 {
 
 #[repr(u8)]
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidR4DisplayColorEncoding {
     RGB444 = 0,
     RGB444YCbCr444,
@@ -1259,33 +1950,56 @@ pub enum EdidR4DisplayColorEncoding {
     RGB444YCbCr444YCbCr422,
 }
 
+impl EdidR4DisplayColorEncoding {
+    /// Returns whether YCbCr 4:4:4 is supported.
+    #[must_use]
+    pub const fn ycbcr_444_supported(self) -> bool {
+        matches!(self, Self::RGB444YCbCr444 | Self::RGB444YCbCr444YCbCr422)
+    }
+
+    /// Returns whether YCbCr 4:2:2 is supported.
+    #[must_use]
+    pub const fn ycbcr_422_supported(self) -> bool {
+        matches!(self, Self::RGB444YCbCr422 | Self::RGB444YCbCr444YCbCr422)
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidR4DisplayColor {
     Analog(EdidDisplayColorType),
     Digital(EdidR4DisplayColorEncoding),
 }
 
 #[derive(Clone, Copy, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct EdidR4FeatureSupport {
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     #[deprecated]
     standby: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     #[deprecated]
     suspend: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     active_off_is_very_low_power: bool,
     color: EdidR4DisplayColor,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     srgb_default_color_space: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     preferred_timing_mode_is_native: bool,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     continuous_frequency: bool,
 }
 
@@ -1341,6 +2055,8 @@ impl IntoBytes for EdidR4FeatureSupport {
 }
 
 #[derive(Clone, Copy, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct EdidR4BasicDisplayParametersFeatures {
     video_input: EdidR4VideoInputDefinition,
     size: EdidR4ImageSize,
@@ -1408,6 +2124,27 @@ impl IntoBytes for EdidBasicDisplayParametersFeatures {
 pub struct EdidChromaticityCoordinate(f32);
 
 impl EdidChromaticityCoordinate {
+    /// Creates a coordinate from its 10 bits binary representation, as found in the EDID.
+    ///
+    /// Unlike the conversion from a float, the coordinate is guaranteed to be encoded back to
+    /// the exact same value.
+    ///
+    /// # Errors
+    ///
+    /// If the value doesn't fit in 10 bits.
+    pub fn from_raw(value: u16) -> Result<Self, EdidTypeConversionError<u16>> {
+        if value > 0x3ff {
+            return Err(EdidTypeConversionError::Range(value, Some(0), Some(0x3ff)));
+        }
+
+        Ok(Self::from_10_bits(value))
+    }
+
+    // Only the lower 10 bits of the value are used.
+    fn from_10_bits(value: u16) -> Self {
+        Self(f32::from(value & 0x3ff) / 1024.0)
+    }
+
     fn into_raw(self) -> u16 {
         (self.0 * 1024.0)
             .round()
@@ -1417,6 +2154,7 @@ impl EdidChromaticityCoordinate {
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
 mod test_chromaticity_coordinate {
     use super::EdidChromaticityCoordinate;
 
@@ -1426,6 +2164,20 @@ mod test_chromaticity_coordinate {
         assert_eq!(EdidChromaticityCoordinate(0.307).into_raw(), 0b01_0011_1010);
         assert_eq!(EdidChromaticityCoordinate(0.150).into_raw(), 0b00_1001_1010);
     }
+
+    #[test]
+    fn test_from_raw() {
+        for raw in 0..=0x3ff {
+            assert_eq!(
+                EdidChromaticityCoordinate::from_raw(raw)
+                    .unwrap()
+                    .into_raw(),
+                raw
+            );
+        }
+
+        assert!(EdidChromaticityCoordinate::from_raw(0x400).is_err());
+    }
 }
 
 impl TryFrom<f32> for EdidChromaticityCoordinate {
@@ -1441,20 +2193,45 @@ impl TryFrom<f32> for EdidChromaticityCoordinate {
 }
 
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "(f32, f32)"))]
 pub struct EdidChromaticityPoint(EdidChromaticityCoordinate, EdidChromaticityCoordinate);
 
 impl TryFrom<(f32, f32)> for EdidChromaticityPoint {
     type Error = EdidTypeConversionError<f32>;
 
     fn try_from(value: (f32, f32)) -> Result<Self, Self::Error> {
-        let x = value.0.try_into()?;
-        let y = value.1.try_into()?;
+        let x = value
+            .0
+            .try_into()
+            .map_err(|e: EdidTypeConversionError<f32>| e.in_field("x"))?;
+        let y = value
+            .1
+            .try_into()
+            .map_err(|e: EdidTypeConversionError<f32>| e.in_field("y"))?;
 
         Ok(Self(x, y))
     }
 }
 
+impl EdidChromaticityPoint {
+    /// Creates a point from the 10 bits binary representation of its coordinates, as found in
+    /// the EDID, to reproduce an existing EDID without any rounding.
+    ///
+    /// # Errors
+    ///
+    /// If a coordinate doesn't fit in 10 bits.
+    pub fn from_raw(x: u16, y: u16) -> Result<Self, EdidTypeConversionError<u16>> {
+        Ok(Self(
+            EdidChromaticityCoordinate::from_raw(x).map_err(|e| e.in_field("x"))?,
+            EdidChromaticityCoordinate::from_raw(y).map_err(|e| e.in_field("y"))?,
+        ))
+    }
+}
+
 #[derive(Clone, Copy, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 #[builder(field_defaults(setter(into)))]
 pub struct EdidChromaticityPoints {
     white: EdidChromaticityPoint,
@@ -1463,6 +2240,79 @@ pub struct EdidChromaticityPoints {
     blue: EdidChromaticityPoint,
 }
 
+impl EdidChromaticityPoints {
+    /// Creates the chromaticity points of the sRGB color space, as required by the EDID 1.4
+    /// Specification when the sRGB Standard is the default color space.
+    #[must_use]
+    pub fn srgb() -> Self {
+        let point = |x, y| {
+            EdidChromaticityPoint(EdidChromaticityCoordinate(x), EdidChromaticityCoordinate(y))
+        };
+
+        Self {
+            white: point(0.3127, 0.3290),
+            red: point(0.640, 0.330),
+            green: point(0.300, 0.600),
+            blue: point(0.150, 0.060),
+        }
+    }
+
+    /// Checks whether the points match the sRGB ones once encoded, allowing for one unit of
+    /// difference to account for the rounding of other tools.
+    pub(crate) fn matches_srgb(&self) -> bool {
+        let srgb = Self::srgb();
+
+        [
+            (self.white, srgb.white),
+            (self.red, srgb.red),
+            (self.green, srgb.green),
+            (self.blue, srgb.blue),
+        ]
+        .iter()
+        .all(|(point, expected)| {
+            point.0.into_raw().abs_diff(expected.0.into_raw()) <= 1
+                && point.1.into_raw().abs_diff(expected.1.into_raw()) <= 1
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_chromaticity_points {
+    use crate::{EdidChromaticityPoint, EdidChromaticityPoints};
+
+    #[test]
+    fn test_srgb() {
+        assert!(EdidChromaticityPoints::srgb().matches_srgb());
+
+        let points = EdidChromaticityPoints::builder()
+            .white(EdidChromaticityPoint::try_from((0.313, 0.329)).unwrap())
+            .red(EdidChromaticityPoint::try_from((0.64, 0.33)).unwrap())
+            .green(EdidChromaticityPoint::try_from((0.3, 0.6)).unwrap())
+            .blue(EdidChromaticityPoint::try_from((0.15, 0.06)).unwrap())
+            .build();
+        assert!(points.matches_srgb());
+
+        let points = EdidChromaticityPoints::builder()
+            .white(EdidChromaticityPoint::try_from((0.3127, 0.3290)).unwrap())
+            .red(EdidChromaticityPoint::try_from((0.708, 0.292)).unwrap())
+            .green(EdidChromaticityPoint::try_from((0.170, 0.797)).unwrap())
+            .blue(EdidChromaticityPoint::try_from((0.131, 0.046)).unwrap())
+            .build();
+        assert!(!points.matches_srgb());
+    }
+
+    #[test]
+    fn test_from_raw() {
+        let point = EdidChromaticityPoint::from_raw(0x271, 0x13a).unwrap();
+        assert_eq!(point.0.into_raw(), 0x271);
+        assert_eq!(point.1.into_raw(), 0x13a);
+
+        assert!(EdidChromaticityPoint::from_raw(0x400, 0).is_err());
+        assert!(EdidChromaticityPoint::from_raw(0, 0x400).is_err());
+    }
+}
+
 #[allow(variant_size_differences)]
 #[derive(Clone, Copy, Debug)]
 pub enum EdidFilterChromaticity {
@@ -1555,13 +2405,178 @@ impl IntoBytes for EdidFilterChromaticity {
     }
 }
 
+/// Decodes the Color Characteristics bytes, as found in the EDID.
+///
+/// The chromaticity is decoded as monochrome if the red, green and blue coordinates are all 0.
+impl From<[u8; EDID_CHROMATICITY_COORDINATES_LEN]> for EdidFilterChromaticity {
+    fn from(value: [u8; EDID_CHROMATICITY_COORDINATES_LEN]) -> Self {
+        // The lower 2 bits of each coordinate are packed in the first two bytes, the upper 8
+        // bits follow in the red, green, blue and white order.
+        let coordinate = |idx: usize| {
+            let lo = value[idx / 4] >> (6 - 2 * (idx % 4)) & 0b11;
+
+            EdidChromaticityCoordinate::from_10_bits(u16::from(value[idx + 2]) << 2 | u16::from(lo))
+        };
+        let point = |idx: usize| EdidChromaticityPoint(coordinate(idx), coordinate(idx + 1));
+
+        let white = point(6);
+        if value[2..8].iter().all(|byte| *byte == 0) && value[0] == 0 && value[1] >> 4 == 0 {
+            return Self::MonoChrome(white);
+        }
+
+        Self::Color(EdidChromaticityPoints {
+            white,
+            red: point(0),
+            green: point(2),
+            blue: point(4),
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EdidMonoChromeDescription {
+    white: EdidChromaticityPoint,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum EdidFilterChromaticityDescription {
+    Hex(String),
+    Bytes([u8; EDID_CHROMATICITY_COORDINATES_LEN]),
+    Color(EdidChromaticityPoints),
+    MonoChrome(EdidMonoChromeDescription),
+}
+
+/// Deserializes the chromaticity either from its typed points, or from the 10 raw bytes of the
+/// EDID for a byte-exact reproduction.
+///
+/// The raw bytes are given either as an array, or as an hexadecimal string such as
+/// `"ee91a3544c99260f5054"`. The typed points are given as `[x, y]` pairs, under the `white`,
+/// `red`, `green` and `blue` keys, or only under the `white` key for a monochrome display.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EdidFilterChromaticity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(
+            match EdidFilterChromaticityDescription::deserialize(deserializer)? {
+                EdidFilterChromaticityDescription::Hex(hex) => {
+                    let hex: String = hex.split_whitespace().collect();
+                    let invalid = || {
+                        serde::de::Error::custom(format!(
+                            "{hex} isn't {EDID_CHROMATICITY_COORDINATES_LEN} hexadecimal bytes"
+                        ))
+                    };
+
+                    if hex.len() != EDID_CHROMATICITY_COORDINATES_LEN * 2 {
+                        return Err(invalid());
+                    }
+
+                    let mut bytes = [0; EDID_CHROMATICITY_COORDINATES_LEN];
+                    for (idx, byte) in bytes.iter_mut().enumerate() {
+                        *byte = hex
+                            .get(idx * 2..idx * 2 + 2)
+                            .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                            .ok_or_else(invalid)?;
+                    }
+
+                    Self::from(bytes)
+                }
+                EdidFilterChromaticityDescription::Bytes(bytes) => Self::from(bytes),
+                EdidFilterChromaticityDescription::Color(points) => Self::Color(points),
+                EdidFilterChromaticityDescription::MonoChrome(mono) => Self::MonoChrome(mono.white),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_filter_chromaticity {
+    use crate::{EdidChromaticityPoint, EdidFilterChromaticity, IntoBytes};
+
+    #[test]
+    fn test_from_bytes() {
+        // Taken from EDID 1.4 Specification, Section 6.1
+        let bytes = [0xee, 0x91, 0xa3, 0x54, 0x4c, 0x99, 0x26, 0x0f, 0x50, 0x54];
+
+        let chroma = EdidFilterChromaticity::from(bytes);
+        assert!(matches!(chroma, EdidFilterChromaticity::Color(_)));
+        assert_eq!(chroma.into_bytes(), bytes);
+
+        let bytes = EdidFilterChromaticity::MonoChrome(
+            EdidChromaticityPoint::try_from((0.3127, 0.3290)).unwrap(),
+        )
+        .into_bytes();
+        let chroma = EdidFilterChromaticity::from(<[u8; 10]>::try_from(bytes.clone()).unwrap());
+        assert!(matches!(chroma, EdidFilterChromaticity::MonoChrome(_)));
+        assert_eq!(chroma.into_bytes(), bytes);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize() {
+        use crate::EdidChromaticityPoints;
+
+        let bytes = [0xee, 0x91, 0xa3, 0x54, 0x4c, 0x99, 0x26, 0x0f, 0x50, 0x54];
+
+        let chroma: EdidFilterChromaticity =
+            serde_json::from_str(r#""ee91a3544c99260f5054""#).unwrap();
+        assert_eq!(chroma.into_bytes(), bytes);
+
+        let chroma: EdidFilterChromaticity =
+            serde_json::from_str(r#""ee 91 a3 54 4c 99 26 0f 50 54""#).unwrap();
+        assert_eq!(chroma.into_bytes(), bytes);
+
+        let chroma: EdidFilterChromaticity =
+            serde_json::from_str("[238, 145, 163, 84, 76, 153, 38, 15, 80, 84]").unwrap();
+        assert_eq!(chroma.into_bytes(), bytes);
+
+        let chroma: EdidFilterChromaticity = serde_json::from_str(
+            r#"{
+                "white": [0.3127, 0.329],
+                "red": [0.64, 0.33],
+                "green": [0.3, 0.6],
+                "blue": [0.15, 0.06]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            chroma.into_bytes(),
+            EdidFilterChromaticity::Color(EdidChromaticityPoints::srgb()).into_bytes()
+        );
+
+        let chroma: EdidFilterChromaticity =
+            serde_json::from_str(r#"{ "white": [0.3127, 0.329] }"#).unwrap();
+        assert!(matches!(chroma, EdidFilterChromaticity::MonoChrome(_)));
+
+        for json in [
+            r#""ee91a3544c99260f50""#,
+            r#""ee91a3544c99260f50zz""#,
+            r#"{ "white": [0.3127, 0.329], "red": [0.64, 0.33] }"#,
+            r#"{ "white": [1.5, 0.329] }"#,
+        ] {
+            assert!(
+                serde_json::from_str::<EdidFilterChromaticity>(json).is_err(),
+                "{json} should be rejected"
+            );
+        }
+    }
+}
+
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum EdidEstablishedTiming {
     ET_1024_768_60hz,
     ET_1024_768_70hz,
     ET_1024_768_75hz,
     ET_1024_768_87hz_Interlaced,
+    /// The Apple Macintosh II 1152x870 mode, which isn't the VESA DMT 1152x864 one. The latter
+    /// is only available as [`EdidR4DescriptorEstablishedTimingsIII::ET_1152_864_75Hz`].
     ET_1152_870_75hz,
     ET_1280_1024_75hz,
     ET_640_480_60hz,
@@ -1584,41 +2599,267 @@ pub enum EdidEstablishedTiming {
     Manufacturer6,
 }
 
-impl IntoBytes for Vec<EdidEstablishedTiming> {
-    fn into_bytes(self) -> Vec<u8> {
-        let mut byte0: u8 = 0;
-        let mut byte1: u8 = 0;
-        let mut byte2: u8 = 0;
-        for et in self {
-            match et {
-                EdidEstablishedTiming::ET_800_600_60hz => byte0 |= 1 << 0,
-                EdidEstablishedTiming::ET_800_600_56hz => byte0 |= 1 << 1,
-                EdidEstablishedTiming::ET_640_480_75hz => byte0 |= 1 << 2,
-                EdidEstablishedTiming::ET_640_480_72hz => byte0 |= 1 << 3,
-                EdidEstablishedTiming::ET_640_480_67hz => byte0 |= 1 << 4,
-                EdidEstablishedTiming::ET_640_480_60hz => byte0 |= 1 << 5,
-                EdidEstablishedTiming::ET_720_400_88hz => byte0 |= 1 << 6,
-                EdidEstablishedTiming::ET_720_400_70hz => byte0 |= 1 << 7,
-                EdidEstablishedTiming::ET_1280_1024_75hz => byte1 |= 1 << 0,
-                EdidEstablishedTiming::ET_1024_768_75hz => byte1 |= 1 << 1,
-                EdidEstablishedTiming::ET_1024_768_70hz => byte1 |= 1 << 2,
-                EdidEstablishedTiming::ET_1024_768_60hz => byte1 |= 1 << 3,
-                EdidEstablishedTiming::ET_1024_768_87hz_Interlaced => byte1 |= 1 << 4,
-                EdidEstablishedTiming::ET_832_624_75hz => byte1 |= 1 << 5,
-                EdidEstablishedTiming::ET_800_600_75hz => byte1 |= 1 << 6,
-                EdidEstablishedTiming::ET_800_600_72hz => byte1 |= 1 << 7,
-                EdidEstablishedTiming::ET_1152_870_75hz => byte2 |= 1 << 7,
-                EdidEstablishedTiming::Manufacturer0 => byte2 |= 1 << 0,
-                EdidEstablishedTiming::Manufacturer1 => byte2 |= 1 << 1,
-                EdidEstablishedTiming::Manufacturer2 => byte2 |= 1 << 2,
-                EdidEstablishedTiming::Manufacturer3 => byte2 |= 1 << 3,
-                EdidEstablishedTiming::Manufacturer4 => byte2 |= 1 << 4,
-                EdidEstablishedTiming::Manufacturer5 => byte2 |= 1 << 5,
-                EdidEstablishedTiming::Manufacturer6 => byte2 |= 1 << 6,
-            };
+impl EdidEstablishedTiming {
+    const fn into_raw(self) -> (usize, u8) {
+        match self {
+            Self::ET_800_600_60hz => (0, 1 << 0),
+            Self::ET_800_600_56hz => (0, 1 << 1),
+            Self::ET_640_480_75hz => (0, 1 << 2),
+            Self::ET_640_480_72hz => (0, 1 << 3),
+            Self::ET_640_480_67hz => (0, 1 << 4),
+            Self::ET_640_480_60hz => (0, 1 << 5),
+            Self::ET_720_400_88hz => (0, 1 << 6),
+            Self::ET_720_400_70hz => (0, 1 << 7),
+            Self::ET_1280_1024_75hz => (1, 1 << 0),
+            Self::ET_1024_768_75hz => (1, 1 << 1),
+            Self::ET_1024_768_70hz => (1, 1 << 2),
+            Self::ET_1024_768_60hz => (1, 1 << 3),
+            Self::ET_1024_768_87hz_Interlaced => (1, 1 << 4),
+            Self::ET_832_624_75hz => (1, 1 << 5),
+            Self::ET_800_600_75hz => (1, 1 << 6),
+            Self::ET_800_600_72hz => (1, 1 << 7),
+            Self::ET_1152_870_75hz => (2, 1 << 7),
+            Self::Manufacturer0 => (2, 1 << 0),
+            Self::Manufacturer1 => (2, 1 << 1),
+            Self::Manufacturer2 => (2, 1 << 2),
+            Self::Manufacturer3 => (2, 1 << 3),
+            Self::Manufacturer4 => (2, 1 << 4),
+            Self::Manufacturer5 => (2, 1 << 5),
+            Self::Manufacturer6 => (2, 1 << 6),
+        }
+    }
+
+    /// Returns the Established Timings I & II bytes for a list of timings, as they will be found
+    /// in the EDID. This can be called in a const context.
+    #[must_use]
+    pub const fn list_into_raw(timings: &[Self]) -> [u8; EDID_ESTABLISHED_TIMINGS_LEN] {
+        let mut bytes = [0; EDID_ESTABLISHED_TIMINGS_LEN];
+
+        let mut idx = 0;
+        while idx < timings.len() {
+            let (byte, mask) = timings[idx].into_raw();
+            bytes[byte] |= mask;
+            idx += 1;
+        }
+
+        bytes
+    }
+
+    /// Maps a list of (horizontal, vertical, refresh rate) modes to their Established Timings,
+    /// dropping any duplicate.
+    ///
+    /// # Errors
+    ///
+    /// If any of the modes can't be represented as an Established Timing. All the offending
+    /// modes are reported.
+    pub fn from_modes(
+        modes: &[(u16, u16, u8)],
+    ) -> Result<Vec<Self>, EdidTypeConversionError<String>> {
+        let mut timings = Vec::with_capacity(modes.len());
+        let mut unmappable = Vec::new();
+
+        for mode in modes {
+            match Self::try_from(*mode) {
+                Ok(et) => {
+                    if !timings.contains(&et) {
+                        timings.push(et);
+                    }
+                }
+                Err(_) => unmappable.push(format!("{}x{}@{}Hz", mode.0, mode.1, mode.2)),
+            }
+        }
+
+        if !unmappable.is_empty() {
+            return Err(EdidTypeConversionError::Value(format!(
+                "Modes can't be represented as Established Timings: {}",
+                unmappable.join(", ")
+            )));
+        }
+
+        Ok(timings)
+    }
+}
+
+impl TryFrom<(u16, u16, u8)> for EdidEstablishedTiming {
+    type Error = EdidTypeConversionError<String>;
+
+    fn try_from(value: (u16, u16, u8)) -> Result<Self, Self::Error> {
+        Ok(match value {
+            (720, 400, 70) => Self::ET_720_400_70hz,
+            (720, 400, 88) => Self::ET_720_400_88hz,
+            (640, 480, 60) => Self::ET_640_480_60hz,
+            (640, 480, 67) => Self::ET_640_480_67hz,
+            (640, 480, 72) => Self::ET_640_480_72hz,
+            (640, 480, 75) => Self::ET_640_480_75hz,
+            (800, 600, 56) => Self::ET_800_600_56hz,
+            (800, 600, 60) => Self::ET_800_600_60hz,
+            (800, 600, 72) => Self::ET_800_600_72hz,
+            (800, 600, 75) => Self::ET_800_600_75hz,
+            (832, 624, 75) => Self::ET_832_624_75hz,
+            (1024, 768, 60) => Self::ET_1024_768_60hz,
+            (1024, 768, 70) => Self::ET_1024_768_70hz,
+            (1024, 768, 75) => Self::ET_1024_768_75hz,
+            (1024, 768, 87) => Self::ET_1024_768_87hz_Interlaced,
+            (1152, 870, 75) => Self::ET_1152_870_75hz,
+            (1280, 1024, 75) => Self::ET_1280_1024_75hz,
+            (1152, 864, 75) => {
+                return Err(EdidTypeConversionError::Value(String::from(
+                    "1152x864@75Hz isn't an Established Timing, only the Apple 1152x870@75Hz mode is. Use the Established Timing III instead.",
+                )))
+            }
+            (h, v, r) => {
+                return Err(EdidTypeConversionError::Value(format!(
+                    "{h}x{v}@{r}Hz isn't an Established Timing."
+                )))
+            }
+        })
+    }
+}
+
+/// Parses an Established Timing from a string such as `800x600@60`.
+///
+/// The refresh rate can be followed by `Hz`, and the interlaced 1024x768 timing can be spelled
+/// `1024x768@87i`. The manufacturer timings are named `manufacturer0` to `manufacturer6`.
+impl FromStr for EdidEstablishedTiming {
+    type Err = EdidTypeConversionError<String>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            || EdidTypeConversionError::Value(format!("{s} isn't a valid Established Timing."));
+
+        if let Some(idx) = s.strip_prefix("manufacturer") {
+            return Ok(match idx {
+                "0" => Self::Manufacturer0,
+                "1" => Self::Manufacturer1,
+                "2" => Self::Manufacturer2,
+                "3" => Self::Manufacturer3,
+                "4" => Self::Manufacturer4,
+                "5" => Self::Manufacturer5,
+                "6" => Self::Manufacturer6,
+                _ => return Err(invalid()),
+            });
+        }
+
+        let (horizontal, rest) = s.split_once('x').ok_or_else(invalid)?;
+        let (vertical, rate) = rest.split_once('@').ok_or_else(invalid)?;
+        let rate = rate.strip_suffix("Hz").unwrap_or(rate);
+        let (rate, interlaced) = rate
+            .strip_suffix('i')
+            .map_or((rate, false), |rate| (rate, true));
+
+        let et = Self::try_from((
+            horizontal.parse().map_err(|_e| invalid())?,
+            vertical.parse().map_err(|_e| invalid())?,
+            rate.parse().map_err(|_e| invalid())?,
+        ))?;
+
+        if interlaced && et != Self::ET_1024_768_87hz_Interlaced {
+            return Err(invalid());
+        }
+
+        Ok(et)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EdidEstablishedTiming {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_established_timings {
+    use crate::{EdidEstablishedTiming, IntoBytes};
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "800x600@60".parse::<EdidEstablishedTiming>().unwrap(),
+            EdidEstablishedTiming::ET_800_600_60hz
+        );
+        assert_eq!(
+            "1152x870@75Hz".parse::<EdidEstablishedTiming>().unwrap(),
+            EdidEstablishedTiming::ET_1152_870_75hz
+        );
+        assert_eq!(
+            "1024x768@87i".parse::<EdidEstablishedTiming>().unwrap(),
+            EdidEstablishedTiming::ET_1024_768_87hz_Interlaced
+        );
+        assert_eq!(
+            "manufacturer3".parse::<EdidEstablishedTiming>().unwrap(),
+            EdidEstablishedTiming::Manufacturer3
+        );
+
+        for invalid in [
+            "",
+            "800x600",
+            "800x600@61",
+            "1024x768@60i",
+            "manufacturer7",
+            "ET_800_600_60hz",
+        ] {
+            assert!(
+                invalid.parse::<EdidEstablishedTiming>().is_err(),
+                "{invalid} should be rejected"
+            );
         }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize() {
+        let timings: Vec<EdidEstablishedTiming> =
+            serde_json::from_str(r#"["640x480@60", "1024x768@87i"]"#).unwrap();
+
+        assert_eq!(
+            timings,
+            vec![
+                EdidEstablishedTiming::ET_640_480_60hz,
+                EdidEstablishedTiming::ET_1024_768_87hz_Interlaced
+            ]
+        );
+        assert!(serde_json::from_str::<EdidEstablishedTiming>(r#""1920x1080@60""#).is_err());
+    }
+
+    #[test]
+    fn test_from_modes() {
+        let timings =
+            EdidEstablishedTiming::from_modes(&[(640, 480, 60), (1024, 768, 87), (640, 480, 60)])
+                .unwrap();
+
+        assert_eq!(
+            timings,
+            vec![
+                EdidEstablishedTiming::ET_640_480_60hz,
+                EdidEstablishedTiming::ET_1024_768_87hz_Interlaced
+            ]
+        );
+        assert_eq!(timings.into_bytes(), &[0x20, 0x10, 0x00]);
+    }
+
+    #[test]
+    fn test_from_modes_unmappable() {
+        let err =
+            EdidEstablishedTiming::from_modes(&[(1920, 1080, 60), (640, 480, 60), (1152, 864, 75)])
+                .unwrap_err();
+
+        let msg = err.to_string();
+        assert!(msg.contains("1920x1080@60Hz"));
+        assert!(msg.contains("1152x864@75Hz"));
+        assert!(!msg.contains("640x480@60Hz"));
+    }
+}
 
-        let bytes = Vec::from(&[byte0, byte1, byte2]);
+impl IntoBytes for Vec<EdidEstablishedTiming> {
+    fn into_bytes(self) -> Vec<u8> {
+        let bytes = Vec::from(&EdidEstablishedTiming::list_into_raw(&self));
         let len = bytes.len();
         assert_eq!(
             len, EDID_ESTABLISHED_TIMINGS_LEN,
@@ -1633,7 +2874,9 @@ impl IntoBytes for Vec<EdidEstablishedTiming> {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16"))]
 pub struct EdidStandardTimingHorizontalSize(u16);
 
 impl TryFrom<u16> for EdidStandardTimingHorizontalSize {
@@ -1644,7 +2887,7 @@ impl TryFrom<u16> for EdidStandardTimingHorizontalSize {
             return Err(EdidTypeConversionError::Range(value, Some(256), Some(2288)));
         }
 
-        if (value % 8) != 0 {
+        if value % 8 != 0 {
             return Err(EdidTypeConversionError::Value(String::from(
                 "Standard Timing Horizontal Size must be a multiple of 8 pixels.",
             )));
@@ -1654,7 +2897,9 @@ impl TryFrom<u16> for EdidStandardTimingHorizontalSize {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8"))]
 pub struct EdidStandardTimingRefreshRate(u8);
 
 impl TryFrom<u8> for EdidStandardTimingRefreshRate {
@@ -1670,15 +2915,19 @@ impl TryFrom<u8> for EdidStandardTimingRefreshRate {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum EdidStandardTimingRatio {
+    /// Encoded as 00, which is 16:10 since EDID 1.3. EDID 1.2 and earlier used it for 1:1.
     Ratio_16_10,
     Ratio_4_3,
     Ratio_5_4,
     Ratio_16_9,
 }
 
-#[derive(Clone, Copy, Debug, TypedBuilder)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 #[builder(field_defaults(setter(into)))]
 pub struct EdidStandardTiming {
     x: EdidStandardTimingHorizontalSize,
@@ -1709,7 +2958,7 @@ impl IntoBytes for Vec<EdidStandardTiming> {
                     bytes.extend_from_slice(&[byte0, byte1]);
                 }
                 None => bytes.extend_from_slice(&[0x01, 0x01]),
-            };
+            }
         }
 
         let len = bytes.len();
@@ -1727,6 +2976,7 @@ impl IntoBytes for Vec<EdidStandardTiming> {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod test_edid_standard_timings {
     use crate::{EdidStandardTiming, EdidStandardTimingRatio, IntoBytes};
 
@@ -1759,74 +3009,142 @@ struct Edid {
     established_timings: Vec<EdidEstablishedTiming>,
     standard_timings: Vec<EdidStandardTiming>,
     descriptors: Vec<EdidDescriptor>,
+    descriptor_padding: EdidDescriptorPadding,
+    raw_descriptors: Option<[u8; EDID_DESCRIPTOR_LEN * EDID_DESCRIPTORS_NUM]>,
     extensions: Vec<EdidExtension>,
 }
 
-impl IntoBytes for Edid {
-    fn into_bytes(self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(0x80);
-
-        bytes.extend_from_slice(&[0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00]);
-
-        bytes.extend_from_slice(&self.manufacturer.into_bytes());
-        bytes.extend_from_slice(&self.product_code.into_bytes());
-
-        if let Some(sn) = self.serial_number {
-            bytes.extend_from_slice(&sn.into_bytes());
-        } else {
-            bytes.extend_from_slice(&[0x00; 4]);
-        }
-
-        bytes.extend_from_slice(&self.date.into_bytes());
-
-        bytes.extend_from_slice(match self.release {
+/// Serializes the EDID, lists the values that couldn't be represented exactly and have been
+/// adjusted in the process, and records the bytes each field has been serialized to.
+pub(crate) fn edid_into_bytes(
+    edid: Edid,
+    warnings: &mut Vec<EdidSerializationWarning>,
+    fields: &mut field_map::FieldMap,
+) -> Vec<u8> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("edid", release = ?edid.release).entered();
+
+    let mut bytes = Vec::with_capacity(0x80);
+
+    fields.push(&mut bytes, "header", &EDID_HEADER);
+    fields.push(&mut bytes, "manufacturer", &edid.manufacturer.into_bytes());
+    fields.push(&mut bytes, "product_code", &edid.product_code.into_bytes());
+
+    let serial_number = edid
+        .serial_number
+        .map_or_else(|| vec![0x00; 4], IntoBytes::into_bytes);
+    fields.push(&mut bytes, "serial_number", &serial_number);
+
+    fields.push(&mut bytes, "date", &edid.date.into_bytes());
+
+    fields.push(
+        &mut bytes,
+        "version",
+        match edid.release {
             EdidRelease::R3 => &[1, 3],
             EdidRelease::R4 => &[1, 4],
-        });
+        },
+    );
 
-        bytes.extend_from_slice(&self.bdpf.into_bytes());
-        bytes.extend_from_slice(&self.chroma_coord.into_bytes());
+    let gamma = match &edid.bdpf {
+        EdidBasicDisplayParametersFeatures::R3(bdpf) => bdpf.display_transfer_characteristic,
+        EdidBasicDisplayParametersFeatures::R4(bdpf) => bdpf.display_transfer_characteristic,
+    };
+    warnings.extend(gamma.rounding().map(EdidSerializationWarning::GammaRounded));
+
+    fields.push(
+        &mut bytes,
+        "display_parameters_features",
+        &edid.bdpf.into_bytes(),
+    );
+    fields.push(
+        &mut bytes,
+        "filter_chromaticity",
+        &edid.chroma_coord.into_bytes(),
+    );
+
+    fields.push(
+        &mut bytes,
+        "established_timings",
+        &edid.established_timings.into_bytes(),
+    );
+    fields.push(
+        &mut bytes,
+        "standard_timings",
+        &edid.standard_timings.into_bytes(),
+    );
+    if let Some(raw) = edid.raw_descriptors {
+        fields.push(&mut bytes, "raw_descriptors", &raw);
+    } else {
+        let mut descriptors_fields = field_map::FieldMap::default();
+        let descriptors_bytes = descriptors::descriptors_into_bytes(
+            edid.descriptors,
+            edid.descriptor_padding,
+            warnings,
+            &mut descriptors_fields,
+        );
 
-        bytes.extend_from_slice(&self.established_timings.into_bytes());
-        bytes.extend_from_slice(&self.standard_timings.into_bytes());
-        bytes.extend_from_slice(&self.descriptors.into_bytes());
+        fields.extend("", bytes.len(), descriptors_fields);
+        bytes.extend_from_slice(&descriptors_bytes);
+    }
 
-        let num_exts = self
-            .extensions
-            .len()
-            .to_u8()
-            .expect("Number of extensions would overflow our type.");
-        bytes.push(num_exts);
+    let num_exts = edid
+        .extensions
+        .len()
+        .to_u8()
+        .expect("Number of extensions would overflow our type.");
+    fields.push(&mut bytes, "extension_count", &[num_exts]);
 
-        let mut sum: u8 = 0;
-        for byte in &bytes {
-            sum = sum.wrapping_add(*byte);
-        }
+    let checksum = edid_block_checksum(&bytes);
+    fields.push(&mut bytes, "checksum", &[checksum]);
 
-        let checksum = 0u8.wrapping_sub(sum);
-        bytes.push(checksum);
+    #[cfg(feature = "tracing")]
+    tracing::debug!(block = 0, bytes = ?bytes, "Base block serialized");
 
-        for ext in self.extensions {
-            bytes.extend_from_slice(&ext.into_bytes());
-        }
+    for (idx, ext) in edid.extensions.into_iter().enumerate() {
+        let mut ext_fields = field_map::FieldMap::default();
+        let ext_bytes = extensions::extension_into_bytes(idx, ext, warnings, &mut ext_fields);
 
-        assert_eq!(
-            bytes.len() % EDID_BASE_LEN,
-            0,
-            "EDID must be {EDID_BASE_LEN} bytes aligned (actual size {})",
-            bytes.len()
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            block = bytes.len() / EDID_BASE_LEN,
+            bytes = ?ext_bytes,
+            "Extension block serialized"
         );
 
-        bytes
+        fields.extend(&format!("extensions[{idx}]."), bytes.len(), ext_fields);
+        bytes.extend_from_slice(&ext_bytes);
+    }
+
+    assert_eq!(
+        bytes.len() % EDID_BASE_LEN,
+        0,
+        "EDID must be {EDID_BASE_LEN} bytes aligned (actual size {})",
+        bytes.len()
+    );
+
+    bytes
+}
+
+impl IntoBytes for Edid {
+    fn into_bytes(self) -> Vec<u8> {
+        edid_into_bytes(self, &mut Vec::new(), &mut field_map::FieldMap::default())
     }
 
     fn size(&self) -> usize {
-        EDID_BASE_LEN
+        EDID_BASE_LEN + self.extensions.iter().map(IntoBytes::size).sum::<usize>()
     }
 }
 
 impl From<EdidRelease3> for Edid {
     fn from(value: EdidRelease3) -> Self {
+        let mut descriptors = value.descriptors;
+        if let (Some(sn), Some(format)) = (value.serial_number, value.serial_number_string) {
+            descriptors.push(EdidDescriptor::R3(EdidR3Descriptor::ProductSerialNumber(
+                sn.to_descriptor_string(format),
+            )));
+        }
+
         Self {
             release: EdidRelease::R3,
             manufacturer: value.manufacturer,
@@ -1837,7 +3155,9 @@ impl From<EdidRelease3> for Edid {
             chroma_coord: value.filter_chromaticity,
             established_timings: value.established_timings,
             standard_timings: value.standard_timings,
-            descriptors: value.descriptors,
+            descriptors,
+            descriptor_padding: value.descriptor_padding,
+            raw_descriptors: value.raw_descriptors,
             extensions: value.extensions,
         }
     }
@@ -1845,6 +3165,13 @@ impl From<EdidRelease3> for Edid {
 
 impl From<EdidRelease4> for Edid {
     fn from(value: EdidRelease4) -> Self {
+        let mut descriptors = value.descriptors;
+        if let (Some(sn), Some(format)) = (value.serial_number, value.serial_number_string) {
+            descriptors.push(EdidDescriptor::R4(EdidR4Descriptor::ProductSerialNumber(
+                sn.to_descriptor_string(format),
+            )));
+        }
+
         Self {
             release: EdidRelease::R4,
             manufacturer: value.manufacturer,
@@ -1855,24 +3182,88 @@ impl From<EdidRelease4> for Edid {
             chroma_coord: value.filter_chromaticity,
             established_timings: value.established_timings,
             standard_timings: value.standard_timings,
-            descriptors: value.descriptors,
+            descriptors,
+            descriptor_padding: value.descriptor_padding,
+            raw_descriptors: value.raw_descriptors,
             extensions: value.extensions,
         }
     }
 }
 
+// The builder setters the descriptors have been set with, as flags. It's part of the public
+// builders state, so it has to be a primitive type.
+const EDID_DESCRIPTOR_SETTERS_LIST: u8 = 1 << 0;
+const EDID_DESCRIPTOR_SETTERS_SLOTS: u8 = 1 << 1;
+const EDID_DESCRIPTOR_SETTERS_MIXED: u8 =
+    EDID_DESCRIPTOR_SETTERS_LIST | EDID_DESCRIPTOR_SETTERS_SLOTS;
+
+// The builders only create the EDIDs that can be serialized.
+impl From<EdidRelease3> for Result<EdidRelease3, EdidValidationError> {
+    fn from(value: EdidRelease3) -> Self {
+        if value.descriptor_setters == EDID_DESCRIPTOR_SETTERS_MIXED {
+            return Err(EdidValidationError::MixedDescriptorSetters);
+        }
+
+        Edid::from(value.clone()).check_layout()?;
+
+        Ok(value)
+    }
+}
+
+impl From<EdidRelease4> for Result<EdidRelease4, EdidValidationError> {
+    fn from(value: EdidRelease4) -> Self {
+        if value.descriptor_setters == EDID_DESCRIPTOR_SETTERS_MIXED {
+            return Err(EdidValidationError::MixedDescriptorSetters);
+        }
+
+        Edid::from(value.clone()).check_layout()?;
+
+        Ok(value)
+    }
+}
+
+/// An EDID 1.3.
+///
+/// Only EDID 1.3 descriptors can be added, so mixing descriptors from different releases is a
+/// compile error:
+///
+/// ```compile_fail
+/// use redid::{EdidR4Descriptor, EdidRelease3};
+///
+/// let _builder = EdidRelease3::builder().add_descriptor(EdidR4Descriptor::Dummy);
+/// ```
 #[derive(Clone, Debug, TypedBuilder)]
+#[builder(build_method(
+    into = Result<EdidRelease3, EdidValidationError>,
+    doc = "Builds the EDID.\n\n# Errors\n\nIf it can't be serialized, for example because more than four descriptors were added, or if the descriptors were set with both the descriptor slots and the other descriptors setters."
+))]
 #[builder(mutators(
     #[allow(unreachable_pub)]
     pub fn descriptors(&mut self, d: Vec<EdidR3Descriptor>) {
+        self.descriptor_setters |= EDID_DESCRIPTOR_SETTERS_LIST;
         self.descriptors = d.into_iter().map(EdidDescriptor::R3).collect();
     }
 
     #[allow(unreachable_pub)]
     pub fn add_descriptor(&mut self, d: EdidR3Descriptor) {
+        self.descriptor_setters |= EDID_DESCRIPTOR_SETTERS_LIST;
         self.descriptors.push(EdidDescriptor::R3(d));
     }
 
+    #[allow(unreachable_pub)]
+    pub fn add_raw_descriptor(&mut self, d: [u8; EDID_DESCRIPTOR_LEN]) {
+        self.descriptor_setters |= EDID_DESCRIPTOR_SETTERS_LIST;
+        self.descriptors.push(EdidDescriptor::Raw(d));
+    }
+
+    /// Sets the descriptors by position. It can't be combined with the other descriptors
+    /// setters.
+    #[allow(unreachable_pub)]
+    pub fn descriptor_slots(&mut self, slots: EdidR3DescriptorSlots) {
+        self.descriptor_setters |= EDID_DESCRIPTOR_SETTERS_SLOTS;
+        self.descriptors = slots.into_descriptors();
+    }
+
     #[allow(unreachable_pub)]
     pub fn established_timings(&mut self, et: Vec<EdidEstablishedTiming>) {
         self.established_timings = et;
@@ -1883,6 +3274,19 @@ impl From<EdidRelease4> for Edid {
         self.established_timings.push(et);
     }
 
+    /// Adds the 640x480, 60Hz, Established Timing, that every CTA-861 (and thus HDMI) sink must
+    /// support, unless it's already listed. `DisplayPort` only displays can omit it.
+    #[allow(unreachable_pub)]
+    pub fn with_vga_fallback(&mut self) {
+        if !self
+            .established_timings
+            .contains(&EdidEstablishedTiming::ET_640_480_60hz)
+        {
+            self.established_timings
+                .push(EdidEstablishedTiming::ET_640_480_60hz);
+        }
+    }
+
     #[allow(unreachable_pub)]
     pub fn standard_timings(&mut self, st: Vec<EdidStandardTiming>) {
         self.standard_timings = st;
@@ -1912,11 +3316,16 @@ pub struct EdidRelease3 {
     #[builder(default)]
     serial_number: Option<EdidSerialNumber>,
 
+    /// Appends a Product Serial Number descriptor derived from the serial number, formatted as
+    /// requested, so that both representations can't diverge.
+    #[builder(default, setter(strip_option))]
+    serial_number_string: Option<EdidSerialNumberFormat>,
+
     date: EdidManufactureDate,
     display_parameters_features: EdidR3BasicDisplayParametersFeatures,
     filter_chromaticity: EdidFilterChromaticity,
 
-    #[builder(via_mutators, default = vec![EdidEstablishedTiming::ET_640_480_60hz])]
+    #[builder(via_mutators)]
     established_timings: Vec<EdidEstablishedTiming>,
 
     #[builder(via_mutators)]
@@ -1928,51 +3337,344 @@ pub struct EdidRelease3 {
     #[builder(via_mutators)]
     descriptors: Vec<EdidDescriptor>,
 
+    #[builder(via_mutators)]
+    descriptor_setters: u8,
+
+    #[builder(default)]
+    descriptor_padding: EdidDescriptorPadding,
+
+    /// Bytes to use verbatim for the whole descriptors area, instead of the descriptors and
+    /// their padding. This is meant to regenerate captured EDIDs byte for byte, even when their
+    /// descriptors don't follow the specification.
+    #[builder(default, setter(strip_option))]
+    raw_descriptors: Option<[u8; EDID_DESCRIPTOR_LEN * EDID_DESCRIPTORS_NUM]>,
+
     #[builder(via_mutators)]
     extensions: Vec<EdidExtension>,
 }
 
-impl IntoBytes for EdidRelease3 {
-    fn into_bytes(self) -> Vec<u8> {
-        let bytes = Edid::from(self).into_bytes();
+fn rotate_descriptors(
+    descriptors: Vec<EdidDescriptor>,
+    swap_preferred_timing: bool,
+) -> Result<Vec<EdidDescriptor>, EdidTypeConversionError<u32>> {
+    descriptors
+        .into_iter()
+        .enumerate()
+        .map(|(idx, desc)| {
+            // The preferred timing is always the first descriptor.
+            let swap = swap_preferred_timing && idx == 0;
+
+            let rotated = |dtd: EdidDescriptorDetailedTiming| {
+                dtd.rotated(swap).map_err(|e| {
+                    e.in_field("detailed_timing")
+                        .at_index(idx)
+                        .in_field("descriptors")
+                })
+            };
 
-        let len = bytes.len();
-        assert_eq!(
-            len % EDID_BASE_LEN,
-            0,
-            "EDID must be {EDID_BASE_LEN} bytes aligned (actual size {len})"
-        );
+            Ok(match desc {
+                EdidDescriptor::R3(EdidR3Descriptor::DetailedTiming(dtd)) => {
+                    EdidDescriptor::R3(EdidR3Descriptor::DetailedTiming(rotated(dtd)?))
+                }
+                EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(dtd)) => {
+                    EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(rotated(dtd)?))
+                }
+                EdidDescriptor::R3(_) | EdidDescriptor::R4(_) | EdidDescriptor::Raw(_) => desc,
+            })
+        })
+        .collect()
+}
 
-        bytes
+// The descriptors are replaced, which would be ignored if a raw descriptors area is used.
+fn check_no_raw_descriptors<D: fmt::Display>(
+    raw_descriptors: Option<&[u8; EDID_DESCRIPTOR_LEN * EDID_DESCRIPTORS_NUM]>,
+) -> Result<(), EdidTypeConversionError<D>> {
+    if raw_descriptors.is_some() {
+        return Err(EdidTypeConversionError::Invalid(
+            EdidValidationError::IgnoredDescriptors,
+        ));
     }
 
-    fn size(&self) -> usize {
-        EDID_BASE_LEN
+    Ok(())
+}
+
+fn check_single_mode_extensions(
+    extensions: &[EdidExtension],
+) -> Result<(), EdidTypeConversionError<u16>> {
+    if extensions.is_empty() {
+        return Ok(());
     }
+
+    Err(
+        EdidTypeConversionError::Value(String::from("Extensions could advertise other timings"))
+            .in_field("extensions"),
+    )
 }
 
-#[derive(Clone, Debug, TypedBuilder)]
-#[builder(mutators(
-    #[allow(unreachable_pub)]
-    pub fn descriptors(&mut self, d: Vec<EdidR4Descriptor>) {
-        self.descriptors = d.into_iter().map(EdidDescriptor::R4).collect();
+impl EdidRelease3 {
+    /// Returns the smallest EDID of the same display advertising only the given timing.
+    ///
+    /// The Established and Standard Timings are removed, and the descriptors are replaced by the
+    /// timing, matching Display Range Limits without a secondary GTF curve, and the Product Name
+    /// descriptor, if any. The default GTF support is cleared, so the Display Range Limits only
+    /// bound the timing, like the EDID 1.4 Range Limits Only descriptor.
+    ///
+    /// # Errors
+    ///
+    /// If the EDID has extensions, since they could advertise other timings, if it uses a raw
+    /// descriptors area, or if the timing rates can't be expressed in a Display Range Limits
+    /// descriptor. The extensions can be dropped first with [`EdidRelease3::strip_to`].
+    pub fn single_mode(
+        mut self,
+        timing: EdidDescriptorDetailedTiming,
+    ) -> Result<Self, EdidTypeConversionError<u16>> {
+        check_no_raw_descriptors(self.raw_descriptors.as_ref())?;
+        check_single_mode_extensions(&self.extensions)?;
+
+        let limits = EdidR3DisplayRangeLimits::from_detailed_timing(&timing)
+            .map_err(|e| e.in_field("range_limits"))?;
+        let name = self
+            .descriptors
+            .into_iter()
+            .find(|desc| matches!(desc, EdidDescriptor::R3(EdidR3Descriptor::ProductName(_))));
+
+        self.established_timings = Vec::new();
+        self.standard_timings = Vec::new();
+        self.descriptors = [
+            EdidDescriptor::R3(EdidR3Descriptor::DetailedTiming(timing)),
+            EdidDescriptor::R3(EdidR3Descriptor::DisplayRangeLimits(limits)),
+        ]
+        .into_iter()
+        .chain(name)
+        .collect();
+
+        self.display_parameters_features
+            .feature_support
+            .default_gtf_supported = false;
+
+        Ok(self)
     }
 
-    #[allow(unreachable_pub)]
-    pub fn add_descriptor(&mut self, d: EdidR4Descriptor) {
-        self.descriptors.push(EdidDescriptor::R4(d));
+    /// Returns the EDID of the same display, rotated by 90 degrees.
+    ///
+    /// The screen size and the image size of every Detailed Timing are swapped. If
+    /// `swap_preferred_timing` is set, the preferred timing resolution is swapped as well. See
+    /// [`EdidDescriptorDetailedTiming::rotated`].
+    ///
+    /// # Errors
+    ///
+    /// If the preferred timing can't be rotated.
+    pub fn rotated(
+        mut self,
+        swap_preferred_timing: bool,
+    ) -> Result<Self, EdidTypeConversionError<u32>> {
+        self.display_parameters_features.size = match self.display_parameters_features.size {
+            EdidR3ImageSize::Size(size) => EdidR3ImageSize::Size(size.rotated()),
+            EdidR3ImageSize::Undefined => EdidR3ImageSize::Undefined,
+        };
+        self.descriptors = rotate_descriptors(self.descriptors, swap_preferred_timing)?;
+
+        Ok(self)
     }
 
-    #[allow(unreachable_pub)]
-    pub fn established_timings(&mut self, et: Vec<EdidEstablishedTiming>) {
-        self.established_timings = et;
+    /// Returns the source physical address of the display, as reported by the first HDMI
+    /// Vendor-Specific Data Block, if any.
+    #[must_use]
+    pub fn source_physical_address(&self) -> Option<CecAddress> {
+        extensions::physical_addresses(&self.extensions)
+            .next()
+            .map(|(_, address)| address)
     }
 
-    #[allow(unreachable_pub)]
-    pub fn add_established_timing(&mut self, et: EdidEstablishedTiming) {
+    /// Sets the source physical address of every HDMI Vendor-Specific Data Block, in every
+    /// extension, so that they can't diverge.
+    #[must_use]
+    pub fn with_source_physical_address(mut self, address: CecAddress) -> Self {
+        extensions::set_physical_address(&mut self.extensions, address);
+        self
+    }
+
+    /// Strips the EDID down to fit in the given budget, dropping the lowest priority extensions
+    /// and data blocks first. See [`EdidSizeBudget`] for the order they are dropped in.
+    #[must_use]
+    pub fn strip_to(mut self, budget: EdidSizeBudget) -> Self {
+        extensions::strip_extensions(&mut self.extensions, budget);
+        self
+    }
+
+    /// Lists all the timings advertised by the EDID, from the Established, Standard and Detailed
+    /// Timings, from the CTA-861 extensions Short Video Descriptors, and from the `DisplayID`
+    /// extensions Type VII Timings.
+    ///
+    /// The Manufacturer's Timings and the VICs without a known Video Format are skipped.
+    pub fn all_timings(&self) -> impl Iterator<Item = EdidTiming> {
+        timings::edid_timings(&Edid::from(self.clone())).into_iter()
+    }
+
+    /// Serializes the EDID, and lists the values that couldn't be represented exactly and have
+    /// been adjusted in the process.
+    #[must_use]
+    pub fn into_bytes_with_warnings(self) -> (Vec<u8>, Vec<EdidSerializationWarning>) {
+        let mut warnings = Vec::new();
+        let bytes = edid_into_bytes(
+            Edid::from(self),
+            &mut warnings,
+            &mut field_map::FieldMap::default(),
+        );
+
+        (bytes, warnings)
+    }
+
+    /// Serializes the EDID, and lists the byte range each field has been serialized to.
+    #[must_use]
+    pub fn into_bytes_with_field_map(self) -> (Vec<u8>, Vec<(EdidFieldPath, Range<usize>)>) {
+        let mut fields = field_map::FieldMap::default();
+        let bytes = edid_into_bytes(Edid::from(self), &mut Vec::new(), &mut fields);
+
+        (bytes, fields.into_fields())
+    }
+
+    /// Lists the inconsistencies that don't make the EDID invalid, but are likely to be
+    /// misinterpreted.
+    #[must_use]
+    pub fn warnings(&self) -> Vec<EdidValidationWarning> {
+        Edid::from(self.clone()).warnings()
+    }
+
+    /// Checks the EDID for inconsistencies that the builder can't prevent.
+    ///
+    /// # Errors
+    ///
+    /// If the EDID isn't valid.
+    pub fn validate(&self) -> Result<(), EdidValidationError> {
+        Edid::from(self.clone()).validate()
+    }
+
+    /// Checks the EDID like [`Self::validate`] does, and also that it hasn't been manufactured
+    /// after the given year.
+    ///
+    /// # Errors
+    ///
+    /// If the EDID isn't valid.
+    pub fn validate_at(&self, year: u16) -> Result<(), EdidValidationError> {
+        Edid::from(self.clone()).validate_at(year)
+    }
+
+    /// Describes the EDID in a layout close to the edid-decode one. See [`EdidDump`].
+    #[must_use]
+    pub fn dump(&self) -> EdidDump {
+        EdidDump::from(Edid::from(self.clone()))
+    }
+
+    /// Gathers the metadata to store next to the generated EDID. See [`EdidSidecar`].
+    #[must_use]
+    pub fn sidecar(&self) -> EdidSidecar {
+        sidecar::edid_sidecar(&Edid::from(self.clone()))
+    }
+
+    /// Describes the blocks the EDID will be serialized to, without serializing it. See
+    /// [`EdidLayout`].
+    #[must_use]
+    pub fn layout(&self) -> EdidLayout {
+        layout::edid_layout(&Edid::from(self.clone()))
+    }
+}
+
+impl IntoBytes for EdidRelease3 {
+    fn into_bytes(self) -> Vec<u8> {
+        let bytes = Edid::from(self).into_bytes();
+
+        let len = bytes.len();
+        assert_eq!(
+            len % EDID_BASE_LEN,
+            0,
+            "EDID must be {EDID_BASE_LEN} bytes aligned (actual size {len})"
+        );
+
+        bytes
+    }
+
+    fn size(&self) -> usize {
+        EDID_BASE_LEN + self.extensions.iter().map(IntoBytes::size).sum::<usize>()
+    }
+}
+
+/// An EDID 1.4.
+///
+/// Only EDID 1.4 descriptors can be added, so mixing descriptors from different releases is a
+/// compile error:
+///
+/// ```compile_fail
+/// use redid::{EdidR3Descriptor, EdidRelease4};
+///
+/// let _builder = EdidRelease4::builder().add_descriptor(EdidR3Descriptor::Dummy);
+/// ```
+///
+/// Requiring a Display Range Limits descriptor for continuous frequency displays is out of the
+/// builder scope: it depends on the feature support, which is built separately. It's checked at
+/// runtime instead, by [`EdidRelease4::validate`] reporting
+/// [`EdidValidationError::MissingDisplayRangeLimits`].
+///
+/// With the `serde` feature, a whole EDID 1.4 can be deserialized from a description using the
+/// builder field names. The fields the builder defaults are optional. Like for the builder, the
+/// deserialized EDID should be checked with [`EdidRelease4::validate`] before being serialized.
+#[derive(Clone, Debug, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[builder(build_method(
+    into = Result<EdidRelease4, EdidValidationError>,
+    doc = "Builds the EDID.\n\n# Errors\n\nIf it can't be serialized, for example because more than four descriptors were added, or if the descriptors were set with both the descriptor slots and the other descriptors setters."
+))]
+#[builder(mutators(
+    #[allow(unreachable_pub)]
+    pub fn descriptors(&mut self, d: Vec<EdidR4Descriptor>) {
+        self.descriptor_setters |= EDID_DESCRIPTOR_SETTERS_LIST;
+        self.descriptors = d.into_iter().map(EdidDescriptor::R4).collect();
+    }
+
+    #[allow(unreachable_pub)]
+    pub fn add_descriptor(&mut self, d: EdidR4Descriptor) {
+        self.descriptor_setters |= EDID_DESCRIPTOR_SETTERS_LIST;
+        self.descriptors.push(EdidDescriptor::R4(d));
+    }
+
+    #[allow(unreachable_pub)]
+    pub fn add_raw_descriptor(&mut self, d: [u8; EDID_DESCRIPTOR_LEN]) {
+        self.descriptor_setters |= EDID_DESCRIPTOR_SETTERS_LIST;
+        self.descriptors.push(EdidDescriptor::Raw(d));
+    }
+
+    /// Sets the descriptors by position. It can't be combined with the other descriptors
+    /// setters.
+    #[allow(unreachable_pub)]
+    pub fn descriptor_slots(&mut self, slots: EdidR4DescriptorSlots) {
+        self.descriptor_setters |= EDID_DESCRIPTOR_SETTERS_SLOTS;
+        self.descriptors = slots.into_descriptors();
+    }
+
+    #[allow(unreachable_pub)]
+    pub fn established_timings(&mut self, et: Vec<EdidEstablishedTiming>) {
+        self.established_timings = et;
+    }
+
+    #[allow(unreachable_pub)]
+    pub fn add_established_timing(&mut self, et: EdidEstablishedTiming) {
         self.established_timings.push(et);
     }
 
+    /// Adds the 640x480, 60Hz, Established Timing, that every CTA-861 (and thus HDMI) sink must
+    /// support, unless it's already listed. `DisplayPort` only displays can omit it.
+    #[allow(unreachable_pub)]
+    pub fn with_vga_fallback(&mut self) {
+        if !self
+            .established_timings
+            .contains(&EdidEstablishedTiming::ET_640_480_60hz)
+        {
+            self.established_timings
+                .push(EdidEstablishedTiming::ET_640_480_60hz);
+        }
+    }
+
     #[allow(unreachable_pub)]
     pub fn standard_timings(&mut self, st: Vec<EdidStandardTiming>) {
         self.standard_timings = st;
@@ -2000,27 +3702,676 @@ pub struct EdidRelease4 {
     product_code: EdidProductCode,
 
     #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
     serial_number: Option<EdidSerialNumber>,
 
+    /// Appends a Product Serial Number descriptor derived from the serial number, formatted as
+    /// requested, so that both representations can't diverge.
+    #[builder(default, setter(strip_option))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    serial_number_string: Option<EdidSerialNumberFormat>,
+
     date: EdidR4Date,
     display_parameters_features: EdidR4BasicDisplayParametersFeatures,
     filter_chromaticity: EdidFilterChromaticity,
 
-    #[builder(via_mutators, default = vec![EdidEstablishedTiming::ET_640_480_60hz])]
+    #[builder(via_mutators)]
+    #[cfg_attr(feature = "serde", serde(default))]
     established_timings: Vec<EdidEstablishedTiming>,
 
     #[builder(via_mutators)]
+    #[cfg_attr(feature = "serde", serde(default))]
     standard_timings: Vec<EdidStandardTiming>,
 
     // FIXME: The Preferred Timing Descriptors is required in the first position
-    // FIXME: If continuous frequency, a display range limits descriptor is required
     #[builder(via_mutators)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "deserialize_r4_descriptors")
+    )]
     descriptors: Vec<EdidDescriptor>,
 
     #[builder(via_mutators)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    descriptor_setters: u8,
+
+    #[builder(default)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    descriptor_padding: EdidDescriptorPadding,
+
+    /// Bytes to use verbatim for the whole descriptors area, instead of the descriptors and
+    /// their padding. This is meant to regenerate captured EDIDs byte for byte, even when their
+    /// descriptors don't follow the specification.
+    #[builder(default, setter(strip_option))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "deserialize_raw_descriptors")
+    )]
+    raw_descriptors: Option<[u8; EDID_DESCRIPTOR_LEN * EDID_DESCRIPTORS_NUM]>,
+
+    #[builder(via_mutators)]
+    #[cfg_attr(feature = "serde", serde(default))]
     extensions: Vec<EdidExtension>,
 }
 
+#[cfg(feature = "serde")]
+fn deserialize_r4_descriptors<'de, D>(deserializer: D) -> Result<Vec<EdidDescriptor>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let descriptors: Vec<EdidR4Descriptor> = serde::Deserialize::deserialize(deserializer)?;
+
+    Ok(descriptors.into_iter().map(EdidDescriptor::R4).collect())
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_raw_descriptors<'de, D>(
+    deserializer: D,
+) -> Result<Option<[u8; EDID_DESCRIPTOR_LEN * EDID_DESCRIPTORS_NUM]>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let bytes: Option<Vec<u8>> = serde::Deserialize::deserialize(deserializer)?;
+
+    bytes
+        .map(|bytes| {
+            let len = bytes.len();
+
+            bytes.try_into().map_err(|_bytes| {
+                serde::de::Error::invalid_length(len, &"the 72 bytes of the descriptors area")
+            })
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+#[allow(clippy::unwrap_used)]
+mod test_edid_release4_serde {
+    use crate::{
+        test_utils::edid_r4_builder, EdidDescriptorDetailedTiming, EdidDescriptorString,
+        EdidEstablishedTiming, EdidExtension, EdidExtensionCTA861,
+        EdidExtensionCTA861HdmiDataBlock, EdidExtensionCTA861NativeFormats,
+        EdidExtensionCTA861Revision3, EdidExtensionCTA861Revision3DataBlock,
+        EdidExtensionCTA861VideoDataBlock, EdidR4Descriptor, EdidRelease4, IntoBytes,
+    };
+
+    const JSON: &str = r#"{
+        "manufacturer": "ABC",
+        "product_code": 61958,
+        "date": { "Manufacture": [1, 2024] },
+        "display_parameters_features": {
+            "video_input": { "Digital": { "color_depth": "Depth8Bpc", "interface": "HDMIa" } },
+            "size": "Undefined",
+            "display_transfer_characteristic": 2.2,
+            "feature_support": { "color": { "Digital": "RGB444" } }
+        },
+        "filter_chromaticity": {
+            "white": [0.3127, 0.329],
+            "red": [0.64, 0.33],
+            "green": [0.3, 0.6],
+            "blue": [0.15, 0.06]
+        },
+        "established_timings": ["640x480@60"],
+        "descriptors": [
+            {
+                "DetailedTiming": {
+                    "pixel_clock": 148500,
+                    "horizontal_addressable": 1920,
+                    "horizontal_blanking": 280,
+                    "vertical_addressable": 1080,
+                    "vertical_blanking": 45,
+                    "horizontal_front_porch": 88,
+                    "horizontal_sync_pulse": 44,
+                    "vertical_front_porch": 4,
+                    "vertical_sync_pulse": 5,
+                    "horizontal_size": 0,
+                    "vertical_size": 0,
+                    "horizontal_border": 0,
+                    "vertical_border": 0
+                }
+            },
+            { "ProductName": "redid" }
+        ],
+        "extensions": [
+            {
+                "CTA861": {
+                    "Revision3": {
+                        "native_formats": 1,
+                        "data_blocks": [
+                            { "Video": { "desc": [{ "Low": [true, 16] }] } },
+                            { "HDMI": { "source_physical_address": [1, 0, 0, 0] } }
+                        ]
+                    }
+                }
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_deserialize() {
+        let edid: EdidRelease4 = serde_json::from_str(JSON).unwrap();
+        edid.validate().unwrap();
+
+        let timing = EdidDescriptorDetailedTiming::builder()
+            .pixel_clock(148_500.try_into().unwrap())
+            .horizontal_addressable(1920.try_into().unwrap())
+            .horizontal_blanking(280.try_into().unwrap())
+            .vertical_addressable(1080.try_into().unwrap())
+            .vertical_blanking(45.try_into().unwrap())
+            .horizontal_front_porch(88.try_into().unwrap())
+            .horizontal_sync_pulse(44.try_into().unwrap())
+            .vertical_front_porch(4.try_into().unwrap())
+            .vertical_sync_pulse(5.try_into().unwrap())
+            .horizontal_size(0.try_into().unwrap())
+            .vertical_size(0.try_into().unwrap())
+            .horizontal_border(0.try_into().unwrap())
+            .vertical_border(0.try_into().unwrap())
+            .build();
+
+        let expected = edid_r4_builder!()
+            .established_timings(vec![EdidEstablishedTiming::ET_640_480_60hz])
+            .add_descriptor(EdidR4Descriptor::DetailedTiming(timing))
+            .add_descriptor(EdidR4Descriptor::ProductName(
+                EdidDescriptorString::try_from("redid").unwrap(),
+            ))
+            .add_extension(EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+                EdidExtensionCTA861Revision3::builder()
+                    .native_formats(EdidExtensionCTA861NativeFormats::try_from(1).unwrap())
+                    .add_data_block(EdidExtensionCTA861Revision3DataBlock::Video(
+                        EdidExtensionCTA861VideoDataBlock::builder()
+                            .add_native_short_video_descriptor(16)
+                            .build()
+                            .unwrap(),
+                    ))
+                    .add_data_block(EdidExtensionCTA861Revision3DataBlock::HDMI(
+                        EdidExtensionCTA861HdmiDataBlock::builder()
+                            .source_physical_address([1, 0, 0, 0].try_into().unwrap())
+                            .build(),
+                    ))
+                    .build(),
+            )))
+            .build()
+            .unwrap();
+
+        assert_eq!(edid.into_bytes(), expected.into_bytes());
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        for (from, to) in [
+            (r#""manufacturer": "ABC""#, r#""manufacturer": "abc""#),
+            (
+                r#""pixel_clock": 148500"#,
+                r#""pixel_clock": 148500, "unknown": 0"#,
+            ),
+            (
+                r#""vertical_sync_pulse": 5"#,
+                r#""vertical_sync_pulse": 64"#,
+            ),
+            (
+                r#""display_transfer_characteristic": 2.2"#,
+                r#""display_transfer_characteristic": 4.0"#,
+            ),
+            (r#""Revision3": {"#, r#""Revision4": {"#),
+        ] {
+            let json = JSON.replacen(from, to, 1);
+            assert!(
+                serde_json::from_str::<EdidRelease4>(&json).is_err(),
+                "{to} should be rejected"
+            );
+        }
+
+        let json = JSON.replacen(
+            r#""extensions": ["#,
+            r#""raw_descriptors": [0, 0], "extensions": ["#,
+            1,
+        );
+        assert!(
+            serde_json::from_str::<EdidRelease4>(&json).is_err(),
+            "Truncated raw descriptors should be rejected"
+        );
+    }
+}
+
+impl EdidRelease4 {
+    /// Returns the smallest EDID of the same display advertising only the given timing.
+    ///
+    /// The Established and Standard Timings are removed, and the descriptors are replaced by the
+    /// timing, marked as native, matching Range Limits Only Display Range Limits and the Product
+    /// Name descriptor, if any. See [`EdidRelease3::single_mode`] for EDID 1.3.
+    ///
+    /// # Errors
+    ///
+    /// If the EDID has extensions, since they could advertise other timings, if it uses a raw
+    /// descriptors area, or if the timing rates can't be expressed in a Display Range Limits
+    /// descriptor. The extensions can be dropped first with [`EdidRelease4::strip_to`].
+    pub fn single_mode(
+        mut self,
+        timing: EdidDescriptorDetailedTiming,
+    ) -> Result<Self, EdidTypeConversionError<u16>> {
+        check_no_raw_descriptors(self.raw_descriptors.as_ref())?;
+        check_single_mode_extensions(&self.extensions)?;
+
+        let limits = EdidR4DisplayRangeLimits::from_detailed_timing(&timing)
+            .map_err(|e| e.in_field("range_limits"))?;
+        let name = self
+            .descriptors
+            .into_iter()
+            .find(|desc| matches!(desc, EdidDescriptor::R4(EdidR4Descriptor::ProductName(_))));
+
+        self.established_timings = Vec::new();
+        self.standard_timings = Vec::new();
+        self.descriptors = [
+            EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(timing)),
+            EdidDescriptor::R4(EdidR4Descriptor::DisplayRangeLimits(limits)),
+        ]
+        .into_iter()
+        .chain(name)
+        .collect();
+
+        let features = &mut self.display_parameters_features.feature_support;
+        features.preferred_timing_mode_is_native = true;
+        features.continuous_frequency = false;
+
+        Ok(self)
+    }
+
+    /// Returns the EDID of the same display using a CVT Reduced Blanking v2 preferred timing, as
+    /// panels typically do.
+    ///
+    /// The preferred timing is computed with [`EdidDescriptorDetailedTiming::cvt_rb2`], using
+    /// the screen size as image size if it's known, and replaces the first Detailed Timing, if
+    /// any. The Display Range Limits are replaced by ones covering the preferred timing and
+    /// advertising CVT Reduced Blanking support, and the display is marked as continuous
+    /// frequency with a native preferred timing. The other descriptors are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// If the timing can't be expressed in a Detailed Timing or in Display Range Limits, or if
+    /// the EDID uses a raw descriptors area.
+    pub fn with_cvt_rb2_preferred_timing(
+        self,
+        hactive: u16,
+        vactive: u16,
+        refresh_rate: u16,
+    ) -> Result<Self, EdidTypeConversionError<u32>> {
+        let timing = self.cvt_rb2_timing(hactive, vactive, refresh_rate, (1, 1))?;
+
+        self.with_preferred_timing(timing)
+    }
+
+    /// Returns the per-tile EDIDs of a tiled display, for the given full resolution split in
+    /// the given number of horizontal and vertical tiles. The EDIDs are listed row by row,
+    /// starting from the top left tile.
+    ///
+    /// Each EDID is derived from this one like with
+    /// [`EdidRelease4::with_cvt_rb2_preferred_timing`], using the tile resolution and a tile
+    /// image size computed from the screen size, and gets an additional
+    /// [`EdidExtensionDisplayID`] with the [`EdidExtensionDisplayIDTiledDisplayTopology`] of its
+    /// tile. All the tiles share the same topology identifier, made of the vendor OUI, the
+    /// product code and the serial number, so sources can group them. The tiles are in a single
+    /// enclosure and their images are displayed at their tile location.
+    ///
+    /// # Errors
+    ///
+    /// If the resolution isn't a multiple of the number of tiles, if the tile timing can't be
+    /// expressed in a Detailed Timing or in Display Range Limits, if the tile counts or
+    /// resolution can't be expressed in a Tiled Display Topology, or if the EDID uses a raw
+    /// descriptors area.
+    pub fn tiled(
+        &self,
+        hactive: u16,
+        vactive: u16,
+        refresh_rate: u16,
+        horizontal_tiles: u8,
+        vertical_tiles: u8,
+        vendor: EdidExtensionCTA861VendorOui,
+    ) -> Result<Vec<Self>, EdidTypeConversionError<u32>> {
+        let htiles = EdidExtensionDisplayIDTileCount::try_from(horizontal_tiles)
+            .map_err(|e| e.map_value(&u32::from).in_field("horizontal_tiles"))?;
+        let vtiles = EdidExtensionDisplayIDTileCount::try_from(vertical_tiles)
+            .map_err(|e| e.map_value(&u32::from).in_field("vertical_tiles"))?;
+
+        if hactive % u16::from(horizontal_tiles) != 0 || vactive % u16::from(vertical_tiles) != 0 {
+            return Err(EdidTypeConversionError::Value(format!(
+                "{hactive}x{vactive} can't be split in {horizontal_tiles}x{vertical_tiles} tiles"
+            )));
+        }
+
+        let tile_hactive = hactive / u16::from(horizontal_tiles);
+        let tile_vactive = vactive / u16::from(vertical_tiles);
+        let timing = self.cvt_rb2_timing(
+            tile_hactive,
+            tile_vactive,
+            refresh_rate,
+            (horizontal_tiles, vertical_tiles),
+        )?;
+
+        let mut edids =
+            Vec::with_capacity(usize::from(horizontal_tiles) * usize::from(vertical_tiles));
+        for vertical in 0..vertical_tiles {
+            for horizontal in 0..horizontal_tiles {
+                let topology = EdidExtensionDisplayIDTiledDisplayTopology::builder()
+                    .single_enclosure(true)
+                    .single_tile_behavior(EdidExtensionDisplayIDTileSingleBehavior::TileLocation)
+                    .multiple_tiles_behavior(
+                        EdidExtensionDisplayIDTileMultipleBehavior::TileLocation,
+                    )
+                    .horizontal_tiles(htiles)
+                    .vertical_tiles(vtiles)
+                    .horizontal_location(
+                        EdidExtensionDisplayIDTileLocation::try_from(horizontal)
+                            .map_err(|e| e.map_value(&u32::from))?,
+                    )
+                    .vertical_location(
+                        EdidExtensionDisplayIDTileLocation::try_from(vertical)
+                            .map_err(|e| e.map_value(&u32::from))?,
+                    )
+                    .horizontal_size(
+                        EdidExtensionDisplayID16BitsTiming::try_from(tile_hactive)
+                            .map_err(|e| e.map_value(&u32::from))?,
+                    )
+                    .vertical_size(
+                        EdidExtensionDisplayID16BitsTiming::try_from(tile_vactive)
+                            .map_err(|e| e.map_value(&u32::from))?,
+                    )
+                    .vendor(vendor)
+                    .product_code(self.product_code)
+                    .serial_number(self.serial_number.unwrap_or(EdidSerialNumber(0)))
+                    .build();
+
+                let mut edid = self.clone().with_preferred_timing(timing)?;
+                edid.extensions.push(EdidExtension::DisplayID(
+                    EdidExtensionDisplayID::builder()
+                        .add_data_block(EdidExtensionDisplayIDDataBlock::TiledDisplayTopology(
+                            topology,
+                        ))
+                        .build(),
+                ));
+
+                edids.push(edid);
+            }
+        }
+
+        Ok(edids)
+    }
+
+    /// Computes a CVT Reduced Blanking v2 timing, with an image size computed from the screen
+    /// size divided by the given number of horizontal and vertical tiles.
+    fn cvt_rb2_timing(
+        &self,
+        hactive: u16,
+        vactive: u16,
+        refresh_rate: u16,
+        (horizontal_tiles, vertical_tiles): (u8, u8),
+    ) -> Result<EdidDescriptorDetailedTiming, EdidTypeConversionError<u32>> {
+        let (hsize, vsize) = match self.display_parameters_features.size {
+            EdidR4ImageSize::Size(s) => (
+                u16::from(s.horizontal_cm.0) * 10 / u16::from(horizontal_tiles),
+                u16::from(s.vertical_cm.0) * 10 / u16::from(vertical_tiles),
+            ),
+            EdidR4ImageSize::LandscapeRatio(_)
+            | EdidR4ImageSize::PortraitRatio(_)
+            | EdidR4ImageSize::Undefined => (0, 0),
+        };
+
+        EdidDescriptorDetailedTiming::cvt_rb2(
+            hactive,
+            vactive,
+            refresh_rate,
+            EdidDetailedTimingSizeMm::try_from(hsize).map_err(|e| e.map_value(&u32::from))?,
+            EdidDetailedTimingSizeMm::try_from(vsize).map_err(|e| e.map_value(&u32::from))?,
+        )
+    }
+
+    fn with_preferred_timing(
+        mut self,
+        timing: EdidDescriptorDetailedTiming,
+    ) -> Result<Self, EdidTypeConversionError<u32>> {
+        check_no_raw_descriptors(self.raw_descriptors.as_ref())?;
+
+        let limits = EdidR4DisplayRangeLimits::cvt_from_detailed_timing(&timing)
+            .map_err(|e| e.map_value(&u32::from).in_field("range_limits"))?;
+
+        let mut descriptors: Vec<_> = self
+            .descriptors
+            .into_iter()
+            .filter(|desc| {
+                !matches!(
+                    desc,
+                    EdidDescriptor::R4(EdidR4Descriptor::DisplayRangeLimits(_))
+                )
+            })
+            .collect();
+
+        let timing = EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(timing));
+        match descriptors.first_mut() {
+            Some(desc @ EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(_))) => *desc = timing,
+            Some(_) | None => descriptors.insert(0, timing),
+        }
+        descriptors.insert(
+            1,
+            EdidDescriptor::R4(EdidR4Descriptor::DisplayRangeLimits(limits)),
+        );
+
+        self.descriptors = descriptors;
+
+        let features = &mut self.display_parameters_features.feature_support;
+        features.preferred_timing_mode_is_native = true;
+        features.continuous_frequency = true;
+
+        Ok(self)
+    }
+
+    /// Keeps the Display Range Limits consistent with the Variable Refresh Rate range
+    /// advertised by the HDMI Forum Vendor-Specific Data Block.
+    ///
+    /// redid doesn't describe the HDMI Forum block, so it's looked for among the
+    /// [`EdidExtensionCTA861VendorDataBlock`] using the
+    /// [`EdidExtensionCTA861VendorOui::HDMI_FORUM`] OUI, created from a typed representation
+    /// through [`EdidExtensionCTA861VendorDataBlock::from_typed`]. The vertical rate range of the
+    /// Display Range Limits is widened to cover its `VRRmin` and `VRRmax`, Display Range Limits
+    /// covering the first Detailed Timing are added if there's none, and the display is marked
+    /// as continuous frequency. The EDID is left untouched if no VRR range is advertised.
+    ///
+    /// # Errors
+    ///
+    /// If the range can't be expressed in Display Range Limits, if there's neither Display
+    /// Range Limits nor Detailed Timing to create them from, or if the EDID uses a raw
+    /// descriptors area.
+    pub fn with_vrr_range_limits(mut self) -> Result<Self, EdidTypeConversionError<u16>> {
+        let Some((min, max)) = extensions::vrr_range(&self.extensions) else {
+            return Ok(self);
+        };
+
+        check_no_raw_descriptors(self.raw_descriptors.as_ref())?;
+
+        let limits = self.descriptors.iter_mut().find_map(|desc| match desc {
+            EdidDescriptor::R4(EdidR4Descriptor::DisplayRangeLimits(limits)) => Some(limits),
+            EdidDescriptor::R3(_) | EdidDescriptor::R4(_) | EdidDescriptor::Raw(_) => None,
+        });
+
+        if let Some(limits) = limits {
+            limits.cover_vertical_range(min, max)?;
+        } else {
+            let timing = self
+                .descriptors
+                .iter()
+                .find_map(|desc| match desc {
+                    EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(timing)) => Some(timing),
+                    EdidDescriptor::R3(_) | EdidDescriptor::R4(_) | EdidDescriptor::Raw(_) => None,
+                })
+                .ok_or_else(|| {
+                    EdidTypeConversionError::Value(String::from(
+                        "Display Range Limits need a Detailed Timing to be created from.",
+                    ))
+                })?;
+
+            let mut limits = EdidR4DisplayRangeLimits::from_detailed_timing(timing)
+                .map_err(|e| e.in_field("range_limits"))?;
+            limits.cover_vertical_range(min, max)?;
+
+            let pos = self
+                .descriptors
+                .iter()
+                .position(|desc| {
+                    matches!(
+                        desc,
+                        EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(_))
+                    )
+                })
+                .map_or(0, |pos| pos + 1);
+            self.descriptors.insert(
+                pos,
+                EdidDescriptor::R4(EdidR4Descriptor::DisplayRangeLimits(limits)),
+            );
+        }
+
+        self.display_parameters_features
+            .feature_support
+            .continuous_frequency = true;
+
+        Ok(self)
+    }
+
+    /// Describes the EDID in a layout close to the edid-decode one. See [`EdidDump`].
+    #[must_use]
+    pub fn dump(&self) -> EdidDump {
+        EdidDump::from(Edid::from(self.clone()))
+    }
+
+    /// Gathers the metadata to store next to the generated EDID. See [`EdidSidecar`].
+    #[must_use]
+    pub fn sidecar(&self) -> EdidSidecar {
+        sidecar::edid_sidecar(&Edid::from(self.clone()))
+    }
+
+    /// Describes the blocks the EDID will be serialized to, without serializing it. See
+    /// [`EdidLayout`].
+    #[must_use]
+    pub fn layout(&self) -> EdidLayout {
+        layout::edid_layout(&Edid::from(self.clone()))
+    }
+
+    /// Returns a summary of the display capabilities advertised by the EDID.
+    #[must_use]
+    pub fn summary(&self) -> EdidSummary {
+        summary::edid_summary(self)
+    }
+
+    /// Returns the EDID of the same display, rotated by 90 degrees.
+    ///
+    /// The screen size or aspect ratio, and the image size of every Detailed Timing are swapped.
+    /// If `swap_preferred_timing` is set, the preferred timing resolution is swapped as well. See
+    /// [`EdidDescriptorDetailedTiming::rotated`].
+    ///
+    /// # Errors
+    ///
+    /// If the preferred timing can't be rotated.
+    pub fn rotated(
+        mut self,
+        swap_preferred_timing: bool,
+    ) -> Result<Self, EdidTypeConversionError<u32>> {
+        self.display_parameters_features.size = match self.display_parameters_features.size {
+            EdidR4ImageSize::LandscapeRatio(ratio) => {
+                EdidR4ImageSize::PortraitRatio(EdidR4ImagePortraitAspectRatio(ratio.1, ratio.0))
+            }
+            EdidR4ImageSize::PortraitRatio(ratio) => {
+                EdidR4ImageSize::LandscapeRatio(EdidR4ImageLandscapeAspectRatio(ratio.1, ratio.0))
+            }
+            EdidR4ImageSize::Size(size) => EdidR4ImageSize::Size(size.rotated()),
+            EdidR4ImageSize::Undefined => EdidR4ImageSize::Undefined,
+        };
+        self.descriptors = rotate_descriptors(self.descriptors, swap_preferred_timing)?;
+
+        Ok(self)
+    }
+
+    /// Returns the source physical address of the display, as reported by the first HDMI
+    /// Vendor-Specific Data Block, if any.
+    #[must_use]
+    pub fn source_physical_address(&self) -> Option<CecAddress> {
+        extensions::physical_addresses(&self.extensions)
+            .next()
+            .map(|(_, address)| address)
+    }
+
+    /// Sets the source physical address of every HDMI Vendor-Specific Data Block, in every
+    /// extension, so that they can't diverge.
+    #[must_use]
+    pub fn with_source_physical_address(mut self, address: CecAddress) -> Self {
+        extensions::set_physical_address(&mut self.extensions, address);
+        self
+    }
+
+    /// Strips the EDID down to fit in the given budget, dropping the lowest priority extensions
+    /// and data blocks first. See [`EdidSizeBudget`] for the order they are dropped in.
+    #[must_use]
+    pub fn strip_to(mut self, budget: EdidSizeBudget) -> Self {
+        extensions::strip_extensions(&mut self.extensions, budget);
+        self
+    }
+
+    /// Lists all the timings advertised by the EDID, from the Established, Standard and Detailed
+    /// Timings, from the CTA-861 extensions Short Video Descriptors, and from the `DisplayID`
+    /// extensions Type VII Timings.
+    ///
+    /// The Manufacturer's Timings and the VICs without a known Video Format are skipped.
+    pub fn all_timings(&self) -> impl Iterator<Item = EdidTiming> {
+        timings::edid_timings(&Edid::from(self.clone())).into_iter()
+    }
+
+    /// Serializes the EDID, and lists the values that couldn't be represented exactly and have
+    /// been adjusted in the process.
+    #[must_use]
+    pub fn into_bytes_with_warnings(self) -> (Vec<u8>, Vec<EdidSerializationWarning>) {
+        let mut warnings = Vec::new();
+        let bytes = edid_into_bytes(
+            Edid::from(self),
+            &mut warnings,
+            &mut field_map::FieldMap::default(),
+        );
+
+        (bytes, warnings)
+    }
+
+    /// Serializes the EDID, and lists the byte range each field has been serialized to.
+    #[must_use]
+    pub fn into_bytes_with_field_map(self) -> (Vec<u8>, Vec<(EdidFieldPath, Range<usize>)>) {
+        let mut fields = field_map::FieldMap::default();
+        let bytes = edid_into_bytes(Edid::from(self), &mut Vec::new(), &mut fields);
+
+        (bytes, fields.into_fields())
+    }
+
+    /// Lists the inconsistencies that don't make the EDID invalid, but are likely to be
+    /// misinterpreted.
+    #[must_use]
+    pub fn warnings(&self) -> Vec<EdidValidationWarning> {
+        Edid::from(self.clone()).warnings()
+    }
+
+    /// Checks the EDID for inconsistencies that the builder can't prevent.
+    ///
+    /// # Errors
+    ///
+    /// If the EDID isn't valid.
+    pub fn validate(&self) -> Result<(), EdidValidationError> {
+        Edid::from(self.clone()).validate()
+    }
+
+    /// Checks the EDID like [`Self::validate`] does, and also that it hasn't been manufactured
+    /// after the given year.
+    ///
+    /// # Errors
+    ///
+    /// If the EDID isn't valid.
+    pub fn validate_at(&self, year: u16) -> Result<(), EdidValidationError> {
+        Edid::from(self.clone()).validate_at(year)
+    }
+}
+
 impl IntoBytes for EdidRelease4 {
     fn into_bytes(self) -> Vec<u8> {
         let bytes = Edid::from(self).into_bytes();
@@ -2036,42 +4387,104 @@ impl IntoBytes for EdidRelease4 {
     }
 
     fn size(&self) -> usize {
-        EDID_BASE_LEN
+        EDID_BASE_LEN + self.extensions.iter().map(IntoBytes::size).sum::<usize>()
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_edid_release3 {
+    use crate::{
+        test_utils::{r3_display_parameters_features, r3_range_limits},
+        EdidChromaticityPoints, EdidDescriptorDetailedTiming, EdidDescriptorString,
+        EdidDetailedTimingSizeMm, EdidEstablishedTiming, EdidFilterChromaticity,
+        EdidManufactureDate, EdidManufacturer, EdidProductCode, EdidR3Descriptor,
+        EdidR3DisplayRangeVideoTimingsSupport, EdidRelease3, IntoBytes,
+    };
+
+    #[test]
+    fn test_single_mode() {
+        let mut features = r3_display_parameters_features();
+        features.feature_support.default_gtf_supported = true;
+
+        let edid = EdidRelease3::builder()
+            .manufacturer(EdidManufacturer::from_ascii(b"ABC"))
+            .product_code(EdidProductCode::new(0xf206))
+            .date(EdidManufactureDate::try_from((1, 2024)).unwrap())
+            .display_parameters_features(features)
+            .filter_chromaticity(EdidFilterChromaticity::Color(EdidChromaticityPoints::srgb()))
+            .established_timings(vec![EdidEstablishedTiming::ET_800_600_60hz])
+            .descriptors(vec![
+                EdidR3Descriptor::ProductName(EdidDescriptorString::try_from("ABC LCD21").unwrap()),
+                EdidR3Descriptor::DisplayRangeLimits(r3_range_limits(
+                    EdidR3DisplayRangeVideoTimingsSupport::DefaultGTF,
+                )),
+            ])
+            .build()
+            .unwrap();
+
+        let edid = edid
+            .single_mode(EdidDescriptorDetailedTiming::cta_1080p_60hz(
+                EdidDetailedTimingSizeMm::try_from(531).unwrap(),
+                EdidDetailedTimingSizeMm::try_from(299).unwrap(),
+            ))
+            .unwrap();
+        edid.validate().unwrap();
+        assert_eq!(edid.all_timings().count(), 1);
+
+        let bytes = edid.into_bytes();
+        assert_eq!(bytes.len(), 128);
+        // Preferred timing mode, no default GTF
+        assert_eq!(bytes[0x18] & 0x03, 0x02);
+        assert_eq!(&bytes[0x23..0x26], &[0x00, 0x00, 0x00]);
+        // Display Range Limits covering 67.5kHz, 60Hz and 148.5MHz, without secondary curve
+        assert_eq!(&bytes[0x48..0x4d], &[0x00, 0x00, 0x00, 0xfd, 0x00]);
+        assert_eq!(&bytes[0x4d..0x53], &[60, 60, 67, 68, 15, 0x00]);
+        assert_eq!(bytes[0x5d], 0xfc);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
 mod test_edid_release4 {
     use crate::{
-        descriptors::EdidDetailedTimingPixelClock, EdidAnalogSignalLevelStandard,
-        EdidAnalogVideoInputDefinition, EdidAnalogVideoSetup, EdidChromaticityPoint,
-        EdidChromaticityPoints, EdidDescriptor10BitsTiming, EdidDescriptor12BitsTiming,
-        EdidDescriptor6BitsTiming, EdidDescriptor8BitsTiming, EdidDescriptorDetailedTiming,
+        descriptors::EdidDetailedTimingPixelClock, edid_block_checksum, rotate_descriptors,
+        test_utils::edid_r4_builder, EdidAnalogSignalLevelStandard, EdidAnalogVideoInputDefinition,
+        EdidAnalogVideoSetup, EdidChromaticityPoint, EdidChromaticityPoints, EdidDescriptor,
+        EdidDescriptor10BitsTiming, EdidDescriptor12BitsTiming, EdidDescriptor6BitsTiming,
+        EdidDescriptor8BitsTiming, EdidDescriptorDetailedTiming, EdidDescriptorPadding,
         EdidDescriptorString, EdidDetailedTimingDigitalSeparateSync, EdidDetailedTimingDigitalSync,
         EdidDetailedTimingDigitalSyncKind, EdidDetailedTimingSizeMm, EdidDetailedTimingStereo,
         EdidDetailedTimingSync, EdidDisplayColorType, EdidDisplayRangePixelClock,
         EdidDisplayRangeVerticalFreq, EdidDisplayTransferCharacteristics, EdidEstablishedTiming,
-        EdidFilterChromaticity, EdidManufacturer, EdidProductCode,
-        EdidR4BasicDisplayParametersFeatures, EdidR4Date, EdidR4Descriptor,
+        EdidExtension, EdidExtensionCTA861, EdidExtensionCTA861NativeFormats,
+        EdidExtensionCTA861Revision3, EdidExtensionCTA861Revision3DataBlock,
+        EdidExtensionCTA861VendorDataBlock, EdidExtensionCTA861VendorDataBlockType,
+        EdidExtensionCTA861VendorOui, EdidExtensionCTA861VendorPayload,
+        EdidExtensionCTA861VideoDataBlock, EdidFilterChromaticity, EdidManufacturer,
+        EdidProductCode, EdidR4BasicDisplayParametersFeatures, EdidR4Date, EdidR4Descriptor,
         EdidR4DescriptorEstablishedTimings, EdidR4DescriptorEstablishedTimingsIII,
-        EdidR4DisplayColor, EdidR4DisplayRangeHorizontalFreq, EdidR4DisplayRangeLimits,
-        EdidR4DisplayRangeVerticalFreq, EdidR4DisplayRangeVideoTimingsAspectRatio,
-        EdidR4DisplayRangeVideoTimingsCVT, EdidR4DisplayRangeVideoTimingsCVTR1,
-        EdidR4DisplayRangeVideoTimingsSupport, EdidR4FeatureSupport, EdidR4ImageSize,
-        EdidR4ManufactureDate, EdidR4VideoInputDefinition, EdidRelease4, EdidScreenSize,
-        EdidScreenSizeLength, EdidSerialNumber, EdidStandardTiming,
+        EdidR4DigitalColorDepth, EdidR4DigitalInterface, EdidR4DisplayColor,
+        EdidR4DisplayRangeHorizontalFreq, EdidR4DisplayRangeLimits, EdidR4DisplayRangeVerticalFreq,
+        EdidR4DisplayRangeVideoTimingsAspectRatio, EdidR4DisplayRangeVideoTimingsCVT,
+        EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels, EdidR4DisplayRangeVideoTimingsCVTR1,
+        EdidR4DisplayRangeVideoTimingsSupport, EdidR4FeatureSupport,
+        EdidR4ImageLandscapeAspectRatio, EdidR4ImageSize, EdidR4ManufactureDate,
+        EdidR4VideoInputDefinition, EdidRelease4, EdidScreenSize, EdidScreenSizeLength,
+        EdidSerialNumber, EdidSerialNumberFormat, EdidSizeBudget, EdidStandardTiming,
         EdidStandardTimingHorizontalSize, EdidStandardTimingRatio, EdidStandardTimingRefreshRate,
-        IntoBytes,
+        EdidTimingSource, EdidTypeConversionError, EdidValidationError, IntoBytes,
     };
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn test_binary_spec_example_1() {
         // This is taken from the EDID 1.4 Section 6.1
 
         let edid = EdidRelease4::builder()
             .manufacturer(EdidManufacturer::try_from("ABC").unwrap())
             .product_code(EdidProductCode::from(0xf206))
-            .serial_number(Some(EdidSerialNumber::from(0x00000001)))
+            .serial_number(Some(EdidSerialNumber::from(0x0000_0001)))
             .date(EdidR4Date::Manufacture(
                 EdidR4ManufactureDate::try_from((1, 2007)).unwrap(),
             ))
@@ -2215,7 +4628,12 @@ mod test_edid_release4 {
                         .timings_support(EdidR4DisplayRangeVideoTimingsSupport::CVTSupported(
                             EdidR4DisplayRangeVideoTimingsCVT::R1(
                                 EdidR4DisplayRangeVideoTimingsCVTR1::builder()
-                                    .maximum_active_pixels_per_line(1600)
+                                    .maximum_active_pixels_per_line(
+                                        EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels::try_from(
+                                            1600,
+                                        )
+                                        .unwrap(),
+                                    )
                                     .supported_aspect_ratios(vec![
                                         EdidR4DisplayRangeVideoTimingsAspectRatio::Ratio_4_3,
                                         EdidR4DisplayRangeVideoTimingsAspectRatio::Ratio_5_4,
@@ -2227,7 +4645,7 @@ mod test_edid_release4 {
                                     .horizontal_stretch_supported(true)
                                     .vertical_stretch_supported(true)
                                     .preferred_vertical_refresh_rate(
-                                        EdidDisplayRangeVerticalFreq::try_from(60).unwrap(),
+                                        EdidDisplayRangeVerticalFreq::try_from(60u8).unwrap(),
                                     )
                                     .build(),
                             ),
@@ -2261,7 +4679,8 @@ mod test_edid_release4 {
                 ),
                 EdidR4Descriptor::ProductName(EdidDescriptorString::try_from("ABC LCD21").unwrap()),
             ])
-            .build();
+            .build()
+            .unwrap();
 
         assert_eq!(
             edid.into_bytes(),
@@ -2304,4 +4723,616 @@ mod test_edid_release4 {
             ]
         );
     }
+
+    #[test]
+    fn test_raw_descriptors() {
+        let mut edid = edid_r4_builder!()
+            .raw_descriptors([0xaa; 72])
+            .build()
+            .unwrap();
+
+        edid.validate().unwrap();
+
+        // The Display Range Limits can't be looked for in the raw descriptors.
+        edid.display_parameters_features
+            .feature_support
+            .continuous_frequency = true;
+        edid.validate().unwrap();
+
+        let bytes = edid.into_bytes();
+        assert_eq!(&bytes[0x36..0x7e], &[0xaa; 72]);
+        assert_eq!(edid_block_checksum(&bytes[..0x7f]), bytes[0x7f]);
+    }
+
+    #[test]
+    fn test_serial_number_string() {
+        let edid = edid_r4_builder!()
+            .serial_number(Some(EdidSerialNumber::from(0x1234_abcd)))
+            .serial_number_string(EdidSerialNumberFormat::Hexadecimal)
+            .descriptors(vec![EdidR4Descriptor::ProductName(
+                EdidDescriptorString::try_from("ABC LCD21").unwrap(),
+            )])
+            .build()
+            .unwrap();
+
+        let bytes = edid.into_bytes();
+        assert_eq!(&bytes[0x0c..0x10], &[0xcd, 0xab, 0x34, 0x12]);
+        assert_eq!(
+            &bytes[0x48..0x5a],
+            &[
+                0x00, 0x00, 0x00, 0xff, 0x00, 0x31, 0x32, 0x33, 0x34, 0x41, 0x42, 0x43, 0x44, 0x0a,
+                0x20, 0x20, 0x20, 0x20
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rotated() {
+        let mut edid = edid_r4_builder!()
+            .descriptors(vec![
+                EdidR4Descriptor::DetailedTiming(EdidDescriptorDetailedTiming::cta_1080p_60hz(
+                    EdidDetailedTimingSizeMm::try_from(531).unwrap(),
+                    EdidDetailedTimingSizeMm::try_from(299).unwrap(),
+                )),
+                EdidR4Descriptor::DetailedTiming(EdidDescriptorDetailedTiming::cta_1080i_50hz(
+                    EdidDetailedTimingSizeMm::try_from(531).unwrap(),
+                    EdidDetailedTimingSizeMm::try_from(299).unwrap(),
+                )),
+            ])
+            .build()
+            .unwrap();
+        edid.display_parameters_features.size = EdidR4ImageSize::LandscapeRatio(
+            EdidR4ImageLandscapeAspectRatio::try_from((16.0, 9.0)).unwrap(),
+        );
+
+        let bytes = edid.clone().rotated(false).unwrap().into_bytes();
+        assert_eq!(&bytes[0x15..0x17], &[0x00, 0x4f]);
+        // Preferred Timing image size
+        assert_eq!(&bytes[0x42..0x45], &[0x2b, 0x13, 0x12]);
+        // Preferred Timing addressable resolution
+        assert_eq!(bytes[0x38], 0x80);
+        // Second Detailed Timing image size
+        assert_eq!(&bytes[0x54..0x57], &[0x2b, 0x13, 0x12]);
+
+        let rotated = edid.clone().rotated(true).unwrap();
+        let preferred = rotated
+            .all_timings()
+            .find(|t| t.source() == EdidTimingSource::DetailedTiming)
+            .unwrap();
+        assert_eq!((preferred.horizontal(), preferred.vertical()), (1080, 1920));
+        assert_eq!(preferred.refresh_rate(), 60);
+
+        let bytes = rotated.into_bytes();
+        assert_eq!(&bytes[0x42..0x45], &[0x2b, 0x13, 0x12]);
+        // Preferred Timing addressable resolution, 1080x1920
+        assert_eq!(bytes[0x38], 0x38);
+        assert_eq!(bytes[0x3b], 0x80);
+        assert_eq!(bytes[0x4a], 0x80);
+
+        // Interlaced timings only hold the lines of a field, and can't be swapped.
+        edid.descriptors[0] = EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(
+            EdidDescriptorDetailedTiming::cta_1080i_60hz(
+                EdidDetailedTimingSizeMm::try_from(531).unwrap(),
+                EdidDetailedTimingSizeMm::try_from(299).unwrap(),
+            ),
+        ));
+        assert!(edid.clone().rotated(false).is_ok());
+        assert!(edid
+            .rotated(true)
+            .unwrap_err()
+            .to_string()
+            .starts_with("descriptors[0].detailed_timing.interlace: "));
+    }
+
+    #[test]
+    fn test_rotated_error_path() {
+        let dtd = EdidDescriptorDetailedTiming::builder()
+            .pixel_clock(655_000.try_into().unwrap())
+            .horizontal_addressable(10.try_into().unwrap())
+            .horizontal_blanking(0.try_into().unwrap())
+            .vertical_addressable(4095.try_into().unwrap())
+            .vertical_blanking(4095.try_into().unwrap())
+            .horizontal_front_porch(0.try_into().unwrap())
+            .horizontal_sync_pulse(0.try_into().unwrap())
+            .vertical_front_porch(0.try_into().unwrap())
+            .vertical_sync_pulse(0.try_into().unwrap())
+            .horizontal_size(0.try_into().unwrap())
+            .vertical_size(0.try_into().unwrap())
+            .horizontal_border(0.try_into().unwrap())
+            .vertical_border(0.try_into().unwrap())
+            .sync_type(EdidDetailedTimingSync::Digital(
+                EdidDetailedTimingDigitalSync::builder()
+                    .kind(EdidDetailedTimingDigitalSyncKind::Separate(
+                        EdidDetailedTimingDigitalSeparateSync::builder().build(),
+                    ))
+                    .build(),
+            ))
+            .stereo(EdidDetailedTimingStereo::None)
+            .build();
+
+        let err = rotate_descriptors(
+            vec![EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(dtd))],
+            true,
+        )
+        .unwrap_err();
+
+        assert!(err
+            .to_string()
+            .starts_with("descriptors[0].detailed_timing.pixel_clock: "));
+    }
+
+    #[test]
+    fn test_single_mode() {
+        let edid = edid_r4_builder!()
+            .established_timings(vec![
+                EdidEstablishedTiming::ET_640_480_60hz,
+                EdidEstablishedTiming::Manufacturer0,
+            ])
+            .standard_timings(vec![EdidStandardTiming::builder()
+                .x(EdidStandardTimingHorizontalSize::try_from(1280).unwrap())
+                .ratio(EdidStandardTimingRatio::Ratio_16_9)
+                .frequency(EdidStandardTimingRefreshRate::try_from(60).unwrap())
+                .build()])
+            .descriptors(vec![
+                EdidR4Descriptor::DetailedTiming(EdidDescriptorDetailedTiming::cta_1080i_50hz(
+                    EdidDetailedTimingSizeMm::try_from(531).unwrap(),
+                    EdidDetailedTimingSizeMm::try_from(299).unwrap(),
+                )),
+                EdidR4Descriptor::ProductName(EdidDescriptorString::try_from("ABC LCD21").unwrap()),
+            ])
+            .extensions(vec![EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+                EdidExtensionCTA861Revision3::builder()
+                    .native_formats(EdidExtensionCTA861NativeFormats::try_from(1).unwrap())
+                    .add_data_block(EdidExtensionCTA861Revision3DataBlock::Video(
+                        EdidExtensionCTA861VideoDataBlock::builder()
+                            .add_native_short_video_descriptor(16)
+                            .add_short_video_descriptor(120)
+                            .build()
+                            .unwrap(),
+                    ))
+                    .build(),
+            ))])
+            .build()
+            .unwrap();
+
+        let timing = EdidDescriptorDetailedTiming::cta_1080i_60hz(
+            EdidDetailedTimingSizeMm::try_from(531).unwrap(),
+            EdidDetailedTimingSizeMm::try_from(299).unwrap(),
+        );
+        assert_eq!(
+            edid.clone().single_mode(timing).unwrap_err().to_string(),
+            "extensions: Invalid Value: Extensions could advertise other timings"
+        );
+
+        let mut raw = edid.clone().strip_to(EdidSizeBudget::BaseBlock);
+        raw.descriptors.clear();
+        raw.raw_descriptors = Some([0; 72]);
+        assert!(matches!(
+            raw.single_mode(timing),
+            Err(EdidTypeConversionError::Invalid(
+                EdidValidationError::IgnoredDescriptors
+            ))
+        ));
+
+        let edid = edid
+            .strip_to(EdidSizeBudget::BaseBlock)
+            .single_mode(timing)
+            .unwrap();
+        edid.validate().unwrap();
+        assert_eq!(edid.all_timings().count(), 1);
+
+        let bytes = edid.into_bytes();
+        assert_eq!(bytes.len(), 128);
+        // Preferred timing mode is native, no continuous frequency
+        assert_eq!(bytes[0x18] & 0x03, 0x02);
+        assert_eq!(&bytes[0x23..0x26], &[0x00, 0x00, 0x00]);
+        assert_eq!(&bytes[0x26..0x36], &[0x01; 16]);
+        // Range Limits Only Display Range Limits covering 33.75kHz, 60Hz and 74.25MHz
+        assert_eq!(&bytes[0x48..0x4d], &[0x00, 0x00, 0x00, 0xfd, 0x00]);
+        assert_eq!(&bytes[0x4d..0x53], &[60, 60, 33, 34, 8, 0x01]);
+        assert_eq!(bytes[0x5d], 0xfc);
+        assert_eq!(bytes[0x7e], 0);
+    }
+
+    #[test]
+    fn test_vga_fallback() {
+        let builder = || edid_r4_builder!();
+
+        let bytes = builder().build().unwrap().into_bytes();
+        assert_eq!(&bytes[0x23..0x26], &[0x00, 0x00, 0x00]);
+
+        let bytes = builder().with_vga_fallback().build().unwrap().into_bytes();
+        assert_eq!(&bytes[0x23..0x26], &[0x20, 0x00, 0x00]);
+
+        let bytes = builder()
+            .add_established_timing(EdidEstablishedTiming::ET_640_480_60hz)
+            .with_vga_fallback()
+            .build()
+            .unwrap()
+            .into_bytes();
+        assert_eq!(&bytes[0x23..0x26], &[0x20, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn test_too_many_descriptors() {
+        let builder = |num| edid_r4_builder!().descriptors(vec![EdidR4Descriptor::Dummy; num]);
+
+        assert!(builder(4).build().is_ok());
+        assert_eq!(
+            builder(4)
+                .add_descriptor(EdidR4Descriptor::Dummy)
+                .build()
+                .unwrap_err(),
+            EdidValidationError::TooManyDescriptors(4)
+        );
+
+        // Descriptors added after the build can't be serialized.
+        let mut edid = builder(4).build().unwrap();
+        edid.descriptors
+            .push(EdidDescriptor::R4(EdidR4Descriptor::Dummy));
+        assert_eq!(
+            edid.validate(),
+            Err(EdidValidationError::TooManyDescriptors(4))
+        );
+        assert!(!edid.layout().fits());
+
+        assert!(builder(4)
+            .descriptor_padding(EdidDescriptorPadding::Forbidden)
+            .build()
+            .is_ok());
+        assert_eq!(
+            builder(3)
+                .descriptor_padding(EdidDescriptorPadding::Forbidden)
+                .build()
+                .unwrap_err(),
+            EdidValidationError::MissingDescriptors(3)
+        );
+    }
+
+    #[test]
+    fn test_cvt_rb2_preferred_timing() {
+        let mut edid = edid_r4_builder!()
+            .descriptors(vec![
+                EdidR4Descriptor::DetailedTiming(EdidDescriptorDetailedTiming::cta_1080i_60hz(
+                    EdidDetailedTimingSizeMm::try_from(340).unwrap(),
+                    EdidDetailedTimingSizeMm::try_from(190).unwrap(),
+                )),
+                EdidR4Descriptor::ProductName(EdidDescriptorString::try_from("ABC Panel").unwrap()),
+            ])
+            .build()
+            .unwrap();
+        edid.display_parameters_features.size = EdidR4ImageSize::Size(
+            EdidScreenSize::builder()
+                .horizontal_cm(EdidScreenSizeLength::try_from(34).unwrap())
+                .vertical_cm(EdidScreenSizeLength::try_from(19).unwrap())
+                .build(),
+        );
+
+        let mut raw = edid.clone();
+        raw.descriptors.clear();
+        raw.raw_descriptors = Some([0; 72]);
+        assert!(matches!(
+            raw.with_cvt_rb2_preferred_timing(1920, 1080, 60),
+            Err(EdidTypeConversionError::Invalid(
+                EdidValidationError::IgnoredDescriptors
+            ))
+        ));
+
+        let edid = edid.with_cvt_rb2_preferred_timing(1920, 1080, 60).unwrap();
+        edid.validate().unwrap();
+
+        let timing = EdidDescriptorDetailedTiming::cvt_rb2(
+            1920,
+            1080,
+            60,
+            EdidDetailedTimingSizeMm::try_from(340).unwrap(),
+            EdidDetailedTimingSizeMm::try_from(190).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            edid.descriptors,
+            vec![
+                EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(timing)),
+                EdidDescriptor::R4(EdidR4Descriptor::DisplayRangeLimits(
+                    EdidR4DisplayRangeLimits::cvt_from_detailed_timing(&timing).unwrap()
+                )),
+                EdidDescriptor::R4(EdidR4Descriptor::ProductName(
+                    EdidDescriptorString::try_from("ABC Panel").unwrap()
+                )),
+            ]
+        );
+
+        let bytes = edid.into_bytes();
+        // Preferred timing mode is native, continuous frequency
+        assert_eq!(bytes[0x18] & 0x03, 0x03);
+        // 133.32MHz
+        assert_eq!(&bytes[0x36..0x38], &[0x14, 0x34]);
+        // CVT supported, 1920 pixels, 16:9, reduced blanking, 60Hz
+        assert_eq!(&bytes[0x48..0x4d], &[0x00, 0x00, 0x00, 0xfd, 0x00]);
+        assert_eq!(bytes[0x52], 0x04);
+        assert_eq!(bytes[0x55], 240);
+        assert_eq!(bytes[0x56], 0x40);
+        assert_eq!(bytes[0x57], 0x30);
+        assert_eq!(bytes[0x59], 60);
+    }
+
+    #[test]
+    fn test_tiled() {
+        let mut edid = edid_r4_builder!()
+            .serial_number(Some(EdidSerialNumber::from(0x1234_5678)))
+            .descriptors(vec![EdidR4Descriptor::ProductName(
+                EdidDescriptorString::try_from("ABC Tiled").unwrap(),
+            )])
+            .build()
+            .unwrap();
+        edid.display_parameters_features.size = EdidR4ImageSize::Size(
+            EdidScreenSize::builder()
+                .horizontal_cm(EdidScreenSizeLength::try_from(60).unwrap())
+                .vertical_cm(EdidScreenSizeLength::try_from(34).unwrap())
+                .build(),
+        );
+
+        let oui = EdidExtensionCTA861VendorOui::try_from(0x3a_0292).unwrap();
+        assert!(edid.tiled(3840, 2160, 60, 7, 1, oui).is_err());
+        assert!(edid.tiled(3840, 2160, 60, 0, 1, oui).is_err());
+
+        let tiles = edid.tiled(3840, 2160, 60, 2, 1, oui).unwrap();
+        assert_eq!(tiles.len(), 2);
+
+        let timing = EdidDescriptorDetailedTiming::cvt_rb2(
+            1920,
+            2160,
+            60,
+            EdidDetailedTimingSizeMm::try_from(300).unwrap(),
+            EdidDetailedTimingSizeMm::try_from(340).unwrap(),
+        )
+        .unwrap();
+
+        let bytes: Vec<_> = tiles
+            .into_iter()
+            .map(|tile| {
+                tile.validate().unwrap();
+                assert_eq!(
+                    tile.descriptors[0],
+                    EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(timing))
+                );
+
+                tile.into_bytes()
+            })
+            .collect();
+
+        for (location, bytes) in bytes.iter().enumerate() {
+            assert_eq!(bytes.len(), 256);
+            // Same serial number in the base block
+            assert_eq!(&bytes[0x0c..0x10], &[0x78, 0x56, 0x34, 0x12]);
+            // DisplayID section with a single Tiled Display Topology Data Block
+            assert_eq!(
+                &bytes[128..136],
+                &[0x70, 0x20, 25, 0x00, 0x00, 0x28, 0x00, 22]
+            );
+            // Single enclosure, tile location behaviors, 2x1 tiles
+            assert_eq!(&bytes[136..138], &[0x89, 0x10]);
+            assert_eq!(usize::from(bytes[138] >> 4), location);
+            // 1920x2160 tiles
+            assert_eq!(&bytes[140..144], &[0x7f, 0x07, 0x6f, 0x08]);
+        }
+
+        // The topology identifier is the same for both tiles
+        assert_eq!(&bytes[0][149..158], &bytes[1][149..158]);
+        assert_eq!(
+            &bytes[0][149..158],
+            &[0x3a, 0x02, 0x92, 0x06, 0xf2, 0x78, 0x56, 0x34, 0x12]
+        );
+    }
+
+    #[test]
+    fn test_vrr_range_limits() {
+        #[derive(Debug)]
+        struct HdmiForum(Vec<u8>);
+
+        impl EdidExtensionCTA861VendorDataBlockType for HdmiForum {
+            fn oui(&self) -> EdidExtensionCTA861VendorOui {
+                EdidExtensionCTA861VendorOui::HDMI_FORUM
+            }
+
+            fn payload(&self) -> EdidExtensionCTA861VendorPayload {
+                EdidExtensionCTA861VendorPayload::try_from(self.0.clone()).unwrap()
+            }
+        }
+
+        let builder = |descriptors, vrr: Option<[u8; 2]>| {
+            let mut payload = vec![0x01, 0x78, 0x80, 0x00, 0x00];
+            payload.extend(vrr.unwrap_or([0x00, 0x00]));
+
+            edid_r4_builder!()
+                .descriptors(descriptors)
+                .add_extension(EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+                    EdidExtensionCTA861Revision3::builder()
+                        .data_blocks(vec![EdidExtensionCTA861Revision3DataBlock::Vendor(
+                            EdidExtensionCTA861VendorDataBlock::from_typed(&HdmiForum(payload)),
+                        )])
+                        .build(),
+                )))
+                .build()
+                .unwrap()
+        };
+        let timing =
+            EdidR4Descriptor::DetailedTiming(EdidDescriptorDetailedTiming::cta_1080p_60hz(
+                EdidDetailedTimingSizeMm::try_from(0).unwrap(),
+                EdidDetailedTimingSizeMm::try_from(0).unwrap(),
+            ));
+
+        // 48Hz to 144Hz, added after the Detailed Timing
+        let edid = builder(vec![timing.clone()], Some([0x30, 0x90]))
+            .with_vrr_range_limits()
+            .unwrap();
+        edid.validate().unwrap();
+
+        let bytes = edid.into_bytes();
+        assert_eq!(bytes[0x18] & 0x01, 0x01);
+        assert_eq!(&bytes[0x48..0x4d], &[0x00, 0x00, 0x00, 0xfd, 0x00]);
+        assert_eq!(&bytes[0x4d..0x4f], &[48, 144]);
+
+        // 24Hz to 300Hz, widening the existing limits
+        let limits = EdidR4DisplayRangeLimits::builder()
+            .min_hfreq(EdidR4DisplayRangeHorizontalFreq::try_from(30).unwrap())
+            .max_hfreq(EdidR4DisplayRangeHorizontalFreq::try_from(160).unwrap())
+            .min_vfreq(EdidR4DisplayRangeVerticalFreq::try_from(50).unwrap())
+            .max_vfreq(EdidR4DisplayRangeVerticalFreq::try_from(75).unwrap())
+            .max_pixelclock(EdidDisplayRangePixelClock::try_from(600).unwrap())
+            .timings_support(EdidR4DisplayRangeVideoTimingsSupport::RangeLimitsOnly)
+            .build();
+        let bytes = builder(
+            vec![
+                timing.clone(),
+                EdidR4Descriptor::DisplayRangeLimits(limits.clone()),
+            ],
+            Some([0x58, 0x2c]),
+        )
+        .with_vrr_range_limits()
+        .unwrap()
+        .into_bytes();
+        assert_eq!(bytes[0x18] & 0x01, 0x01);
+        // Maximum vertical rate offset
+        assert_eq!(bytes[0x4c] & 0x03, 0x02);
+        assert_eq!(&bytes[0x4d..0x4f], &[24, 45]);
+
+        // No VRR range
+        let edid = builder(
+            vec![timing, EdidR4Descriptor::DisplayRangeLimits(limits)],
+            None,
+        );
+        assert_eq!(
+            edid.clone().with_vrr_range_limits().unwrap().into_bytes(),
+            edid.into_bytes()
+        );
+
+        assert!(builder(Vec::new(), Some([0x30, 0x90]))
+            .with_vrr_range_limits()
+            .is_err());
+
+        let mut edid = builder(Vec::new(), Some([0x30, 0x90]));
+        edid.raw_descriptors = Some([0; 72]);
+        assert!(matches!(
+            edid.with_vrr_range_limits(),
+            Err(EdidTypeConversionError::Invalid(
+                EdidValidationError::IgnoredDescriptors
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_summary() {
+        let edid = edid_r4_builder!()
+            .established_timings(vec![
+                EdidEstablishedTiming::ET_640_480_60hz,
+                EdidEstablishedTiming::Manufacturer0,
+            ])
+            .standard_timings(vec![EdidStandardTiming::builder()
+                .x(EdidStandardTimingHorizontalSize::try_from(1280).unwrap())
+                .ratio(EdidStandardTimingRatio::Ratio_16_9)
+                .frequency(EdidStandardTimingRefreshRate::try_from(60).unwrap())
+                .build()])
+            .descriptors(vec![EdidR4Descriptor::DetailedTiming(
+                EdidDescriptorDetailedTiming::cta_1080i_50hz(
+                    EdidDetailedTimingSizeMm::try_from(531).unwrap(),
+                    EdidDetailedTimingSizeMm::try_from(299).unwrap(),
+                ),
+            )])
+            .extensions(vec![EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+                EdidExtensionCTA861Revision3::builder()
+                    .native_formats(EdidExtensionCTA861NativeFormats::try_from(1).unwrap())
+                    .add_data_block(EdidExtensionCTA861Revision3DataBlock::Video(
+                        EdidExtensionCTA861VideoDataBlock::builder()
+                            .add_native_short_video_descriptor(16)
+                            .add_short_video_descriptor(120)
+                            .build()
+                            .unwrap(),
+                    ))
+                    .build(),
+            ))])
+            .build()
+            .unwrap();
+
+        let summary = edid.summary();
+        assert_eq!(summary.max_resolution(), Some((3840, 2160)));
+        assert_eq!(summary.max_refresh_rate(), Some(120));
+        assert_eq!(summary.audio_channels(), None);
+        assert_eq!(
+            summary.color_depth(),
+            Some(EdidR4DigitalColorDepth::Depth8Bpc)
+        );
+        assert_eq!(summary.interface(), Some(EdidR4DigitalInterface::HDMIa));
+        assert!(!summary.is_hdmi());
+    }
+
+    #[test]
+    fn test_all_timings() {
+        let edid = edid_r4_builder!()
+            .established_timings(vec![
+                EdidEstablishedTiming::ET_640_480_60hz,
+                EdidEstablishedTiming::Manufacturer0,
+            ])
+            .standard_timings(vec![EdidStandardTiming::builder()
+                .x(EdidStandardTimingHorizontalSize::try_from(1280).unwrap())
+                .ratio(EdidStandardTimingRatio::Ratio_16_9)
+                .frequency(EdidStandardTimingRefreshRate::try_from(60).unwrap())
+                .build()])
+            .descriptors(vec![EdidR4Descriptor::DetailedTiming(
+                EdidDescriptorDetailedTiming::cta_1080i_50hz(
+                    EdidDetailedTimingSizeMm::try_from(531).unwrap(),
+                    EdidDetailedTimingSizeMm::try_from(299).unwrap(),
+                ),
+            )])
+            .extensions(vec![EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+                EdidExtensionCTA861Revision3::builder()
+                    .native_formats(EdidExtensionCTA861NativeFormats::try_from(1).unwrap())
+                    .add_data_block(EdidExtensionCTA861Revision3DataBlock::Video(
+                        EdidExtensionCTA861VideoDataBlock::builder()
+                            .add_native_short_video_descriptor(16)
+                            .add_short_video_descriptor(120)
+                            .build()
+                            .unwrap(),
+                    ))
+                    .build(),
+            ))])
+            .build()
+            .unwrap();
+
+        let timings: Vec<_> = edid
+            .all_timings()
+            .map(|t| {
+                (
+                    t.source(),
+                    t.horizontal(),
+                    t.vertical(),
+                    t.refresh_rate(),
+                    t.is_interlaced(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            timings,
+            vec![
+                (EdidTimingSource::EstablishedTiming, 640, 480, 60, false),
+                (EdidTimingSource::StandardTiming, 1280, 720, 60, false),
+                (EdidTimingSource::DetailedTiming, 1920, 1080, 50, true),
+                (
+                    EdidTimingSource::ShortVideoDescriptor(16),
+                    1920,
+                    1080,
+                    60,
+                    false
+                ),
+                (
+                    EdidTimingSource::ShortVideoDescriptor(120),
+                    3840,
+                    2160,
+                    120,
+                    false
+                ),
+            ]
+        );
+    }
 }
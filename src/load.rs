@@ -0,0 +1,442 @@
+//! Helpers to retrieve the bytes of existing EDIDs, as found in bug reports and logs.
+
+use std::{fs, io, path::Path};
+
+use crate::{EdidTypeConversionError, EDID_BASE_LEN};
+
+/// The separator edid-decode prints between the hexadecimal dump and the decoded blocks.
+const EDID_DECODE_SEPARATOR: &str = "----";
+
+/// The number of bytes on a line of the dumps prefixed by an offset.
+const HEX_DUMP_LINE_LEN: usize = 16;
+
+fn is_hex(token: &str) -> bool {
+    !token.is_empty() && token.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+pub(crate) fn check_len(bytes: Vec<u8>) -> Result<Vec<u8>, EdidTypeConversionError<String>> {
+    if bytes.is_empty() || bytes.len() % EDID_BASE_LEN != 0 {
+        return Err(EdidTypeConversionError::Value(format!(
+            "EDID must be a non-zero multiple of {EDID_BASE_LEN} bytes long (actual size {})",
+            bytes.len()
+        )));
+    }
+
+    Ok(bytes)
+}
+
+fn invalid_line(idx: usize, msg: &str) -> EdidTypeConversionError<String> {
+    EdidTypeConversionError::Value(format!("Line {}: {msg}", idx + 1))
+}
+
+/// Parses an EDID from its hexadecimal representation.
+///
+/// The common dump formats are supported:
+///
+/// - Continuous hexadecimal, possibly split across lines, like in the `xrandr --verbose` output,
+///   optionally preceded by the `EDID:` label;
+/// - Space-separated bytes, like the `edid-decode` hexadecimal blocks. Anything after the
+///   `----------------` separator is ignored, so a whole `edid-decode` output can be passed;
+/// - `xxd` dumps, with their offsets and ASCII columns;
+/// - `hexdump -C` dumps, with their offsets, ASCII columns and collapsed duplicate lines;
+/// - The `IODisplayEDID` property of the macOS `ioreg -l` output.
+///
+/// ```
+/// let bytes = redid::parse_hex(
+///     "00000000: 00ff ffff ffff ff00 0469 1ab8 0101 0101  .........i......",
+/// );
+///
+/// // A single line isn't a complete EDID.
+/// assert!(bytes.is_err());
+/// ```
+///
+/// # Errors
+///
+/// If the text has a line that isn't part of a supported format, or if the bytes don't add up to
+/// complete EDID blocks.
+pub fn parse_hex(text: &str) -> Result<Vec<u8>, EdidTypeConversionError<String>> {
+    let mut bytes: Vec<u8> = Vec::new();
+    let mut repeat = false;
+
+    for (idx, line) in text.lines().enumerate() {
+        let line = line.trim();
+
+        if line.starts_with(EDID_DECODE_SEPARATOR) {
+            break;
+        }
+
+        // hexdump -C collapses identical lines into a single star.
+        if line == "*" {
+            repeat = true;
+            continue;
+        }
+
+        // Extract the value of the macOS ioreg properties, before the tree drawing gets in the
+        // way.
+        let line = match line.split_once("= <") {
+            Some((_, value)) => value.strip_suffix('>').unwrap_or(value),
+            None => line,
+        };
+
+        // Strip the ASCII column of hexdump -C.
+        let line = line.split_once('|').map_or(line, |(hex, _)| hex);
+
+        // Strip the xxd offset, or a label like the xrandr EDID one.
+        let (line, mut offset) = match line.split_once(':') {
+            Some((prefix, hex)) if is_hex(prefix) => (hex, Some(prefix)),
+            Some((_, hex)) => (hex, None),
+            None => (line, None),
+        };
+
+        let mut tokens: Vec<_> = line.split_whitespace().collect();
+
+        // The hexdump -C offset isn't followed by a colon. Its last line only holds the length
+        // of the dump.
+        if offset.is_none() {
+            if let Some(first) = tokens.first().copied() {
+                let lone_offset = tokens.len() == 1
+                    && (repeat || usize::from_str_radix(first, 16).ok() == Some(bytes.len()));
+
+                if first.len() == 8 && is_hex(first) && (tokens.len() > 1 || lone_offset) {
+                    offset = Some(first);
+                    tokens.remove(0);
+                }
+            }
+        }
+
+        if let Some(offset) = offset {
+            let offset = usize::from_str_radix(offset, 16)
+                .map_err(|_e| invalid_line(idx, "Invalid offset"))?;
+
+            if repeat {
+                let last = bytes.len().saturating_sub(HEX_DUMP_LINE_LEN);
+                while bytes.len() < offset && last < bytes.len() {
+                    bytes.extend_from_within(last..last + HEX_DUMP_LINE_LEN);
+                }
+
+                repeat = false;
+            }
+
+            if offset != bytes.len() {
+                return Err(invalid_line(idx, "Offset doesn't match the previous lines"));
+            }
+        }
+
+        let mut line_len = 0;
+        for token in tokens {
+            if offset.is_some() && line_len >= HEX_DUMP_LINE_LEN {
+                break;
+            }
+
+            if !is_hex(token) || token.len() % 2 != 0 {
+                // The xxd ASCII column follows the bytes.
+                if offset.is_some() && line_len > 0 {
+                    break;
+                }
+
+                return Err(invalid_line(
+                    idx,
+                    &format!("Invalid hexadecimal bytes: {token}"),
+                ));
+            }
+
+            for pair in token.as_bytes().chunks(2) {
+                let byte = core::str::from_utf8(pair)
+                    .ok()
+                    .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                    .ok_or_else(|| invalid_line(idx, "Invalid hexadecimal byte"))?;
+
+                bytes.push(byte);
+                line_len += 1;
+            }
+        }
+    }
+
+    check_len(bytes)
+}
+
+fn base64_value(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' | b'-' => Some(62),
+        b'/' | b'_' => Some(63),
+        _ => None,
+    }
+}
+
+/// Parses an EDID from its base64 representation.
+///
+/// Both the standard and URL-safe alphabets are accepted, with or without padding. Whitespace is
+/// ignored, so the output of `base64` wrapped over multiple lines or the `<data>` content of a
+/// macOS `ioreg -a` property list can be passed directly.
+///
+/// ```
+/// let bytes = redid::parse_base64("AP///////wA=");
+///
+/// // The EDID header alone isn't a complete EDID.
+/// assert!(bytes.is_err());
+/// ```
+///
+/// # Errors
+///
+/// If the text isn't valid base64, or if the bytes don't add up to complete EDID blocks.
+pub fn parse_base64(text: &str) -> Result<Vec<u8>, EdidTypeConversionError<String>> {
+    let text = text.trim_end_matches(|c: char| c == '=' || c.is_ascii_whitespace());
+
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+
+    for c in text.bytes().filter(|c| !c.is_ascii_whitespace()) {
+        let value = base64_value(c).ok_or_else(|| {
+            EdidTypeConversionError::Value(format!("Invalid base64 character: {:?}", char::from(c)))
+        })?;
+
+        acc = (acc << 6) | u32::from(value);
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push(u8::try_from((acc >> bits) & 0xff)?);
+        }
+    }
+
+    if bits >= 6 {
+        return Err(EdidTypeConversionError::Value(String::from(
+            "Truncated base64 input",
+        )));
+    }
+
+    check_len(bytes)
+}
+
+/// Loads a raw, binary, EDID from a file.
+///
+/// This is the format the kernel exposes the EDID of each connector in, in
+/// `/sys/class/drm/*/edid`, and the one `edid-decode` and most tools expect.
+///
+/// # Errors
+///
+/// If the file can't be read, or if it doesn't hold complete EDID blocks. This is typically the
+/// case for the sysfs file of a disconnected connector, which is empty.
+pub fn load_raw<P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
+    let bytes = fs::read(path)?;
+
+    check_len(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_parse_hex {
+    use super::parse_hex;
+    use crate::{edid_fixtures, edid_preset_avr, CecAddress, EdidManufacturer, IntoBytes};
+
+    fn edid() -> Vec<u8> {
+        let fixture = edid_fixtures()
+            .into_iter()
+            .find(|f| f.bytes().len() > 128)
+            .unwrap();
+
+        fixture.bytes().to_vec()
+    }
+
+    fn hex(bytes: &[u8], sep: &str) -> Vec<String> {
+        bytes
+            .chunks(16)
+            .map(|line| {
+                line.iter()
+                    .map(|b| format!("{b:02x}"))
+                    .collect::<Vec<_>>()
+                    .join(sep)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_continuous() {
+        let bytes = edid();
+
+        assert_eq!(parse_hex(&hex(&bytes, "").concat()).unwrap(), bytes);
+
+        let xrandr = format!("\tEDID:\n\t\t{}\n", hex(&bytes, "").join("\n\t\t"));
+        assert_eq!(parse_hex(&xrandr).unwrap(), bytes);
+
+        let ioreg = format!(
+            "    | |   \"IODisplayEDID\" = <{}>",
+            hex(&bytes, "").concat()
+        );
+        assert_eq!(parse_hex(&ioreg).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_edid_decode() {
+        let edid = edid_preset_avr(
+            EdidManufacturer::try_from("RDD").unwrap(),
+            0x1234.into(),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        );
+
+        assert_eq!(
+            parse_hex(&edid.dump().to_string()).unwrap(),
+            edid.into_bytes()
+        );
+    }
+
+    #[test]
+    fn test_xxd() {
+        let bytes = edid();
+        let xxd: Vec<_> = bytes
+            .chunks(16)
+            .enumerate()
+            .map(|(idx, line)| {
+                let groups: Vec<_> = line.chunks(2).flat_map(|g| hex(g, "")).collect();
+                let ascii: String = line
+                    .iter()
+                    .map(|b| {
+                        if b.is_ascii_graphic() {
+                            char::from(*b)
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect();
+
+                format!("{:08x}: {}  {ascii}", idx * 16, groups.join(" "))
+            })
+            .collect();
+
+        assert_eq!(parse_hex(&xxd.join("\n")).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_hexdump() {
+        let bytes = edid();
+        let mut dump = Vec::new();
+        let mut previous = None;
+        for (idx, line) in hex(&bytes, " ").into_iter().enumerate() {
+            if previous.as_ref() == Some(&line) {
+                if dump.last().map(String::as_str) != Some("*") {
+                    dump.push(String::from("*"));
+                }
+                continue;
+            }
+
+            dump.push(format!("{:08x}  {line}  |................|", idx * 16));
+            previous = Some(line);
+        }
+        dump.push(format!("{:08x}", bytes.len()));
+
+        // The extension padding is collapsed.
+        assert!(dump.contains(&String::from("*")));
+
+        assert_eq!(parse_hex(&dump.join("\n")).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let bytes = edid();
+
+        assert!(parse_hex("").is_err());
+        assert!(parse_hex(&hex(&bytes[..100], "").concat()).is_err());
+        assert!(parse_hex(&format!("{}0", hex(&bytes, "").concat())).is_err());
+        assert!(parse_hex(&format!("{}\nNot an EDID", hex(&bytes, " ").join("\n"))).is_err());
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_parse_base64 {
+    use super::parse_base64;
+    use crate::edid_fixtures;
+
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    fn encode(bytes: &[u8]) -> String {
+        let mut out = String::new();
+
+        for chunk in bytes.chunks(3) {
+            let mut buf = [0u8; 3];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            let acc = u32::from(buf[0]) << 16 | u32::from(buf[1]) << 8 | u32::from(buf[2]);
+
+            for idx in 0..4 {
+                if idx <= chunk.len() {
+                    out.push(char::from(
+                        ALPHABET[((acc >> (18 - idx * 6)) & 0x3f) as usize],
+                    ));
+                } else {
+                    out.push('=');
+                }
+            }
+        }
+
+        out
+    }
+
+    #[test]
+    fn test_fixtures() {
+        for fixture in edid_fixtures() {
+            let bytes = fixture.bytes();
+
+            assert_eq!(parse_base64(&encode(bytes)).unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn test_wrapped() {
+        let bytes = edid_fixtures()[0].bytes().to_vec();
+        let encoded = encode(&bytes);
+        let wrapped = encoded
+            .as_bytes()
+            .chunks(76)
+            .map(|line| core::str::from_utf8(line).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(parse_base64(&format!("\t{wrapped}\n")).unwrap(), bytes);
+        assert_eq!(parse_base64(encoded.trim_end_matches('=')).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let bytes = edid_fixtures()[0].bytes().to_vec();
+        let encoded = encode(&bytes);
+
+        assert!(parse_base64("").is_err());
+        assert!(parse_base64(&encode(&bytes[..100])).is_err());
+        assert!(parse_base64(&format!("{encoded}A")).is_err());
+        assert!(parse_base64(&encoded.replace('A', "*")).is_err());
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_load_raw {
+    use std::{env, fs, process};
+
+    use super::load_raw;
+    use crate::edid_fixtures;
+
+    #[test]
+    fn test_load() {
+        let dir = env::temp_dir();
+
+        let path = dir.join(format!("redid-load-raw-{}.bin", process::id()));
+        let bytes = edid_fixtures()[0].bytes().to_vec();
+        fs::write(&path, &bytes).unwrap();
+        let loaded = load_raw(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap(), bytes);
+
+        // A disconnected connector exposes an empty file.
+        let path = dir.join(format!("redid-load-raw-empty-{}.bin", process::id()));
+        fs::write(&path, []).unwrap();
+        let loaded = load_raw(&path);
+        fs::remove_file(&path).unwrap();
+        assert!(loaded.is_err());
+
+        assert!(load_raw(dir.join("redid-does-not-exist")).is_err());
+    }
+}
@@ -0,0 +1,456 @@
+//! Ready-made EDIDs for common kinds of sinks.
+//!
+//! Unlike the fixtures, presets are returned as structured EDIDs, so they can be inspected,
+//! adjusted through their conveniences, and validated before being serialized.
+
+use crate::{
+    CecAddress, EdidChromaticityPoint, EdidChromaticityPoints, EdidDescriptorDetailedTiming,
+    EdidDescriptorString, EdidDetailedTimingSizeMm, EdidDisplayTransferCharacteristics,
+    EdidEstablishedTiming, EdidExtension, EdidExtensionCTA861, EdidExtensionCTA861AudioDataBlock,
+    EdidExtensionCTA861AudioDataBlockChannels, EdidExtensionCTA861AudioDataBlockDesc,
+    EdidExtensionCTA861AudioDataBlockLPCM, EdidExtensionCTA861AudioDataBlockSamplingFrequency,
+    EdidExtensionCTA861AudioDataBlockSamplingRate, EdidExtensionCTA861Hdmi14bDataBlockVideo,
+    EdidExtensionCTA861HdmiDataBlock, EdidExtensionCTA861NativeFormats,
+    EdidExtensionCTA861Revision3, EdidExtensionCTA861Revision3DataBlock,
+    EdidExtensionCTA861SpeakerAllocationDataBlock, EdidExtensionCTA861VideoDataBlock,
+    EdidFilterChromaticity, EdidManufacturer, EdidProductCode,
+    EdidR4BasicDisplayParametersFeatures, EdidR4Date, EdidR4Descriptor, EdidR4DigitalColorDepth,
+    EdidR4DigitalInterface, EdidR4DigitalVideoInputDefinition, EdidR4DisplayColor,
+    EdidR4DisplayColorEncoding, EdidR4FeatureSupport, EdidR4ImageSize, EdidR4ManufactureDate,
+    EdidR4VideoInputDefinition, EdidRelease4, EdidScreenSize, EdidScreenSizeLength,
+};
+
+fn display_parameters_features(size: EdidR4ImageSize) -> EdidR4BasicDisplayParametersFeatures {
+    EdidR4BasicDisplayParametersFeatures::builder()
+        .video_input(EdidR4VideoInputDefinition::Digital(
+            EdidR4DigitalVideoInputDefinition::builder()
+                .color_depth(EdidR4DigitalColorDepth::Depth8Bpc)
+                .interface(EdidR4DigitalInterface::HDMIa)
+                .build(),
+        ))
+        .size(size)
+        .display_transfer_characteristic(
+            EdidDisplayTransferCharacteristics::try_from(2.2).expect("Preset gamma is invalid"),
+        )
+        .feature_support(
+            EdidR4FeatureSupport::builder()
+                .color(EdidR4DisplayColor::Digital(
+                    EdidR4DisplayColorEncoding::RGB444,
+                ))
+                .srgb_default_color_space(true)
+                .preferred_timing_mode_is_native(true)
+                .build(),
+        )
+        .build()
+}
+
+fn lpcm(channels: u8) -> EdidExtensionCTA861AudioDataBlockDesc {
+    EdidExtensionCTA861AudioDataBlockDesc::LPCM(
+        EdidExtensionCTA861AudioDataBlockLPCM::builder()
+            .channels(
+                EdidExtensionCTA861AudioDataBlockChannels::try_from(channels)
+                    .expect("Preset channels count is invalid"),
+            )
+            .sampling_frequencies(vec![
+                EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency32kHz,
+                EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency44_1kHz,
+                EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency48kHz,
+                EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency88_2kHz,
+                EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency96kHz,
+                EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency176_4kHz,
+                EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency192kHz,
+            ])
+            .sampling_rates(vec![
+                EdidExtensionCTA861AudioDataBlockSamplingRate::Rate16Bit,
+                EdidExtensionCTA861AudioDataBlockSamplingRate::Rate20Bit,
+                EdidExtensionCTA861AudioDataBlockSamplingRate::Rate24Bit,
+            ])
+            .build(),
+    )
+}
+
+/// Creates the EDID of an audio-only HDMI sink, such as an Audio/Video Receiver.
+///
+/// The only video mode supported is the mandatory 640x480, 60Hz, one. The CTA-861 extension
+/// advertises basic audio support, 2 and 8 channels L-PCM up to 192kHz and 24 bits, a 7.1
+/// speaker allocation, and an HDMI Vendor-Specific Data Block with the given physical address
+/// and ACP, ISRC1 and ISRC2 packets support.
+///
+/// # Panics
+///
+/// Never, the preset is always valid.
+#[must_use]
+pub fn edid_preset_avr(
+    manufacturer: EdidManufacturer,
+    product_code: EdidProductCode,
+    physical_address: CecAddress,
+) -> EdidRelease4 {
+    let size = EdidDetailedTimingSizeMm::try_from(0).expect("Preset size is invalid");
+
+    EdidRelease4::builder()
+        .manufacturer(manufacturer)
+        .product_code(product_code)
+        .date(EdidR4Date::Manufacture(
+            EdidR4ManufactureDate::try_from((1, 2024)).expect("Preset date is invalid"),
+        ))
+        .display_parameters_features(display_parameters_features(EdidR4ImageSize::Undefined))
+        .filter_chromaticity(EdidFilterChromaticity::Color(EdidChromaticityPoints::srgb()))
+        .established_timings(vec![EdidEstablishedTiming::ET_640_480_60hz])
+        .descriptors(vec![
+            EdidR4Descriptor::DetailedTiming(EdidDescriptorDetailedTiming::cta_640x480p_60hz(
+                size, size,
+            )),
+            EdidR4Descriptor::ProductName(
+                EdidDescriptorString::try_from("redid AVR").expect("Preset name is invalid"),
+            ),
+        ])
+        .extensions(vec![EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+            EdidExtensionCTA861Revision3::builder()
+                .native_formats(
+                    EdidExtensionCTA861NativeFormats::try_from(1)
+                        .expect("Preset native formats count is invalid"),
+                )
+                .audio_supported(true)
+                .add_data_block(EdidExtensionCTA861Revision3DataBlock::Video(
+                    EdidExtensionCTA861VideoDataBlock::builder()
+                        .add_native_short_video_descriptor(1)
+                        .build()
+                        .expect("Preset Video Data Block is invalid"),
+                ))
+                .add_data_block(EdidExtensionCTA861Revision3DataBlock::Audio(
+                    EdidExtensionCTA861AudioDataBlock::builder()
+                        .add_short_audio_descriptor(lpcm(2))
+                        .add_short_audio_descriptor(lpcm(8))
+                        .build()
+                        .expect("Preset Audio Data Block is invalid"),
+                ))
+                .add_data_block(EdidExtensionCTA861Revision3DataBlock::SpeakerAllocation(
+                    EdidExtensionCTA861SpeakerAllocationDataBlock::builder()
+                        .front_left_front_right()
+                        .low_frequency_effects()
+                        .front_center()
+                        .back_left_back_right()
+                        .rear_left_of_center_rear_right_of_center()
+                        .build(),
+                ))
+                .add_data_block(EdidExtensionCTA861Revision3DataBlock::HDMI(
+                    EdidExtensionCTA861HdmiDataBlock::builder()
+                        .source_physical_address(physical_address)
+                        .acp_isrc(true)
+                        .build(),
+                ))
+                .build(),
+        ))])
+        .build()
+        .expect("Preset is invalid")
+}
+
+/// Creates the EDID of a 1080p HDMI 1.4b TV supporting stereoscopic 3D.
+///
+/// The preferred timing is 1920x1080, 60Hz, and the Video Data Block lists the 1080p, 1080i and
+/// 720p formats at 24, 50 and 60Hz, which covers the 2D formats of every 3D format mandatory for
+/// HDMI 1.4b sinks. The HDMI Vendor-Specific Data Block has the given physical address and the
+/// `3D_present` bit set, advertising support of Frame Packing and Top-and-Bottom for 1080p24 and
+/// 720p, and Side-by-Side (Half) for 1080i. Audio is limited to 2 channels L-PCM.
+///
+/// # Panics
+///
+/// Never, the preset is always valid.
+#[must_use]
+pub fn edid_preset_3d_tv(
+    manufacturer: EdidManufacturer,
+    product_code: EdidProductCode,
+    physical_address: CecAddress,
+) -> EdidRelease4 {
+    let hsize = EdidDetailedTimingSizeMm::try_from(1209).expect("Preset size is invalid");
+    let vsize = EdidDetailedTimingSizeMm::try_from(680).expect("Preset size is invalid");
+
+    let mut vdb =
+        EdidExtensionCTA861VideoDataBlock::builder().add_native_short_video_descriptor(16);
+    // 1, 4, 5, 19, 20, 31 and 32 are respectively 640x480p60, 720p60, 1080i60, 720p50,
+    // 1080i50, 1080p50 and 1080p24.
+    for vic in [1, 4, 5, 19, 20, 31, 32] {
+        vdb = vdb.add_short_video_descriptor(vic);
+    }
+
+    EdidRelease4::builder()
+        .manufacturer(manufacturer)
+        .product_code(product_code)
+        .date(EdidR4Date::Manufacture(
+            EdidR4ManufactureDate::try_from((1, 2024)).expect("Preset date is invalid"),
+        ))
+        .display_parameters_features(display_parameters_features(EdidR4ImageSize::Size(
+            EdidScreenSize::builder()
+                .horizontal_cm(EdidScreenSizeLength::try_from(121).expect("Preset size is invalid"))
+                .vertical_cm(EdidScreenSizeLength::try_from(68).expect("Preset size is invalid"))
+                .build(),
+        )))
+        .filter_chromaticity(EdidFilterChromaticity::Color(EdidChromaticityPoints::srgb()))
+        .established_timings(vec![EdidEstablishedTiming::ET_640_480_60hz])
+        .descriptors(vec![
+            EdidR4Descriptor::DetailedTiming(EdidDescriptorDetailedTiming::cta_1080p_60hz(
+                hsize, vsize,
+            )),
+            EdidR4Descriptor::DetailedTiming(EdidDescriptorDetailedTiming::cta_720p_60hz(
+                hsize, vsize,
+            )),
+            EdidR4Descriptor::ProductName(
+                EdidDescriptorString::try_from("redid 3D TV").expect("Preset name is invalid"),
+            ),
+        ])
+        .extensions(vec![EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+            EdidExtensionCTA861Revision3::builder()
+                .native_formats(
+                    EdidExtensionCTA861NativeFormats::try_from(1)
+                        .expect("Preset native formats count is invalid"),
+                )
+                .audio_supported(true)
+                .add_data_block(EdidExtensionCTA861Revision3DataBlock::Video(
+                    vdb.build().expect("Preset Video Data Block is invalid"),
+                ))
+                .add_data_block(EdidExtensionCTA861Revision3DataBlock::Audio(
+                    EdidExtensionCTA861AudioDataBlock::builder()
+                        .add_short_audio_descriptor(lpcm(2))
+                        .build()
+                        .expect("Preset Audio Data Block is invalid"),
+                ))
+                .add_data_block(EdidExtensionCTA861Revision3DataBlock::SpeakerAllocation(
+                    EdidExtensionCTA861SpeakerAllocationDataBlock::builder()
+                        .front_left_front_right()
+                        .build(),
+                ))
+                .add_data_block(EdidExtensionCTA861Revision3DataBlock::HDMI(
+                    EdidExtensionCTA861HdmiDataBlock::builder()
+                        .source_physical_address(physical_address)
+                        .video(
+                            EdidExtensionCTA861Hdmi14bDataBlockVideo::builder()
+                                .three_d_present(true)
+                                .build()
+                                .expect("Preset HDMI video capabilities are invalid"),
+                        )
+                        .build(),
+                ))
+                .build(),
+        ))])
+        .build()
+        .expect("Preset is invalid")
+}
+
+/// Creates the EDID of a 3 megapixels grayscale medical display, connected through
+/// `DisplayPort`.
+///
+/// The display is monochrome: the chromaticity only has a D65 white point, the only Color
+/// Encoding Format is RGB 4:4:4 and the sRGB Standard isn't the default color space. It
+/// reports a 10 bits per color component input, and its preferred timing is 1536x2048, 60Hz,
+/// with CVT reduced blanking. The 640x480, 60Hz, mode every `DisplayPort` sink must support is
+/// also listed. Since such displays aren't meant to be used with
+/// video sources, there's no CTA-861 extension.
+///
+/// # Panics
+///
+/// Never, the preset is always valid.
+#[must_use]
+pub fn edid_preset_monochrome_medical(
+    manufacturer: EdidManufacturer,
+    product_code: EdidProductCode,
+) -> EdidRelease4 {
+    let hsize = EdidDetailedTimingSizeMm::try_from(324).expect("Preset size is invalid");
+    let vsize = EdidDetailedTimingSizeMm::try_from(432).expect("Preset size is invalid");
+
+    EdidRelease4::builder()
+        .manufacturer(manufacturer)
+        .product_code(product_code)
+        .date(EdidR4Date::Manufacture(
+            EdidR4ManufactureDate::try_from((1, 2024)).expect("Preset date is invalid"),
+        ))
+        .display_parameters_features(
+            EdidR4BasicDisplayParametersFeatures::builder()
+                .video_input(EdidR4VideoInputDefinition::Digital(
+                    EdidR4DigitalVideoInputDefinition::builder()
+                        .color_depth(EdidR4DigitalColorDepth::Depth10Bpc)
+                        .interface(EdidR4DigitalInterface::DisplayPort)
+                        .build(),
+                ))
+                .size(EdidR4ImageSize::Size(
+                    EdidScreenSize::builder()
+                        .horizontal_cm(
+                            EdidScreenSizeLength::try_from(32).expect("Preset size is invalid"),
+                        )
+                        .vertical_cm(
+                            EdidScreenSizeLength::try_from(43).expect("Preset size is invalid"),
+                        )
+                        .build(),
+                ))
+                .display_transfer_characteristic(
+                    EdidDisplayTransferCharacteristics::try_from(2.2)
+                        .expect("Preset gamma is invalid"),
+                )
+                .feature_support(
+                    EdidR4FeatureSupport::builder()
+                        .color(EdidR4DisplayColor::Digital(
+                            EdidR4DisplayColorEncoding::RGB444,
+                        ))
+                        .preferred_timing_mode_is_native(true)
+                        .build(),
+                )
+                .build(),
+        )
+        .filter_chromaticity(EdidFilterChromaticity::MonoChrome(
+            EdidChromaticityPoint::try_from((0.3127, 0.3290))
+                .expect("Preset white point is invalid"),
+        ))
+        .with_vga_fallback()
+        .descriptors(vec![
+            EdidR4Descriptor::DetailedTiming(
+                EdidDescriptorDetailedTiming::cvt_rb2(1536, 2048, 60, hsize, vsize)
+                    .expect("Preset timing is invalid"),
+            ),
+            EdidR4Descriptor::ProductName(
+                EdidDescriptorString::try_from("redid Mono").expect("Preset name is invalid"),
+            ),
+        ])
+        .build()
+        .expect("Preset is invalid")
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_presets {
+    use super::{edid_preset_3d_tv, edid_preset_avr, edid_preset_monochrome_medical};
+    use crate::{
+        CecAddress, EdidExtension, EdidExtensionCTA861, EdidExtensionCTA861HdmiDataBlock,
+        EdidExtensionCTA861Revision3, EdidExtensionCTA861Revision3DataBlock, EdidManufacturer,
+        EdidProductCode, EdidValidationError, IntoBytes, EDID_BASE_LEN,
+    };
+
+    #[test]
+    fn test_avr() {
+        let edid = edid_preset_avr(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+            CecAddress::try_from([2, 0, 0, 0]).unwrap(),
+        );
+        assert_eq!(edid.validate(), Ok(()));
+        assert!(edid
+            .all_timings()
+            .all(|t| (t.horizontal(), t.vertical(), t.refresh_rate()) == (640, 480, 60)));
+
+        let bytes = edid.into_bytes();
+        assert_eq!(bytes.len(), 2 * EDID_BASE_LEN);
+
+        // 640x480@60Hz Established Timing
+        assert_eq!(&bytes[0x23..0x26], &[0x20, 0x00, 0x00]);
+
+        let cta = &bytes[EDID_BASE_LEN..];
+        assert!(cta.len() > 24);
+        // Basic audio support, one native format
+        assert_eq!(cta[3], 0x41);
+        // VIC 1, native
+        assert_eq!(&cta[4..6], &[0x41, 0x81]);
+        // 2 and 8 channels L-PCM
+        assert_eq!(&cta[6..13], &[0x26, 0x09, 0x7f, 0x07, 0x0f, 0x7f, 0x07]);
+        // Speaker allocation: FL/FR, LFE, FC, RL/RR, RLC/RRC
+        assert_eq!(&cta[13..17], &[0x83, 0x4f, 0x00, 0x00]);
+        // HDMI VSDB, physical address 2.0.0.0, ACP / ISRC
+        assert_eq!(&cta[17..24], &[0x66, 0x03, 0x0c, 0x00, 0x20, 0x00, 0x80]);
+    }
+
+    #[test]
+    fn test_3d_tv() {
+        let edid = edid_preset_3d_tv(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        );
+        assert_eq!(edid.validate(), Ok(()));
+
+        let bytes = edid.into_bytes();
+        assert_eq!(bytes.len(), 2 * EDID_BASE_LEN);
+
+        let cta = &bytes[EDID_BASE_LEN..];
+        assert!(cta.len() > 32);
+        // 1080p60, native, followed by the other formats
+        assert_eq!(
+            &cta[4..13],
+            &[0x48, 0x90, 0x01, 0x04, 0x05, 0x13, 0x14, 0x1f, 0x20]
+        );
+        // HDMI VSDB, physical address 1.0.0.0, HDMI Video present, 3D present
+        assert_eq!(
+            &cta[21..32],
+            &[0x6a, 0x03, 0x0c, 0x00, 0x10, 0x00, 0x00, 0x00, 0x20, 0x80, 0x00]
+        );
+    }
+
+    #[test]
+    fn test_monochrome_medical() {
+        let edid = edid_preset_monochrome_medical(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+        );
+        assert_eq!(edid.validate(), Ok(()));
+        assert_eq!(edid.warnings(), vec![]);
+        assert_eq!(
+            edid.all_timings()
+                .map(|t| (t.horizontal(), t.vertical(), t.refresh_rate()))
+                .collect::<Vec<_>>(),
+            vec![(640, 480, 60), (1536, 2048, 60)]
+        );
+
+        let bytes = edid.into_bytes();
+        assert_eq!(bytes.len(), EDID_BASE_LEN);
+
+        // Digital, 10 bpc, DisplayPort
+        assert_eq!(bytes[0x14], 0xb5);
+        // RGB 4:4:4 only, not sRGB, preferred timing is native
+        assert_eq!(bytes[0x18] & 0x1e, 0x02);
+        // Only the white point is set
+        assert_eq!(
+            &bytes[0x19..0x23],
+            &[0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x50, 0x54]
+        );
+    }
+
+    #[test]
+    fn test_physical_address() {
+        let hdmi = |address| {
+            EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+                EdidExtensionCTA861Revision3::builder()
+                    .add_data_block(EdidExtensionCTA861Revision3DataBlock::HDMI(
+                        EdidExtensionCTA861HdmiDataBlock::builder()
+                            .source_physical_address(CecAddress::try_from(address).unwrap())
+                            .build(),
+                    ))
+                    .build(),
+            ))
+        };
+
+        let mut edid = edid_preset_avr(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+            CecAddress::try_from([2, 0, 0, 0]).unwrap(),
+        );
+        edid.extensions.push(hdmi([3, 1, 0, 0]));
+
+        assert_eq!(
+            edid.source_physical_address(),
+            Some(CecAddress::try_from([2, 0, 0, 0]).unwrap())
+        );
+        assert_eq!(
+            edid.validate(),
+            Err(EdidValidationError::PhysicalAddressMismatch(1))
+        );
+
+        let edid = edid.with_source_physical_address(CecAddress::try_from([1, 2, 3, 4]).unwrap());
+        assert_eq!(edid.validate(), Ok(()));
+
+        let bytes = edid.into_bytes();
+        assert_eq!(
+            &bytes[EDID_BASE_LEN + 21..EDID_BASE_LEN + 23],
+            &[0x12, 0x34]
+        );
+        assert_eq!(
+            &bytes[2 * EDID_BASE_LEN + 8..2 * EDID_BASE_LEN + 10],
+            &[0x12, 0x34]
+        );
+    }
+}
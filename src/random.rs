@@ -0,0 +1,557 @@
+//! Deterministic generation of pseudo-random, valid, EDIDs.
+//!
+//! The generated EDIDs are meant to be fed to the property-based tests of EDID consumers: they
+//! cover a wide range of manufacturers, dates, sizes, timings and capabilities, but the same seed
+//! and profile always generate the same EDID, so that failures can be reproduced.
+
+use core::ops::RangeInclusive;
+
+use crate::{
+    CecAddress, EdidChromaticityPoint, EdidChromaticityPoints, EdidDescriptorDetailedTiming,
+    EdidDescriptorString, EdidDetailedTimingSizeMm, EdidDisplayTransferCharacteristics,
+    EdidEstablishedTiming, EdidExtension, EdidExtensionCTA861, EdidExtensionCTA861AudioDataBlock,
+    EdidExtensionCTA861AudioDataBlockChannels, EdidExtensionCTA861AudioDataBlockDesc,
+    EdidExtensionCTA861AudioDataBlockLPCM, EdidExtensionCTA861AudioDataBlockSamplingFrequency,
+    EdidExtensionCTA861AudioDataBlockSamplingRate, EdidExtensionCTA861HdmiDataBlock,
+    EdidExtensionCTA861NativeFormats, EdidExtensionCTA861Revision3,
+    EdidExtensionCTA861Revision3DataBlock, EdidExtensionCTA861SpeakerAllocationDataBlock,
+    EdidExtensionCTA861VideoDataBlock, EdidFilterChromaticity, EdidManufacturer, EdidProductCode,
+    EdidR4BasicDisplayParametersFeatures, EdidR4Date, EdidR4Descriptor, EdidR4DigitalColorDepth,
+    EdidR4DigitalInterface, EdidR4DigitalVideoInputDefinition, EdidR4DisplayColor,
+    EdidR4DisplayColorEncoding, EdidR4DisplayRangeLimits, EdidR4FeatureSupport, EdidR4ImageSize,
+    EdidR4ManufactureDate, EdidR4ModelDate, EdidR4VideoInputDefinition, EdidRelease4,
+    EdidScreenSize, EdidScreenSizeLength, EdidSerialNumber, EdidSerialNumberFormat,
+    EdidStandardTiming, EdidStandardTimingHorizontalSize, EdidStandardTimingRatio,
+    EdidStandardTimingRefreshRate,
+};
+
+/// The kind of display to generate an EDID for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EdidRandomProfile {
+    /// A computer monitor, connected through `DisplayPort` or DVI, without any extension. Its
+    /// preferred timing is a CVT Reduced Blanking v2 one, and it might report its range limits
+    /// and continuous frequency support.
+    Monitor,
+
+    /// An HDMI TV, with a CTA-861 extension listing CTA-861 video formats, an HDMI
+    /// Vendor-Specific Data Block and possibly audio support.
+    Television,
+}
+
+/// A `SplitMix64` generator. It's small, fast and good enough to explore the EDID space, and
+/// doesn't pull any dependency.
+struct EdidRandom(u64);
+
+impl EdidRandom {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    fn range(&mut self, range: RangeInclusive<u32>) -> u32 {
+        let (start, end) = range.into_inner();
+        let len = u64::from(end - start) + 1;
+
+        start + u32::try_from(self.next_u64() % len).expect("Random value doesn't fit")
+    }
+
+    fn index(&mut self, len: usize) -> usize {
+        let len = u64::try_from(len).expect("Length doesn't fit");
+
+        usize::try_from(self.next_u64() % len).expect("Random index doesn't fit")
+    }
+
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.index(items.len())]
+    }
+
+    fn subset<T: Copy>(&mut self, items: &[T], max: usize) -> Vec<T> {
+        let mut items = items.to_vec();
+        let count = self.index(max.min(items.len()) + 1);
+
+        (0..count)
+            .map(|_| items.swap_remove(self.index(items.len())))
+            .collect()
+    }
+}
+
+const MONITOR_MODES: [(u16, u16); 10] = [
+    (1280, 720),
+    (1280, 1024),
+    (1366, 768),
+    (1600, 900),
+    (1680, 1050),
+    (1920, 1080),
+    (1920, 1200),
+    (2560, 1440),
+    (3440, 1440),
+    (3840, 2160),
+];
+
+const MONITOR_REFRESH_RATES: [u16; 6] = [50, 60, 75, 120, 144, 165];
+
+const ESTABLISHED_TIMINGS: [EdidEstablishedTiming; 17] = [
+    EdidEstablishedTiming::ET_1024_768_60hz,
+    EdidEstablishedTiming::ET_1024_768_70hz,
+    EdidEstablishedTiming::ET_1024_768_75hz,
+    EdidEstablishedTiming::ET_1024_768_87hz_Interlaced,
+    EdidEstablishedTiming::ET_1152_870_75hz,
+    EdidEstablishedTiming::ET_1280_1024_75hz,
+    EdidEstablishedTiming::ET_640_480_60hz,
+    EdidEstablishedTiming::ET_640_480_67hz,
+    EdidEstablishedTiming::ET_640_480_72hz,
+    EdidEstablishedTiming::ET_640_480_75hz,
+    EdidEstablishedTiming::ET_720_400_70hz,
+    EdidEstablishedTiming::ET_720_400_88hz,
+    EdidEstablishedTiming::ET_800_600_56hz,
+    EdidEstablishedTiming::ET_800_600_60hz,
+    EdidEstablishedTiming::ET_800_600_72hz,
+    EdidEstablishedTiming::ET_800_600_75hz,
+    EdidEstablishedTiming::ET_832_624_75hz,
+];
+
+const STANDARD_TIMINGS: [(u16, EdidStandardTimingRatio, u8); 8] = [
+    (1152, EdidStandardTimingRatio::Ratio_4_3, 75),
+    (1280, EdidStandardTimingRatio::Ratio_16_10, 60),
+    (1280, EdidStandardTimingRatio::Ratio_5_4, 60),
+    (1440, EdidStandardTimingRatio::Ratio_16_10, 60),
+    (1600, EdidStandardTimingRatio::Ratio_4_3, 60),
+    (1680, EdidStandardTimingRatio::Ratio_16_10, 60),
+    (1920, EdidStandardTimingRatio::Ratio_16_9, 60),
+    (1920, EdidStandardTimingRatio::Ratio_16_10, 60),
+];
+
+// 640x480p60, 480p60, 720p60, 1080i60, 720p50, 1080i50, 1080p50, 1080p24, 1080p25 and 1080p30
+const TELEVISION_VICS: [u8; 11] = [1, 2, 3, 4, 5, 19, 20, 31, 32, 33, 34];
+
+const COLOR_DEPTHS: [EdidR4DigitalColorDepth; 4] = [
+    EdidR4DigitalColorDepth::Depth6Bpc,
+    EdidR4DigitalColorDepth::Depth8Bpc,
+    EdidR4DigitalColorDepth::Depth10Bpc,
+    EdidR4DigitalColorDepth::Depth12Bpc,
+];
+
+const COLOR_ENCODINGS: [EdidR4DisplayColorEncoding; 4] = [
+    EdidR4DisplayColorEncoding::RGB444,
+    EdidR4DisplayColorEncoding::RGB444YCbCr444,
+    EdidR4DisplayColorEncoding::RGB444YCbCr422,
+    EdidR4DisplayColorEncoding::RGB444YCbCr444YCbCr422,
+];
+
+fn manufacturer(rng: &mut EdidRandom) -> EdidManufacturer {
+    let mut id = [0; 3];
+    for c in &mut id {
+        *c = b'A' + u8::try_from(rng.range(0..=25)).expect("Letter doesn't fit");
+    }
+
+    EdidManufacturer::from_ascii(&id)
+}
+
+fn date(rng: &mut EdidRandom) -> EdidR4Date {
+    let year = u16::try_from(rng.range(1990..=2024)).expect("Year doesn't fit");
+
+    if rng.range(0..=3) == 0 {
+        EdidR4Date::Model(EdidR4ModelDate::try_from(year).expect("Model year is invalid"))
+    } else {
+        let week = u8::try_from(rng.range(1..=52)).expect("Week doesn't fit");
+
+        EdidR4Date::Manufacture(
+            EdidR4ManufactureDate::try_from((week, year)).expect("Manufacture date is invalid"),
+        )
+    }
+}
+
+fn product_name(rng: &mut EdidRandom) -> EdidDescriptorString {
+    const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+    let len = rng.range(4..=7);
+    let model: String = (0..len).map(|_| char::from(rng.pick(CHARS))).collect();
+
+    EdidDescriptorString::try_from(format!("redid {model}").as_str())
+        .expect("Product name is invalid")
+}
+
+// Returns the screen size in centimeters, with the aspect ratio of the given resolution.
+fn screen_size(rng: &mut EdidRandom, hactive: u16, vactive: u16, min: u32, max: u32) -> (u8, u8) {
+    let width = rng.range(min..=max);
+    let height = (width * u32::from(vactive) + u32::from(hactive) / 2) / u32::from(hactive);
+
+    (
+        u8::try_from(width).expect("Width doesn't fit"),
+        u8::try_from(height.max(1)).expect("Height doesn't fit"),
+    )
+}
+
+fn chromaticity(rng: &mut EdidRandom) -> (bool, EdidChromaticityPoints) {
+    fn point(x: f32, y: f32) -> EdidChromaticityPoint {
+        EdidChromaticityPoint::try_from((x, y)).expect("Chromaticity point is invalid")
+    }
+
+    if rng.range(0..=2) == 0 {
+        // BT.2020 primaries, with a D65 white point.
+        (
+            false,
+            EdidChromaticityPoints::builder()
+                .red(point(0.708, 0.292))
+                .green(point(0.170, 0.797))
+                .blue(point(0.131, 0.046))
+                .white(point(0.3127, 0.3290))
+                .build(),
+        )
+    } else {
+        (rng.bool(), EdidChromaticityPoints::srgb())
+    }
+}
+
+fn display_parameters_features(
+    rng: &mut EdidRandom,
+    interface: EdidR4DigitalInterface,
+    color: EdidR4DisplayColorEncoding,
+    size: EdidScreenSize,
+    srgb: bool,
+    continuous_frequency: bool,
+) -> EdidR4BasicDisplayParametersFeatures {
+    let gamma = f32::from(u8::try_from(rng.range(18..=26)).expect("Gamma doesn't fit")) / 10.0;
+
+    EdidR4BasicDisplayParametersFeatures::builder()
+        .video_input(EdidR4VideoInputDefinition::Digital(
+            EdidR4DigitalVideoInputDefinition::builder()
+                .color_depth(rng.pick(&COLOR_DEPTHS))
+                .interface(interface)
+                .build(),
+        ))
+        .size(EdidR4ImageSize::Size(size))
+        .display_transfer_characteristic(
+            EdidDisplayTransferCharacteristics::try_from(gamma).expect("Gamma is invalid"),
+        )
+        .feature_support(
+            EdidR4FeatureSupport::builder()
+                .active_off_is_very_low_power(rng.bool())
+                .color(EdidR4DisplayColor::Digital(color))
+                .srgb_default_color_space(srgb)
+                .preferred_timing_mode_is_native(true)
+                .continuous_frequency(continuous_frequency)
+                .build(),
+        )
+        .build()
+}
+
+fn monitor(rng: &mut EdidRandom) -> EdidRelease4 {
+    let manufacturer = manufacturer(rng);
+    let product_code = EdidProductCode::new(
+        u16::try_from(rng.range(0..=0xffff)).expect("Product code doesn't fit"),
+    );
+    let date = date(rng);
+
+    let (hactive, vactive) = rng.pick(&MONITOR_MODES);
+    let (width, height) = screen_size(rng, hactive, vactive, 28, 100);
+    let hsize = EdidDetailedTimingSizeMm::try_from(u16::from(width) * 10).expect("Size is invalid");
+    let vsize =
+        EdidDetailedTimingSizeMm::try_from(u16::from(height) * 10).expect("Size is invalid");
+
+    // The highest refresh rates don't fit in a Detailed Timing at the highest resolutions.
+    let dtd = EdidDescriptorDetailedTiming::cvt_rb2(
+        hactive,
+        vactive,
+        rng.pick(&MONITOR_REFRESH_RATES),
+        hsize,
+        vsize,
+    )
+    .or_else(|_e| EdidDescriptorDetailedTiming::cvt_rb2(hactive, vactive, 60, hsize, vsize))
+    .expect("Preferred timing is invalid");
+
+    let mut descriptors = vec![EdidR4Descriptor::DetailedTiming(dtd)];
+
+    let range_limits = rng.bool();
+    let continuous_frequency = range_limits && rng.bool();
+    if range_limits {
+        let limits = if continuous_frequency {
+            EdidR4DisplayRangeLimits::cvt_from_detailed_timing(&dtd)
+        } else {
+            EdidR4DisplayRangeLimits::from_detailed_timing(&dtd)
+        };
+
+        descriptors.push(EdidR4Descriptor::DisplayRangeLimits(
+            limits.expect("Range limits are invalid"),
+        ));
+    }
+
+    descriptors.push(EdidR4Descriptor::ProductName(product_name(rng)));
+
+    let (srgb, chroma) = chromaticity(rng);
+    let interface = rng.pick(&[
+        EdidR4DigitalInterface::DisplayPort,
+        EdidR4DigitalInterface::DVI,
+        EdidR4DigitalInterface::Undefined,
+    ]);
+
+    let standard_timings = rng
+        .subset(&STANDARD_TIMINGS, 8)
+        .into_iter()
+        .map(|(x, ratio, frequency)| {
+            EdidStandardTiming::builder()
+                .x(EdidStandardTimingHorizontalSize::try_from(x)
+                    .expect("Standard Timing size is invalid"))
+                .ratio(ratio)
+                .frequency(
+                    EdidStandardTimingRefreshRate::try_from(frequency)
+                        .expect("Standard Timing frequency is invalid"),
+                )
+                .build()
+        })
+        .collect();
+
+    let builder = EdidRelease4::builder()
+        .manufacturer(manufacturer)
+        .product_code(product_code)
+        .serial_number(
+            rng.bool()
+                .then(|| EdidSerialNumber::from(rng.range(1..=u32::MAX))),
+        )
+        .date(date)
+        .display_parameters_features(display_parameters_features(
+            rng,
+            interface,
+            EdidR4DisplayColorEncoding::RGB444,
+            EdidScreenSize::builder()
+                .horizontal_cm(EdidScreenSizeLength::try_from(width).expect("Size is invalid"))
+                .vertical_cm(EdidScreenSizeLength::try_from(height).expect("Size is invalid"))
+                .build(),
+            srgb,
+            continuous_frequency,
+        ))
+        .filter_chromaticity(EdidFilterChromaticity::Color(chroma))
+        .established_timings(rng.subset(&ESTABLISHED_TIMINGS, ESTABLISHED_TIMINGS.len()))
+        .standard_timings(standard_timings)
+        .descriptors(descriptors)
+        .extensions(Vec::new());
+
+    if rng.bool() {
+        builder
+            .serial_number_string(EdidSerialNumberFormat::Hexadecimal)
+            .build()
+            .expect("Generated EDID is invalid")
+    } else {
+        builder.build().expect("Generated EDID is invalid")
+    }
+}
+
+fn lpcm(channels: u8) -> EdidExtensionCTA861AudioDataBlockDesc {
+    EdidExtensionCTA861AudioDataBlockDesc::LPCM(
+        EdidExtensionCTA861AudioDataBlockLPCM::builder()
+            .channels(
+                EdidExtensionCTA861AudioDataBlockChannels::try_from(channels)
+                    .expect("Channels count is invalid"),
+            )
+            .sampling_frequencies(vec![
+                EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency32kHz,
+                EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency44_1kHz,
+                EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency48kHz,
+            ])
+            .sampling_rates(vec![
+                EdidExtensionCTA861AudioDataBlockSamplingRate::Rate16Bit,
+                EdidExtensionCTA861AudioDataBlockSamplingRate::Rate24Bit,
+            ])
+            .build(),
+    )
+}
+
+fn television(rng: &mut EdidRandom) -> EdidRelease4 {
+    let manufacturer = manufacturer(rng);
+    let product_code = EdidProductCode::new(
+        u16::try_from(rng.range(0..=0xffff)).expect("Product code doesn't fit"),
+    );
+    let date = date(rng);
+
+    let (width, height) = screen_size(rng, 1920, 1080, 50, 200);
+    let hsize = EdidDetailedTimingSizeMm::try_from(u16::from(width) * 10).expect("Size is invalid");
+    let vsize =
+        EdidDetailedTimingSizeMm::try_from(u16::from(height) * 10).expect("Size is invalid");
+
+    let mut descriptors = vec![EdidR4Descriptor::DetailedTiming(
+        EdidDescriptorDetailedTiming::cta_1080p_60hz(hsize, vsize),
+    )];
+
+    if rng.bool() {
+        descriptors.push(EdidR4Descriptor::DetailedTiming(
+            EdidDescriptorDetailedTiming::cta_720p_60hz(hsize, vsize),
+        ));
+    }
+
+    descriptors.push(EdidR4Descriptor::ProductName(product_name(rng)));
+
+    let (srgb, chroma) = chromaticity(rng);
+    let color = rng.pick(&COLOR_ENCODINGS);
+
+    let mut vdb =
+        EdidExtensionCTA861VideoDataBlock::builder().add_native_short_video_descriptor(16);
+    for vic in rng.subset(&TELEVISION_VICS, TELEVISION_VICS.len()) {
+        vdb = vdb.add_short_video_descriptor(vic);
+    }
+
+    let audio = rng.bool();
+    let mut cta = EdidExtensionCTA861Revision3::builder()
+        .ycbcr_422_supported(color.ycbcr_422_supported())
+        .ycbcr_444_supported(color.ycbcr_444_supported())
+        .audio_supported(audio)
+        .underscan_it_formats_by_default(rng.bool())
+        .native_formats(
+            EdidExtensionCTA861NativeFormats::try_from(1)
+                .expect("Random native formats count is invalid"),
+        )
+        .add_data_block(EdidExtensionCTA861Revision3DataBlock::Video(
+            vdb.build().expect("Random Video Data Block is invalid"),
+        ));
+
+    if audio {
+        let mut adb =
+            EdidExtensionCTA861AudioDataBlock::builder().add_short_audio_descriptor(lpcm(2));
+        let speakers =
+            EdidExtensionCTA861SpeakerAllocationDataBlock::builder().front_left_front_right();
+
+        // 5.1 or stereo
+        let speakers = if rng.bool() {
+            adb = adb.add_short_audio_descriptor(lpcm(6));
+            speakers
+                .low_frequency_effects()
+                .front_center()
+                .back_left_back_right()
+                .build()
+        } else {
+            speakers.build()
+        };
+
+        cta = cta
+            .add_data_block(EdidExtensionCTA861Revision3DataBlock::Audio(
+                adb.build().expect("Random Audio Data Block is invalid"),
+            ))
+            .add_data_block(EdidExtensionCTA861Revision3DataBlock::SpeakerAllocation(
+                speakers,
+            ));
+    }
+
+    let port = u8::try_from(rng.range(1..=4)).expect("Port doesn't fit");
+    cta = cta.add_data_block(EdidExtensionCTA861Revision3DataBlock::HDMI(
+        EdidExtensionCTA861HdmiDataBlock::builder()
+            .source_physical_address(
+                CecAddress::try_from([port, 0, 0, 0]).expect("Physical address is invalid"),
+            )
+            .build(),
+    ));
+
+    EdidRelease4::builder()
+        .manufacturer(manufacturer)
+        .product_code(product_code)
+        .serial_number(
+            rng.bool()
+                .then(|| EdidSerialNumber::from(rng.range(1..=u32::MAX))),
+        )
+        .date(date)
+        .display_parameters_features(display_parameters_features(
+            rng,
+            EdidR4DigitalInterface::HDMIa,
+            color,
+            EdidScreenSize::builder()
+                .horizontal_cm(EdidScreenSizeLength::try_from(width).expect("Size is invalid"))
+                .vertical_cm(EdidScreenSizeLength::try_from(height).expect("Size is invalid"))
+                .build(),
+            srgb,
+            false,
+        ))
+        .filter_chromaticity(EdidFilterChromaticity::Color(chroma))
+        .descriptors(descriptors)
+        .extensions(vec![EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+            cta.build(),
+        ))])
+        .build()
+        .expect("Generated EDID is invalid")
+}
+
+/// Generates a pseudo-random EDID for the given profile.
+///
+/// The same seed and profile always generate the same EDID, on every platform. The generated
+/// EDIDs pass [`EdidRelease4::validate`].
+///
+/// ```
+/// use redid::random::{generate, EdidRandomProfile};
+/// use redid::IntoBytes;
+///
+/// for seed in 0..16 {
+///     let edid = generate(seed, EdidRandomProfile::Television);
+///     assert_eq!(edid.validate(), Ok(()));
+///
+///     let _bytes = edid.into_bytes();
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Never, the generated EDIDs are always valid.
+#[must_use]
+pub fn generate(seed: u64, profile: EdidRandomProfile) -> EdidRelease4 {
+    let mut rng = EdidRandom(seed);
+
+    match profile {
+        EdidRandomProfile::Monitor => monitor(&mut rng),
+        EdidRandomProfile::Television => television(&mut rng),
+    }
+}
+
+#[cfg(test)]
+mod test_random {
+    use super::{generate, EdidRandom, EdidRandomProfile};
+    use crate::{utils::assert_size_coherent, Edid, IntoBytes};
+
+    #[test]
+    fn test_generator() {
+        let mut rng = EdidRandom(0);
+        assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+        assert_eq!(rng.next_u64(), 0x6e78_9e6a_a1b9_65f4);
+
+        for _ in 0..1000 {
+            assert!((3..=7).contains(&rng.range(3..=7)));
+        }
+    }
+
+    #[test]
+    fn test_deterministic() {
+        for profile in [EdidRandomProfile::Monitor, EdidRandomProfile::Television] {
+            for seed in 0..32 {
+                assert_eq!(
+                    generate(seed, profile).into_bytes(),
+                    generate(seed, profile).into_bytes()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_valid() {
+        for profile in [EdidRandomProfile::Monitor, EdidRandomProfile::Television] {
+            for seed in 0..256 {
+                let edid = generate(seed, profile);
+                assert_eq!(edid.validate(), Ok(()), "Seed {seed}, {profile:?}");
+                assert_size_coherent(&Edid::from(edid.clone()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_diverse() {
+        let mut edids: Vec<_> = (0..64)
+            .map(|seed| generate(seed, EdidRandomProfile::Monitor).into_bytes())
+            .collect();
+
+        edids.sort();
+        edids.dedup();
+        assert_eq!(edids.len(), 64);
+    }
+}
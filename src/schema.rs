@@ -0,0 +1,96 @@
+use serde::Deserialize;
+
+/// The current version of the serde description format.
+pub const EDID_SCHEMA_VERSION: u32 = 1;
+
+/// A description deserialized along with the version of the format it has been written for.
+///
+/// The description is expected to be found under the `data` key, next to a `schema_version`
+/// key:
+///
+/// ```json
+/// {
+///     "schema_version": 1,
+///     "data": ["640x480@60", "800x600@60"]
+/// }
+/// ```
+///
+/// Descriptions written for any version up to [`EDID_SCHEMA_VERSION`] are accepted. Whenever a
+/// field is renamed, the former name is kept as an alias so that older descriptions still
+/// deserialize.
+///
+/// # Changelog
+///
+/// - Version 1: Initial version, covering the HDMI Vendor-Specific Data Block and the
+///   Established Timings.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EdidSchema<T> {
+    #[serde(deserialize_with = "schema_version")]
+    schema_version: u32,
+    data: T,
+}
+
+impl<T> EdidSchema<T> {
+    /// Returns the version of the format the description has been written for.
+    #[must_use]
+    pub const fn schema_version(&self) -> u32 {
+        self.schema_version
+    }
+
+    /// Returns the description.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+fn schema_version<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let version = u32::deserialize(deserializer)?;
+
+    if !(1..=EDID_SCHEMA_VERSION).contains(&version) {
+        return Err(serde::de::Error::custom(format!(
+            "Unsupported schema version {version} (Range: 1..={EDID_SCHEMA_VERSION})"
+        )));
+    }
+
+    Ok(version)
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_result_states, clippy::unwrap_used)]
+mod test_schema {
+    use crate::{EdidEstablishedTiming, EdidSchema, EDID_SCHEMA_VERSION};
+
+    #[test]
+    fn test_deserialize() {
+        let schema: EdidSchema<Vec<EdidEstablishedTiming>> =
+            serde_json::from_str(r#"{ "schema_version": 1, "data": ["640x480@60"] }"#).unwrap();
+
+        assert_eq!(schema.schema_version(), 1);
+        assert_eq!(
+            schema.into_inner(),
+            vec![EdidEstablishedTiming::ET_640_480_60hz]
+        );
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        for version in [0, EDID_SCHEMA_VERSION + 1] {
+            let json = format!(r#"{{ "schema_version": {version}, "data": [] }}"#);
+
+            assert!(
+                serde_json::from_str::<EdidSchema<Vec<EdidEstablishedTiming>>>(&json).is_err(),
+                "Version {version} should be rejected"
+            );
+        }
+
+        assert!(
+            serde_json::from_str::<EdidSchema<Vec<EdidEstablishedTiming>>>(r#"{ "data": [] }"#)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,181 @@
+//! Metadata to store next to a generated EDID, for traceability.
+
+use crate::{Edid, EdidDump, EdidRelease, EDID_BASE_LEN};
+
+/// Metadata describing how an EDID has been generated.
+///
+/// It records the crate version, a dump of the EDID, the validation results and the checksum of
+/// each block, so that a binary found in the field can be traced back to what it has been
+/// generated from.
+///
+/// The checksums are only recorded for a valid EDID, since an invalid one isn't meant to be
+/// stored, and might not even fit in its blocks.
+///
+/// With the `serde` feature, it can be serialized, typically to a JSON file stored next to
+/// the binary:
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use redid::{edid_preset_avr, CecAddress, EdidManufacturer, EdidProductCode, IntoBytes};
+///
+/// let edid = edid_preset_avr(
+///     EdidManufacturer::from_ascii(b"RED"),
+///     EdidProductCode::new(0x1234),
+///     CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+/// );
+///
+/// let sidecar = serde_json::to_string_pretty(&edid.sidecar()).unwrap();
+/// let bytes = edid.into_bytes();
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EdidSidecar {
+    crate_version: &'static str,
+    edid_version: &'static str,
+    dump: String,
+    error: Option<String>,
+    warnings: Vec<String>,
+    block_checksums: Option<Vec<u8>>,
+}
+
+impl EdidSidecar {
+    /// Returns the version of the crate that generated the EDID.
+    #[must_use]
+    pub const fn crate_version(&self) -> &'static str {
+        self.crate_version
+    }
+
+    /// Returns the EDID structure version, either `1.3` or `1.4`.
+    #[must_use]
+    pub const fn edid_version(&self) -> &'static str {
+        self.edid_version
+    }
+
+    /// Returns a textual description of the EDID, in the [`EdidDump`] layout.
+    #[must_use]
+    pub fn dump(&self) -> &str {
+        &self.dump
+    }
+
+    /// Returns the reason the EDID has been found invalid, if any.
+    #[must_use]
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Returns the validation warnings raised for the EDID.
+    #[must_use]
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Returns the checksum of each block, in order, or `None` if the EDID is invalid.
+    #[must_use]
+    pub fn block_checksums(&self) -> Option<&[u8]> {
+        self.block_checksums.as_deref()
+    }
+}
+
+pub(crate) fn edid_sidecar(edid: &Edid) -> EdidSidecar {
+    let error = edid.validate().err();
+
+    // The dump serializes the EDID if it fits, so its bytes are reused for the checksums.
+    let dump = EdidDump::from(edid.clone());
+    let block_checksums = dump.bytes().filter(|_| error.is_none()).map(|bytes| {
+        bytes
+            .chunks(EDID_BASE_LEN)
+            .map(|block| block[EDID_BASE_LEN - 1])
+            .collect()
+    });
+
+    EdidSidecar {
+        crate_version: env!("CARGO_PKG_VERSION"),
+        edid_version: match edid.release {
+            EdidRelease::R3 => "1.3",
+            EdidRelease::R4 => "1.4",
+        },
+        dump: dump.to_string(),
+        error: error.map(|err| err.to_string()),
+        warnings: edid
+            .warnings()
+            .into_iter()
+            .map(|warning| warning.to_string())
+            .collect(),
+        block_checksums,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_sidecar {
+    use crate::{
+        edid_preset_avr, CecAddress, EdidDescriptor, EdidManufacturer, EdidProductCode,
+        EdidR4Descriptor, EdidValidationError, IntoBytes, EDID_BASE_LEN,
+    };
+
+    #[test]
+    fn test_sidecar() {
+        let edid = edid_preset_avr(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        );
+        let sidecar = edid.sidecar();
+        let bytes = edid.into_bytes();
+
+        assert_eq!(sidecar.crate_version(), env!("CARGO_PKG_VERSION"));
+        assert_eq!(sidecar.edid_version(), "1.4");
+        assert_eq!(sidecar.error(), None);
+        assert!(sidecar.dump().contains("Manufacturer: RED"));
+        assert_eq!(
+            sidecar.block_checksums(),
+            Some(&[bytes[EDID_BASE_LEN - 1], bytes[2 * EDID_BASE_LEN - 1]][..])
+        );
+    }
+
+    #[test]
+    fn test_too_many_descriptors() {
+        let mut edid = edid_preset_avr(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        );
+        for _ in 0..3 {
+            edid.descriptors
+                .push(EdidDescriptor::R4(EdidR4Descriptor::Dummy));
+        }
+
+        let sidecar = edid.sidecar();
+        assert_eq!(
+            sidecar.error(),
+            Some(
+                EdidValidationError::TooManyDescriptors(4)
+                    .to_string()
+                    .as_str()
+            )
+        );
+        assert_eq!(sidecar.block_checksums(), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize() {
+        let edid = edid_preset_avr(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        );
+        let sidecar = edid.sidecar();
+
+        let json = serde_json::to_value(&sidecar).unwrap();
+        assert_eq!(json["crate_version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(json["edid_version"], "1.4");
+        assert!(json["error"].is_null());
+        assert_eq!(
+            json["block_checksums"][0],
+            u64::from(sidecar.block_checksums().unwrap()[0])
+        );
+    }
+}
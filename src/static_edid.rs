@@ -0,0 +1,240 @@
+use crate::{load::check_len, EdidTypeConversionError, EDID_BASE_LEN, EDID_HEADER_LEN};
+
+/// The fixed pattern every EDID starts with.
+pub const EDID_HEADER: [u8; EDID_HEADER_LEN] = [0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00];
+
+pub(crate) const EDID_BLOCK_LEN: usize = EDID_BASE_LEN;
+
+/// A serialized EDID block, along with its checksum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EdidBlock {
+    bytes: [u8; EDID_BLOCK_LEN],
+}
+
+impl EdidBlock {
+    /// The block bytes, including the checksum.
+    #[must_use]
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The block checksum, computed from the first 127 bytes like a sink would.
+    #[must_use]
+    pub const fn checksum(&self) -> u8 {
+        let (content, _) = self.bytes.split_at(EDID_BLOCK_LEN - 1);
+
+        edid_block_checksum(content)
+    }
+
+    /// Returns whether the checksum stored in the last byte of the block is the expected one.
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.bytes[EDID_BLOCK_LEN - 1] == self.checksum()
+    }
+}
+
+/// Splits a serialized EDID into its base block and extension blocks.
+///
+/// This is useful to log the checksum of every block, or for hardware that validates blocks
+/// independently.
+///
+/// ```
+/// use redid::{edid_blocks, edid_fixtures};
+///
+/// let fixture = &edid_fixtures()[0];
+///
+/// for block in edid_blocks(fixture.bytes()).unwrap() {
+///     assert!(block.is_valid());
+///     println!("Block checksum: {:#04x}", block.checksum());
+/// }
+/// ```
+///
+/// # Errors
+///
+/// If the bytes don't add up to complete EDID blocks.
+pub fn edid_blocks(bytes: &[u8]) -> Result<Vec<EdidBlock>, EdidTypeConversionError<String>> {
+    Ok(check_len(bytes.to_vec())?
+        .chunks_exact(EDID_BLOCK_LEN)
+        .map(|chunk| {
+            let mut bytes = [0; EDID_BLOCK_LEN];
+            bytes.copy_from_slice(chunk);
+
+            EdidBlock { bytes }
+        })
+        .collect())
+}
+
+/// Computes the byte to append to `bytes` so that their sum is equal to 0 (modulo 256), as
+/// required for the last byte of every EDID block.
+#[must_use]
+pub const fn edid_block_checksum(bytes: &[u8]) -> u8 {
+    let mut sum: u8 = 0;
+
+    let mut idx = 0;
+    while idx < bytes.len() {
+        sum = sum.wrapping_add(bytes[idx]);
+        idx += 1;
+    }
+
+    0u8.wrapping_sub(sum)
+}
+
+/// Assembles a 128 bytes EDID block at compile time.
+///
+/// The macro takes a list of const expressions evaluating to bytes arrays, concatenates them, and
+/// appends the block checksum. The chunks must add up to exactly 127 bytes, which is checked at
+/// compilation time.
+///
+/// This is meant to be used along with the `const fn` encoders of the various types, so that
+/// firmwares can embed an EDID without any runtime cost.
+///
+/// ```
+/// use redid::{
+///     edid_bytes, EdidEstablishedTiming, EdidManufacturer, EdidProductCode, EDID_HEADER,
+/// };
+///
+/// const EDID: [u8; 128] = edid_bytes![
+///     EDID_HEADER,
+///     EdidManufacturer::from_ascii(b"ABC").into_raw(),
+///     EdidProductCode::new(0xf206).into_raw(),
+///     [0x00; 4],
+///     [0x01, 0x11, 0x01, 0x04],
+///     [0x00; 15],
+///     EdidEstablishedTiming::list_into_raw(&[EdidEstablishedTiming::ET_640_480_60hz]),
+///     [0x01; 16],
+///     [0x00; 73],
+/// ];
+///
+/// assert_eq!(EDID.iter().fold(0u8, |sum, b| sum.wrapping_add(*b)), 0);
+/// ```
+#[macro_export]
+macro_rules! edid_bytes {
+    ($($chunk:expr),+ $(,)?) => {{
+        // The block length is spelled out, since the crate constants are private.
+        const BLOCK: [u8; 128] = {
+            let mut block = [0u8; 128];
+            let mut offset = 0;
+
+            $(
+                let chunk: &[u8] = &$chunk;
+                let mut idx = 0;
+                while idx < chunk.len() {
+                    assert!(
+                        offset < 127,
+                        "EDID block content is larger than 127 bytes."
+                    );
+
+                    block[offset] = chunk[idx];
+                    offset += 1;
+                    idx += 1;
+                }
+            )+
+
+            assert!(
+                offset == 127,
+                "EDID block content must be exactly 127 bytes long."
+            );
+
+            block[127] = $crate::edid_block_checksum(&block);
+            block
+        };
+
+        BLOCK
+    }};
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_edid_bytes {
+    use super::EDID_BLOCK_LEN;
+    use crate::{
+        edid_block_checksum, edid_blocks, edid_fixtures, EdidEstablishedTiming,
+        EdidExtensionCTA861VideoDataBlockDesc, EdidManufacturer, EdidProductCode,
+        EdidTypeConversionError, IntoBytes, EDID_HEADER,
+    };
+
+    #[test]
+    fn test_leaf_types() {
+        const MANUFACTURER: [u8; 2] = EdidManufacturer::from_ascii(b"ABC").into_raw();
+        const PRODUCT_CODE: [u8; 2] = EdidProductCode::new(0xf206).into_raw();
+        const ET: [u8; 3] = EdidEstablishedTiming::list_into_raw(&[
+            EdidEstablishedTiming::ET_720_400_70hz,
+            EdidEstablishedTiming::ET_640_480_60hz,
+            EdidEstablishedTiming::ET_1024_768_60hz,
+        ]);
+        const VICS: [u8; 3] = EdidExtensionCTA861VideoDataBlockDesc::list_into_raw(&[
+            EdidExtensionCTA861VideoDataBlockDesc::native(16),
+            EdidExtensionCTA861VideoDataBlockDesc::from_vic(4),
+            EdidExtensionCTA861VideoDataBlockDesc::from_vic(97),
+        ]);
+
+        assert_eq!(
+            MANUFACTURER.to_vec(),
+            EdidManufacturer::try_from("ABC").unwrap().into_bytes()
+        );
+        assert_eq!(PRODUCT_CODE, [0x06, 0xf2]);
+        assert_eq!(ET, [0xa0, 0x08, 0x00]);
+        assert_eq!(VICS, [0x90, 0x04, 0x61]);
+    }
+
+    #[test]
+    fn test_block() {
+        const BLOCK: [u8; 128] = edid_bytes![
+            EDID_HEADER,
+            EdidManufacturer::from_ascii(b"ABC").into_raw(),
+            EdidProductCode::new(0xf206).into_raw(),
+            [0x00; 115],
+        ];
+
+        assert_eq!(&BLOCK[0..8], &EDID_HEADER);
+        assert_eq!(&BLOCK[8..12], &[0x04, 0x43, 0x06, 0xf2]);
+        assert_eq!(BLOCK[127], edid_block_checksum(&BLOCK[..127]));
+        assert_eq!(BLOCK.iter().fold(0u8, |sum, b| sum.wrapping_add(*b)), 0);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let fixtures = edid_fixtures();
+        let bytes = fixtures[0].bytes();
+        let blocks = edid_blocks(bytes).unwrap();
+
+        assert_eq!(blocks.len(), 2);
+        for (block, chunk) in blocks.iter().zip(bytes.chunks(EDID_BLOCK_LEN)) {
+            assert_eq!(block.bytes(), chunk);
+            assert_eq!(block.checksum(), chunk[EDID_BLOCK_LEN - 1]);
+            assert!(block.is_valid());
+        }
+    }
+
+    #[test]
+    fn test_blocks_truncated() {
+        let fixtures = edid_fixtures();
+        let bytes = fixtures[0].bytes();
+
+        assert_eq!(
+            edid_blocks(&bytes[..bytes.len() - 1])
+                .unwrap_err()
+                .to_string(),
+            "Invalid Value: EDID must be a non-zero multiple of 128 bytes long (actual size 255)"
+        );
+        assert!(matches!(
+            edid_blocks(&[]).unwrap_err(),
+            EdidTypeConversionError::Value(_)
+        ));
+    }
+
+    #[test]
+    fn test_blocks_corrupted() {
+        let fixtures = edid_fixtures();
+        let mut bytes = fixtures[0].bytes().to_vec();
+        bytes[EDID_BLOCK_LEN - 1] = bytes[EDID_BLOCK_LEN - 1].wrapping_add(1);
+
+        let blocks = edid_blocks(&bytes).unwrap();
+        assert!(!blocks[0].is_valid());
+        assert_eq!(
+            blocks[0].checksum(),
+            edid_block_checksum(&bytes[..EDID_BLOCK_LEN - 1])
+        );
+        assert!(blocks[1].is_valid());
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    EdidExtension, EdidExtensionCTA861, EdidExtensionCTA861Revision3, EdidR4DigitalColorDepth,
+    EdidR4DigitalInterface, EdidR4VideoInputDefinition, EdidRelease4, EdidTiming,
+};
+
+/// A summary of the display capabilities advertised by an EDID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EdidSummary {
+    max_resolution: Option<(u16, u16)>,
+    max_refresh_rate: Option<u16>,
+    audio_channels: Option<usize>,
+    color_depth: Option<EdidR4DigitalColorDepth>,
+    interface: Option<EdidR4DigitalInterface>,
+    hdmi: bool,
+}
+
+impl EdidSummary {
+    /// Returns the resolution with the most pixels among all the timings advertised, if any.
+    #[must_use]
+    pub const fn max_resolution(&self) -> Option<(u16, u16)> {
+        self.max_resolution
+    }
+
+    /// Returns the highest refresh rate, in Hz, among all the timings advertised, if any.
+    #[must_use]
+    pub const fn max_refresh_rate(&self) -> Option<u16> {
+        self.max_refresh_rate
+    }
+
+    /// Returns the maximum number of LPCM audio channels, if the display supports audio.
+    #[must_use]
+    pub const fn audio_channels(&self) -> Option<usize> {
+        self.audio_channels
+    }
+
+    /// Returns the color depth, if the display has a digital input.
+    #[must_use]
+    pub const fn color_depth(&self) -> Option<EdidR4DigitalColorDepth> {
+        self.color_depth
+    }
+
+    /// Returns the digital interface, if the display has a digital input.
+    #[must_use]
+    pub const fn interface(&self) -> Option<EdidR4DigitalInterface> {
+        self.interface
+    }
+
+    /// Returns whether an HDMI Vendor Specific Data Block is present.
+    #[must_use]
+    pub const fn is_hdmi(&self) -> bool {
+        self.hdmi
+    }
+}
+
+pub(crate) fn edid_summary(edid: &EdidRelease4) -> EdidSummary {
+    let timings: Vec<_> = edid.all_timings().collect();
+
+    let (color_depth, interface) = match edid.display_parameters_features.video_input {
+        EdidR4VideoInputDefinition::Analog(_) => (None, None),
+        EdidR4VideoInputDefinition::Digital(input) => {
+            (Some(input.color_depth), Some(input.interface))
+        }
+    };
+
+    let ctas = edid.extensions.iter().filter_map(|ext| match ext {
+        EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => Some(cta),
+        EdidExtension::DisplayID(_) => None,
+    });
+
+    EdidSummary {
+        max_resolution: timings
+            .iter()
+            .map(|t| (t.horizontal(), t.vertical()))
+            .max_by_key(|(h, v)| u32::from(*h) * u32::from(*v)),
+        max_refresh_rate: timings.iter().map(EdidTiming::refresh_rate).max(),
+        audio_channels: ctas
+            .clone()
+            .filter_map(EdidExtensionCTA861Revision3::max_lpcm_channels)
+            .max(),
+        color_depth,
+        interface,
+        hdmi: ctas
+            .clone()
+            .any(EdidExtensionCTA861Revision3::has_hdmi_block),
+    }
+}
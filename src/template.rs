@@ -0,0 +1,249 @@
+//! EDIDs shared between threads, to provision many units of the same display.
+
+use alloc::sync::Arc;
+
+use static_assertions::assert_impl_all;
+use typed_builder::TypedBuilder;
+
+use crate::{
+    EdidDescriptor, EdidDescriptorString, EdidR4Descriptor, EdidRelease4, EdidSerialNumber,
+    EdidValidationError, EDID_DESCRIPTORS_NUM,
+};
+
+/// The data identifying a particular unit, overriding the template ones.
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct EdidTemplateOverrides {
+    /// Replaces the serial number. If the template has a serial number string format, the
+    /// Product Serial Number descriptor follows it.
+    #[builder(default, setter(strip_option))]
+    serial_number: Option<EdidSerialNumber>,
+
+    /// Replaces the content of the first Product Name descriptor, or adds one if the template
+    /// doesn't have any.
+    #[builder(default, setter(strip_option))]
+    product_name: Option<EdidDescriptorString>,
+}
+
+/// A validated, immutable, EDID that can be shared between threads.
+///
+/// Clones share the same EDID, so they are cheap to create, and each unit is instantiated from
+/// it with its own serial number and product name, without going through the builder again.
+///
+/// ```
+/// use redid::{
+///     edid_preset_avr, CecAddress, EdidManufacturer, EdidProductCode, EdidSerialNumber,
+///     EdidTemplate, EdidTemplateOverrides, IntoBytes,
+/// };
+///
+/// let template = EdidTemplate::try_from(edid_preset_avr(
+///     EdidManufacturer::from_ascii(b"RED"),
+///     EdidProductCode::new(0x1234),
+///     CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+/// ))
+/// .unwrap();
+///
+/// let bytes = template
+///     .instantiate(
+///         &EdidTemplateOverrides::builder()
+///             .serial_number(EdidSerialNumber::from(42))
+///             .build(),
+///     )
+///     .unwrap()
+///     .into_bytes();
+/// ```
+#[derive(Clone, Debug)]
+pub struct EdidTemplate(Arc<EdidRelease4>);
+
+assert_impl_all!(EdidTemplate: Send, Sync);
+
+impl TryFrom<EdidRelease4> for EdidTemplate {
+    type Error = EdidValidationError;
+
+    fn try_from(value: EdidRelease4) -> Result<Self, Self::Error> {
+        value.validate()?;
+
+        Ok(Self(Arc::new(value)))
+    }
+}
+
+impl EdidTemplate {
+    /// Returns the EDID the template has been created from.
+    #[must_use]
+    pub fn edid(&self) -> &EdidRelease4 {
+        &self.0
+    }
+
+    /// Creates the EDID of a unit, with the template data replaced by the overrides.
+    ///
+    /// # Errors
+    ///
+    /// If a product name is set, but the template uses a raw descriptors area, or if it has to be
+    /// added but there's no descriptor slot left for it.
+    pub fn instantiate(
+        &self,
+        overrides: &EdidTemplateOverrides,
+    ) -> Result<EdidRelease4, EdidValidationError> {
+        let mut edid = EdidRelease4::clone(&self.0);
+
+        if let Some(serial_number) = overrides.serial_number {
+            edid.serial_number = Some(serial_number);
+        }
+
+        if let Some(name) = &overrides.product_name {
+            // The descriptors, and thus the Product Name, would be ignored.
+            if edid.raw_descriptors.is_some() {
+                return Err(EdidValidationError::IgnoredDescriptors);
+            }
+
+            let existing = edid.descriptors.iter_mut().find_map(|desc| match desc {
+                EdidDescriptor::R4(EdidR4Descriptor::ProductName(s)) => Some(s),
+                EdidDescriptor::R3(_) | EdidDescriptor::R4(_) | EdidDescriptor::Raw(_) => None,
+            });
+
+            if let Some(existing) = existing {
+                existing.clone_from(name);
+            } else if edid.descriptors.len() >= EDID_DESCRIPTORS_NUM {
+                return Err(EdidValidationError::NoFreeDescriptorSlot);
+            } else {
+                edid.descriptors
+                    .push(EdidDescriptor::R4(EdidR4Descriptor::ProductName(
+                        name.clone(),
+                    )));
+            }
+        }
+
+        Ok(edid)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_template {
+    use std::thread;
+
+    use super::{EdidTemplate, EdidTemplateOverrides};
+    use crate::{
+        edid_preset_avr, edid_preset_monochrome_medical, CecAddress, EdidDescriptor,
+        EdidDescriptorPadding, EdidDescriptorString, EdidManufacturer, EdidProductCode,
+        EdidR4Descriptor, EdidSerialNumber, EdidValidationError, IntoBytes,
+    };
+
+    fn template() -> EdidTemplate {
+        EdidTemplate::try_from(edid_preset_avr(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_overrides() {
+        let template = template();
+
+        let bytes = template
+            .instantiate(&EdidTemplateOverrides::builder().build())
+            .unwrap()
+            .into_bytes();
+        assert_eq!(bytes, template.edid().clone().into_bytes());
+
+        let bytes = template
+            .instantiate(
+                &EdidTemplateOverrides::builder()
+                    .serial_number(EdidSerialNumber::from(0x1234_abcd))
+                    .product_name(EdidDescriptorString::try_from("Unit 42").unwrap())
+                    .build(),
+            )
+            .unwrap()
+            .into_bytes();
+        assert_eq!(&bytes[0x0c..0x10], &[0xcd, 0xab, 0x34, 0x12]);
+        // The Product Name is the second descriptor
+        assert_eq!(
+            &bytes[0x48..0x5a],
+            &[
+                0x00, 0x00, 0x00, 0xfc, 0x00, 0x55, 0x6e, 0x69, 0x74, 0x20, 0x34, 0x32, 0x0a, 0x20,
+                0x20, 0x20, 0x20, 0x20
+            ]
+        );
+    }
+
+    #[test]
+    fn test_added_product_name() {
+        let mut edid = edid_preset_monochrome_medical(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0x1234),
+        );
+        edid.descriptors.pop();
+        let template = EdidTemplate::try_from(edid).unwrap();
+        let overrides = EdidTemplateOverrides::builder()
+            .product_name(EdidDescriptorString::try_from("Unit 42").unwrap())
+            .build();
+
+        let edid = template.instantiate(&overrides).unwrap();
+        assert!(matches!(
+            edid.descriptors.last(),
+            Some(EdidDescriptor::R4(EdidR4Descriptor::ProductName(_)))
+        ));
+
+        let mut edid = template.edid().clone();
+        edid.descriptors
+            .push(EdidDescriptor::R4(EdidR4Descriptor::Dummy));
+        edid.descriptors
+            .push(EdidDescriptor::R4(EdidR4Descriptor::Dummy));
+        edid.descriptors
+            .push(EdidDescriptor::R4(EdidR4Descriptor::Dummy));
+        let template = EdidTemplate::try_from(edid).unwrap();
+        assert_eq!(
+            template.instantiate(&overrides).unwrap_err(),
+            EdidValidationError::NoFreeDescriptorSlot
+        );
+
+        let mut edid = template.edid().clone();
+        edid.descriptors.clear();
+        edid.raw_descriptors = Some([0; 72]);
+        let template = EdidTemplate::try_from(edid).unwrap();
+        assert_eq!(
+            template.instantiate(&overrides).unwrap_err(),
+            EdidValidationError::IgnoredDescriptors
+        );
+    }
+
+    #[test]
+    fn test_threads() {
+        let template = template();
+
+        let handles: Vec<_> = (0..4u32)
+            .map(|serial| {
+                let template = template.clone();
+
+                thread::spawn(move || {
+                    template
+                        .instantiate(
+                            &EdidTemplateOverrides::builder()
+                                .serial_number(EdidSerialNumber::from(serial))
+                                .build(),
+                        )
+                        .unwrap()
+                        .into_bytes()
+                })
+            })
+            .collect();
+
+        for (serial, handle) in handles.into_iter().enumerate() {
+            let bytes = handle.join().unwrap();
+            assert_eq!(usize::from(bytes[0x0c]), serial);
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        let mut edid = template().edid().clone();
+        edid.descriptors.clear();
+        edid.descriptor_padding = EdidDescriptorPadding::Forbidden;
+
+        assert_eq!(
+            EdidTemplate::try_from(edid).unwrap_err(),
+            EdidValidationError::MissingDescriptors(0)
+        );
+    }
+}
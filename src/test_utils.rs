@@ -0,0 +1,115 @@
+//! Fixtures shared by the unit tests.
+
+use crate::{
+    EdidDisplayColorType, EdidDisplayRangeHorizontalFreq, EdidDisplayRangePixelClock,
+    EdidDisplayRangeVerticalFreq, EdidDisplayRangeVideoTimingsGTF,
+    EdidDisplayRangeVideoTimingsGTFBlankingOffset,
+    EdidDisplayRangeVideoTimingsGTFBlankingScalingFactorWeighting,
+    EdidDisplayRangeVideoTimingsGTFStartFrequency, EdidDisplayTransferCharacteristics,
+    EdidExtension, EdidExtensionCTA861, EdidExtensionCTA861Revision3,
+    EdidExtensionCTA861Revision3DataBlock, EdidR3BasicDisplayParametersFeatures,
+    EdidR3DigitalVideoInputDefinition, EdidR3DisplayRangeLimits,
+    EdidR3DisplayRangeVideoTimingsSupport, EdidR3FeatureSupport, EdidR3ImageSize,
+    EdidR3VideoInputDefinition, EdidR4BasicDisplayParametersFeatures, EdidR4DigitalColorDepth,
+    EdidR4DigitalInterface, EdidR4DigitalVideoInputDefinition, EdidR4DisplayColor,
+    EdidR4DisplayColorEncoding, EdidR4FeatureSupport, EdidR4ImageSize, EdidR4VideoInputDefinition,
+};
+
+/// Starts an [`crate::EdidRelease4`] builder for an HDMI display, with its Basic Display
+/// Parameters and Features coming from [`r4_display_parameters_features`] and an sRGB
+/// colorimetry. The timings, descriptors and extensions are left to the caller.
+macro_rules! edid_r4_builder {
+    () => {
+        $crate::EdidRelease4::builder()
+            .manufacturer($crate::EdidManufacturer::from_ascii(b"ABC"))
+            .product_code($crate::EdidProductCode::new(0xf206))
+            .date($crate::EdidR4Date::Manufacture(
+                $crate::EdidR4ManufactureDate::try_from((1, 2024)).unwrap(),
+            ))
+            .display_parameters_features($crate::test_utils::r4_display_parameters_features())
+            .filter_chromaticity($crate::EdidFilterChromaticity::Color(
+                $crate::EdidChromaticityPoints::srgb(),
+            ))
+    };
+}
+
+pub(crate) use edid_r4_builder;
+
+/// Returns the Basic Display Parameters and Features of an 8 bpc HDMI display, with a 2.2
+/// gamma, an RGB 4:4:4 color encoding and an undefined size.
+pub(crate) fn r4_display_parameters_features() -> EdidR4BasicDisplayParametersFeatures {
+    EdidR4BasicDisplayParametersFeatures::builder()
+        .video_input(EdidR4VideoInputDefinition::Digital(
+            EdidR4DigitalVideoInputDefinition::builder()
+                .color_depth(EdidR4DigitalColorDepth::Depth8Bpc)
+                .interface(EdidR4DigitalInterface::HDMIa)
+                .build(),
+        ))
+        .size(EdidR4ImageSize::Undefined)
+        .display_transfer_characteristic(EdidDisplayTransferCharacteristics::try_from(2.2).unwrap())
+        .feature_support(
+            EdidR4FeatureSupport::builder()
+                .color(EdidR4DisplayColor::Digital(
+                    EdidR4DisplayColorEncoding::RGB444,
+                ))
+                .build(),
+        )
+        .build()
+}
+
+/// Returns the Basic Display Parameters and Features of an EDID 1.3 digital RGB display, with
+/// a 2.2 gamma and an undefined size.
+pub(crate) fn r3_display_parameters_features() -> EdidR3BasicDisplayParametersFeatures {
+    EdidR3BasicDisplayParametersFeatures::builder()
+        .video_input(EdidR3VideoInputDefinition::Digital(
+            EdidR3DigitalVideoInputDefinition::builder().build(),
+        ))
+        .size(EdidR3ImageSize::Undefined)
+        .display_transfer_characteristic(EdidDisplayTransferCharacteristics::try_from(2.2).unwrap())
+        .feature_support(
+            EdidR3FeatureSupport::builder()
+                .display_type(EdidDisplayColorType::RGBColor)
+                .build(),
+        )
+        .build()
+}
+
+/// Returns the Display Range Limits of the VESA EDID 1.3 Section 3.10.3.4 example, with the
+/// given timings support.
+pub(crate) fn r3_range_limits(
+    timings_support: EdidR3DisplayRangeVideoTimingsSupport,
+) -> EdidR3DisplayRangeLimits {
+    EdidR3DisplayRangeLimits::builder()
+        .min_vfreq(EdidDisplayRangeVerticalFreq::try_from(50u16).unwrap())
+        .max_vfreq(EdidDisplayRangeVerticalFreq::try_from(90u16).unwrap())
+        .min_hfreq(EdidDisplayRangeHorizontalFreq::try_from(30u16).unwrap())
+        .max_hfreq(EdidDisplayRangeHorizontalFreq::try_from(110u16).unwrap())
+        .max_pixelclock(EdidDisplayRangePixelClock::try_from(230).unwrap())
+        .timings_support(timings_support)
+        .build()
+}
+
+/// Returns the secondary GTF curve of the VESA EDID 1.3 Section 3.10.3.4 example: the GTF
+/// Standard default coefficients, starting at 60kHz.
+pub(crate) fn secondary_gtf() -> EdidDisplayRangeVideoTimingsGTF {
+    EdidDisplayRangeVideoTimingsGTF::builder()
+        .horizontal_start_frequency(
+            EdidDisplayRangeVideoTimingsGTFStartFrequency::try_from(60).unwrap(),
+        )
+        .blanking_offset(EdidDisplayRangeVideoTimingsGTFBlankingOffset::try_from(40).unwrap())
+        .blanking_gradient(600)
+        .blanking_scaling_factor(128)
+        .blanking_scaling_factor_weighting(
+            EdidDisplayRangeVideoTimingsGTFBlankingScalingFactorWeighting::try_from(20).unwrap(),
+        )
+        .build()
+}
+
+/// Returns a CTA-861 Extension without any native format, holding the given data blocks.
+pub(crate) fn cta_extension(blocks: Vec<EdidExtensionCTA861Revision3DataBlock>) -> EdidExtension {
+    EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+        EdidExtensionCTA861Revision3::builder()
+            .data_blocks(blocks)
+            .build(),
+    ))
+}
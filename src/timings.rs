@@ -0,0 +1,568 @@
+use num_traits::ToPrimitive;
+
+use crate::{
+    Edid, EdidDescriptor, EdidDescriptorDetailedTiming, EdidEstablishedTiming, EdidExtension,
+    EdidExtensionCTA861, EdidExtensionDisplayIDTypeVIITiming, EdidR3Descriptor, EdidR4Descriptor,
+    EdidR4DescriptorEstablishedTimingsIII, EdidRelease, EdidStandardTiming,
+    EdidStandardTimingRatio,
+};
+
+/// Where a timing advertised by an EDID is coming from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EdidTimingSource {
+    /// The Established Timings I & II of the base block.
+    EstablishedTiming,
+
+    /// An Established Timings III Descriptor.
+    EstablishedTimingIII,
+
+    /// The Standard Timings of the base block.
+    StandardTiming,
+
+    /// A Detailed Timing Descriptor, either in the base block or in an extension, or a `DisplayID`
+    /// Type VII Timing.
+    DetailedTiming,
+
+    /// A Short Video Descriptor of a CTA-861 Video Data Block, with its VIC.
+    ShortVideoDescriptor(u8),
+}
+
+/// A timing advertised by an EDID, normalized across all the ways an EDID can describe one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EdidTiming {
+    source: EdidTimingSource,
+    horizontal: u16,
+    vertical: u16,
+    refresh_rate: u16,
+    interlaced: bool,
+}
+
+impl EdidTiming {
+    /// Returns where the timing is coming from.
+    #[must_use]
+    pub const fn source(&self) -> EdidTimingSource {
+        self.source
+    }
+
+    /// Returns the number of horizontal addressable pixels.
+    #[must_use]
+    pub const fn horizontal(&self) -> u16 {
+        self.horizontal
+    }
+
+    /// Returns the number of vertical addressable lines of a frame, including both fields for
+    /// interlaced timings.
+    #[must_use]
+    pub const fn vertical(&self) -> u16 {
+        self.vertical
+    }
+
+    /// Returns the refresh rate, rounded to the nearest Hz. It's the field rate for interlaced
+    /// timings.
+    #[must_use]
+    pub const fn refresh_rate(&self) -> u16 {
+        self.refresh_rate
+    }
+
+    /// Returns whether the timing is interlaced.
+    #[must_use]
+    pub const fn is_interlaced(&self) -> bool {
+        self.interlaced
+    }
+}
+
+/// A CTA-861 Video Format, as identified by its VIC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EdidCtaVideoFormat {
+    vic: u8,
+    hactive: u16,
+    vactive: u16,
+    interlaced: bool,
+    pixel_clock_khz: u32,
+    htotal: u16,
+    vtotal: u16,
+    dmt_id: Option<u8>,
+}
+
+impl EdidCtaVideoFormat {
+    /// Returns the Video Format identified by the given VIC, if CTA-861 defines it.
+    #[must_use]
+    pub fn from_vic(vic: u8) -> Option<&'static Self> {
+        EDID_CTA_VIDEO_FORMATS
+            .binary_search_by_key(&vic, Self::vic)
+            .ok()
+            .map(|idx| &EDID_CTA_VIDEO_FORMATS[idx])
+    }
+
+    /// Returns the Video Identification Code.
+    #[must_use]
+    pub const fn vic(&self) -> u8 {
+        self.vic
+    }
+
+    /// Returns the number of horizontal addressable pixels.
+    #[must_use]
+    pub const fn hactive(&self) -> u16 {
+        self.hactive
+    }
+
+    /// Returns the number of vertical addressable lines of a frame, including both fields for
+    /// interlaced formats.
+    #[must_use]
+    pub const fn vactive(&self) -> u16 {
+        self.vactive
+    }
+
+    /// Returns whether the format is interlaced.
+    #[must_use]
+    pub const fn is_interlaced(&self) -> bool {
+        self.interlaced
+    }
+
+    /// Returns the pixel clock, in kHz, for the integer refresh rate variant of the format.
+    #[must_use]
+    pub const fn pixel_clock_khz(&self) -> u32 {
+        self.pixel_clock_khz
+    }
+
+    /// Returns the total number of pixels of a line.
+    #[must_use]
+    pub const fn htotal(&self) -> u16 {
+        self.htotal
+    }
+
+    /// Returns the total number of lines of a frame.
+    #[must_use]
+    pub const fn vtotal(&self) -> u16 {
+        self.vtotal
+    }
+
+    /// Returns the ID of the VESA DMT timing identical to this format, if any.
+    #[must_use]
+    pub const fn dmt_id(&self) -> Option<u8> {
+        self.dmt_id
+    }
+}
+
+/// The CTA-861 Video Formats, sorted by VIC.
+///
+/// The VICs 1 to 127 and 193 to 219 are defined, so the VIC of a format isn't its index in the
+/// table. Use [`EdidCtaVideoFormat::from_vic`] to look a format up.
+///
+/// The table is generated at build time from `data/cta_video_formats.csv`, which is the file to
+/// update when new VICs are defined.
+#[allow(clippy::unreadable_literal)]
+pub const EDID_CTA_VIDEO_FORMATS: &[EdidCtaVideoFormat] =
+    &include!(concat!(env!("OUT_DIR"), "/cta_video_formats.rs"));
+
+fn cta_timing(vic: u8) -> Option<EdidTiming> {
+    let format = EdidCtaVideoFormat::from_vic(vic)?;
+
+    let fields = if format.interlaced { 2 } else { 1 };
+    let total = u64::from(format.htotal) * u64::from(format.vtotal);
+    let rate = (u64::from(format.pixel_clock_khz) * 1000 * fields + total / 2) / total;
+
+    Some(EdidTiming {
+        source: EdidTimingSource::ShortVideoDescriptor(vic),
+        horizontal: format.hactive,
+        vertical: format.vactive,
+        refresh_rate: rate.to_u16()?,
+        interlaced: format.interlaced,
+    })
+}
+
+/// Returns whether the Detailed Timing describes the same mode than the VIC, at the same or at
+/// the 1000/1001 refresh rate, but with a different blanking.
+pub(crate) fn conflicts_with_vic(dtd: &EdidDescriptorDetailedTiming, vic: u8) -> bool {
+    let Some(timing) = cta_timing(vic) else {
+        return false;
+    };
+
+    if dtd.frame_resolution() != (timing.horizontal, timing.vertical)
+        || dtd.is_interlaced() != timing.interlaced
+    {
+        return false;
+    }
+
+    let rate = f64::from(timing.refresh_rate);
+    if (dtd.field_rate() - rate).abs() > rate * 0.002 {
+        return false;
+    }
+
+    let Some(format) = EdidCtaVideoFormat::from_vic(vic) else {
+        return false;
+    };
+
+    dtd.horizontal_total() != u32::from(format.htotal)
+        || dtd.vertical_frame_total() != u32::from(format.vtotal)
+}
+
+const fn established_timing(et: EdidEstablishedTiming) -> Option<EdidTiming> {
+    let (horizontal, vertical, refresh_rate, interlaced) = match et {
+        EdidEstablishedTiming::ET_1024_768_60hz => (1024, 768, 60, false),
+        EdidEstablishedTiming::ET_1024_768_70hz => (1024, 768, 70, false),
+        EdidEstablishedTiming::ET_1024_768_75hz => (1024, 768, 75, false),
+        EdidEstablishedTiming::ET_1024_768_87hz_Interlaced => (1024, 768, 87, true),
+        EdidEstablishedTiming::ET_1152_870_75hz => (1152, 870, 75, false),
+        EdidEstablishedTiming::ET_1280_1024_75hz => (1280, 1024, 75, false),
+        EdidEstablishedTiming::ET_640_480_60hz => (640, 480, 60, false),
+        EdidEstablishedTiming::ET_640_480_67hz => (640, 480, 67, false),
+        EdidEstablishedTiming::ET_640_480_72hz => (640, 480, 72, false),
+        EdidEstablishedTiming::ET_640_480_75hz => (640, 480, 75, false),
+        EdidEstablishedTiming::ET_720_400_70hz => (720, 400, 70, false),
+        EdidEstablishedTiming::ET_720_400_88hz => (720, 400, 88, false),
+        EdidEstablishedTiming::ET_800_600_56hz => (800, 600, 56, false),
+        EdidEstablishedTiming::ET_800_600_60hz => (800, 600, 60, false),
+        EdidEstablishedTiming::ET_800_600_72hz => (800, 600, 72, false),
+        EdidEstablishedTiming::ET_800_600_75hz => (800, 600, 75, false),
+        EdidEstablishedTiming::ET_832_624_75hz => (832, 624, 75, false),
+        EdidEstablishedTiming::Manufacturer0
+        | EdidEstablishedTiming::Manufacturer1
+        | EdidEstablishedTiming::Manufacturer2
+        | EdidEstablishedTiming::Manufacturer3
+        | EdidEstablishedTiming::Manufacturer4
+        | EdidEstablishedTiming::Manufacturer5
+        | EdidEstablishedTiming::Manufacturer6 => return None,
+    };
+
+    Some(EdidTiming {
+        source: EdidTimingSource::EstablishedTiming,
+        horizontal,
+        vertical,
+        refresh_rate,
+        interlaced,
+    })
+}
+
+pub(crate) const fn established_timing_iii(
+    et: EdidR4DescriptorEstablishedTimingsIII,
+) -> EdidTiming {
+    let (horizontal, vertical, refresh_rate) = match et {
+        EdidR4DescriptorEstablishedTimingsIII::ET_1152_864_75Hz => (1152, 864, 75),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1024_768_85Hz => (1024, 768, 85),
+        EdidR4DescriptorEstablishedTimingsIII::ET_800_600_85Hz => (800, 600, 85),
+        EdidR4DescriptorEstablishedTimingsIII::ET_848_480_60Hz => (848, 480, 60),
+        EdidR4DescriptorEstablishedTimingsIII::ET_640_480_85Hz => (640, 480, 85),
+        EdidR4DescriptorEstablishedTimingsIII::ET_720_400_85Hz => (720, 400, 85),
+        EdidR4DescriptorEstablishedTimingsIII::ET_640_400_85Hz => (640, 400, 85),
+        EdidR4DescriptorEstablishedTimingsIII::ET_640_350_85Hz => (640, 350, 85),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1280_1024_85Hz => (1280, 1024, 85),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1280_1024_60Hz => (1280, 1024, 60),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1280_960_85Hz => (1280, 960, 85),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1280_960_60Hz => (1280, 960, 60),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1280_768_85Hz => (1280, 768, 85),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1280_768_75Hz => (1280, 768, 75),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1280_768_60Hz
+        | EdidR4DescriptorEstablishedTimingsIII::ET_1280_768_60Hz_RB => (1280, 768, 60),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1400_1050_75Hz => (1400, 1050, 75),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1400_1050_60Hz
+        | EdidR4DescriptorEstablishedTimingsIII::ET_1400_1050_60Hz_RB => (1400, 1050, 60),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1440_900_85Hz => (1440, 900, 85),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1440_900_75Hz => (1440, 900, 75),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1440_900_60Hz
+        | EdidR4DescriptorEstablishedTimingsIII::ET_1440_900_60Hz_RB => (1440, 900, 60),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1360_768_60Hz => (1360, 768, 60),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1600_1200_70Hz => (1600, 1200, 70),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1600_1200_65Hz => (1600, 1200, 65),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1600_1200_60Hz => (1600, 1200, 60),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1680_1050_85Hz => (1680, 1050, 85),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1680_1050_75Hz => (1680, 1050, 75),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1680_1050_60Hz
+        | EdidR4DescriptorEstablishedTimingsIII::ET_1680_1050_60Hz_RB => (1680, 1050, 60),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1400_1050_85Hz => (1400, 1050, 85),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1920_1200_60Hz
+        | EdidR4DescriptorEstablishedTimingsIII::ET_1920_1200_60Hz_RB => (1920, 1200, 60),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1856_1392_75Hz => (1856, 1392, 75),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1856_1392_60Hz => (1856, 1392, 60),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1792_1344_75Hz => (1792, 1344, 75),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1792_1344_60Hz => (1792, 1344, 60),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1600_1200_85Hz => (1600, 1200, 85),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1600_1200_75Hz => (1600, 1200, 75),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1920_1440_75Hz => (1920, 1440, 75),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1920_1440_60Hz => (1920, 1440, 60),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1920_1200_85Hz => (1920, 1200, 85),
+        EdidR4DescriptorEstablishedTimingsIII::ET_1920_1200_75Hz => (1920, 1200, 75),
+    };
+
+    EdidTiming {
+        source: EdidTimingSource::EstablishedTimingIII,
+        horizontal,
+        vertical,
+        refresh_rate,
+        interlaced: false,
+    }
+}
+
+fn standard_timing(release: EdidRelease, st: EdidStandardTiming) -> EdidTiming {
+    let horizontal = st.x.0;
+    let vertical = match st.ratio {
+        // The aspect ratio code 00 was 1:1 up to EDID 1.2, and is 16:10 since EDID 1.3. Both the
+        // releases redid generates thus use 16:10.
+        EdidStandardTimingRatio::Ratio_16_10 => match release {
+            EdidRelease::R3 | EdidRelease::R4 => horizontal * 10 / 16,
+        },
+        EdidStandardTimingRatio::Ratio_4_3 => horizontal * 3 / 4,
+        EdidStandardTimingRatio::Ratio_5_4 => horizontal * 4 / 5,
+        EdidStandardTimingRatio::Ratio_16_9 => horizontal * 9 / 16,
+    };
+
+    EdidTiming {
+        source: EdidTimingSource::StandardTiming,
+        horizontal,
+        vertical,
+        refresh_rate: u16::from(st.frequency.0),
+        interlaced: false,
+    }
+}
+
+fn detailed_timing(dtd: &EdidDescriptorDetailedTiming) -> Option<EdidTiming> {
+    let (horizontal, vertical) = dtd.frame_resolution();
+
+    Some(EdidTiming {
+        source: EdidTimingSource::DetailedTiming,
+        horizontal,
+        vertical,
+        refresh_rate: dtd.field_rate().round().to_u16()?,
+        interlaced: dtd.is_interlaced(),
+    })
+}
+
+pub(crate) fn descriptor_timings(desc: &EdidDescriptor) -> Vec<EdidTiming> {
+    match desc {
+        EdidDescriptor::R3(EdidR3Descriptor::DetailedTiming(dtd))
+        | EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(dtd)) => {
+            detailed_timing(dtd).into_iter().collect()
+        }
+        EdidDescriptor::R4(EdidR4Descriptor::EstablishedTimings(et)) => et
+            .timings()
+            .iter()
+            .copied()
+            .map(established_timing_iii)
+            .collect(),
+        EdidDescriptor::R3(_) | EdidDescriptor::R4(_) | EdidDescriptor::Raw(_) => Vec::new(),
+    }
+}
+
+fn displayid_timing(timing: &EdidExtensionDisplayIDTypeVIITiming) -> Option<EdidTiming> {
+    let (horizontal, vertical) = timing.frame_resolution();
+
+    Some(EdidTiming {
+        source: EdidTimingSource::DetailedTiming,
+        horizontal,
+        vertical,
+        refresh_rate: timing.field_rate().round().to_u16()?,
+        interlaced: timing.is_interlaced(),
+    })
+}
+
+fn extension_timings(ext: &EdidExtension) -> Vec<EdidTiming> {
+    match ext {
+        EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => cta
+            .short_video_descriptors()
+            .filter_map(|desc| cta_timing(desc.vic()))
+            .chain(cta.detailed_timings().iter().filter_map(detailed_timing))
+            .collect(),
+        EdidExtension::DisplayID(displayid) => displayid
+            .type_vii_timings()
+            .filter_map(displayid_timing)
+            .collect(),
+    }
+}
+
+/// Lists all the timings advertised by the EDID, in the order they are found in the EDID.
+///
+/// The Manufacturer's Timings and the VICs that don't have a Video Format defined by CTA-861 are
+/// skipped.
+pub(crate) fn edid_timings(edid: &Edid) -> Vec<EdidTiming> {
+    let mut timings: Vec<EdidTiming> = edid
+        .established_timings
+        .iter()
+        .copied()
+        .filter_map(established_timing)
+        .collect();
+
+    timings.extend(
+        edid.standard_timings
+            .iter()
+            .map(|st| standard_timing(edid.release, *st)),
+    );
+    timings.extend(edid.descriptors.iter().flat_map(descriptor_timings));
+    timings.extend(edid.extensions.iter().flat_map(extension_timings));
+
+    timings
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_timings {
+    use crate::{
+        EdidCtaVideoFormat, EdidDescriptor, EdidDescriptorDetailedTiming, EdidEstablishedTiming,
+        EdidR4Descriptor, EdidR4DescriptorEstablishedTimings,
+        EdidR4DescriptorEstablishedTimingsIII, EdidRelease, EdidStandardTiming,
+        EdidStandardTimingHorizontalSize, EdidStandardTimingRatio, EdidStandardTimingRefreshRate,
+        EdidTimingSource, EDID_CTA_VIDEO_FORMATS,
+    };
+
+    use super::{cta_timing, descriptor_timings, established_timing, standard_timing};
+
+    #[test]
+    fn test_cta_table() {
+        assert_eq!(EDID_CTA_VIDEO_FORMATS.len(), 127 + 27);
+
+        let vics: Vec<_> = EDID_CTA_VIDEO_FORMATS
+            .iter()
+            .map(EdidCtaVideoFormat::vic)
+            .collect();
+        assert_eq!(vics, (1..=127).chain(193..=219).collect::<Vec<_>>());
+
+        let format = EdidCtaVideoFormat::from_vic(219).unwrap();
+        assert_eq!((format.hactive(), format.vactive()), (4096, 2160));
+        assert_eq!((format.htotal(), format.vtotal()), (4400, 2250));
+        assert_eq!(format.pixel_clock_khz(), 1_188_000);
+        assert!(EdidCtaVideoFormat::from_vic(128).is_none());
+
+        let format = EDID_CTA_VIDEO_FORMATS[15];
+        assert_eq!((format.hactive(), format.vactive()), (1920, 1080));
+        assert_eq!((format.htotal(), format.vtotal()), (2200, 1125));
+        assert_eq!(format.pixel_clock_khz(), 148_500);
+        assert!(!format.is_interlaced());
+        assert_eq!(format.dmt_id(), Some(0x52));
+
+        assert!(EDID_CTA_VIDEO_FORMATS[4].is_interlaced());
+        assert_eq!(EDID_CTA_VIDEO_FORMATS[4].dmt_id(), None);
+    }
+
+    #[test]
+    fn test_cta() {
+        let timing = cta_timing(16).unwrap();
+        assert_eq!(timing.source(), EdidTimingSource::ShortVideoDescriptor(16));
+        assert_eq!(
+            (
+                timing.horizontal(),
+                timing.vertical(),
+                timing.refresh_rate()
+            ),
+            (1920, 1080, 60)
+        );
+        assert!(!timing.is_interlaced());
+
+        let timing = cta_timing(20).unwrap();
+        assert_eq!(
+            (
+                timing.horizontal(),
+                timing.vertical(),
+                timing.refresh_rate()
+            ),
+            (1920, 1080, 50)
+        );
+        assert!(timing.is_interlaced());
+
+        let timing = cta_timing(97).unwrap();
+        assert_eq!(
+            (
+                timing.horizontal(),
+                timing.vertical(),
+                timing.refresh_rate()
+            ),
+            (3840, 2160, 60)
+        );
+
+        let timing = cta_timing(199).unwrap();
+        assert_eq!(
+            (
+                timing.horizontal(),
+                timing.vertical(),
+                timing.refresh_rate()
+            ),
+            (7680, 4320, 60)
+        );
+
+        for vic in (1..=127).chain(193..=219) {
+            assert!(cta_timing(vic).is_some());
+        }
+
+        assert!(cta_timing(0).is_none());
+        assert!(cta_timing(128).is_none());
+        assert!(cta_timing(220).is_none());
+    }
+
+    #[test]
+    fn test_established() {
+        let timing =
+            established_timing(EdidEstablishedTiming::ET_1024_768_87hz_Interlaced).unwrap();
+        assert_eq!(
+            (
+                timing.horizontal(),
+                timing.vertical(),
+                timing.refresh_rate()
+            ),
+            (1024, 768, 87)
+        );
+        assert!(timing.is_interlaced());
+
+        assert!(established_timing(EdidEstablishedTiming::Manufacturer0).is_none());
+    }
+
+    #[test]
+    fn test_standard() {
+        let st = EdidStandardTiming::builder()
+            .x(EdidStandardTimingHorizontalSize::try_from(1920).unwrap())
+            .ratio(EdidStandardTimingRatio::Ratio_16_10)
+            .frequency(EdidStandardTimingRefreshRate::try_from(75).unwrap())
+            .build();
+
+        for release in [EdidRelease::R3, EdidRelease::R4] {
+            let timing = standard_timing(release, st);
+            assert_eq!(timing.source(), EdidTimingSource::StandardTiming);
+            assert_eq!(
+                (
+                    timing.horizontal(),
+                    timing.vertical(),
+                    timing.refresh_rate()
+                ),
+                (1920, 1200, 75)
+            );
+        }
+    }
+
+    #[test]
+    fn test_descriptors() {
+        let dtd = EdidDescriptorDetailedTiming::cta_1080i_60hz(
+            0.try_into().unwrap(),
+            0.try_into().unwrap(),
+        );
+
+        let timings =
+            descriptor_timings(&EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(dtd)));
+        assert_eq!(timings.len(), 1);
+        assert_eq!(timings[0].source(), EdidTimingSource::DetailedTiming);
+        assert_eq!(
+            (
+                timings[0].horizontal(),
+                timings[0].vertical(),
+                timings[0].refresh_rate()
+            ),
+            (1920, 1080, 60)
+        );
+        assert!(timings[0].is_interlaced());
+
+        let timings =
+            descriptor_timings(&EdidDescriptor::R4(EdidR4Descriptor::EstablishedTimings(
+                EdidR4DescriptorEstablishedTimings::builder()
+                    .add_established_timing(
+                        EdidR4DescriptorEstablishedTimingsIII::ET_1920_1200_60Hz_RB,
+                    )
+                    .build(),
+            )));
+        assert_eq!(timings.len(), 1);
+        assert_eq!(timings[0].source(), EdidTimingSource::EstablishedTimingIII);
+        assert_eq!(
+            (
+                timings[0].horizontal(),
+                timings[0].vertical(),
+                timings[0].refresh_rate()
+            ),
+            (1920, 1200, 60)
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, Euclid, FromPrimitive, Num};
 
+use crate::IntoBytes;
+
 pub(crate) fn round_up<T>(number: &T, multiple: &T) -> T
 where
     T: Copy + Num + CheckedAdd + CheckedMul + Euclid + FromPrimitive,
@@ -24,3 +26,25 @@ where
 
     T::checked_div(&rounded, denominator).expect("Division by zero or would overflow")
 }
+
+/// Returns whether the size reported by an [`IntoBytes`] implementation matches the length of
+/// what it actually serializes to.
+pub(crate) fn size_is_coherent<T>(value: &T) -> bool
+where
+    T: IntoBytes + Clone,
+{
+    value.size() == value.clone().into_bytes().len()
+}
+
+/// Checks that the size reported by an [`IntoBytes`] implementation matches the length of what it
+/// actually serializes to.
+#[cfg(test)]
+pub(crate) fn assert_size_coherent<T>(value: &T)
+where
+    T: IntoBytes + Clone + core::fmt::Debug,
+{
+    assert!(
+        size_is_coherent(value),
+        "size() and into_bytes() disagree for {value:?}"
+    );
+}
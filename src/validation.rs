@@ -0,0 +1,2330 @@
+use core::fmt;
+
+use crate::{
+    extensions, timings, utils::size_is_coherent, Edid, EdidBasicDisplayParametersFeatures,
+    EdidDate, EdidDescriptor, EdidDescriptorPadding, EdidDisplayColorType, EdidEstablishedTiming,
+    EdidExtension, EdidExtensionCTA861, EdidExtensionCTA861Revision3,
+    EdidExtensionCTA861Revision3DataBlock, EdidExtensionDisplayIDTiledDisplayTopology,
+    EdidFilterChromaticity, EdidManufactureDate, EdidR3Descriptor, EdidR3DisplayRangeLimits,
+    EdidR3DisplayRangeVideoTimingsSupport, EdidR3ImageSize, EdidR3VideoInputDefinition, EdidR4Date,
+    EdidR4Descriptor, EdidR4DisplayColor, EdidR4DisplayRangeLimits,
+    EdidR4DisplayRangeVideoTimingsSupport, EdidR4ImageSize, EdidR4ManufactureDate,
+    EdidR4VideoInputDefinition, EdidStandardTiming, EDID_DESCRIPTORS_NUM, EDID_EXTENSIONS_MAX,
+};
+
+/// Errors reported when checking an EDID against the constraints that can't be expressed by the
+/// types themselves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EdidValidationError {
+    /// The Established Timing at the given index was already listed.
+    DuplicateEstablishedTiming(usize),
+
+    /// The manufacture year is after the current year.
+    FutureManufactureDate(u16),
+
+    /// The sRGB Standard is the default color space, but the chromaticity points aren't the
+    /// sRGB ones.
+    SrgbChromaticityMismatch,
+
+    /// The display supports continuous frequencies, but doesn't have a Display Range Limits
+    /// descriptor.
+    MissingDisplayRangeLimits,
+
+    /// The Display Range Limits descriptor at the given index reports supported timings, but the
+    /// display doesn't support continuous frequencies. Only the Range Limits Only subtype is
+    /// allowed in such a case.
+    RangeLimitsWithoutContinuousFrequency(usize),
+
+    /// The EDID 1.3 Display Range Limits descriptor at the given index uses a secondary GTF
+    /// curve, but the display doesn't support GTF.
+    SecondaryGtfWithoutGtfSupport(usize),
+
+    /// The Standard Timing at the given index was already listed.
+    DuplicateStandardTiming(usize),
+
+    /// Descriptors have been set, but would be ignored since a raw descriptors area is used.
+    IgnoredDescriptors,
+
+    /// The descriptors have been set with both the descriptor slots and the descriptors list
+    /// setters, so the slots positions can't be guaranteed.
+    MixedDescriptorSetters,
+
+    /// The Descriptor at the given index is identical to a previous one.
+    DuplicateDescriptor(usize),
+
+    /// The Descriptor at the given index doesn't fit in the base block.
+    TooManyDescriptors(usize),
+
+    /// All the descriptor slots are used, so no descriptor can be added.
+    NoFreeDescriptorSlot,
+
+    /// The Descriptor at the given index serializes to a different number of bytes than the
+    /// size it reports.
+    DescriptorSizeMismatch(usize),
+
+    /// The vertical front porch and sync pulse of the Detailed Timing Descriptor at the given
+    /// index don't fit in its vertical blanking. For interlaced timings, all these values are
+    /// expressed in lines per field.
+    VerticalSyncOutsideBlanking(usize),
+
+    /// Only the given number of Descriptors were provided, but padding is forbidden.
+    MissingDescriptors(usize),
+
+    /// The number of extensions can't be stored in the extension count byte.
+    TooManyExtensions(usize),
+
+    /// The content of the extension at the given index doesn't fit in an extension block. The
+    /// number of bytes it would need is given.
+    ExtensionTooLarge(usize, usize),
+
+    /// The data block at the given index, in the extension at the given index, serializes to a
+    /// different number of bytes than the size it reports.
+    DataBlockSizeMismatch(usize, usize),
+
+    /// The data block at the given index, in the extension at the given index, can only be
+    /// listed once but was already listed. It's the case for the Speaker Allocation,
+    /// Colorimetry, Video Capability and Vendor-Specific Data Blocks, for a given OUI.
+    DuplicateDataBlock(usize, usize),
+
+    /// The data block at the given index, in the extension at the given index, isn't in the
+    /// canonical order.
+    DataBlockOutOfOrder(usize, usize),
+
+    /// The extension at the given index lists an Audio Data Block, but doesn't report Basic Audio
+    /// support.
+    MissingBasicAudio(usize),
+
+    /// The YCbCr support reported by the extension at the given index doesn't match the Color
+    /// Encoding Formats of the base block.
+    YCbCrSupportMismatch(usize),
+
+    /// The extension at the given index allocates more speakers than the maximum number of LPCM
+    /// channels it supports. The number of speakers and channels are given, in that order.
+    TooManySpeakers(usize, usize, usize),
+
+    /// The extension at the given index lists the given VIC more than once.
+    DuplicateVic(usize, u8),
+
+    /// The extension at the given index marks more than one Short Video Descriptor as native.
+    MultipleNativeVics(usize),
+
+    /// The extension at the given index reports a selectable YCbCr quantization range, but
+    /// doesn't support YCbCr.
+    YccQuantizationWithoutYCbCr(usize),
+
+    /// The extension at the given index uses data blocks, native formats or support flags that
+    /// its CTA-861 revision doesn't have.
+    UnsupportedByCtaRevision(usize),
+
+    /// The extension at the given index has an HDMI Vendor-Specific Data Block with a source
+    /// physical address different from the first one of the EDID.
+    PhysicalAddressMismatch(usize),
+
+    /// The chromaticity only has a white point, but the display color type isn't monochrome or
+    /// undefined, or the EDID 1.4 digital Color Encoding Formats list YCbCr. Conversely, the
+    /// display color type is monochrome but red, green and blue points are given.
+    MonochromeMismatch,
+
+    /// The display is monochrome, but the extension at the given index reports YCbCr support or
+    /// lists a Colorimetry Data Block.
+    MonochromeWithColorEncodings(usize),
+
+    /// The `DisplayID` Extension at the given index has a Tiled Display Topology whose tile
+    /// location is outside of its tiles grid.
+    TileOutsideTopology(usize),
+}
+
+impl fmt::Display for EdidValidationError {
+    #[allow(clippy::too_many_lines)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EdidValidationError::DuplicateEstablishedTiming(idx) => {
+                write!(f, "Established Timing {idx} is a duplicate")
+            }
+            EdidValidationError::FutureManufactureDate(year) => {
+                write!(f, "Manufacture year {year} is in the future")
+            }
+            EdidValidationError::SrgbChromaticityMismatch => write!(
+                f,
+                "sRGB is the default color space, but the chromaticity points aren't sRGB's"
+            ),
+            EdidValidationError::MissingDisplayRangeLimits => write!(
+                f,
+                "Continuous frequency is supported, but there's no Display Range Limits descriptor"
+            ),
+            EdidValidationError::RangeLimitsWithoutContinuousFrequency(idx) => write!(
+                f,
+                "Descriptor {idx} reports supported timings, but continuous frequency isn't supported"
+            ),
+            EdidValidationError::SecondaryGtfWithoutGtfSupport(idx) => write!(
+                f,
+                "Descriptor {idx} uses a secondary GTF curve, but GTF isn't supported"
+            ),
+            EdidValidationError::DuplicateStandardTiming(idx) => {
+                write!(f, "Standard Timing {idx} is a duplicate")
+            }
+            EdidValidationError::IgnoredDescriptors => write!(
+                f,
+                "Descriptors are ignored since a raw descriptors area is used"
+            ),
+            EdidValidationError::MixedDescriptorSetters => write!(
+                f,
+                "Descriptors are set with both the descriptor slots and the descriptors list"
+            ),
+            EdidValidationError::DuplicateDescriptor(idx) => {
+                write!(f, "Descriptor {idx} is a duplicate")
+            }
+            EdidValidationError::VerticalSyncOutsideBlanking(idx) => write!(
+                f,
+                "Descriptor {idx} vertical front porch and sync pulse don't fit in its vertical blanking"
+            ),
+            EdidValidationError::MissingDescriptors(num) => write!(
+                f,
+                "{num} descriptors provided, but {EDID_DESCRIPTORS_NUM} are required when padding is forbidden"
+            ),
+            EdidValidationError::TooManyExtensions(num) => write!(
+                f,
+                "{num} extensions provided, an EDID can only hold {EDID_EXTENSIONS_MAX} extensions"
+            ),
+            EdidValidationError::ExtensionTooLarge(ext, size) => write!(
+                f,
+                "Extension {ext} content needs {size} bytes, but an extension block is only 128 bytes"
+            ),
+            EdidValidationError::DataBlockSizeMismatch(ext, idx) => write!(
+                f,
+                "Data Block {idx} of Extension {ext} doesn't serialize to its reported size"
+            ),
+            EdidValidationError::DuplicateDataBlock(ext, idx) => write!(
+                f,
+                "Data Block {idx} of Extension {ext} can only be listed once"
+            ),
+            EdidValidationError::DataBlockOutOfOrder(ext, idx) => write!(
+                f,
+                "Data Block {idx} of Extension {ext} isn't in the canonical order"
+            ),
+            EdidValidationError::MissingBasicAudio(ext) => write!(
+                f,
+                "Extension {ext} has an Audio Data Block but doesn't support Basic Audio"
+            ),
+            EdidValidationError::YCbCrSupportMismatch(ext) => write!(
+                f,
+                "Extension {ext} YCbCr support doesn't match the base block Color Encoding Formats"
+            ),
+            EdidValidationError::TooManySpeakers(ext, speakers, channels) => write!(
+                f,
+                "Extension {ext} allocates {speakers} speakers but supports at most {channels} channels"
+            ),
+            EdidValidationError::DuplicateVic(ext, vic) => {
+                write!(f, "Extension {ext} lists VIC {vic} more than once")
+            }
+            EdidValidationError::MultipleNativeVics(ext) => write!(
+                f,
+                "Extension {ext} has more than one native Short Video Descriptor"
+            ),
+            EdidValidationError::PhysicalAddressMismatch(ext) => write!(
+                f,
+                "Extension {ext} has a source physical address different from the first one"
+            ),
+            EdidValidationError::UnsupportedByCtaRevision(ext) => write!(
+                f,
+                "Extension {ext} uses features its CTA-861 revision doesn't support"
+            ),
+            EdidValidationError::YccQuantizationWithoutYCbCr(ext) => write!(
+                f,
+                "Extension {ext} has a selectable YCbCr quantization range but doesn't support YCbCr"
+            ),
+            EdidValidationError::TooManyDescriptors(idx) => write!(
+                f,
+                "Descriptor {idx} doesn't fit, an EDID can only hold {EDID_DESCRIPTORS_NUM} descriptors"
+            ),
+            EdidValidationError::NoFreeDescriptorSlot => write!(
+                f,
+                "All {EDID_DESCRIPTORS_NUM} descriptor slots are used, no descriptor can be added"
+            ),
+            EdidValidationError::DescriptorSizeMismatch(idx) => write!(
+                f,
+                "Descriptor {idx} doesn't serialize to its reported size"
+            ),
+            EdidValidationError::MonochromeMismatch => write!(
+                f,
+                "The display color type or encodings don't match the monochrome chromaticity"
+            ),
+            EdidValidationError::MonochromeWithColorEncodings(ext) => write!(
+                f,
+                "Extension {ext} reports color encodings, but the display is monochrome"
+            ),
+            EdidValidationError::TileOutsideTopology(ext) => write!(
+                f,
+                "Extension {ext} has a tile location outside of its Tiled Display Topology"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for EdidValidationError {}
+
+/// Inconsistencies that don't make an EDID invalid, but that are likely to be interpreted
+/// differently by sources.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EdidValidationWarning {
+    /// The Video Capability Data Block of the extension at the given index disagrees with the
+    /// extension header on whether IT Video Formats are underscanned.
+    UnderscanMismatch(usize),
+
+    /// The Display Range Limits descriptor at the given index uses a GTF subtype, which is
+    /// deprecated since EDID 1.4. Digital displays report
+    /// [`EdidValidationWarning::DigitalInputWithGtfRangeLimits`] instead.
+    DeprecatedGtfRangeLimits(usize),
+
+    /// The image size of the Detailed Timing Descriptor at the given index differs by more than
+    /// a centimeter from the screen size of the base block. Operating systems don't agree on
+    /// which one to trust, so they will compute different DPIs.
+    DetailedTimingSizeMismatch(usize),
+
+    /// A Detailed Timing Descriptor describes the same mode than the given VIC, but with a
+    /// different blanking. Userspace will usually end up listing the mode twice.
+    ConflictingVideoFormat(u8),
+
+    /// The EDID has a CTA-861 extension, but doesn't list the 640x480, 60Hz, mode that every
+    /// CTA-861 (and thus HDMI) sink must support. It can be omitted for DP only
+    /// displays.
+    Missing640x480,
+
+    /// The base block declares an analog video input, but the CTA-861 extension at the given
+    /// index has Data Blocks, which only make sense for digital (HDMI or `DisplayPort`) sinks.
+    AnalogInputWithDataBlocks(usize),
+
+    /// The base block declares a digital video input, but the Display Range Limits descriptor at
+    /// the given index uses a GTF subtype, which was designed for analog CRT monitors.
+    DigitalInputWithGtfRangeLimits(usize),
+
+    /// The EDID 1.3 base block declares a digital video input, but also claims to support the
+    /// default GTF, which was designed for analog CRT monitors.
+    DigitalInputWithGtfSupport,
+
+    /// The EDID 1.3 base block declares a DFP 1.x compatible input, which implies 8 bits per
+    /// color component, but the HDMI Vendor-Specific Data Block of the CTA-861 extension at the
+    /// given index advertises deep color. EDID 1.3 can't express any other color depth, an EDID
+    /// 1.4 with the appropriate color depth should be used instead.
+    DfpInputWithDeepColor(usize),
+}
+
+impl fmt::Display for EdidValidationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EdidValidationWarning::UnderscanMismatch(ext) => write!(
+                f,
+                "Extension {ext} Video Capability Data Block disagrees with its header on IT Video Formats underscan"
+            ),
+            EdidValidationWarning::DetailedTimingSizeMismatch(idx) => write!(
+                f,
+                "Descriptor {idx} image size doesn't match the base block screen size"
+            ),
+            EdidValidationWarning::DeprecatedGtfRangeLimits(idx) => write!(
+                f,
+                "Descriptor {idx} uses a GTF Display Range Limits subtype, deprecated since EDID 1.4"
+            ),
+            EdidValidationWarning::ConflictingVideoFormat(vic) => write!(
+                f,
+                "VIC {vic} is also described by a Detailed Timing with a different blanking"
+            ),
+            EdidValidationWarning::Missing640x480 => write!(
+                f,
+                "A CTA-861 extension is present, but 640x480 at 60Hz isn't listed"
+            ),
+            EdidValidationWarning::AnalogInputWithDataBlocks(ext) => write!(
+                f,
+                "The video input is analog, but extension {ext} has CTA-861 Data Blocks"
+            ),
+            EdidValidationWarning::DigitalInputWithGtfRangeLimits(idx) => write!(
+                f,
+                "The video input is digital, but descriptor {idx} uses a GTF Display Range Limits subtype"
+            ),
+            EdidValidationWarning::DigitalInputWithGtfSupport => write!(
+                f,
+                "The video input is digital, but the default GTF is reported as supported"
+            ),
+            EdidValidationWarning::DfpInputWithDeepColor(ext) => write!(
+                f,
+                "The video input is DFP 1.x compatible, so 8 bits per color, but extension {ext} advertises deep color"
+            ),
+        }
+    }
+}
+
+/// Values that can't be represented exactly in an EDID, and are adjusted when serializing it.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum EdidSerializationWarning {
+    /// The gamma is rounded to the nearest hundredth.
+    GammaRounded(f32),
+
+    /// The pixel clock, in kHz, of the Detailed Timing Descriptor at the given index is
+    /// truncated to a multiple of 10kHz.
+    DetailedTimingPixelClockTruncated(usize, u32),
+
+    /// The pixel clock, in kHz, of the Detailed Timing Descriptor at the given index of the
+    /// extension at the given index is truncated to a multiple of 10kHz.
+    ExtensionDetailedTimingPixelClockTruncated(usize, usize, u32),
+
+    /// The maximum pixel clock, in MHz, of the Display Range Limits descriptor at the given
+    /// index is rounded up to a multiple of 10MHz.
+    RangeLimitsPixelClockRounded(usize, u16),
+
+    /// The string of the Display Descriptor at the given index is shorter than the payload, and
+    /// is terminated and padded.
+    DescriptorStringPadded(usize),
+}
+
+impl fmt::Display for EdidSerializationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EdidSerializationWarning::GammaRounded(gamma) => {
+                write!(f, "Gamma {gamma} is rounded to the nearest hundredth")
+            }
+            EdidSerializationWarning::DetailedTimingPixelClockTruncated(idx, clock) => write!(
+                f,
+                "Descriptor {idx} pixel clock {clock}kHz is truncated to a multiple of 10kHz"
+            ),
+            EdidSerializationWarning::ExtensionDetailedTimingPixelClockTruncated(
+                ext,
+                idx,
+                clock,
+            ) => write!(
+                f,
+                "Extension {ext} Detailed Timing {idx} pixel clock {clock}kHz is truncated to a multiple of 10kHz"
+            ),
+            EdidSerializationWarning::RangeLimitsPixelClockRounded(idx, clock) => write!(
+                f,
+                "Descriptor {idx} maximum pixel clock {clock}MHz is rounded up to a multiple of 10MHz"
+            ),
+            EdidSerializationWarning::DescriptorStringPadded(idx) => {
+                write!(f, "Descriptor {idx} string is padded to 13 bytes")
+            }
+        }
+    }
+}
+
+fn find_duplicate<T: PartialEq>(items: &[T], skip: impl Fn(&T) -> bool) -> Option<usize> {
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| !skip(item))
+        .find(|(idx, item)| items[..*idx].contains(item))
+        .map(|(idx, _)| idx)
+}
+
+fn check_established_timings(timings: &[EdidEstablishedTiming]) -> Result<(), EdidValidationError> {
+    if let Some(idx) = find_duplicate(timings, |_| false) {
+        return Err(EdidValidationError::DuplicateEstablishedTiming(idx));
+    }
+
+    Ok(())
+}
+
+fn check_srgb(
+    bdpf: &EdidBasicDisplayParametersFeatures,
+    chroma: &EdidFilterChromaticity,
+) -> Result<(), EdidValidationError> {
+    let srgb = match bdpf {
+        EdidBasicDisplayParametersFeatures::R3(bdpf) => {
+            bdpf.feature_support.srgb_default_color_space
+        }
+        EdidBasicDisplayParametersFeatures::R4(bdpf) => {
+            bdpf.feature_support.srgb_default_color_space
+        }
+    };
+
+    if !srgb {
+        return Ok(());
+    }
+
+    let EdidFilterChromaticity::Color(points) = chroma else {
+        return Err(EdidValidationError::SrgbChromaticityMismatch);
+    };
+
+    if !points.matches_srgb() {
+        return Err(EdidValidationError::SrgbChromaticityMismatch);
+    }
+
+    Ok(())
+}
+
+fn check_date(date: EdidDate, now: u16) -> Result<(), EdidValidationError> {
+    // Model years may legitimately be ahead of the calendar, but a display can't have been
+    // manufactured in the future.
+    let year = match date {
+        EdidDate::R3(EdidManufactureDate(_, year))
+        | EdidDate::R4(EdidR4Date::Manufacture(EdidR4ManufactureDate(_, year))) => year.0,
+        EdidDate::R4(EdidR4Date::Model(_)) => return Ok(()),
+    };
+
+    if year > now {
+        return Err(EdidValidationError::FutureManufactureDate(year));
+    }
+
+    Ok(())
+}
+
+fn check_standard_timings(timings: &[EdidStandardTiming]) -> Result<(), EdidValidationError> {
+    if let Some(idx) = find_duplicate(timings, |_| false) {
+        return Err(EdidValidationError::DuplicateStandardTiming(idx));
+    }
+
+    Ok(())
+}
+
+fn check_descriptors_count(
+    descriptors: &[EdidDescriptor],
+    padding: EdidDescriptorPadding,
+) -> Result<(), EdidValidationError> {
+    if descriptors.len() > EDID_DESCRIPTORS_NUM {
+        return Err(EdidValidationError::TooManyDescriptors(
+            EDID_DESCRIPTORS_NUM,
+        ));
+    }
+
+    if padding == EdidDescriptorPadding::Forbidden && descriptors.len() < EDID_DESCRIPTORS_NUM {
+        return Err(EdidValidationError::MissingDescriptors(descriptors.len()));
+    }
+
+    Ok(())
+}
+
+fn check_descriptors(descriptors: &[EdidDescriptor]) -> Result<(), EdidValidationError> {
+    // Dummy Descriptors are meant to be repeated, so we don't want to report them.
+    if let Some(idx) = find_duplicate(descriptors, |desc| {
+        matches!(
+            desc,
+            EdidDescriptor::R3(EdidR3Descriptor::Dummy)
+                | EdidDescriptor::R4(EdidR4Descriptor::Dummy)
+        )
+    }) {
+        return Err(EdidValidationError::DuplicateDescriptor(idx));
+    }
+
+    for (idx, desc) in descriptors.iter().enumerate() {
+        let (EdidDescriptor::R3(EdidR3Descriptor::DetailedTiming(dtd))
+        | EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(dtd))) = desc
+        else {
+            continue;
+        };
+
+        if !dtd.vertical_sync_in_blanking() {
+            return Err(EdidValidationError::VerticalSyncOutsideBlanking(idx));
+        }
+    }
+
+    Ok(())
+}
+
+fn r4_range_limits(
+    descriptors: &[EdidDescriptor],
+) -> impl Iterator<Item = (usize, &EdidR4DisplayRangeLimits)> {
+    descriptors
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, desc)| match desc {
+            EdidDescriptor::R4(EdidR4Descriptor::DisplayRangeLimits(limits)) => Some((idx, limits)),
+            EdidDescriptor::R3(_) | EdidDescriptor::R4(_) | EdidDescriptor::Raw(_) => None,
+        })
+}
+
+fn r3_range_limits(
+    descriptors: &[EdidDescriptor],
+) -> impl Iterator<Item = (usize, &EdidR3DisplayRangeLimits)> {
+    descriptors
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, desc)| match desc {
+            EdidDescriptor::R3(EdidR3Descriptor::DisplayRangeLimits(limits)) => Some((idx, limits)),
+            EdidDescriptor::R3(_) | EdidDescriptor::R4(_) | EdidDescriptor::Raw(_) => None,
+        })
+}
+
+fn check_range_limits(
+    bdpf: &EdidBasicDisplayParametersFeatures,
+    descriptors: &[EdidDescriptor],
+) -> Result<(), EdidValidationError> {
+    let bdpf = match bdpf {
+        EdidBasicDisplayParametersFeatures::R3(bdpf) => {
+            // EDID 1.3 has no Range Limits Only subtype, GTF support is reported through the
+            // feature support byte instead, and a secondary curve only makes sense on top of it.
+            if bdpf.feature_support.default_gtf_supported {
+                return Ok(());
+            }
+
+            for (idx, limits) in r3_range_limits(descriptors) {
+                if matches!(
+                    limits.timings_support(),
+                    EdidR3DisplayRangeVideoTimingsSupport::SecondaryGTF(_)
+                ) {
+                    return Err(EdidValidationError::SecondaryGtfWithoutGtfSupport(idx));
+                }
+            }
+
+            return Ok(());
+        }
+        EdidBasicDisplayParametersFeatures::R4(bdpf) => bdpf,
+    };
+
+    let continuous = bdpf.feature_support.continuous_frequency;
+    if continuous && r4_range_limits(descriptors).next().is_none() {
+        return Err(EdidValidationError::MissingDisplayRangeLimits);
+    }
+
+    for (idx, limits) in r4_range_limits(descriptors) {
+        if !continuous
+            && *limits.timings_support() != EdidR4DisplayRangeVideoTimingsSupport::RangeLimitsOnly
+        {
+            return Err(EdidValidationError::RangeLimitsWithoutContinuousFrequency(
+                idx,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// The serialization lays the descriptors and data blocks out from their reported size.
+fn check_descriptor_sizes(descriptors: &[EdidDescriptor]) -> Result<(), EdidValidationError> {
+    let mismatch = descriptors.iter().position(|desc| match desc {
+        EdidDescriptor::R3(desc) => !size_is_coherent(desc),
+        EdidDescriptor::R4(desc) => !size_is_coherent(desc),
+        EdidDescriptor::Raw(_) => false,
+    });
+
+    if let Some(idx) = mismatch {
+        return Err(EdidValidationError::DescriptorSizeMismatch(idx));
+    }
+
+    Ok(())
+}
+
+fn check_data_block_sizes(extensions: &[EdidExtension]) -> Result<(), EdidValidationError> {
+    for (ext_idx, ext) in extensions.iter().enumerate() {
+        let mismatch = match ext {
+            EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => cta
+                .data_blocks()
+                .iter()
+                .position(|block| !size_is_coherent(block)),
+            EdidExtension::DisplayID(displayid) => displayid
+                .data_blocks()
+                .iter()
+                .position(|block| !size_is_coherent(block)),
+        };
+
+        if let Some(idx) = mismatch {
+            return Err(EdidValidationError::DataBlockSizeMismatch(ext_idx, idx));
+        }
+    }
+
+    Ok(())
+}
+
+fn check_extensions_count(extensions: &[EdidExtension]) -> Result<(), EdidValidationError> {
+    // The number of extensions is derived from the extensions list when serializing, so the
+    // count byte can't get out of sync with the extensions as long as it fits.
+    if extensions.len() > EDID_EXTENSIONS_MAX {
+        return Err(EdidValidationError::TooManyExtensions(extensions.len()));
+    }
+
+    Ok(())
+}
+
+fn check_extensions(extensions: &[EdidExtension]) -> Result<(), EdidValidationError> {
+    // There's a single physical address per sink, every HDMI VSDB must report the same one.
+    let mut addresses = extensions::physical_addresses(extensions);
+    if let Some((_, first)) = addresses.next() {
+        if let Some((ext_idx, _)) = addresses.find(|(_, address)| *address != first) {
+            return Err(EdidValidationError::PhysicalAddressMismatch(ext_idx));
+        }
+    }
+
+    for (ext_idx, ext) in extensions.iter().enumerate() {
+        match ext {
+            EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => {
+                if cta.revision_mismatch() {
+                    return Err(EdidValidationError::UnsupportedByCtaRevision(ext_idx));
+                }
+
+                if !cta.fits() {
+                    return Err(EdidValidationError::ExtensionTooLarge(
+                        ext_idx,
+                        cta.used_bytes(),
+                    ));
+                }
+
+                if let Some(idx) = cta.duplicate_data_block() {
+                    return Err(EdidValidationError::DuplicateDataBlock(ext_idx, idx));
+                }
+
+                if let Some(idx) = cta.misordered_data_block() {
+                    return Err(EdidValidationError::DataBlockOutOfOrder(ext_idx, idx));
+                }
+
+                // CTA-861 requires Sinks listing audio formats to support Basic Audio.
+                if cta.missing_basic_audio() {
+                    return Err(EdidValidationError::MissingBasicAudio(ext_idx));
+                }
+
+                if cta.ycc_quantization_without_ycbcr() {
+                    return Err(EdidValidationError::YccQuantizationWithoutYCbCr(ext_idx));
+                }
+
+                if let Some(vic) = cta.duplicate_vic() {
+                    return Err(EdidValidationError::DuplicateVic(ext_idx, vic));
+                }
+
+                if cta.num_native_vics() > 1 {
+                    return Err(EdidValidationError::MultipleNativeVics(ext_idx));
+                }
+
+                if let Some((speakers, channels)) = cta.speakers_channels_mismatch() {
+                    return Err(EdidValidationError::TooManySpeakers(
+                        ext_idx, speakers, channels,
+                    ));
+                }
+            }
+            EdidExtension::DisplayID(displayid) => {
+                if !displayid.fits() {
+                    return Err(EdidValidationError::ExtensionTooLarge(
+                        ext_idx,
+                        displayid.used_bytes(),
+                    ));
+                }
+
+                if !displayid
+                    .tiled_display_topologies()
+                    .all(EdidExtensionDisplayIDTiledDisplayTopology::location_is_valid)
+                {
+                    return Err(EdidValidationError::TileOutsideTopology(ext_idx));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn check_color_encoding(
+    bdpf: &EdidBasicDisplayParametersFeatures,
+    extensions: &[EdidExtension],
+) -> Result<(), EdidValidationError> {
+    // Only EDID 1.4 digital displays report their color encoding formats in the base block.
+    let EdidBasicDisplayParametersFeatures::R4(bdpf) = bdpf else {
+        return Ok(());
+    };
+
+    let EdidR4DisplayColor::Digital(encoding) = bdpf.feature_support.color else {
+        return Ok(());
+    };
+
+    for (ext_idx, ext) in extensions.iter().enumerate() {
+        match ext {
+            EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => {
+                if cta.ycbcr_supported()
+                    != (
+                        encoding.ycbcr_444_supported(),
+                        encoding.ycbcr_422_supported(),
+                    )
+                {
+                    return Err(EdidValidationError::YCbCrSupportMismatch(ext_idx));
+                }
+            }
+            EdidExtension::DisplayID(_) => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn check_monochrome(
+    bdpf: &EdidBasicDisplayParametersFeatures,
+    chroma: &EdidFilterChromaticity,
+    extensions: &[EdidExtension],
+) -> Result<(), EdidValidationError> {
+    let monochrome = matches!(chroma, EdidFilterChromaticity::MonoChrome(_));
+
+    let display_type = match bdpf {
+        EdidBasicDisplayParametersFeatures::R3(bdpf) => bdpf.feature_support.display_type,
+        EdidBasicDisplayParametersFeatures::R4(bdpf) => match bdpf.feature_support.color {
+            EdidR4DisplayColor::Analog(display_type) => display_type,
+            // Digital displays don't have a display color type, but a monochrome one can only
+            // take RGB 4:4:4.
+            EdidR4DisplayColor::Digital(encoding) => {
+                if monochrome && (encoding.ycbcr_444_supported() || encoding.ycbcr_422_supported())
+                {
+                    return Err(EdidValidationError::MonochromeMismatch);
+                }
+
+                EdidDisplayColorType::Undefined
+            }
+        },
+    };
+
+    let consistent = match display_type {
+        EdidDisplayColorType::MonochromeGrayScale => monochrome,
+        EdidDisplayColorType::RGBColor | EdidDisplayColorType::NonRGBColor => !monochrome,
+        EdidDisplayColorType::Undefined => true,
+    };
+
+    if !consistent {
+        return Err(EdidValidationError::MonochromeMismatch);
+    }
+
+    if !monochrome {
+        return Ok(());
+    }
+
+    for (ext_idx, ext) in extensions.iter().enumerate() {
+        match ext {
+            EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => {
+                if cta.ycbcr_supported() != (false, false)
+                    || cta
+                        .data_blocks()
+                        .iter()
+                        .any(|b| matches!(b, EdidExtensionCTA861Revision3DataBlock::Colorimetry(_)))
+                {
+                    return Err(EdidValidationError::MonochromeWithColorEncodings(ext_idx));
+                }
+            }
+            EdidExtension::DisplayID(_) => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn r4_gtf_range_limits(descriptors: &[EdidDescriptor]) -> impl Iterator<Item = usize> + '_ {
+    r4_range_limits(descriptors)
+        .filter(|(_, limits)| {
+            #[allow(deprecated)]
+            let gtf = matches!(
+                limits.timings_support(),
+                EdidR4DisplayRangeVideoTimingsSupport::DefaultGTF
+                    | EdidR4DisplayRangeVideoTimingsSupport::SecondaryGTF(_)
+            );
+
+            gtf
+        })
+        .map(|(idx, _)| idx)
+}
+
+fn descriptors_warnings(
+    bdpf: &EdidBasicDisplayParametersFeatures,
+    descriptors: &[EdidDescriptor],
+) -> Vec<EdidValidationWarning> {
+    // GTF doesn't make sense at all for a digital input, which is worth more than a
+    // deprecation notice.
+    let digital = match bdpf {
+        EdidBasicDisplayParametersFeatures::R3(bdpf) => {
+            matches!(bdpf.video_input, EdidR3VideoInputDefinition::Digital(_))
+        }
+        EdidBasicDisplayParametersFeatures::R4(bdpf) => {
+            matches!(bdpf.video_input, EdidR4VideoInputDefinition::Digital(_))
+        }
+    };
+
+    r4_gtf_range_limits(descriptors)
+        .map(|idx| {
+            if digital {
+                EdidValidationWarning::DigitalInputWithGtfRangeLimits(idx)
+            } else {
+                EdidValidationWarning::DeprecatedGtfRangeLimits(idx)
+            }
+        })
+        .collect()
+}
+
+fn size_warnings(
+    bdpf: &EdidBasicDisplayParametersFeatures,
+    descriptors: &[EdidDescriptor],
+) -> Vec<EdidValidationWarning> {
+    let size = match bdpf {
+        EdidBasicDisplayParametersFeatures::R3(bdpf) => match bdpf.size {
+            EdidR3ImageSize::Size(size) => size,
+            EdidR3ImageSize::Undefined => return Vec::new(),
+        },
+        EdidBasicDisplayParametersFeatures::R4(bdpf) => match bdpf.size {
+            EdidR4ImageSize::Size(size) => size,
+            EdidR4ImageSize::LandscapeRatio(_)
+            | EdidR4ImageSize::PortraitRatio(_)
+            | EdidR4ImageSize::Undefined => return Vec::new(),
+        },
+    };
+
+    let screen_mm = (
+        u16::from(size.horizontal_cm.0) * 10,
+        u16::from(size.vertical_cm.0) * 10,
+    );
+
+    descriptors
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, desc)| match desc {
+            EdidDescriptor::R3(EdidR3Descriptor::DetailedTiming(dtd))
+            | EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(dtd)) => {
+                Some((idx, dtd.size_mm()))
+            }
+            EdidDescriptor::R3(_) | EdidDescriptor::R4(_) | EdidDescriptor::Raw(_) => None,
+        })
+        // A size of 0 means that the image size isn't specified.
+        .filter(|(_, (h, v))| *h != 0 && *v != 0)
+        .filter(|(_, (h, v))| h.abs_diff(screen_mm.0) > 10 || v.abs_diff(screen_mm.1) > 10)
+        .map(|(idx, _)| EdidValidationWarning::DetailedTimingSizeMismatch(idx))
+        .collect()
+}
+
+fn input_warnings(
+    bdpf: &EdidBasicDisplayParametersFeatures,
+    extensions: &[EdidExtension],
+) -> Vec<EdidValidationWarning> {
+    let mut warnings = Vec::new();
+
+    let analog = match bdpf {
+        EdidBasicDisplayParametersFeatures::R3(bdpf) => {
+            let analog = matches!(bdpf.video_input, EdidR3VideoInputDefinition::Analog(_));
+
+            if !analog && bdpf.feature_support.default_gtf_supported {
+                warnings.push(EdidValidationWarning::DigitalInputWithGtfSupport);
+            }
+
+            if let EdidR3VideoInputDefinition::Digital(input) = bdpf.video_input {
+                if input.dfp1_compatible {
+                    warnings.extend(extensions.iter().enumerate().filter_map(|(ext_idx, ext)| {
+                        match ext {
+                            EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => cta
+                                .deep_color()
+                                .then_some(EdidValidationWarning::DfpInputWithDeepColor(ext_idx)),
+                            EdidExtension::DisplayID(_) => None,
+                        }
+                    }));
+                }
+            }
+
+            analog
+        }
+        EdidBasicDisplayParametersFeatures::R4(bdpf) => {
+            matches!(bdpf.video_input, EdidR4VideoInputDefinition::Analog(_))
+        }
+    };
+
+    if analog {
+        for (ext_idx, ext) in extensions.iter().enumerate() {
+            match ext {
+                EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => {
+                    if !cta.data_blocks().is_empty() {
+                        warnings.push(EdidValidationWarning::AnalogInputWithDataBlocks(ext_idx));
+                    }
+                }
+                EdidExtension::DisplayID(_) => {}
+            }
+        }
+    }
+
+    warnings
+}
+
+fn extensions_warnings(extensions: &[EdidExtension]) -> Vec<EdidValidationWarning> {
+    let mut warnings = Vec::new();
+
+    for (ext_idx, ext) in extensions.iter().enumerate() {
+        match ext {
+            EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => {
+                if cta.underscan_mismatch() {
+                    warnings.push(EdidValidationWarning::UnderscanMismatch(ext_idx));
+                }
+            }
+            EdidExtension::DisplayID(_) => {}
+        }
+    }
+
+    warnings
+}
+
+fn video_formats_warnings(
+    descriptors: &[EdidDescriptor],
+    extensions: &[EdidExtension],
+) -> Vec<EdidValidationWarning> {
+    let ctas = extensions.iter().filter_map(|ext| match ext {
+        EdidExtension::CTA861(EdidExtensionCTA861::Revision3(cta)) => Some(cta),
+        EdidExtension::DisplayID(_) => None,
+    });
+
+    let dtds: Vec<_> = descriptors
+        .iter()
+        .filter_map(|desc| match desc {
+            EdidDescriptor::R3(EdidR3Descriptor::DetailedTiming(dtd))
+            | EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(dtd)) => Some(dtd),
+            EdidDescriptor::R3(_) | EdidDescriptor::R4(_) | EdidDescriptor::Raw(_) => None,
+        })
+        .chain(
+            ctas.clone()
+                .flat_map(EdidExtensionCTA861Revision3::detailed_timings),
+        )
+        .collect();
+
+    ctas.flat_map(EdidExtensionCTA861Revision3::short_video_descriptors)
+        .map(|desc| desc.vic())
+        .filter(|vic| {
+            dtds.iter()
+                .any(|dtd| timings::conflicts_with_vic(dtd, *vic))
+        })
+        .map(EdidValidationWarning::ConflictingVideoFormat)
+        .collect()
+}
+
+fn mandatory_timings_warnings(edid: &Edid) -> Option<EdidValidationWarning> {
+    if edid.extensions.is_empty() {
+        return None;
+    }
+
+    let vga = timings::edid_timings(edid).iter().any(|timing| {
+        (
+            timing.horizontal(),
+            timing.vertical(),
+            timing.refresh_rate(),
+        ) == (640, 480, 60)
+            && !timing.is_interlaced()
+    });
+
+    (!vga).then_some(EdidValidationWarning::Missing640x480)
+}
+
+impl Edid {
+    pub(crate) fn warnings(&self) -> Vec<EdidValidationWarning> {
+        let mut warnings = descriptors_warnings(&self.bdpf, &self.descriptors);
+        warnings.extend(size_warnings(&self.bdpf, &self.descriptors));
+        warnings.extend(extensions_warnings(&self.extensions));
+        warnings.extend(video_formats_warnings(&self.descriptors, &self.extensions));
+        warnings.extend(mandatory_timings_warnings(self));
+        warnings.extend(input_warnings(&self.bdpf, &self.extensions));
+
+        warnings
+    }
+
+    // The checks the serialization relies on, enforced by the builders.
+    pub(crate) fn check_layout(&self) -> Result<(), EdidValidationError> {
+        if self.raw_descriptors.is_none() {
+            check_descriptors_count(&self.descriptors, self.descriptor_padding)?;
+        }
+
+        check_extensions_count(&self.extensions)?;
+
+        Ok(())
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), EdidValidationError> {
+        self.check_layout()?;
+        check_established_timings(&self.established_timings)?;
+        check_standard_timings(&self.standard_timings)?;
+        // The raw descriptors area is opaque, so neither the descriptors nor the Display Range
+        // Limits the features rely on can be checked.
+        if self.raw_descriptors.is_none() {
+            check_descriptors(&self.descriptors)?;
+            check_descriptor_sizes(&self.descriptors)?;
+            check_range_limits(&self.bdpf, &self.descriptors)?;
+        } else if !self.descriptors.is_empty() {
+            return Err(EdidValidationError::IgnoredDescriptors);
+        }
+
+        check_extensions(&self.extensions)?;
+        check_data_block_sizes(&self.extensions)?;
+        check_color_encoding(&self.bdpf, &self.extensions)?;
+        check_srgb(&self.bdpf, &self.chroma_coord)?;
+        check_monochrome(&self.bdpf, &self.chroma_coord, &self.extensions)?;
+
+        Ok(())
+    }
+
+    pub(crate) fn validate_at(&self, year: u16) -> Result<(), EdidValidationError> {
+        self.validate()?;
+        check_date(self.date, year)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_validation_duplicates {
+    use super::{
+        check_date, check_descriptors, check_descriptors_count, check_established_timings,
+        check_extensions_count, check_standard_timings,
+    };
+    use crate::{
+        EdidDate, EdidDescriptor, EdidDescriptor10BitsTiming, EdidDescriptor12BitsTiming,
+        EdidDescriptor6BitsTiming, EdidDescriptor8BitsTiming, EdidDescriptorDetailedTiming,
+        EdidDescriptorPadding, EdidDescriptorString, EdidDetailedTimingAnalogSync,
+        EdidDetailedTimingPixelClock, EdidDetailedTimingSizeMm, EdidDetailedTimingStereo,
+        EdidDetailedTimingSync, EdidEstablishedTiming, EdidExtension, EdidExtensionCTA861,
+        EdidExtensionCTA861Revision3, EdidManufactureDate, EdidR3Descriptor, EdidR4Date,
+        EdidR4Descriptor, EdidR4ManufactureDate, EdidR4ModelDate, EdidStandardTiming,
+        EdidStandardTimingHorizontalSize, EdidStandardTimingRatio, EdidStandardTimingRefreshRate,
+        EdidValidationError,
+    };
+
+    #[test]
+    fn test_established_timings() {
+        assert_eq!(
+            check_established_timings(&[
+                EdidEstablishedTiming::ET_640_480_60hz,
+                EdidEstablishedTiming::ET_800_600_60hz,
+            ]),
+            Ok(())
+        );
+
+        assert_eq!(
+            check_established_timings(&[
+                EdidEstablishedTiming::ET_640_480_60hz,
+                EdidEstablishedTiming::ET_800_600_60hz,
+                EdidEstablishedTiming::ET_640_480_60hz,
+            ]),
+            Err(EdidValidationError::DuplicateEstablishedTiming(2))
+        );
+    }
+
+    #[test]
+    fn test_date() {
+        let r3 = |year| EdidDate::R3(EdidManufactureDate::try_from(year).unwrap());
+        let r4 = |year| {
+            EdidDate::R4(EdidR4Date::Manufacture(
+                EdidR4ManufactureDate::try_from(year).unwrap(),
+            ))
+        };
+
+        assert_eq!(check_date(r3(2024), 2024), Ok(()));
+        assert_eq!(check_date(r4(2023), 2024), Ok(()));
+        assert_eq!(
+            check_date(r3(2045), 2024),
+            Err(EdidValidationError::FutureManufactureDate(2045))
+        );
+        assert_eq!(
+            check_date(r4(2045), 2024),
+            Err(EdidValidationError::FutureManufactureDate(2045))
+        );
+        assert_eq!(
+            check_date(
+                EdidDate::R4(EdidR4Date::Model(EdidR4ModelDate::try_from(2025).unwrap())),
+                2024
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_standard_timings() {
+        let st = |x, freq| {
+            EdidStandardTiming::builder()
+                .x(EdidStandardTimingHorizontalSize::try_from(x).unwrap())
+                .ratio(EdidStandardTimingRatio::Ratio_4_3)
+                .frequency(EdidStandardTimingRefreshRate::try_from(freq).unwrap())
+                .build()
+        };
+
+        assert_eq!(
+            check_standard_timings(&[st(1600, 85), st(1600, 75)]),
+            Ok(())
+        );
+        assert_eq!(
+            check_standard_timings(&[st(1600, 85), st(1600, 75), st(1600, 85)]),
+            Err(EdidValidationError::DuplicateStandardTiming(2))
+        );
+    }
+
+    #[test]
+    fn test_descriptors() {
+        let name = || {
+            EdidDescriptor::R3(EdidR3Descriptor::ProductName(
+                EdidDescriptorString::try_from("Monitor").unwrap(),
+            ))
+        };
+
+        assert_eq!(
+            check_descriptors(&[
+                name(),
+                EdidDescriptor::R3(EdidR3Descriptor::Dummy),
+                EdidDescriptor::R4(EdidR4Descriptor::Dummy),
+                EdidDescriptor::R3(EdidR3Descriptor::Dummy),
+            ]),
+            Ok(())
+        );
+
+        assert_eq!(
+            check_descriptors(&[name(), EdidDescriptor::R3(EdidR3Descriptor::Dummy), name()]),
+            Err(EdidValidationError::DuplicateDescriptor(2))
+        );
+    }
+
+    #[test]
+    fn test_descriptors_count() {
+        let name = |name| {
+            EdidDescriptor::R4(EdidR4Descriptor::ProductName(
+                EdidDescriptorString::try_from(name).unwrap(),
+            ))
+        };
+
+        assert_eq!(
+            check_descriptors_count(
+                &[name("A"), name("B"), name("C"), name("D")],
+                EdidDescriptorPadding::Dummy
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            check_descriptors_count(
+                &[name("A"), name("B"), name("C"), name("D"), name("E")],
+                EdidDescriptorPadding::Dummy
+            ),
+            Err(EdidValidationError::TooManyDescriptors(4))
+        );
+    }
+
+    #[test]
+    fn test_descriptors_padding() {
+        let name = |name| {
+            EdidDescriptor::R4(EdidR4Descriptor::ProductName(
+                EdidDescriptorString::try_from(name).unwrap(),
+            ))
+        };
+
+        assert_eq!(
+            check_descriptors_count(&[name("A"), name("B")], EdidDescriptorPadding::Dummy),
+            Ok(())
+        );
+        assert_eq!(
+            check_descriptors_count(
+                &[name("A"), name("B"), name("C"), name("D")],
+                EdidDescriptorPadding::Forbidden
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            check_descriptors_count(&[name("A"), name("B")], EdidDescriptorPadding::Forbidden),
+            Err(EdidValidationError::MissingDescriptors(2))
+        );
+    }
+
+    #[test]
+    fn test_descriptors_vertical_sync() {
+        let dtd = |vblank| {
+            EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(
+                EdidDescriptorDetailedTiming::builder()
+                    .pixel_clock(EdidDetailedTimingPixelClock::try_from(74_250).unwrap())
+                    .horizontal_addressable(EdidDescriptor12BitsTiming::try_from(1920).unwrap())
+                    .horizontal_blanking(EdidDescriptor12BitsTiming::try_from(280).unwrap())
+                    .vertical_addressable(
+                        EdidDescriptor12BitsTiming::from_interlaced_frame(1080).unwrap(),
+                    )
+                    .vertical_blanking(vblank)
+                    .horizontal_front_porch(EdidDescriptor10BitsTiming::try_from(88).unwrap())
+                    .horizontal_sync_pulse(EdidDescriptor10BitsTiming::try_from(44).unwrap())
+                    .vertical_front_porch(EdidDescriptor6BitsTiming::try_from(4).unwrap())
+                    .vertical_sync_pulse(EdidDescriptor6BitsTiming::try_from(10).unwrap())
+                    .horizontal_size(EdidDetailedTimingSizeMm::try_from(0).unwrap())
+                    .vertical_size(EdidDetailedTimingSizeMm::try_from(0).unwrap())
+                    .horizontal_border(EdidDescriptor8BitsTiming::try_from(0).unwrap())
+                    .vertical_border(EdidDescriptor8BitsTiming::try_from(0).unwrap())
+                    .interlace(true)
+                    .sync_type(EdidDetailedTimingSync::Analog(
+                        EdidDetailedTimingAnalogSync::Composite(false, false),
+                    ))
+                    .stereo(EdidDetailedTimingStereo::None)
+                    .build(),
+            ))
+        };
+
+        assert_eq!(
+            check_descriptors(&[dtd(
+                EdidDescriptor12BitsTiming::from_interlaced_frame(45).unwrap()
+            )]),
+            Ok(())
+        );
+
+        // Frame values used for an interlaced timing.
+        assert_eq!(
+            check_descriptors(&[dtd(EdidDescriptor12BitsTiming::try_from(5).unwrap())]),
+            Err(EdidValidationError::VerticalSyncOutsideBlanking(0))
+        );
+    }
+
+    #[test]
+    fn test_extensions_count() {
+        let ext = EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+            EdidExtensionCTA861Revision3::builder().build(),
+        ));
+
+        assert_eq!(check_extensions_count(&vec![ext.clone(); 255]), Ok(()));
+        assert_eq!(
+            check_extensions_count(&vec![ext; 256]),
+            Err(EdidValidationError::TooManyExtensions(256))
+        );
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_validation_extensions {
+    use super::check_extensions;
+    use crate::{
+        test_utils::cta_extension, CecAddress, EdidExtension, EdidExtensionCTA861,
+        EdidExtensionCTA861AudioDataBlock, EdidExtensionCTA861AudioDataBlockChannels,
+        EdidExtensionCTA861AudioDataBlockDesc, EdidExtensionCTA861AudioDataBlockLPCM,
+        EdidExtensionCTA861AudioDataBlockSamplingFrequency,
+        EdidExtensionCTA861AudioDataBlockSamplingRate, EdidExtensionCTA861DataBlockOrdering,
+        EdidExtensionCTA861HdmiDataBlock, EdidExtensionCTA861NativeFormats,
+        EdidExtensionCTA861Revision3, EdidExtensionCTA861Revision3DataBlock,
+        EdidExtensionCTA861RevisionNumber, EdidExtensionCTA861SpeakerAllocationDataBlock,
+        EdidExtensionCTA861VendorDataBlock, EdidExtensionCTA861VendorDataBlockType,
+        EdidExtensionCTA861VendorOui, EdidExtensionCTA861VendorPayload,
+        EdidExtensionCTA861VideoCapabilityDataBlock, EdidExtensionCTA861VideoDataBlock,
+        EdidExtensionCTA861VideoDataBlockDesc, EdidValidationError,
+    };
+
+    fn cta(
+        ordering: EdidExtensionCTA861DataBlockOrdering,
+        blocks: Vec<EdidExtensionCTA861Revision3DataBlock>,
+    ) -> EdidExtension {
+        EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+            EdidExtensionCTA861Revision3::builder()
+                .data_blocks(blocks)
+                .data_block_ordering(ordering)
+                .build(),
+        ))
+    }
+
+    #[test]
+    fn test_data_block_ordering() {
+        let video = || {
+            EdidExtensionCTA861Revision3DataBlock::Video(
+                EdidExtensionCTA861VideoDataBlock::builder()
+                    .add_short_video_descriptor(16)
+                    .build()
+                    .unwrap(),
+            )
+        };
+        let hdmi = || {
+            EdidExtensionCTA861Revision3DataBlock::HDMI(
+                EdidExtensionCTA861HdmiDataBlock::builder()
+                    .source_physical_address(CecAddress::try_from([1, 0, 0, 0]).unwrap())
+                    .build(),
+            )
+        };
+
+        assert_eq!(
+            check_extensions(&[cta(
+                EdidExtensionCTA861DataBlockOrdering::Preserve,
+                vec![hdmi(), video()]
+            )]),
+            Ok(())
+        );
+        assert_eq!(
+            check_extensions(&[cta(
+                EdidExtensionCTA861DataBlockOrdering::Strict,
+                vec![video(), hdmi()]
+            )]),
+            Ok(())
+        );
+        assert_eq!(
+            check_extensions(&[
+                cta(EdidExtensionCTA861DataBlockOrdering::Strict, vec![video()]),
+                cta(
+                    EdidExtensionCTA861DataBlockOrdering::Strict,
+                    vec![video(), hdmi(), video()]
+                )
+            ]),
+            Err(EdidValidationError::DataBlockOutOfOrder(1, 2))
+        );
+    }
+
+    #[test]
+    fn test_basic_audio() {
+        let audio = || {
+            EdidExtensionCTA861Revision3DataBlock::Audio(
+                EdidExtensionCTA861AudioDataBlock::builder()
+                    .add_short_audio_descriptor(EdidExtensionCTA861AudioDataBlockDesc::LPCM(
+                        EdidExtensionCTA861AudioDataBlockLPCM::builder()
+                            .channels(
+                                EdidExtensionCTA861AudioDataBlockChannels::try_from(2).unwrap(),
+                            )
+                            .add_sampling_frequency(
+                                EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency48kHz,
+                            )
+                            .add_sampling_rate(
+                                EdidExtensionCTA861AudioDataBlockSamplingRate::Rate16Bit,
+                            )
+                            .build(),
+                    ))
+                    .build()
+                    .unwrap(),
+            )
+        };
+        let cta = |basic_audio, blocks| {
+            EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+                EdidExtensionCTA861Revision3::builder()
+                    .audio_supported(basic_audio)
+                    .data_blocks(blocks)
+                    .build(),
+            ))
+        };
+
+        assert_eq!(check_extensions(&[cta(false, vec![])]), Ok(()));
+        assert_eq!(check_extensions(&[cta(true, vec![audio()])]), Ok(()));
+        assert_eq!(
+            check_extensions(&[cta(false, vec![audio()])]),
+            Err(EdidValidationError::MissingBasicAudio(0))
+        );
+    }
+
+    #[test]
+    fn test_speakers() {
+        let audio = |channels| {
+            EdidExtensionCTA861Revision3DataBlock::Audio(
+                EdidExtensionCTA861AudioDataBlock::builder()
+                    .add_short_audio_descriptor(EdidExtensionCTA861AudioDataBlockDesc::LPCM(
+                        EdidExtensionCTA861AudioDataBlockLPCM::builder()
+                            .channels(
+                                EdidExtensionCTA861AudioDataBlockChannels::try_from(channels)
+                                    .unwrap(),
+                            )
+                            .add_sampling_frequency(
+                                EdidExtensionCTA861AudioDataBlockSamplingFrequency::Frequency48kHz,
+                            )
+                            .add_sampling_rate(
+                                EdidExtensionCTA861AudioDataBlockSamplingRate::Rate16Bit,
+                            )
+                            .build(),
+                    ))
+                    .build()
+                    .unwrap(),
+            )
+        };
+        let speakers = EdidExtensionCTA861Revision3DataBlock::SpeakerAllocation(
+            EdidExtensionCTA861SpeakerAllocationDataBlock::builder()
+                .front_left_front_right()
+                .low_frequency_effects()
+                .front_center()
+                .back_left_back_right()
+                .build(),
+        );
+        let cta = |blocks| {
+            EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+                EdidExtensionCTA861Revision3::builder()
+                    .audio_supported(true)
+                    .data_blocks(blocks)
+                    .build(),
+            ))
+        };
+
+        assert_eq!(check_extensions(&[cta(vec![audio(2)])]), Ok(()));
+        assert_eq!(
+            check_extensions(&[cta(vec![audio(6), speakers.clone()])]),
+            Ok(())
+        );
+        assert_eq!(
+            check_extensions(&[cta(vec![audio(2), audio(8), speakers.clone()])]),
+            Ok(())
+        );
+        assert_eq!(
+            check_extensions(&[cta(vec![audio(2), speakers])]),
+            Err(EdidValidationError::TooManySpeakers(0, 6, 2))
+        );
+    }
+
+    #[test]
+    fn test_vics() {
+        let video = |vics: &[u8], native| {
+            let mut vdb = EdidExtensionCTA861VideoDataBlock::builder();
+            for vic in vics {
+                if Some(*vic) == native {
+                    vdb = vdb.add_native_short_video_descriptor(*vic);
+                } else {
+                    vdb = vdb.add_short_video_descriptor(*vic);
+                }
+            }
+
+            EdidExtensionCTA861Revision3DataBlock::Video(vdb.build().unwrap())
+        };
+
+        assert_eq!(
+            check_extensions(&[cta_extension(vec![
+                video(&[16, 4, 97], Some(16)),
+                video(&[3], None)
+            ])]),
+            Ok(())
+        );
+        assert_eq!(
+            check_extensions(&[cta_extension(vec![video(&[16, 4, 16], Some(4))])]),
+            Err(EdidValidationError::DuplicateVic(0, 16))
+        );
+        assert_eq!(
+            check_extensions(&[
+                cta_extension(vec![video(&[16, 4], Some(16)), video(&[3], None)]),
+                cta_extension(vec![video(&[16], Some(16)), video(&[4], Some(4))])
+            ]),
+            Err(EdidValidationError::MultipleNativeVics(1))
+        );
+    }
+
+    #[test]
+    fn test_quantization() {
+        let cta = |ycbcr, qy, qs| {
+            EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+                EdidExtensionCTA861Revision3::builder()
+                    .ycbcr_444_supported(ycbcr)
+                    .add_data_block(EdidExtensionCTA861Revision3DataBlock::VideoCapability(
+                        EdidExtensionCTA861VideoCapabilityDataBlock::builder()
+                            .qy_quant(qy)
+                            .qs_quant(qs)
+                            .build(),
+                    ))
+                    .build(),
+            ))
+        };
+
+        assert_eq!(check_extensions(&[cta(false, false, true)]), Ok(()));
+        assert_eq!(check_extensions(&[cta(true, true, true)]), Ok(()));
+        assert_eq!(
+            check_extensions(&[cta(false, true, true)]),
+            Err(EdidValidationError::YccQuantizationWithoutYCbCr(0))
+        );
+    }
+
+    #[test]
+    fn test_extension_size() {
+        let video = |first: u8| {
+            EdidExtensionCTA861Revision3DataBlock::Video(
+                EdidExtensionCTA861VideoDataBlock::builder()
+                    .descriptors(
+                        (first..first + 30)
+                            .map(EdidExtensionCTA861VideoDataBlockDesc::from_vic)
+                            .collect(),
+                    )
+                    .build()
+                    .unwrap(),
+            )
+        };
+
+        assert_eq!(
+            check_extensions(&[cta(
+                EdidExtensionCTA861DataBlockOrdering::Preserve,
+                vec![video(1), video(31), video(61)]
+            )]),
+            Ok(())
+        );
+        assert_eq!(
+            check_extensions(&[cta(
+                EdidExtensionCTA861DataBlockOrdering::Preserve,
+                vec![video(1), video(31), video(61), video(91)]
+            )]),
+            Err(EdidValidationError::ExtensionTooLarge(0, 129))
+        );
+    }
+
+    #[test]
+    fn test_duplicate_data_blocks() {
+        // The HDMI OUI is rejected by TryFrom, but a typed block can still use it.
+        #[derive(Debug)]
+        struct Vendor(u32);
+
+        impl EdidExtensionCTA861VendorDataBlockType for Vendor {
+            fn oui(&self) -> EdidExtensionCTA861VendorOui {
+                EdidExtensionCTA861VendorOui::try_from(self.0).unwrap()
+            }
+
+            fn payload(&self) -> EdidExtensionCTA861VendorPayload {
+                EdidExtensionCTA861VendorPayload::try_from(vec![0x10, 0x00]).unwrap()
+            }
+        }
+
+        let video = |vic| {
+            EdidExtensionCTA861Revision3DataBlock::Video(
+                EdidExtensionCTA861VideoDataBlock::builder()
+                    .add_short_video_descriptor(vic)
+                    .build()
+                    .unwrap(),
+            )
+        };
+        let vcdb = || {
+            EdidExtensionCTA861Revision3DataBlock::VideoCapability(
+                EdidExtensionCTA861VideoCapabilityDataBlock::builder().build(),
+            )
+        };
+        let hdmi = || {
+            EdidExtensionCTA861Revision3DataBlock::HDMI(
+                EdidExtensionCTA861HdmiDataBlock::builder()
+                    .source_physical_address(CecAddress::try_from([1, 0, 0, 0]).unwrap())
+                    .build(),
+            )
+        };
+        let vendor = |oui| {
+            EdidExtensionCTA861Revision3DataBlock::Vendor(
+                EdidExtensionCTA861VendorDataBlock::from_typed(&Vendor(oui)),
+            )
+        };
+
+        assert_eq!(
+            check_extensions(&[cta(
+                EdidExtensionCTA861DataBlockOrdering::Preserve,
+                vec![video(16), video(4), hdmi(), vendor(0x00_d0_46), vcdb()]
+            )]),
+            Ok(())
+        );
+        assert_eq!(
+            check_extensions(&[cta(
+                EdidExtensionCTA861DataBlockOrdering::Preserve,
+                vec![vcdb(), video(16), vcdb()]
+            )]),
+            Err(EdidValidationError::DuplicateDataBlock(0, 2))
+        );
+        assert_eq!(
+            check_extensions(&[cta(
+                EdidExtensionCTA861DataBlockOrdering::Preserve,
+                vec![hdmi(), hdmi()]
+            )]),
+            Err(EdidValidationError::DuplicateDataBlock(0, 1))
+        );
+        assert_eq!(
+            check_extensions(&[cta(
+                EdidExtensionCTA861DataBlockOrdering::Preserve,
+                vec![vendor(0x00_d0_46), hdmi(), vendor(0x00_0c_03)]
+            )]),
+            Err(EdidValidationError::DuplicateDataBlock(0, 2))
+        );
+    }
+
+    #[test]
+    fn test_revision() {
+        let cta = |revision, audio, native, blocks| {
+            EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+                EdidExtensionCTA861Revision3::builder()
+                    .revision(revision)
+                    .audio_supported(audio)
+                    .native_formats(EdidExtensionCTA861NativeFormats::try_from(native).unwrap())
+                    .data_blocks(blocks)
+                    .build(),
+            ))
+        };
+        let video = || {
+            vec![EdidExtensionCTA861Revision3DataBlock::Video(
+                EdidExtensionCTA861VideoDataBlock::builder()
+                    .add_short_video_descriptor(16)
+                    .build()
+                    .unwrap(),
+            )]
+        };
+
+        assert_eq!(
+            check_extensions(&[cta(
+                EdidExtensionCTA861RevisionNumber::Revision1,
+                false,
+                0,
+                Vec::new()
+            )]),
+            Ok(())
+        );
+        assert_eq!(
+            check_extensions(&[cta(
+                EdidExtensionCTA861RevisionNumber::Revision1,
+                true,
+                0,
+                Vec::new()
+            )]),
+            Err(EdidValidationError::UnsupportedByCtaRevision(0))
+        );
+        assert_eq!(
+            check_extensions(&[cta(
+                EdidExtensionCTA861RevisionNumber::Revision2,
+                true,
+                0,
+                Vec::new()
+            )]),
+            Ok(())
+        );
+        assert_eq!(
+            check_extensions(&[cta(
+                EdidExtensionCTA861RevisionNumber::Revision2,
+                true,
+                1,
+                Vec::new()
+            )]),
+            Err(EdidValidationError::UnsupportedByCtaRevision(0))
+        );
+        assert_eq!(
+            check_extensions(&[cta(
+                EdidExtensionCTA861RevisionNumber::Revision2,
+                false,
+                0,
+                video()
+            )]),
+            Err(EdidValidationError::UnsupportedByCtaRevision(0))
+        );
+        assert_eq!(
+            check_extensions(&[cta(
+                EdidExtensionCTA861RevisionNumber::Revision3,
+                false,
+                1,
+                video()
+            )]),
+            Ok(())
+        );
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_validation_color_encoding {
+    use super::{check_color_encoding, check_monochrome, check_srgb};
+    use crate::{
+        test_utils::{cta_extension, r4_display_parameters_features},
+        EdidAnalogSignalLevelStandard, EdidAnalogVideoInputDefinition, EdidAnalogVideoSetup,
+        EdidBasicDisplayParametersFeatures, EdidChromaticityPoint, EdidChromaticityPoints,
+        EdidDisplayColorType, EdidExtension, EdidExtensionCTA861,
+        EdidExtensionCTA861ColorimetryDataBlock, EdidExtensionCTA861Revision3,
+        EdidExtensionCTA861Revision3DataBlock, EdidFilterChromaticity, EdidR4DisplayColor,
+        EdidR4DisplayColorEncoding, EdidR4VideoInputDefinition, EdidValidationError,
+    };
+
+    fn bdpf(encoding: EdidR4DisplayColorEncoding) -> EdidBasicDisplayParametersFeatures {
+        let mut features = r4_display_parameters_features();
+        features.feature_support.color = EdidR4DisplayColor::Digital(encoding);
+
+        EdidBasicDisplayParametersFeatures::R4(features)
+    }
+
+    fn cta(ycbcr_444: bool, ycbcr_422: bool) -> EdidExtension {
+        EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+            EdidExtensionCTA861Revision3::builder()
+                .ycbcr_444_supported(ycbcr_444)
+                .ycbcr_422_supported(ycbcr_422)
+                .build(),
+        ))
+    }
+
+    #[test]
+    fn test_ycbcr() {
+        assert_eq!(
+            check_color_encoding(
+                &bdpf(EdidR4DisplayColorEncoding::RGB444),
+                &[cta(false, false)]
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            check_color_encoding(
+                &bdpf(EdidR4DisplayColorEncoding::RGB444YCbCr444YCbCr422),
+                &[cta(true, true)]
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            check_color_encoding(
+                &bdpf(EdidR4DisplayColorEncoding::RGB444YCbCr444),
+                &[cta(true, true)]
+            ),
+            Err(EdidValidationError::YCbCrSupportMismatch(0))
+        );
+        assert_eq!(
+            check_color_encoding(
+                &bdpf(EdidR4DisplayColorEncoding::RGB444YCbCr422),
+                &[cta(true, false)]
+            ),
+            Err(EdidValidationError::YCbCrSupportMismatch(0))
+        );
+    }
+
+    #[test]
+    fn test_srgb() {
+        let mut srgb_features = r4_display_parameters_features();
+        srgb_features.feature_support.srgb_default_color_space = true;
+        let srgb_bdpf = EdidBasicDisplayParametersFeatures::R4(srgb_features);
+        let bt2020 = EdidFilterChromaticity::Color(
+            EdidChromaticityPoints::builder()
+                .white(EdidChromaticityPoint::try_from((0.3127, 0.3290)).unwrap())
+                .red(EdidChromaticityPoint::try_from((0.708, 0.292)).unwrap())
+                .green(EdidChromaticityPoint::try_from((0.170, 0.797)).unwrap())
+                .blue(EdidChromaticityPoint::try_from((0.131, 0.046)).unwrap())
+                .build(),
+        );
+
+        assert_eq!(
+            check_srgb(
+                &srgb_bdpf,
+                &EdidFilterChromaticity::Color(EdidChromaticityPoints::srgb())
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            check_srgb(&bdpf(EdidR4DisplayColorEncoding::RGB444), &bt2020),
+            Ok(())
+        );
+        assert_eq!(
+            check_srgb(&srgb_bdpf, &bt2020),
+            Err(EdidValidationError::SrgbChromaticityMismatch)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn test_monochrome() {
+        let mono = EdidFilterChromaticity::MonoChrome(
+            EdidChromaticityPoint::try_from((0.3127, 0.3290)).unwrap(),
+        );
+        let color = EdidFilterChromaticity::Color(EdidChromaticityPoints::srgb());
+        let analog = |display_type| {
+            let mut features = r4_display_parameters_features();
+            features.video_input = EdidR4VideoInputDefinition::Analog(
+                EdidAnalogVideoInputDefinition::builder()
+                    .signal_level(EdidAnalogSignalLevelStandard::V_0_700_S_0_300_T_1_000)
+                    .setup(EdidAnalogVideoSetup::BlankLevelIsBlackLevel)
+                    .separate_hv_sync_signals(true)
+                    .composite_sync_signal_on_hsync(false)
+                    .composite_sync_signal_on_green_video(false)
+                    .serrations_on_vsync(false)
+                    .build(),
+            );
+            features.feature_support.color = EdidR4DisplayColor::Analog(display_type);
+
+            EdidBasicDisplayParametersFeatures::R4(features)
+        };
+        let colorimetry = cta_extension(vec![EdidExtensionCTA861Revision3DataBlock::Colorimetry(
+            EdidExtensionCTA861ColorimetryDataBlock::builder().build(),
+        )]);
+
+        assert_eq!(
+            check_monochrome(
+                &analog(EdidDisplayColorType::MonochromeGrayScale),
+                &mono,
+                &[]
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            check_monochrome(&analog(EdidDisplayColorType::Undefined), &mono, &[]),
+            Ok(())
+        );
+        assert_eq!(
+            check_monochrome(&analog(EdidDisplayColorType::RGBColor), &mono, &[]),
+            Err(EdidValidationError::MonochromeMismatch)
+        );
+        assert_eq!(
+            check_monochrome(
+                &analog(EdidDisplayColorType::MonochromeGrayScale),
+                &color,
+                &[]
+            ),
+            Err(EdidValidationError::MonochromeMismatch)
+        );
+
+        assert_eq!(
+            check_monochrome(
+                &bdpf(EdidR4DisplayColorEncoding::RGB444),
+                &mono,
+                &[cta(false, false)]
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            check_monochrome(
+                &bdpf(EdidR4DisplayColorEncoding::RGB444YCbCr444),
+                &mono,
+                &[]
+            ),
+            Err(EdidValidationError::MonochromeMismatch)
+        );
+        assert_eq!(
+            check_monochrome(
+                &bdpf(EdidR4DisplayColorEncoding::RGB444),
+                &mono,
+                &[cta(false, false), cta(false, true)]
+            ),
+            Err(EdidValidationError::MonochromeWithColorEncodings(1))
+        );
+        assert_eq!(
+            check_monochrome(
+                &bdpf(EdidR4DisplayColorEncoding::RGB444),
+                &mono,
+                core::slice::from_ref(&colorimetry)
+            ),
+            Err(EdidValidationError::MonochromeWithColorEncodings(0))
+        );
+        assert_eq!(
+            check_monochrome(
+                &bdpf(EdidR4DisplayColorEncoding::RGB444),
+                &color,
+                &[colorimetry]
+            ),
+            Ok(())
+        );
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_validation_warnings {
+    use super::{
+        extensions_warnings, input_warnings, mandatory_timings_warnings, size_warnings,
+        video_formats_warnings,
+    };
+    use crate::{
+        edid_preset_avr,
+        test_utils::{
+            cta_extension, r3_display_parameters_features, r4_display_parameters_features,
+        },
+        CecAddress, Edid, EdidAnalogSignalLevelStandard, EdidAnalogVideoInputDefinition,
+        EdidAnalogVideoSetup, EdidBasicDisplayParametersFeatures, EdidDescriptor,
+        EdidDescriptorDetailedTiming, EdidDetailedTimingSizeMm, EdidDisplayColorType,
+        EdidDisplayTransferCharacteristics, EdidExtension, EdidExtensionCTA861,
+        EdidExtensionCTA861HdmiDataBlock, EdidExtensionCTA861Revision3,
+        EdidExtensionCTA861Revision3DataBlock, EdidExtensionCTA861VideoCapabilityDataBlock,
+        EdidExtensionCTA861VideoCapabilityScanBehavior, EdidExtensionCTA861VideoDataBlock,
+        EdidManufacturer, EdidProductCode, EdidR3DigitalVideoInputDefinition,
+        EdidR3VideoInputDefinition, EdidR4BasicDisplayParametersFeatures, EdidR4Descriptor,
+        EdidR4DigitalColorDepth, EdidR4DigitalInterface, EdidR4DigitalVideoInputDefinition,
+        EdidR4DisplayColor, EdidR4DisplayColorEncoding, EdidR4FeatureSupport, EdidR4ImageSize,
+        EdidR4VideoInputDefinition, EdidScreenSize, EdidScreenSizeLength, EdidValidationWarning,
+    };
+
+    fn cta(
+        underscan: bool,
+        it_scan: EdidExtensionCTA861VideoCapabilityScanBehavior,
+    ) -> EdidExtension {
+        EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+            EdidExtensionCTA861Revision3::builder()
+                .underscan_it_formats_by_default(underscan)
+                .add_data_block(EdidExtensionCTA861Revision3DataBlock::VideoCapability(
+                    EdidExtensionCTA861VideoCapabilityDataBlock::builder()
+                        .it_scan(it_scan)
+                        .build(),
+                ))
+                .build(),
+        ))
+    }
+
+    #[test]
+    fn test_underscan() {
+        assert_eq!(
+            extensions_warnings(&[
+                cta(
+                    true,
+                    EdidExtensionCTA861VideoCapabilityScanBehavior::Underscanned
+                ),
+                cta(
+                    false,
+                    EdidExtensionCTA861VideoCapabilityScanBehavior::Overscanned
+                ),
+                cta(true, EdidExtensionCTA861VideoCapabilityScanBehavior::Both),
+            ]),
+            Vec::new()
+        );
+        assert_eq!(
+            extensions_warnings(&[
+                cta(
+                    true,
+                    EdidExtensionCTA861VideoCapabilityScanBehavior::Overscanned
+                ),
+                cta(
+                    false,
+                    EdidExtensionCTA861VideoCapabilityScanBehavior::Underscanned
+                ),
+            ]),
+            vec![
+                EdidValidationWarning::UnderscanMismatch(0),
+                EdidValidationWarning::UnderscanMismatch(1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_size() {
+        let bdpf = EdidBasicDisplayParametersFeatures::R4(
+            EdidR4BasicDisplayParametersFeatures::builder()
+                .video_input(EdidR4VideoInputDefinition::Digital(
+                    EdidR4DigitalVideoInputDefinition::builder()
+                        .color_depth(EdidR4DigitalColorDepth::Depth8Bpc)
+                        .interface(EdidR4DigitalInterface::HDMIa)
+                        .build(),
+                ))
+                .size(EdidR4ImageSize::Size(
+                    EdidScreenSize::builder()
+                        .horizontal_cm(EdidScreenSizeLength::try_from(53).unwrap())
+                        .vertical_cm(EdidScreenSizeLength::try_from(30).unwrap())
+                        .build(),
+                ))
+                .display_transfer_characteristic(
+                    EdidDisplayTransferCharacteristics::try_from(2.2).unwrap(),
+                )
+                .feature_support(
+                    EdidR4FeatureSupport::builder()
+                        .color(EdidR4DisplayColor::Digital(
+                            EdidR4DisplayColorEncoding::RGB444,
+                        ))
+                        .build(),
+                )
+                .build(),
+        );
+        let dtd = |h, v| {
+            EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(
+                EdidDescriptorDetailedTiming::cta_1080i_60hz(
+                    EdidDetailedTimingSizeMm::try_from(h).unwrap(),
+                    EdidDetailedTimingSizeMm::try_from(v).unwrap(),
+                ),
+            ))
+        };
+
+        assert_eq!(
+            size_warnings(&bdpf, &[dtd(531, 299), dtd(0, 0)]),
+            Vec::new()
+        );
+        assert_eq!(
+            size_warnings(&bdpf, &[dtd(531, 299), dtd(597, 336)]),
+            vec![EdidValidationWarning::DetailedTimingSizeMismatch(1)]
+        );
+    }
+
+    #[test]
+    fn test_video_formats() {
+        let dtd = EdidDescriptor::R4(EdidR4Descriptor::DetailedTiming(
+            EdidDescriptorDetailedTiming::cta_1080i_50hz(
+                EdidDetailedTimingSizeMm::try_from(0).unwrap(),
+                EdidDetailedTimingSizeMm::try_from(0).unwrap(),
+            ),
+        ));
+        let cta = |vics: &[u8]| {
+            let mut vdb = EdidExtensionCTA861VideoDataBlock::builder();
+            for vic in vics {
+                vdb = vdb.add_short_video_descriptor(*vic);
+            }
+
+            cta_extension(vec![EdidExtensionCTA861Revision3DataBlock::Video(
+                vdb.build().unwrap(),
+            )])
+        };
+
+        assert_eq!(
+            video_formats_warnings(core::slice::from_ref(&dtd), &[cta(&[5, 16, 20])]),
+            Vec::new()
+        );
+
+        // VIC 39 is also 1920x1080i at 50Hz, but with 1250 lines instead of 1125.
+        assert_eq!(
+            video_formats_warnings(&[dtd], &[cta(&[20, 39])]),
+            vec![EdidValidationWarning::ConflictingVideoFormat(39)]
+        );
+    }
+
+    #[test]
+    fn test_mandatory_timings() {
+        let mut edid = Edid::from(edid_preset_avr(
+            EdidManufacturer::from_ascii(b"RED"),
+            EdidProductCode::new(0),
+            CecAddress::try_from([1, 0, 0, 0]).unwrap(),
+        ));
+        assert_eq!(mandatory_timings_warnings(&edid), None);
+
+        // 640x480 is still listed in the Video Data Block.
+        edid.established_timings.clear();
+        edid.descriptors.clear();
+        assert_eq!(mandatory_timings_warnings(&edid), None);
+
+        edid.extensions = vec![EdidExtension::CTA861(EdidExtensionCTA861::Revision3(
+            EdidExtensionCTA861Revision3::builder()
+                .add_data_block(EdidExtensionCTA861Revision3DataBlock::Video(
+                    EdidExtensionCTA861VideoDataBlock::builder()
+                        .add_short_video_descriptor(16)
+                        .build()
+                        .unwrap(),
+                ))
+                .build(),
+        ))];
+        assert_eq!(
+            mandatory_timings_warnings(&edid),
+            Some(EdidValidationWarning::Missing640x480)
+        );
+
+        // DisplayPort only displays don't need it.
+        edid.extensions.clear();
+        assert_eq!(mandatory_timings_warnings(&edid), None);
+    }
+
+    #[test]
+    fn test_analog_input() {
+        let mut analog_features = r4_display_parameters_features();
+        analog_features.video_input = EdidR4VideoInputDefinition::Analog(
+            EdidAnalogVideoInputDefinition::builder()
+                .signal_level(EdidAnalogSignalLevelStandard::V_0_700_S_0_300_T_1_000)
+                .setup(EdidAnalogVideoSetup::BlankLevelIsBlackLevel)
+                .build(),
+        );
+        analog_features.feature_support.color =
+            EdidR4DisplayColor::Analog(EdidDisplayColorType::RGBColor);
+        let analog = EdidBasicDisplayParametersFeatures::R4(analog_features);
+        let digital = EdidBasicDisplayParametersFeatures::R4(r4_display_parameters_features());
+        let extensions = [
+            cta_extension(Vec::new()),
+            cta(false, EdidExtensionCTA861VideoCapabilityScanBehavior::Both),
+        ];
+
+        assert_eq!(input_warnings(&analog, &[]), Vec::new());
+        assert_eq!(input_warnings(&digital, &extensions), Vec::new());
+        assert_eq!(
+            input_warnings(&analog, &extensions),
+            vec![EdidValidationWarning::AnalogInputWithDataBlocks(1)]
+        );
+    }
+
+    #[test]
+    fn test_dfp_deep_color() {
+        let bdpf = |dfp1_compatible| {
+            let mut features = r3_display_parameters_features();
+            features.video_input = EdidR3VideoInputDefinition::Digital(
+                EdidR3DigitalVideoInputDefinition::builder()
+                    .dfp1_compatible(dfp1_compatible)
+                    .build(),
+            );
+
+            EdidBasicDisplayParametersFeatures::R3(features)
+        };
+        let hdmi = |deep_color| {
+            cta_extension(vec![EdidExtensionCTA861Revision3DataBlock::HDMI(
+                EdidExtensionCTA861HdmiDataBlock::builder()
+                    .source_physical_address(CecAddress::try_from([1, 0, 0, 0]).unwrap())
+                    .deep_color_30_bits(deep_color)
+                    .build(),
+            )])
+        };
+
+        assert_eq!(input_warnings(&bdpf(true), &[hdmi(false)]), Vec::new());
+        assert_eq!(input_warnings(&bdpf(false), &[hdmi(true)]), Vec::new());
+        assert_eq!(
+            input_warnings(&bdpf(true), &[hdmi(false), hdmi(true)]),
+            vec![EdidValidationWarning::DfpInputWithDeepColor(1)]
+        );
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_validation_range_limits {
+    use super::{check_range_limits, descriptors_warnings, input_warnings};
+    use crate::{
+        test_utils::{
+            r3_display_parameters_features, r3_range_limits, r4_display_parameters_features,
+            secondary_gtf,
+        },
+        EdidAnalogSignalLevelStandard, EdidAnalogVideoInputDefinition, EdidAnalogVideoSetup,
+        EdidBasicDisplayParametersFeatures, EdidDescriptor, EdidDisplayRangePixelClock,
+        EdidR3Descriptor, EdidR3DisplayRangeVideoTimingsSupport, EdidR4Descriptor,
+        EdidR4DisplayRangeHorizontalFreq, EdidR4DisplayRangeLimits, EdidR4DisplayRangeVerticalFreq,
+        EdidR4DisplayRangeVideoTimingsSupport, EdidR4VideoInputDefinition, EdidValidationError,
+        EdidValidationWarning,
+    };
+
+    fn bdpf(continuous: bool) -> EdidBasicDisplayParametersFeatures {
+        let mut features = r4_display_parameters_features();
+        features.feature_support.continuous_frequency = continuous;
+
+        EdidBasicDisplayParametersFeatures::R4(features)
+    }
+
+    fn limits(support: EdidR4DisplayRangeVideoTimingsSupport) -> EdidDescriptor {
+        EdidDescriptor::R4(EdidR4Descriptor::DisplayRangeLimits(
+            EdidR4DisplayRangeLimits::builder()
+                .min_vfreq(EdidR4DisplayRangeVerticalFreq::try_from(50).unwrap())
+                .max_vfreq(EdidR4DisplayRangeVerticalFreq::try_from(90).unwrap())
+                .min_hfreq(EdidR4DisplayRangeHorizontalFreq::try_from(30).unwrap())
+                .max_hfreq(EdidR4DisplayRangeHorizontalFreq::try_from(110).unwrap())
+                .max_pixelclock(EdidDisplayRangePixelClock::try_from(230).unwrap())
+                .timings_support(support)
+                .build(),
+        ))
+    }
+
+    #[test]
+    fn test_continuous_frequency() {
+        assert_eq!(check_range_limits(&bdpf(false), &[]), Ok(()));
+        assert_eq!(
+            check_range_limits(
+                &bdpf(false),
+                &[limits(
+                    EdidR4DisplayRangeVideoTimingsSupport::RangeLimitsOnly
+                )]
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            check_range_limits(
+                &bdpf(true),
+                &[limits(EdidR4DisplayRangeVideoTimingsSupport::DefaultGTF)]
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            check_range_limits(&bdpf(true), &[]),
+            Err(EdidValidationError::MissingDisplayRangeLimits)
+        );
+        assert_eq!(
+            check_range_limits(
+                &bdpf(false),
+                &[limits(EdidR4DisplayRangeVideoTimingsSupport::DefaultGTF)]
+            ),
+            Err(EdidValidationError::RangeLimitsWithoutContinuousFrequency(
+                0
+            ))
+        );
+    }
+
+    #[test]
+    fn test_gtf() {
+        let mut analog_features = r4_display_parameters_features();
+        analog_features.video_input = EdidR4VideoInputDefinition::Analog(
+            EdidAnalogVideoInputDefinition::builder()
+                .signal_level(EdidAnalogSignalLevelStandard::V_0_700_S_0_300_T_1_000)
+                .setup(EdidAnalogVideoSetup::BlankLevelIsBlackLevel)
+                .build(),
+        );
+        let analog = EdidBasicDisplayParametersFeatures::R4(analog_features);
+        let descriptors = [
+            EdidDescriptor::R4(EdidR4Descriptor::Dummy),
+            limits(EdidR4DisplayRangeVideoTimingsSupport::DefaultGTF),
+        ];
+
+        assert_eq!(
+            descriptors_warnings(
+                &bdpf(true),
+                &[limits(
+                    EdidR4DisplayRangeVideoTimingsSupport::RangeLimitsOnly
+                )]
+            ),
+            Vec::new()
+        );
+        assert_eq!(
+            descriptors_warnings(&analog, &descriptors),
+            vec![EdidValidationWarning::DeprecatedGtfRangeLimits(1)]
+        );
+        // A digital input only gets the more specific warning.
+        assert_eq!(
+            descriptors_warnings(&bdpf(true), &descriptors),
+            vec![EdidValidationWarning::DigitalInputWithGtfRangeLimits(1)]
+        );
+    }
+
+    #[test]
+    fn test_r3_secondary_gtf() {
+        let bdpf = |gtf| {
+            let mut features = r3_display_parameters_features();
+            features.feature_support.default_gtf_supported = gtf;
+
+            EdidBasicDisplayParametersFeatures::R3(features)
+        };
+
+        let limits = |support| {
+            EdidDescriptor::R3(EdidR3Descriptor::DisplayRangeLimits(r3_range_limits(
+                support,
+            )))
+        };
+
+        let secondary = EdidR3DisplayRangeVideoTimingsSupport::SecondaryGTF(secondary_gtf());
+
+        // A fixed frequency EDID 1.3 display doesn't support GTF, and has no secondary curve.
+        assert_eq!(
+            check_range_limits(
+                &bdpf(false),
+                &[limits(EdidR3DisplayRangeVideoTimingsSupport::DefaultGTF)]
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            check_range_limits(&bdpf(true), &[limits(secondary.clone())]),
+            Ok(())
+        );
+        assert_eq!(
+            check_range_limits(
+                &bdpf(false),
+                &[
+                    limits(EdidR3DisplayRangeVideoTimingsSupport::DefaultGTF),
+                    limits(secondary)
+                ]
+            ),
+            Err(EdidValidationError::SecondaryGtfWithoutGtfSupport(1))
+        );
+
+        assert_eq!(input_warnings(&bdpf(false), &[]), Vec::new());
+        assert_eq!(
+            input_warnings(&bdpf(true), &[]),
+            vec![EdidValidationWarning::DigitalInputWithGtfSupport]
+        );
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test_validation_serialization {
+    use crate::{
+        test_utils::edid_r4_builder, EdidDescriptorDetailedTiming, EdidDescriptorString,
+        EdidDetailedTimingSizeMm, EdidDisplayRangePixelClock, EdidDisplayTransferCharacteristics,
+        EdidR4Descriptor, EdidR4DisplayRangeHorizontalFreq, EdidR4DisplayRangeLimits,
+        EdidR4DisplayRangeVerticalFreq, EdidR4DisplayRangeVideoTimingsSupport, EdidRelease4,
+        EdidSerializationWarning, IntoBytes,
+    };
+
+    fn edid(descriptors: Vec<EdidR4Descriptor>) -> EdidRelease4 {
+        edid_r4_builder!().descriptors(descriptors).build().unwrap()
+    }
+
+    fn dtd() -> EdidR4Descriptor {
+        EdidR4Descriptor::DetailedTiming(EdidDescriptorDetailedTiming::cta_1080i_60hz(
+            EdidDetailedTimingSizeMm::try_from(0).unwrap(),
+            EdidDetailedTimingSizeMm::try_from(0).unwrap(),
+        ))
+    }
+
+    #[test]
+    fn test_gamma() {
+        let (_, warnings) = edid(vec![dtd()]).into_bytes_with_warnings();
+        assert_eq!(warnings, Vec::new());
+
+        let mut edid = edid(vec![dtd()]);
+        edid.display_parameters_features
+            .display_transfer_characteristic =
+            EdidDisplayTransferCharacteristics::try_from(2.225).unwrap();
+        let (_, warnings) = edid.into_bytes_with_warnings();
+        assert_eq!(
+            warnings,
+            vec![EdidSerializationWarning::GammaRounded(2.225)]
+        );
+    }
+
+    #[test]
+    fn test_descriptors() {
+        let limits = EdidR4Descriptor::DisplayRangeLimits(
+            EdidR4DisplayRangeLimits::builder()
+                .min_vfreq(EdidR4DisplayRangeVerticalFreq::try_from(50).unwrap())
+                .max_vfreq(EdidR4DisplayRangeVerticalFreq::try_from(90).unwrap())
+                .min_hfreq(EdidR4DisplayRangeHorizontalFreq::try_from(30).unwrap())
+                .max_hfreq(EdidR4DisplayRangeHorizontalFreq::try_from(110).unwrap())
+                .max_pixelclock(EdidDisplayRangePixelClock::try_from(225).unwrap())
+                .timings_support(EdidR4DisplayRangeVideoTimingsSupport::RangeLimitsOnly)
+                .build(),
+        );
+        let edid = edid(vec![
+            dtd(),
+            limits,
+            EdidR4Descriptor::ProductName(EdidDescriptorString::try_from("ABC LCD21").unwrap()),
+            EdidR4Descriptor::DataString(EdidDescriptorString::try_from("ABCDEFGHIJKLM").unwrap()),
+        ]);
+
+        let (bytes, warnings) = edid.clone().into_bytes_with_warnings();
+        assert_eq!(bytes, edid.into_bytes());
+        assert_eq!(
+            warnings,
+            vec![
+                EdidSerializationWarning::RangeLimitsPixelClockRounded(1, 225),
+                EdidSerializationWarning::DescriptorStringPadded(2),
+            ]
+        );
+    }
+}
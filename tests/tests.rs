@@ -1,9 +1,12 @@
+//! Rebuilds the EDIDs of the edid.tv and linuxhw databases from their decoded JSON
+//! description, and checks they match the original binaries.
+
+use core::str::FromStr;
 use std::{
     fs::File,
     io::Read,
     path::{Path, PathBuf},
     process::Command,
-    str::FromStr,
 };
 
 use num_traits::ToPrimitive;
@@ -13,11 +16,14 @@ use serde_json::Value;
 use redid::{
     EdidAnalogSignalLevelStandard, EdidAnalogVideoInputDefinition, EdidAnalogVideoSetup,
     EdidChromaticityPoint, EdidChromaticityPoints, EdidDescriptorCustom,
-    EdidDescriptorDetailedTiming, EdidDescriptorString, EdidDetailedTimingAnalogSync,
-    EdidDetailedTimingDigitalCompositeSync, EdidDetailedTimingDigitalSeparateSync,
-    EdidDetailedTimingDigitalSync, EdidDetailedTimingDigitalSyncKind, EdidDetailedTimingStereo,
-    EdidDetailedTimingSync, EdidDisplayColorType, EdidDisplayRangeHorizontalFreq,
-    EdidDisplayRangePixelClock, EdidDisplayRangeVerticalFreq, EdidDisplayRangeVideoTimingsGTF,
+    EdidDescriptorDetailedTiming, EdidDescriptorString, EdidDescriptorStringEncodingPolicy,
+    EdidDetailedTimingAnalogSync, EdidDetailedTimingDigitalCompositeSync,
+    EdidDetailedTimingDigitalSeparateSync, EdidDetailedTimingDigitalSync,
+    EdidDetailedTimingDigitalSyncKind, EdidDetailedTimingStereo, EdidDetailedTimingSync,
+    EdidDisplayColorType, EdidDisplayRangeHorizontalFreq, EdidDisplayRangePixelClock,
+    EdidDisplayRangeVerticalFreq, EdidDisplayRangeVideoTimingsGTF,
+    EdidDisplayRangeVideoTimingsGTFBlankingOffset,
+    EdidDisplayRangeVideoTimingsGTFBlankingScalingFactorWeighting,
     EdidDisplayRangeVideoTimingsGTFStartFrequency, EdidDisplayTransferCharacteristics,
     EdidEstablishedTiming, EdidFilterChromaticity, EdidManufactureDate, EdidManufacturer,
     EdidProductCode, EdidR3BasicDisplayParametersFeatures, EdidR3Descriptor,
@@ -29,14 +35,15 @@ use redid::{
     EdidR4DisplayColor, EdidR4DisplayColorEncoding, EdidR4DisplayRangeHorizontalFreq,
     EdidR4DisplayRangeLimits, EdidR4DisplayRangeVerticalFreq,
     EdidR4DisplayRangeVideoTimingsAspectRatio, EdidR4DisplayRangeVideoTimingsCVT,
-    EdidR4DisplayRangeVideoTimingsCVTR1, EdidR4DisplayRangeVideoTimingsSupport,
-    EdidR4FeatureSupport, EdidR4ImageSize, EdidR4ManufactureDate, EdidR4VideoInputDefinition,
-    EdidRelease3, EdidRelease4, EdidScreenSize, EdidSerialNumber, EdidStandardTiming,
-    EdidStandardTimingHorizontalSize, EdidStandardTimingRatio, EdidStandardTimingRefreshRate,
-    IntoBytes,
+    EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels, EdidR4DisplayRangeVideoTimingsCVTR1,
+    EdidR4DisplayRangeVideoTimingsSupport, EdidR4FeatureSupport, EdidR4ImageSize,
+    EdidR4ManufactureDate, EdidR4VideoInputDefinition, EdidRelease3, EdidRelease4, EdidScreenSize,
+    EdidSerialNumber, EdidStandardTiming, EdidStandardTimingHorizontalSize,
+    EdidStandardTimingRatio, EdidStandardTimingRefreshRate, IntoBytes,
 };
 use uom::si::{f32::Frequency, frequency::kilohertz};
 
+#[allow(clippy::unwrap_used)]
 fn decode_manufacturer_name(manufacturer_info: &Value) -> EdidManufacturer {
     manufacturer_info["Manufacturer ID"]
         .as_str()
@@ -45,6 +52,7 @@ fn decode_manufacturer_name(manufacturer_info: &Value) -> EdidManufacturer {
         .unwrap()
 }
 
+#[allow(clippy::cast_possible_truncation)]
 fn decode_product_code(manufacturer_info: &Value) -> EdidProductCode {
     let code = manufacturer_info["ID Product Code"]
         .as_u64()
@@ -53,12 +61,14 @@ fn decode_product_code(manufacturer_info: &Value) -> EdidProductCode {
     code.into()
 }
 
+#[allow(clippy::cast_possible_truncation)]
 fn decode_serial_number(manufacturer_info: &Value) -> Option<EdidSerialNumber> {
     manufacturer_info["Serial number"]
         .as_u64()
         .map(|v| (v as u32).into())
 }
 
+#[allow(clippy::cast_possible_truncation, clippy::unwrap_used)]
 fn decode_date_release_3(manufacturer_info: &Value) -> EdidManufactureDate {
     let year = manufacturer_info["Year of manufacture"].as_u64().unwrap() as u16;
 
@@ -69,6 +79,7 @@ fn decode_date_release_3(manufacturer_info: &Value) -> EdidManufactureDate {
     }
 }
 
+#[allow(clippy::cast_possible_truncation, clippy::unwrap_used)]
 fn decode_date_release_4(manufacturer_info: &Value) -> EdidR4Date {
     if let Some(year) = manufacturer_info["Model year"].as_u64() {
         EdidR4Date::Model((year as u16).try_into().unwrap())
@@ -136,8 +147,7 @@ fn decode_analog_input(basic_display: &Value) -> EdidAnalogVideoInputDefinition
 fn decode_digital_input_release_3(basic_display: &Value) -> EdidR3DigitalVideoInputDefinition {
     let dfp_bool = basic_display["Digital Video Interface Standard Support"]
         .as_str()
-        .map(|s| if s == "DVI" { true } else { false })
-        .unwrap_or_default();
+        .is_some_and(|s| s == "DVI");
 
     EdidR3DigitalVideoInputDefinition::builder()
         .dfp1_compatible(dfp_bool)
@@ -158,6 +168,7 @@ fn decode_video_input_release_3(basic_display: &Value) -> EdidR3VideoInputDefini
     }
 }
 
+#[allow(clippy::unwrap_used)]
 fn decode_size_release_3(basic_display: &Value) -> EdidR3ImageSize {
     if let Some(val) = basic_display["Maximum dimensions (cm)"].as_object() {
         let x_val = val["x"]
@@ -181,7 +192,7 @@ fn decode_size_release_3(basic_display: &Value) -> EdidR3ImageSize {
                 .vertical_cm(y_val)
                 .build(),
         );
-    };
+    }
 
     EdidR3ImageSize::Undefined
 }
@@ -250,6 +261,11 @@ fn decode_feature_support_release_3(basic_display: &Value) -> EdidR3FeatureSuppo
         .build()
 }
 
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::float_cmp,
+    clippy::unwrap_used
+)]
 fn decode_basic_display_release_3(basic_display: &Value) -> EdidR3BasicDisplayParametersFeatures {
     let gamma_val = basic_display["Display gamma"]
         .as_f64()
@@ -315,9 +331,10 @@ fn decode_video_input_release_4(basic_display: &Value) -> EdidR4VideoInputDefini
     }
 }
 
+#[allow(clippy::unwrap_used)]
 fn decode_size_release_4(basic_display: &Value) -> EdidR4ImageSize {
     if let Some(val) = basic_display["Aspect ratio (portrait)"].as_str() {
-        let mut split = val.split(":");
+        let mut split = val.split(':');
 
         let num = split
             .next()
@@ -335,10 +352,10 @@ fn decode_size_release_4(basic_display: &Value) -> EdidR4ImageSize {
 
         let ratio = (num, denum).try_into().unwrap();
         return EdidR4ImageSize::PortraitRatio(ratio);
-    };
+    }
 
     if let Some(val) = basic_display["Aspect ratio (landscape)"].as_str() {
-        let mut split = val.split(":");
+        let mut split = val.split(':');
 
         let num = split
             .next()
@@ -356,7 +373,7 @@ fn decode_size_release_4(basic_display: &Value) -> EdidR4ImageSize {
 
         let ratio = (num, denum).try_into().unwrap();
         return EdidR4ImageSize::LandscapeRatio(ratio);
-    };
+    }
 
     if let Some(val) = basic_display["Maximum dimensions (cm)"].as_object() {
         let x_val = val["x"]
@@ -380,7 +397,7 @@ fn decode_size_release_4(basic_display: &Value) -> EdidR4ImageSize {
                 .vertical_cm(y_val)
                 .build(),
         );
-    };
+    }
 
     EdidR4ImageSize::Undefined
 }
@@ -459,6 +476,11 @@ fn decode_feature_support_release_4(basic_display: &Value) -> EdidR4FeatureSuppo
         .build()
 }
 
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::float_cmp,
+    clippy::unwrap_used
+)]
 fn decode_basic_display_release_4(basic_display: &Value) -> EdidR4BasicDisplayParametersFeatures {
     let gamma_val = basic_display["Display gamma"]
         .as_f64()
@@ -478,6 +500,7 @@ fn decode_basic_display_release_4(basic_display: &Value) -> EdidR4BasicDisplayPa
         .build()
 }
 
+#[allow(clippy::cast_possible_truncation, clippy::unwrap_used)]
 fn decode_color_chromaticity(chroma: &serde_json::Map<String, Value>) -> EdidChromaticityPoint {
     let x_val = chroma["x"]
         .as_f64()
@@ -491,6 +514,7 @@ fn decode_color_chromaticity(chroma: &serde_json::Map<String, Value>) -> EdidChr
     (x_val, y_val).try_into().unwrap()
 }
 
+#[allow(clippy::unwrap_used)]
 fn decode_basic_display_chromaticity(
     basic_display: &Value,
     chromaticity: &Value,
@@ -511,7 +535,12 @@ fn decode_basic_display_chromaticity(
         }
     }
 
-    if !monochrome {
+    if monochrome {
+        let white = chromaticity["White"].as_object().unwrap();
+        let white = decode_color_chromaticity(white);
+
+        EdidFilterChromaticity::MonoChrome(white)
+    } else {
         let blue = chromaticity["Blue"].as_object().unwrap();
         let blue = decode_color_chromaticity(blue);
 
@@ -532,11 +561,6 @@ fn decode_basic_display_chromaticity(
                 .white(white)
                 .build(),
         )
-    } else {
-        let white = chromaticity["White"].as_object().unwrap();
-        let white = decode_color_chromaticity(white);
-
-        EdidFilterChromaticity::MonoChrome(white)
     }
 }
 
@@ -547,7 +571,7 @@ fn decode_established_timings(timings: &Value) -> Vec<EdidEstablishedTiming> {
         .as_object()
         .expect("Couldn't decode the established timings section");
 
-    for (timing, timing_val) in map.iter() {
+    for (timing, timing_val) in map {
         let et = match timing.as_str() {
             "1024x768 @ 60 Hz" => EdidEstablishedTiming::ET_1024_768_60hz,
             "1024x768 @ 72 Hz" => EdidEstablishedTiming::ET_1024_768_70hz,
@@ -589,6 +613,7 @@ fn decode_established_timings(timings: &Value) -> Vec<EdidEstablishedTiming> {
     list
 }
 
+#[allow(clippy::cast_possible_truncation, clippy::unwrap_used)]
 fn decode_standard_timings(timings: &Value) -> Vec<EdidStandardTiming> {
     let mut st = Vec::new();
 
@@ -639,6 +664,11 @@ fn decode_standard_timings(timings: &Value) -> Vec<EdidStandardTiming> {
     st
 }
 
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::too_many_lines,
+    clippy::unwrap_used
+)]
 fn decode_descriptor_dtd(desc: &Value) -> EdidDescriptorDetailedTiming {
     let addressable = desc["Addressable"]
         .as_object()
@@ -866,25 +896,28 @@ fn decode_descriptor_dtd(desc: &Value) -> EdidDescriptorDetailedTiming {
         .build()
 }
 
+#[allow(clippy::cast_possible_truncation, clippy::unwrap_used)]
 fn decode_range_limit_cvt(desc: &Value) -> EdidR4DisplayRangeVideoTimingsCVT {
     let cvt_version_str = desc["CVT Version"]
         .as_str()
         .expect("Couldn't decode CVT Version");
 
-    assert_eq!(cvt_version_str, "1.1");
+    assert_eq!(cvt_version_str, "1.1", "Unsupported CVT version");
 
     let cvt = EdidR4DisplayRangeVideoTimingsCVTR1::builder();
 
     let max_active = desc["Maximum active pixels"]
         .as_u64()
         .expect("Couldn't decode the Maximum active pixels") as u16;
-    let mut cvt = cvt.maximum_active_pixels_per_line(max_active);
+    let mut cvt = cvt.maximum_active_pixels_per_line(
+        EdidR4DisplayRangeVideoTimingsCVTMaxActivePixels::try_from(max_active).unwrap(),
+    );
 
     let aspect_ratio_supported = desc["Supported aspect ratios"]
         .as_object()
         .expect("Couldn't decode the supported aspect ratios");
 
-    for (ratio, supported) in aspect_ratio_supported.iter() {
+    for (ratio, supported) in aspect_ratio_supported {
         let supported = supported
             .as_bool()
             .expect("Couldn't decode the ratio value");
@@ -969,6 +1002,7 @@ fn decode_range_limit_cvt(desc: &Value) -> EdidR4DisplayRangeVideoTimingsCVT {
     EdidR4DisplayRangeVideoTimingsCVT::R1(cvt.build())
 }
 
+#[allow(clippy::unwrap_used)]
 fn decode_secondary_gtf_release_3(desc: &Value) -> EdidDisplayRangeVideoTimingsGTF {
     let horizontal_start_frequency_str = desc["Start break frequency"]
         .as_str()
@@ -983,9 +1017,11 @@ fn decode_secondary_gtf_release_3(desc: &Value) -> EdidDisplayRangeVideoTimingsG
             .try_into()
             .unwrap();
 
-    let c: u8 = desc["C"]
+    let c: EdidDisplayRangeVideoTimingsGTFBlankingOffset = desc["C"]
         .as_u64()
         .expect("Couldn't decode GTF Blanking Offset")
+        .to_u8()
+        .unwrap()
         .try_into()
         .unwrap();
 
@@ -1001,9 +1037,11 @@ fn decode_secondary_gtf_release_3(desc: &Value) -> EdidDisplayRangeVideoTimingsG
         .try_into()
         .unwrap();
 
-    let j: u8 = desc["J"]
+    let j: EdidDisplayRangeVideoTimingsGTFBlankingScalingFactorWeighting = desc["J"]
         .as_u64()
         .expect("Couldn't decode GTF Blanking Scaling Factor Weighting")
+        .to_u8()
+        .unwrap()
         .try_into()
         .unwrap();
 
@@ -1016,6 +1054,11 @@ fn decode_secondary_gtf_release_3(desc: &Value) -> EdidDisplayRangeVideoTimingsG
         .build()
 }
 
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::unwrap_used
+)]
 fn decode_display_range_release_3(desc: &Value) -> EdidR3DisplayRangeLimits {
     let hrate = desc["Horizontal rate (kHz)"]
         .as_object()
@@ -1083,6 +1126,11 @@ fn decode_display_range_release_3(desc: &Value) -> EdidR3DisplayRangeLimits {
         .build()
 }
 
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::unwrap_used
+)]
 fn decode_display_range_release_4(desc: &Value) -> EdidR4DisplayRangeLimits {
     let hrate = desc["Horizontal rate (kHz)"]
         .as_object()
@@ -1157,7 +1205,7 @@ fn decode_descriptor_established_timings(desc: &Value) -> EdidR4DescriptorEstabl
         .expect("Couldn't decode the established timings section");
 
     let mut timings = EdidR4DescriptorEstablishedTimings::builder();
-    for (timing, timing_val) in map.iter() {
+    for (timing, timing_val) in map {
         let supported = timing_val
             .as_bool()
             .expect("Couldn't decode the timing value");
@@ -1227,29 +1275,42 @@ fn decode_data_string(desc: &Value) -> EdidDescriptorString {
     let string = desc["Data string"]
         .as_str()
         .expect("Couldn't decode Product Name")
-        .to_string();
+        .to_owned();
 
-    EdidDescriptorString::from_str_encoding_unchecked(&string)
+    EdidDescriptorString::from_str_with_policy(
+        &string,
+        EdidDescriptorStringEncodingPolicy::MapToLatin1,
+    )
+    .expect("Couldn't encode the string")
 }
 
 fn decode_descriptor_name(desc: &Value) -> EdidDescriptorString {
     let name = desc["Data string"]
         .as_str()
         .expect("Couldn't decode Product Name")
-        .to_string();
+        .to_owned();
 
-    EdidDescriptorString::from_str_encoding_unchecked(&name)
+    EdidDescriptorString::from_str_with_policy(
+        &name,
+        EdidDescriptorStringEncodingPolicy::MapToLatin1,
+    )
+    .expect("Couldn't encode the string")
 }
 
 fn decode_descriptor_serial(desc: &Value) -> EdidDescriptorString {
     let serial = desc["Data string"]
         .as_str()
         .expect("Couldn't decode Product Name")
-        .to_string();
+        .to_owned();
 
-    EdidDescriptorString::from_str_encoding_unchecked(&serial)
+    EdidDescriptorString::from_str_with_policy(
+        &serial,
+        EdidDescriptorStringEncodingPolicy::MapToLatin1,
+    )
+    .expect("Couldn't encode the string")
 }
 
+#[allow(clippy::cast_possible_truncation, clippy::unwrap_used)]
 fn decode_custom_descriptor(desc: &Value) -> EdidDescriptorCustom {
     let tag = desc["Tag"]
         .as_u64()
@@ -1258,7 +1319,7 @@ fn decode_custom_descriptor(desc: &Value) -> EdidDescriptorCustom {
     let data = desc["Blob"]
         .as_array()
         .expect("Couldn't decode Product Name")
-        .into_iter()
+        .iter()
         .map(|val| val.as_u64().expect("Couldn't decode blob") as u8)
         .collect();
 
@@ -1293,9 +1354,9 @@ fn decode_descriptors_release_3(descriptors: &Value) -> Vec<EdidR3Descriptor> {
             }
             "Dummy descriptor" => EdidR3Descriptor::Dummy,
             "Manufacturer Specified Display Descriptor" => {
-                EdidR3Descriptor::Custom(decode_custom_descriptor(desc).into())
+                EdidR3Descriptor::Custom(decode_custom_descriptor(desc))
             }
-            _ => panic!("Couldn't decode the descriptor's type: {}", desc_type),
+            _ => panic!("Couldn't decode the descriptor's type: {desc_type}"),
         };
 
         descs.push(desc);
@@ -1335,9 +1396,9 @@ fn decode_descriptors_release_4(descriptors: &Value) -> Vec<EdidR4Descriptor> {
                 EdidR4Descriptor::EstablishedTimings(decode_descriptor_established_timings(desc))
             }
             "Manufacturer Specified Display Descriptor" => {
-                EdidR4Descriptor::Custom(decode_custom_descriptor(desc).into())
+                EdidR4Descriptor::Custom(decode_custom_descriptor(desc))
             }
-            _ => panic!("Couldn't decode the descriptor's type: {}", desc_type),
+            _ => panic!("Couldn't decode the descriptor's type: {desc_type}"),
         };
 
         descs.push(desc);
@@ -1374,9 +1435,9 @@ fn decode_and_check_edid_release_3(json: &Value, expected: &[u8]) {
     let descriptors = &base["Descriptors"];
     let edid = edid.descriptors(decode_descriptors_release_3(descriptors));
 
-    let bytes = edid.build().into_bytes();
+    let bytes = edid.build().expect("Couldn't build the EDID").into_bytes();
 
-    assert!(edid_equals(&bytes, &expected));
+    assert!(edid_equals(&bytes, expected), "Generated EDID differs");
 }
 
 fn decode_and_check_edid_release_4(json: &Value, expected: &[u8]) {
@@ -1407,11 +1468,12 @@ fn decode_and_check_edid_release_4(json: &Value, expected: &[u8]) {
     let descriptors = &base["Descriptors"];
     let edid = edid.descriptors(decode_descriptors_release_4(descriptors));
 
-    let bytes = edid.build().into_bytes();
+    let bytes = edid.build().expect("Couldn't build the EDID").into_bytes();
 
-    assert!(edid_equals(&bytes, &expected));
+    assert!(edid_equals(&bytes, expected), "Generated EDID differs");
 }
 
+#[allow(clippy::todo, clippy::unwrap_used)]
 fn decode_and_check_edid(json: &Value, expected: &[u8]) {
     let version = json["Version"].as_str().unwrap();
 
@@ -1426,7 +1488,7 @@ fn compare_unordered_slots<const CHUNK: usize, const LEN: usize>(
     val: &[u8; LEN],
     expected: &[u8; LEN],
 ) -> bool {
-    assert!((LEN % CHUNK) == 0);
+    assert!(LEN % CHUNK == 0, "Slots must have the same size");
 
     let mut val_chunks: Vec<_> = val.chunks_exact(CHUNK).collect();
     val_chunks.sort();
@@ -1437,6 +1499,7 @@ fn compare_unordered_slots<const CHUNK: usize, const LEN: usize>(
     val_chunks == expected_chunks
 }
 
+#[allow(clippy::print_stdout, clippy::unwrap_used)]
 fn edid_equals(current: &[u8], expected: &[u8]) -> bool {
     let mut checksum_offset = [0; 2];
 
@@ -1457,7 +1520,9 @@ fn edid_equals(current: &[u8], expected: &[u8]) -> bool {
 
         // The lower bit of Stereo Viewing Support in a Detailed Timing (Bit 0 of Byte 17) can be
         // set either to 0 or 1. Most of the EDIDs in the wild will set it to 0, and that's what
-        // we do too but some set it to 1, so we need to consider both equivalents.
+        // we do too but some set it to 1, so we need to consider both equivalents. The JSON
+        // description doesn't tell us which one was used, so we can't pick
+        // EdidDetailedTimingStereo::NoneWithLowBitSet when needed.
         if (i > 0x36) && ((i - 0x36) % 18) == 17 {
             let diff = (current[i] ^ expected[i]) & 0x61;
 
@@ -1496,20 +1561,21 @@ fn edid_equals(current: &[u8], expected: &[u8]) -> bool {
     true
 }
 
+#[allow(clippy::unwrap_used)]
 fn test_edid(edid: &Path) {
     let output = Command::new("tests/tools/edid-chamelium/edid2json.py")
         .arg(edid)
         .output()
         .expect("Couldn't decode the EDID");
 
-    assert!(output.status.success());
+    assert!(output.status.success(), "edid2json.py failed");
 
     let mut input_file = File::open(edid).unwrap();
     let mut input_data: [u8; 0x80] = [0; 0x80];
     input_file.read_exact(&mut input_data).unwrap();
 
     let output_str =
-        std::str::from_utf8(&output.stdout).expect("Couldn't convert the output to UTF-8");
+        core::str::from_utf8(&output.stdout).expect("Couldn't convert the output to UTF-8");
 
     let json: Value = serde_json::from_str(output_str).expect("Couldn't parse the JSON output");
 
@@ -1518,10 +1584,10 @@ fn test_edid(edid: &Path) {
 
 #[rstest]
 fn test_edidtv(#[files("tests/edid-db/edid.tv/*.bin")] edid: PathBuf) {
-    test_edid(&edid)
+    test_edid(&edid);
 }
 
 #[rstest]
 fn test_linuxhw(#[files("tests/edid-db/linuxhw/*.bin")] edid: PathBuf) {
-    test_edid(&edid)
+    test_edid(&edid);
 }